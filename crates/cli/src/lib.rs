@@ -23,6 +23,28 @@ pub struct CommandLine {
     #[arg(long, value_name = "FILE")]
     pub iwad: Option<PathBuf>,
 
+    /// Load the specified PWAD files
+    #[arg(long, num_args = 1.., value_name = "FILES")]
+    pub file: Vec<PathBuf>,
+
+    /// Load the specified DeHackEd patch files
+    #[arg(long, num_args = 1.., value_name = "FILES")]
+    pub deh: Vec<PathBuf>,
+
+    /// Set the skill level, from 1 (I'm too young to die)
+    /// to 5 (Nightmare!)
+    #[arg(long, value_name = "SKILL", value_parser = clap::value_parser!(u8).range(1..=5))]
+    pub skill: Option<u8>,
+
+    /// Run without a window nor a display, e.g. on CI machines
+    #[arg(long)]
+    pub headless: bool,
+
+    /// Read the input from the given script instead of the keyboard,
+    /// only when running headless
+    #[arg(long, value_name = "FILE", requires = "headless")]
+    pub input_script: Option<PathBuf>,
+
     /// Load extra command line arguments from the given response file
     #[arg(long, num_args = 1.., value_name = "FILES")]
     pub response: Vec<PathBuf>,
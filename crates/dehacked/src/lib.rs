@@ -0,0 +1,50 @@
+use anyhow::{bail, Context};
+use bevy::prelude::*;
+use cli::CommandLine;
+use exit::macros::sys_fail;
+use std::fs;
+use wad::prelude::*;
+use wad::{IwadDirs, WadLoaded};
+
+pub use parser::DehPatchParser;
+pub use patch::*;
+
+mod parser;
+mod patch;
+
+/// Loads the DeHackEd patches given with `--deh`, then the `DEHACKED`
+/// lumps of the PWADs, into the [`DehPatch`] resource.
+#[derive(Default)]
+pub struct DehackedPlugin;
+
+impl Plugin for DehackedPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DehPatch>()
+            .add_systems(Update, load_patches.run_if(on_event::<WadLoaded>));
+    }
+}
+
+/// Mirrors `DEH_ParseCommandLine` and the loading of `DEHACKED` lumps.
+#[sys_fail]
+fn load_patches(cli: Res<CommandLine>, wad_file: Res<WadFile>, mut commands: Commands) {
+    let mut patch = DehPatch::default();
+
+    let dirs = IwadDirs::try_new()?;
+    for name in &cli.deh {
+        let Some(path) = dirs.find_wad(name) else {
+            bail!("Could not find DeHackEd patch {name:?}");
+        };
+        debug!("Loading DeHackEd patch: {path:?}");
+        let data = fs::read(&path).with_context(|| format!("Couldn't read {path:?}"))?;
+        let file_patch = DehPatchParser::parse(&data)
+            .with_context(|| format!("Couldn't load DeHackEd patch {path:?}"))?;
+        patch.extend(file_patch);
+    }
+
+    for lump in wad_file.dehacked_lumps() {
+        let lump_patch = DehPatchParser::parse(lump).context("Couldn't load DEHACKED lump")?;
+        patch.extend(lump_patch);
+    }
+
+    commands.insert_resource(patch);
+}
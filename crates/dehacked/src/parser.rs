@@ -0,0 +1,432 @@
+use crate::patch::{Cheat, DehPatch, FieldKind, NUM_AMMO, NUM_FRAMES, NUM_THINGS, NUM_WEAPONS};
+use anyhow::{bail, Result};
+use bevy::prelude::*;
+
+/// First line of every patch. The version of DeHackEd follows.
+const SIGNATURE: &str = "Patch File for DeHackEd v";
+
+/// Only patches for Doom 1.9, written by DeHackEd 3.0, are supported.
+const DOOM_VERSION: i32 = 19;
+const PATCH_FORMAT: i32 = 6;
+
+/// Block being parsed, with the index of the entry it changes.
+#[derive(Debug, Clone, Copy)]
+enum Section {
+    Thing(usize),
+    Frame(usize),
+    Pointer(usize),
+    Weapon(usize),
+    Ammo(usize),
+    Misc,
+    Cheat,
+    /// The lines following the strings of a `Text` block.
+    Text,
+    /// Lines of an unsupported block, which are skipped.
+    Skipped,
+}
+
+pub struct DehPatchParser;
+
+impl DehPatchParser {
+    /// Parse a patch, from a `.deh` file or a `DEHACKED` lump.
+    ///
+    /// Like vanilla, problems that don't prevent the rest of the patch from
+    /// being applied, e.g. an unknown field, are only warnings.
+    ///
+    /// Mirrors `DEH_ParseFile`.
+    pub fn parse(data: &[u8]) -> Result<DehPatch> {
+        // Patches are DOS text files. Every byte is kept as one char, so
+        // the lengths of the strings of Text blocks are counted in bytes.
+        let text: String = data
+            .iter()
+            .filter(|byte| **byte != b'\r')
+            .map(|byte| *byte as char)
+            .collect();
+        let mut reader = PatchReader::new(&text);
+
+        let Some((_, signature)) = reader.next_line() else {
+            bail!("DeHackEd patch is empty");
+        };
+        if !signature.starts_with(SIGNATURE) {
+            bail!("This is not a valid DeHackEd patch file");
+        }
+
+        let mut patch = DehPatch::default();
+        let mut section = None;
+        while let Some((line_num, line)) = reader.next_line() {
+            if line.trim().is_empty() {
+                // A blank line ends the current block.
+                section = None;
+                continue;
+            }
+            if line.starts_with('#') {
+                // Comment.
+                continue;
+            }
+            match section {
+                None => {
+                    section = Self::parse_section_start(&mut patch, &mut reader, &line, line_num)?;
+                }
+                Some(section) => {
+                    Self::parse_assignment(&mut patch, section, &line, line_num)?;
+                }
+            }
+        }
+
+        Ok(patch)
+    }
+
+    /// Parse the first line of a block, or one of the lines giving the
+    /// version of the patch.
+    fn parse_section_start(
+        patch: &mut DehPatch,
+        reader: &mut PatchReader,
+        line: &str,
+        line_num: usize,
+    ) -> Result<Option<Section>> {
+        if let Some((name, value)) = line.split_once('=') {
+            let (name, value) = (name.trim(), value.trim());
+            let expected = if name.eq_ignore_ascii_case("Doom version") {
+                DOOM_VERSION
+            } else if name.eq_ignore_ascii_case("Patch format") {
+                PATCH_FORMAT
+            } else {
+                warn!("DeHackEd patch line {line_num}: unknown setting '{name}'");
+                return Ok(None);
+            };
+            if value.parse() != Ok(expected) {
+                warn!("DeHackEd patch line {line_num}: {name} {value} is not supported, expected {expected}");
+            }
+            return Ok(None);
+        }
+
+        let mut words = line.split_whitespace();
+        let name = words.next().unwrap_or_default().to_ascii_lowercase();
+        let number = words.next().and_then(|word| word.parse::<usize>().ok());
+        let in_table = |len: usize, first: usize| {
+            let index = number
+                .and_then(|number| number.checked_sub(first))
+                .filter(|index| *index < len);
+            if index.is_none() {
+                warn!("DeHackEd patch line {line_num}: invalid {name} number");
+            }
+            index
+        };
+
+        let section = match name.as_str() {
+            // Things are numbered from 1.
+            "thing" => in_table(NUM_THINGS, 1).map(Section::Thing),
+            "frame" => in_table(NUM_FRAMES, 0).map(Section::Frame),
+            "pointer" => {
+                // Pointers are identified by the frame they are used by,
+                // e.g. `Pointer 12 (Frame 34)`.
+                let frame = line
+                    .split_once('(')
+                    .and_then(|(_, frame)| frame.trim_end().strip_suffix(')'))
+                    .and_then(|frame| frame.split_whitespace().last())
+                    .and_then(|frame| frame.parse::<usize>().ok())
+                    .filter(|frame| *frame < NUM_FRAMES);
+                if frame.is_none() {
+                    warn!("DeHackEd patch line {line_num}: invalid pointer frame");
+                }
+                frame.map(Section::Pointer)
+            }
+            "weapon" => in_table(NUM_WEAPONS, 0).map(Section::Weapon),
+            "ammo" => in_table(NUM_AMMO, 0).map(Section::Ammo),
+            "misc" => Some(Section::Misc),
+            "cheat" => Some(Section::Cheat),
+            "text" => {
+                Self::parse_text(patch, reader, line, line_num)?;
+                Some(Section::Text)
+            }
+            _ => {
+                warn!("DeHackEd patch line {line_num}: unknown block '{line}'");
+                None
+            }
+        };
+        // The lines of invalid blocks are skipped.
+        Ok(Some(section.unwrap_or(Section::Skipped)))
+    }
+
+    /// Parse a `Text` block, e.g. `Text 4 4` followed by the original
+    /// string and its replacement, which may span several lines.
+    ///
+    /// Mirrors `DEH_TextStart`.
+    fn parse_text(
+        patch: &mut DehPatch,
+        reader: &mut PatchReader,
+        line: &str,
+        line_num: usize,
+    ) -> Result<()> {
+        let lengths: Vec<_> = line
+            .split_whitespace()
+            .skip(1)
+            .map(|length| length.parse::<usize>().ok())
+            .collect();
+        let [Some(from_len), Some(to_len)] = lengths[..] else {
+            bail!("DeHackEd patch line {line_num}: invalid Text block '{line}'");
+        };
+
+        let (Some(from), Some(to)) = (reader.read_chars(from_len), reader.read_chars(to_len))
+        else {
+            bail!("DeHackEd patch line {line_num}: the patch ends within the Text block");
+        };
+        let max_len = max_string_length(from_len);
+        if to_len > max_len {
+            bail!(
+                "DeHackEd patch line {line_num}: replacement text '{to}' is longer than \
+                the {max_len} characters doom.exe has room for"
+            );
+        }
+        patch.texts.insert(from, to);
+        Ok(())
+    }
+
+    /// Parse a `name = value` line within a block.
+    fn parse_assignment(
+        patch: &mut DehPatch,
+        section: Section,
+        line: &str,
+        line_num: usize,
+    ) -> Result<()> {
+        if matches!(section, Section::Text | Section::Skipped) {
+            return Ok(());
+        }
+        let Some((name, value)) = line.split_once('=') else {
+            warn!("DeHackEd patch line {line_num}: failed to parse assignment '{line}'");
+            return Ok(());
+        };
+        let (name, value) = (name.trim(), value.trim());
+
+        if let Section::Cheat = section {
+            return Self::parse_cheat(patch, name, value, line_num);
+        }
+
+        let Ok(value) = value.parse::<i32>() else {
+            warn!("DeHackEd patch line {line_num}: invalid number '{value}'");
+            return Ok(());
+        };
+        let field = match section {
+            Section::Thing(thing) => patch.things.entry(thing).or_default().field_mut(name),
+            Section::Frame(frame) => patch.frames.entry(frame).or_default().field_mut(name),
+            Section::Weapon(weapon) => patch.weapons.entry(weapon).or_default().field_mut(name),
+            Section::Ammo(ammo) => patch.ammo.entry(ammo).or_default().field_mut(name),
+            Section::Misc => patch.misc.field_mut(name),
+            Section::Pointer(frame) => {
+                if !name.eq_ignore_ascii_case("Codep Frame") {
+                    warn!("DeHackEd patch line {line_num}: unknown pointer field '{name}'");
+                } else if !FieldKind::Frame.is_valid(value) {
+                    warn!("DeHackEd patch line {line_num}: invalid frame {value}");
+                } else {
+                    patch.pointers.insert(frame, value as usize);
+                }
+                return Ok(());
+            }
+            Section::Cheat | Section::Text | Section::Skipped => unreachable!(),
+        };
+
+        match field {
+            Some((field, kind)) if kind.is_valid(value) => *field = Some(value),
+            Some(_) => warn!("DeHackEd patch line {line_num}: invalid value {value} for '{name}'"),
+            None => warn!("DeHackEd patch line {line_num}: unknown field '{name}'"),
+        }
+        Ok(())
+    }
+
+    /// Mirrors `DEH_CheatParseLine`.
+    fn parse_cheat(patch: &mut DehPatch, name: &str, value: &str, line_num: usize) -> Result<()> {
+        let Some(cheat) = Cheat::from_deh_name(name) else {
+            warn!("DeHackEd patch line {line_num}: unknown cheat '{name}'");
+            return Ok(());
+        };
+        // Old versions of DeHackEd ended sequences with 0xff.
+        let sequence: String = value.chars().take_while(|c| *c != '\u{ff}').collect();
+        if sequence.chars().count() > cheat.original().len() {
+            bail!(
+                "DeHackEd patch line {line_num}: cheat sequence '{sequence}' is longer than \
+                the original '{}'",
+                cheat.original()
+            );
+        }
+        patch.cheats.insert(cheat, sequence);
+        Ok(())
+    }
+}
+
+/// Longest string that can replace one of `len` characters. Strings of
+/// `doom.exe` start on 4 bytes boundaries, so the padding after them
+/// can be used too, less the nul terminator.
+///
+/// Mirrors `TXT_MaxStringLength`.
+fn max_string_length(len: usize) -> usize {
+    (len + 1).next_multiple_of(4) - 1
+}
+
+/// Reads a patch line by line, or char by char for the strings of
+/// `Text` blocks.
+struct PatchReader<'a> {
+    chars: std::str::Chars<'a>,
+    /// Number of lines read so far.
+    lines: usize,
+}
+
+impl<'a> PatchReader<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            chars: text.chars(),
+            lines: 0,
+        }
+    }
+
+    /// Read the rest of the current line, with its number.
+    fn next_line(&mut self) -> Option<(usize, String)> {
+        if self.chars.as_str().is_empty() {
+            return None;
+        }
+        let line_num = self.lines + 1;
+        let mut line = String::new();
+        for c in self.chars.by_ref() {
+            if c == '\n' {
+                self.lines += 1;
+                break;
+            }
+            line.push(c);
+        }
+        Some((line_num, line))
+    }
+
+    /// Read exactly `count` chars, including line breaks.
+    fn read_chars(&mut self, count: usize) -> Option<String> {
+        let text: String = self.chars.by_ref().take(count).collect();
+        self.lines += text.matches('\n').count();
+        (text.chars().count() == count).then_some(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(body: &str) -> Result<DehPatch> {
+        let patch =
+            format!("Patch File for DeHackEd v3.0\nDoom version = 19\nPatch format = 6\n\n{body}");
+        DehPatchParser::parse(patch.as_bytes())
+    }
+
+    #[test]
+    fn blocks_are_parsed() {
+        let patch = parse(
+            "# Tougher zombiemen.\n\
+            Thing 2 (Trooper)\n\
+            Hit points = 60\n\
+            bits = 4194310\n\
+            \n\
+            Frame 174\n\
+            Duration = 2\n\
+            Next frame = 175\n\
+            \n\
+            Pointer 64 (Frame 174)\n\
+            Codep Frame = 176\n\
+            \n\
+            Weapon 3\n\
+            Ammo type = 1\n\
+            \n\
+            Ammo 0\r\n\
+            Max ammo = 400\r\n\
+            \r\n\
+            Misc 0\n\
+            Initial Health = 150\n\
+            Monsters Infight = 202\n\
+            \n\
+            Cheat 0\n\
+            God mode = iddqx\n\
+            \n\
+            Text 4 4\n\
+            BAR1BEXP\n\
+            \n\
+            Text 9 10\n\
+            E1M1: HanHANGAR\n\
+            ONE\n\
+            \n",
+        )
+        .unwrap();
+
+        let zombieman = &patch.things[&1];
+        assert_eq!(zombieman.spawn_health, Some(60));
+        assert_eq!(zombieman.flags, Some(4194310));
+        assert_eq!(zombieman.speed, None);
+        assert_eq!(patch.frames[&174].tics, Some(2));
+        assert_eq!(patch.frames[&174].next_state, Some(175));
+        assert_eq!(patch.pointers[&174], 176);
+        assert_eq!(patch.weapons[&3].ammo, Some(1));
+        assert_eq!(patch.ammo[&0].max_ammo, Some(400));
+        assert_eq!(patch.misc.initial_health, Some(150));
+        assert_eq!(patch.misc.monsters_infight, Some(202));
+        assert_eq!(patch.cheat(Cheat::God), "iddqx");
+        assert_eq!(patch.cheat(Cheat::Ammo), "idfa");
+        assert_eq!(patch.text("BAR1"), "BEXP");
+        // Strings may span lines.
+        assert_eq!(patch.text("E1M1: Han"), "HANGAR\nONE");
+        assert_eq!(patch.text("BAR2"), "BAR2");
+    }
+
+    #[test]
+    fn invalid_entries_are_skipped() {
+        let patch = parse(
+            "Thing 0 (Nothing)\n\
+            Hit points = 10\n\
+            \n\
+            Thing 138 (Too far)\n\
+            Hit points = 10\n\
+            \n\
+            Frame 10\n\
+            Next frame = 967\n\
+            Sprite number = 137\n\
+            Unknown field = 3\n\
+            Duration = fast\n\
+            \n\
+            Weapon 0\n\
+            Ammo type = 4\n\
+            \n\
+            Weapon 7\n\
+            Ammo type = 5\n",
+        )
+        .unwrap();
+
+        assert!(patch.things.is_empty());
+        assert_eq!(patch.frames[&10].next_state, None);
+        assert_eq!(patch.frames[&10].sprite, Some(137));
+        assert_eq!(patch.frames[&10].tics, None);
+        assert_eq!(patch.weapons[&0].ammo, None);
+        assert_eq!(patch.weapons[&7].ammo, Some(5));
+    }
+
+    #[test]
+    fn vanilla_limits_are_errors() {
+        assert!(DehPatchParser::parse(b"Thing 1\nHit points = 10\n").is_err());
+        // "BAR1" is followed by 3 bytes of padding and a nul.
+        assert!(parse("Text 4 7\nBAR1TOOLONG\n").is_ok());
+        assert!(parse("Text 4 8\nBAR1TOOLONGG\n").is_err());
+        // The block ends before the end of the replacement.
+        assert!(parse("Text 4 4\nBAR1BE").is_err());
+        let mut terminated = b"Patch File for DeHackEd v3.0\n\nCheat 0\nAmmo = idfa".to_vec();
+        terminated.extend([0xff, b'\n']);
+        assert_eq!(
+            DehPatchParser::parse(&terminated)
+                .unwrap()
+                .cheat(Cheat::Ammo),
+            "idfa"
+        );
+        assert!(parse("Cheat 0\nAmmo = idfaa\n").is_err());
+    }
+
+    #[test]
+    fn later_patches_win() {
+        let mut patch = parse("Thing 1\nHit points = 200\nSpeed = 3\n").unwrap();
+        patch.extend(parse("Thing 1\nSpeed = 5\n\nText 4 4\nPLAYHERO\n").unwrap());
+
+        assert_eq!(patch.things[&0].spawn_health, Some(200));
+        assert_eq!(patch.things[&0].speed, Some(5));
+        assert_eq!(patch.text("PLAY"), "HERO");
+    }
+}
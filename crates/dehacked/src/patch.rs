@@ -0,0 +1,329 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+/// Number of things in the `mobjinfo` table of `doom.exe`.
+pub const NUM_THINGS: usize = 137;
+/// Number of frames in the `states` table of `doom.exe`.
+pub const NUM_FRAMES: usize = 967;
+/// Number of sprites in the `sprnames` table of `doom.exe`.
+pub const NUM_SPRITES: usize = 138;
+/// Number of weapons in the `weaponinfo` table of `doom.exe`.
+pub const NUM_WEAPONS: usize = 9;
+/// Number of ammo types, without the "no ammo" type.
+pub const NUM_AMMO: usize = 4;
+/// Ammo type of the weapons that don't use any, i.e. the fist and
+/// the chainsaw.
+pub const NO_AMMO: i32 = 5;
+
+/// Every change made by the DeHackEd patches that were loaded, in the
+/// order they were loaded. The tables of the game look up their entries
+/// here when they are built.
+///
+/// Things, frames, weapons and ammo types are indexed like the tables of
+/// the game, starting from 0. DeHackEd numbers things from 1, so its
+/// `Thing 1` is the player, at index 0.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct DehPatch {
+    pub things: HashMap<usize, ThingPatch>,
+    pub frames: HashMap<usize, FramePatch>,
+    /// For each frame, the frame whose original action it now calls.
+    pub pointers: HashMap<usize, usize>,
+    pub weapons: HashMap<usize, WeaponPatch>,
+    pub ammo: HashMap<usize, AmmoPatch>,
+    pub misc: MiscPatch,
+    /// Strings replaced by `Text` blocks, including sprite
+    /// and music names, by original string.
+    pub texts: HashMap<String, String>,
+    pub cheats: HashMap<Cheat, String>,
+}
+
+impl DehPatch {
+    /// Apply the changes of `other` on top of the ones of this patch.
+    pub fn extend(&mut self, other: DehPatch) {
+        merge_blocks(&mut self.things, other.things, ThingPatch::merge);
+        merge_blocks(&mut self.frames, other.frames, FramePatch::merge);
+        merge_blocks(&mut self.weapons, other.weapons, WeaponPatch::merge);
+        merge_blocks(&mut self.ammo, other.ammo, AmmoPatch::merge);
+        self.misc.merge(&other.misc);
+        self.pointers.extend(other.pointers);
+        self.texts.extend(other.texts);
+        self.cheats.extend(other.cheats);
+    }
+
+    /// Get the replacement of `original`, or `original` itself when
+    /// no patch replaces it.
+    ///
+    /// Mirrors `DEH_String`.
+    pub fn text<'a>(&'a self, original: &'a str) -> &'a str {
+        self.texts.get(original).map_or(original, String::as_str)
+    }
+
+    /// Get the sequence typed to activate `cheat`.
+    pub fn cheat(&self, cheat: Cheat) -> &str {
+        self.cheats
+            .get(&cheat)
+            .map_or(cheat.original(), String::as_str)
+    }
+}
+
+fn merge_blocks<T>(blocks: &mut HashMap<usize, T>, other: HashMap<usize, T>, merge: fn(&mut T, &T))
+where
+    T: Default,
+{
+    for (index, other_block) in other {
+        merge(blocks.entry(index).or_default(), &other_block);
+    }
+}
+
+/// What a field of a block holds, to check it against the limits of
+/// the tables of `doom.exe`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum FieldKind {
+    Value,
+    Frame,
+    Sprite,
+    Ammo,
+}
+
+impl FieldKind {
+    pub(crate) fn is_valid(self, value: i32) -> bool {
+        let in_table = |len: usize| usize::try_from(value).is_ok_and(|value| value < len);
+        match self {
+            FieldKind::Value => true,
+            FieldKind::Frame => in_table(NUM_FRAMES),
+            FieldKind::Sprite => in_table(NUM_SPRITES),
+            FieldKind::Ammo => in_table(NUM_AMMO) || value == NO_AMMO,
+        }
+    }
+}
+
+/// Declares the block of a patch changing an entry of a table. Every
+/// field is optional, and only set when the patch assigns it.
+macro_rules! deh_block {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $deh_name:literal => $kind:ident,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+        pub struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: Option<i32>,
+            )*
+        }
+
+        impl $name {
+            /// Get the field called `deh_name` in patches, ignoring case.
+            pub(crate) fn field_mut(
+                &mut self,
+                deh_name: &str,
+            ) -> Option<(&mut Option<i32>, FieldKind)> {
+                $(
+                    if deh_name.eq_ignore_ascii_case($deh_name) {
+                        return Some((&mut self.$field, FieldKind::$kind));
+                    }
+                )*
+                None
+            }
+
+            fn merge(&mut self, other: &Self) {
+                $(
+                    if other.$field.is_some() {
+                        self.$field = other.$field;
+                    }
+                )*
+            }
+        }
+    };
+}
+
+deh_block! {
+    /// Changes to an entry of `mobjinfo`. Speeds and sizes are fixed
+    /// point numbers, with 16 bits of fraction.
+    pub struct ThingPatch {
+        doomed_num: "ID #" => Value,
+        spawn_state: "Initial frame" => Frame,
+        spawn_health: "Hit points" => Value,
+        see_state: "First moving frame" => Frame,
+        see_sound: "Alert sound" => Value,
+        reaction_time: "Reaction time" => Value,
+        attack_sound: "Attack sound" => Value,
+        pain_state: "Injury frame" => Frame,
+        pain_chance: "Pain chance" => Value,
+        pain_sound: "Pain sound" => Value,
+        melee_state: "Close attack frame" => Frame,
+        missile_state: "Far attack frame" => Frame,
+        death_state: "Death frame" => Frame,
+        xdeath_state: "Exploding frame" => Frame,
+        death_sound: "Death sound" => Value,
+        speed: "Speed" => Value,
+        radius: "Width" => Value,
+        height: "Height" => Value,
+        mass: "Mass" => Value,
+        damage: "Missile damage" => Value,
+        active_sound: "Action sound" => Value,
+        flags: "Bits" => Value,
+        raise_state: "Respawn frame" => Frame,
+    }
+}
+
+deh_block! {
+    /// Changes to an entry of `states`.
+    pub struct FramePatch {
+        sprite: "Sprite number" => Sprite,
+        /// Frame of the sprite, with the full brightness bit.
+        frame: "Sprite subnumber" => Value,
+        tics: "Duration" => Value,
+        next_state: "Next frame" => Frame,
+        misc1: "Unknown 1" => Value,
+        misc2: "Unknown 2" => Value,
+    }
+}
+
+deh_block! {
+    /// Changes to an entry of `weaponinfo`. DeHackEd swapped the
+    /// names of the raise and lower frames.
+    pub struct WeaponPatch {
+        ammo: "Ammo type" => Ammo,
+        up_state: "Deselect frame" => Frame,
+        down_state: "Select frame" => Frame,
+        ready_state: "Bobbing frame" => Frame,
+        attack_state: "Shooting frame" => Frame,
+        flash_state: "Firing frame" => Frame,
+    }
+}
+
+deh_block! {
+    /// Changes to the maximum and pick-up amounts of an ammo type.
+    pub struct AmmoPatch {
+        max_ammo: "Max ammo" => Value,
+        clip_ammo: "Per ammo" => Value,
+    }
+}
+
+deh_block! {
+    /// Changes to the constants of the game rules.
+    pub struct MiscPatch {
+        initial_health: "Initial Health" => Value,
+        initial_bullets: "Initial Bullets" => Value,
+        max_health: "Max Health" => Value,
+        max_armor: "Max Armor" => Value,
+        green_armor_class: "Green Armor Class" => Value,
+        blue_armor_class: "Blue Armor Class" => Value,
+        max_soulsphere: "Max Soulsphere" => Value,
+        soulsphere_health: "Soulsphere Health" => Value,
+        megasphere_health: "Megasphere Health" => Value,
+        god_mode_health: "God Mode Health" => Value,
+        idfa_armor: "IDFA Armor" => Value,
+        idfa_armor_class: "IDFA Armor Class" => Value,
+        idkfa_armor: "IDKFA Armor" => Value,
+        idkfa_armor_class: "IDKFA Armor Class" => Value,
+        bfg_cells_per_shot: "BFG Cells/Shot" => Value,
+        /// 202 lets monsters of the same species fight each other,
+        /// 221 doesn't.
+        monsters_infight: "Monsters Infight" => Value,
+    }
+}
+
+/// The cheats whose sequence can be changed by a patch.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Cheat {
+    ChangeMusic,
+    Chainsaw,
+    God,
+    AmmoAndKeys,
+    Ammo,
+    NoClipping,
+    NoClipping2,
+    Invincibility,
+    Berserk,
+    Invisibility,
+    RadiationSuit,
+    AutoMap,
+    LiteAmp,
+    Behold,
+    LevelWarp,
+    PlayerPosition,
+    MapCheat,
+}
+
+impl Cheat {
+    const ALL: [Cheat; 17] = [
+        Cheat::ChangeMusic,
+        Cheat::Chainsaw,
+        Cheat::God,
+        Cheat::AmmoAndKeys,
+        Cheat::Ammo,
+        Cheat::NoClipping,
+        Cheat::NoClipping2,
+        Cheat::Invincibility,
+        Cheat::Berserk,
+        Cheat::Invisibility,
+        Cheat::RadiationSuit,
+        Cheat::AutoMap,
+        Cheat::LiteAmp,
+        Cheat::Behold,
+        Cheat::LevelWarp,
+        Cheat::PlayerPosition,
+        Cheat::MapCheat,
+    ];
+
+    /// Get the cheat called `deh_name` in patches, ignoring case.
+    pub(crate) fn from_deh_name(deh_name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|cheat| cheat.deh_name().eq_ignore_ascii_case(deh_name))
+    }
+
+    fn deh_name(self) -> &'static str {
+        match self {
+            Cheat::ChangeMusic => "Change music",
+            Cheat::Chainsaw => "Chainsaw",
+            Cheat::God => "God mode",
+            Cheat::AmmoAndKeys => "Ammo & Keys",
+            Cheat::Ammo => "Ammo",
+            Cheat::NoClipping => "No Clipping 1",
+            Cheat::NoClipping2 => "No Clipping 2",
+            Cheat::Invincibility => "Invincibility",
+            Cheat::Berserk => "Berserk",
+            Cheat::Invisibility => "Invisibility",
+            Cheat::RadiationSuit => "Radiation Suit",
+            Cheat::AutoMap => "Auto-map",
+            Cheat::LiteAmp => "Lite-Amp Goggles",
+            Cheat::Behold => "BEHOLD menu",
+            Cheat::LevelWarp => "Level Warp",
+            Cheat::PlayerPosition => "Player Position",
+            Cheat::MapCheat => "Map cheat",
+        }
+    }
+
+    /// Sequence of the cheat in `doom.exe`. The music and level
+    /// cheats are followed by two digits.
+    pub fn original(self) -> &'static str {
+        match self {
+            Cheat::ChangeMusic => "idmus",
+            Cheat::Chainsaw => "idchoppers",
+            Cheat::God => "iddqd",
+            Cheat::AmmoAndKeys => "idkfa",
+            Cheat::Ammo => "idfa",
+            Cheat::NoClipping => "idspispopd",
+            Cheat::NoClipping2 => "idclip",
+            Cheat::Invincibility => "idbeholdv",
+            Cheat::Berserk => "idbeholds",
+            Cheat::Invisibility => "idbeholdi",
+            Cheat::RadiationSuit => "idbeholdr",
+            Cheat::AutoMap => "idbeholda",
+            Cheat::LiteAmp => "idbeholdl",
+            Cheat::Behold => "idbehold",
+            Cheat::LevelWarp => "idclev",
+            Cheat::PlayerPosition => "idmypos",
+            Cheat::MapCheat => "iddt",
+        }
+    }
+}
@@ -19,3 +19,4 @@ impl_in_state!(exiting, GameState::Exiting);
 impl_in_state!(intro, PlayingState::Intro);
 impl_in_state!(level, PlayingState::Level);
 impl_in_state!(screen_melt, PlayingState::ScreenMelt);
+impl_in_state!(intermission, PlayingState::Intermission);
@@ -36,4 +36,6 @@ pub enum PlayingState {
     Intro,
     Level,
     ScreenMelt,
+    /// The stats screen shown between two levels.
+    Intermission,
 }
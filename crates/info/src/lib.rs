@@ -0,0 +1,372 @@
+use bevy::prelude::*;
+use dehacked::{DehPatch, MiscPatch};
+
+pub use mobj_info::{MobjFlags, MobjInfo, MobjType, FRACUNIT};
+pub use sounds::Sfx;
+pub use sprites::SpriteNum;
+pub use states::{Action, State, StateNum, FF_FRAMEMASK, FF_FULLBRIGHT};
+pub use weapons::{AmmoType, WeaponInfo, WeaponType};
+
+/// Declares an enum numbering the entries of a table of `info.c`, and the
+/// table itself, in the same order.
+macro_rules! table {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident => $table_vis:vis const $table:ident: [$entry:ty] {
+            $($variant:ident => $value:expr,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+        $vis enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            const ALL: &[$name] = &[$($name::$variant,)*];
+
+            /// Get the entry numbered `index` in the table.
+            pub fn from_index(index: usize) -> Option<Self> {
+                Self::ALL.get(index).copied()
+            }
+
+            /// Number of the entry in the table.
+            pub fn index(self) -> usize {
+                self as usize
+            }
+        }
+
+        $table_vis const $table: &[$entry] = &[$($value,)*];
+    };
+}
+
+mod mobj_info;
+mod sounds;
+mod sprites;
+mod states;
+mod weapons;
+
+/// Holds the tables of things, states, sprites and sounds.
+#[derive(Default)]
+pub struct InfoPlugin;
+
+impl Plugin for InfoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Info>().add_systems(
+            PostUpdate,
+            apply_patch.run_if(resource_exists_and_changed::<DehPatch>),
+        );
+    }
+}
+
+/// Rebuilds the tables when the DeHackEd patches are loaded.
+fn apply_patch(patch: Res<DehPatch>, mut info: ResMut<Info>) {
+    *info = Info::new(&patch);
+}
+
+/// The tables of `info.c`, with the changes of the DeHackEd patches.
+#[derive(Resource, Debug)]
+pub struct Info {
+    states: Vec<State>,
+    mobj_info: Vec<MobjInfo>,
+    sprite_names: Vec<String>,
+    sound_names: Vec<String>,
+    weapon_info: Vec<WeaponInfo>,
+    max_ammo: Vec<i32>,
+    clip_ammo: Vec<i32>,
+    /// Mirrors `deh_initial_health`.
+    initial_health: i32,
+    /// Mirrors `deh_initial_bullets`.
+    initial_bullets: i32,
+    /// Mirrors `deh_bfg_cells_per_shot`.
+    bfg_cells_per_shot: i32,
+}
+
+impl Default for Info {
+    fn default() -> Self {
+        Info::new(&DehPatch::default())
+    }
+}
+
+impl Info {
+    /// Builds the tables of `doom.exe` and applies `patch` to them.
+    pub fn new(patch: &DehPatch) -> Self {
+        let mut states = states::STATES.to_vec();
+        for (&index, frame) in &patch.frames {
+            let Some(state) = states.get_mut(index) else {
+                continue;
+            };
+            patch_field(&mut state.sprite, frame.sprite, sprite_num);
+            patch_field(&mut state.frame, frame.frame, |value| Some(value as u32));
+            patch_field(&mut state.tics, frame.tics, Some);
+            patch_field(&mut state.next_state, frame.next_state, state_num);
+            patch_field(&mut state.misc1, frame.misc1, Some);
+            patch_field(&mut state.misc2, frame.misc2, Some);
+        }
+        for (&index, &source) in &patch.pointers {
+            let Some(source) = states::STATES.get(source) else {
+                continue;
+            };
+            if let Some(state) = states.get_mut(index) {
+                state.action = source.action;
+            }
+        }
+
+        let mut mobj_info = mobj_info::MOBJ_INFO.to_vec();
+        for (&index, thing) in &patch.things {
+            let Some(info) = mobj_info.get_mut(index) else {
+                continue;
+            };
+            patch_field(&mut info.doomed_num, thing.doomed_num, Some);
+            patch_field(&mut info.spawn_state, thing.spawn_state, state_num);
+            patch_field(&mut info.spawn_health, thing.spawn_health, Some);
+            patch_field(&mut info.see_state, thing.see_state, state_num);
+            patch_field(&mut info.see_sound, thing.see_sound, sfx);
+            patch_field(&mut info.reaction_time, thing.reaction_time, Some);
+            patch_field(&mut info.attack_sound, thing.attack_sound, sfx);
+            patch_field(&mut info.pain_state, thing.pain_state, state_num);
+            patch_field(&mut info.pain_chance, thing.pain_chance, Some);
+            patch_field(&mut info.pain_sound, thing.pain_sound, sfx);
+            patch_field(&mut info.melee_state, thing.melee_state, state_num);
+            patch_field(&mut info.missile_state, thing.missile_state, state_num);
+            patch_field(&mut info.death_state, thing.death_state, state_num);
+            patch_field(&mut info.xdeath_state, thing.xdeath_state, state_num);
+            patch_field(&mut info.death_sound, thing.death_sound, sfx);
+            patch_field(&mut info.speed, thing.speed, Some);
+            patch_field(&mut info.radius, thing.radius, Some);
+            patch_field(&mut info.height, thing.height, Some);
+            patch_field(&mut info.mass, thing.mass, Some);
+            patch_field(&mut info.damage, thing.damage, Some);
+            patch_field(&mut info.active_sound, thing.active_sound, sfx);
+            patch_field(&mut info.flags, thing.flags, |value| {
+                Some(MobjFlags::from_bits_retain(value as u32))
+            });
+            patch_field(&mut info.raise_state, thing.raise_state, state_num);
+        }
+
+        let sprite_names = sprites::SPRITE_NAMES
+            .iter()
+            .map(|&name| patch.text(name).to_owned())
+            .collect();
+
+        let sound_names = sounds::SFX_NAMES
+            .iter()
+            .map(|&name| patch.text(name).to_owned())
+            .collect();
+
+        let mut weapon_info = weapons::WEAPON_INFO.to_vec();
+        for (&index, weapon) in &patch.weapons {
+            let Some(info) = weapon_info.get_mut(index) else {
+                continue;
+            };
+            patch_field(&mut info.ammo, weapon.ammo, ammo_type);
+            patch_field(&mut info.up_state, weapon.up_state, state_num);
+            patch_field(&mut info.down_state, weapon.down_state, state_num);
+            patch_field(&mut info.ready_state, weapon.ready_state, state_num);
+            patch_field(&mut info.attack_state, weapon.attack_state, state_num);
+            patch_field(&mut info.flash_state, weapon.flash_state, state_num);
+        }
+
+        warn_unapplied_misc(&patch.misc);
+
+        let mut max_ammo = weapons::MAX_AMMO.to_vec();
+        let mut clip_ammo = weapons::CLIP_AMMO.to_vec();
+        for (&index, ammo) in &patch.ammo {
+            if let Some(max) = max_ammo.get_mut(index) {
+                patch_field(max, ammo.max_ammo, Some);
+            }
+            if let Some(clip) = clip_ammo.get_mut(index) {
+                patch_field(clip, ammo.clip_ammo, Some);
+            }
+        }
+
+        Info {
+            states,
+            mobj_info,
+            sprite_names,
+            sound_names,
+            weapon_info,
+            max_ammo,
+            clip_ammo,
+            initial_health: patch.misc.initial_health.unwrap_or(100),
+            initial_bullets: patch.misc.initial_bullets.unwrap_or(50),
+            bfg_cells_per_shot: patch.misc.bfg_cells_per_shot.unwrap_or(40),
+        }
+    }
+
+    pub fn state(&self, state: StateNum) -> &State {
+        &self.states[state.index()]
+    }
+
+    pub fn mobj_info(&self, mobj_type: MobjType) -> &MobjInfo {
+        &self.mobj_info[mobj_type.index()]
+    }
+
+    /// Name of the lumps of the sprite, without the frame and rotation.
+    pub fn sprite_name(&self, sprite: SpriteNum) -> &str {
+        &self.sprite_names[sprite.index()]
+    }
+
+    /// Name of the lump of the sound, without the `DS` prefix.
+    pub fn sound_name(&self, sfx: Sfx) -> &str {
+        &self.sound_names[sfx.index()]
+    }
+
+    pub fn weapon_info(&self, weapon: WeaponType) -> &WeaponInfo {
+        &self.weapon_info[weapon.index()]
+    }
+
+    /// Most ammo of `ammo` the player can carry, without a backpack.
+    pub fn max_ammo(&self, ammo: AmmoType) -> i32 {
+        self.max_ammo[ammo.index()]
+    }
+
+    /// Ammo of `ammo` given by a clip.
+    pub fn clip_ammo(&self, ammo: AmmoType) -> i32 {
+        self.clip_ammo[ammo.index()]
+    }
+
+    /// Health the player starts with.
+    pub fn initial_health(&self) -> i32 {
+        self.initial_health
+    }
+
+    /// Bullets the player starts with.
+    pub fn initial_bullets(&self) -> i32 {
+        self.initial_bullets
+    }
+
+    /// Cells used by a shot of the BFG.
+    pub fn bfg_cells_per_shot(&self) -> i32 {
+        self.bfg_cells_per_shot
+    }
+
+    /// Find the kind of thing placed in the maps with number `doomed_num`.
+    pub fn find_doomed_num(&self, doomed_num: i32) -> Option<MobjType> {
+        let index = self
+            .mobj_info
+            .iter()
+            .position(|info| info.doomed_num == doomed_num)?;
+        MobjType::from_index(index)
+    }
+}
+
+/// Set `field` to the value assigned by a patch, unless it
+/// isn't valid for the field.
+fn patch_field<T>(field: &mut T, value: Option<i32>, convert: impl FnOnce(i32) -> Option<T>) {
+    if let Some(value) = value.and_then(convert) {
+        *field = value;
+    }
+}
+
+/// Warn about the values of the `Misc` block used by the parts of the
+/// game that don't exist yet, i.e. armor, pick-ups, cheats and monster
+/// fights, as they have no effect.
+fn warn_unapplied_misc(misc: &MiscPatch) {
+    let unapplied = [
+        ("Max Health", misc.max_health),
+        ("Max Armor", misc.max_armor),
+        ("Green Armor Class", misc.green_armor_class),
+        ("Blue Armor Class", misc.blue_armor_class),
+        ("Max Soulsphere", misc.max_soulsphere),
+        ("Soulsphere Health", misc.soulsphere_health),
+        ("Megasphere Health", misc.megasphere_health),
+        ("God Mode Health", misc.god_mode_health),
+        ("IDFA Armor", misc.idfa_armor),
+        ("IDFA Armor Class", misc.idfa_armor_class),
+        ("IDKFA Armor", misc.idkfa_armor),
+        ("IDKFA Armor Class", misc.idkfa_armor_class),
+        ("Monsters Infight", misc.monsters_infight),
+    ];
+    for (name, value) in unapplied {
+        if value.is_some() {
+            warn!("DeHackEd patch sets '{name}', which isn't applied yet");
+        }
+    }
+}
+
+fn state_num(value: i32) -> Option<StateNum> {
+    StateNum::from_index(usize::try_from(value).ok()?)
+}
+
+fn sprite_num(value: i32) -> Option<SpriteNum> {
+    SpriteNum::from_index(usize::try_from(value).ok()?)
+}
+
+fn sfx(value: i32) -> Option<Sfx> {
+    Sfx::from_index(usize::try_from(value).ok()?)
+}
+
+/// Ammo type of a weapon, where [`dehacked::NO_AMMO`] is valid too.
+fn ammo_type(value: i32) -> Option<Option<AmmoType>> {
+    if value == dehacked::NO_AMMO {
+        return Some(None);
+    }
+    AmmoType::from_index(usize::try_from(value).ok()?).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dehacked::DehPatchParser;
+
+    #[test]
+    fn tables_match_doom_exe() {
+        assert_eq!(states::STATES.len(), dehacked::NUM_FRAMES);
+        assert_eq!(mobj_info::MOBJ_INFO.len(), dehacked::NUM_THINGS);
+        assert_eq!(sprites::SPRITE_NAMES.len(), dehacked::NUM_SPRITES);
+        assert_eq!(weapons::WEAPON_INFO.len(), dehacked::NUM_WEAPONS);
+        assert_eq!(weapons::MAX_AMMO.len(), dehacked::NUM_AMMO);
+        assert_eq!(weapons::CLIP_AMMO.len(), dehacked::NUM_AMMO);
+    }
+
+    #[test]
+    fn patches_are_applied() {
+        let patch = DehPatchParser::parse(
+            b"Patch File for DeHackEd v3.0\n\
+            Doom version = 19\n\
+            Patch format = 6\n\
+            \n\
+            Thing 31 (Barrel)\n\
+            ID # = 9999\n\
+            Hit points = 5\n\
+            \n\
+            Frame 806\n\
+            Duration = 2\n\
+            \n\
+            Pointer 0 (Frame 807)\n\
+            Codep Frame = 2\n\
+            \n\
+            Weapon 1 (Pistol)\n\
+            Ammo type = 5\n\
+            \n\
+            Ammo 1 (Shells)\n\
+            Max ammo = 80\n\
+            \n\
+            Misc 0\n\
+            Initial Health = 150\n\
+            \n\
+            Text 4 4\n\
+            BAR1BAL1\n",
+        )
+        .unwrap();
+        let info = Info::new(&patch);
+
+        let barrel = info.mobj_info(MobjType::MT_BARREL);
+        assert_eq!(barrel.doomed_num, 9999);
+        assert_eq!(barrel.spawn_health, 5);
+        assert_eq!(barrel.radius, 10 * FRACUNIT);
+        assert_eq!(info.state(StateNum::S_BAR1).tics, 2);
+        assert_eq!(
+            info.state(StateNum::S_BAR2).action,
+            Some(Action::A_WeaponReady)
+        );
+        assert_eq!(info.sprite_name(SpriteNum::SPR_BAR1), "BAL1");
+        assert_eq!(info.find_doomed_num(9999), Some(MobjType::MT_BARREL));
+        assert_eq!(info.weapon_info(WeaponType::wp_pistol).ammo, None);
+        assert_eq!(info.max_ammo(AmmoType::am_shell), 80);
+        assert_eq!(info.clip_ammo(AmmoType::am_shell), 4);
+        assert_eq!(info.initial_health(), 150);
+    }
+}
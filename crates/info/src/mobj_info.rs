@@ -0,0 +1,1438 @@
+use crate::sounds::Sfx::{self, *};
+use crate::states::StateNum::{self, *};
+use bitflags::bitflags;
+
+/// 1.0 in the fixed point numbers of the tables, with 16 bits of fraction.
+pub const FRACUNIT: i32 = 1 << 16;
+
+bitflags! {
+    /// Properties of a thing, i.e. the `MF_*` flags.
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+    pub struct MobjFlags: u32 {
+        /// Can be picked up.
+        const SPECIAL = 0x1;
+        /// Blocks other things.
+        const SOLID = 0x2;
+        /// Can be hit and damaged.
+        const SHOOTABLE = 0x4;
+        /// Not linked into the sectors, so it's never drawn.
+        const NOSECTOR = 0x8;
+        /// Not linked into the blockmap, so it's never hit.
+        const NOBLOCKMAP = 0x10;
+        /// Deaf monster, only woken up by seeing the player.
+        const AMBUSH = 0x20;
+        const JUSTHIT = 0x40;
+        const JUSTATTACKED = 0x80;
+        /// Spawned hanging from the ceiling instead of on the floor.
+        const SPAWNCEILING = 0x100;
+        const NOGRAVITY = 0x200;
+        /// Can walk off ledges.
+        const DROPOFF = 0x400;
+        /// Picks up items.
+        const PICKUP = 0x800;
+        const NOCLIP = 0x1000;
+        const SLIDE = 0x2000;
+        const FLOAT = 0x4000;
+        const TELEPORT = 0x8000;
+        const MISSILE = 0x10000;
+        /// Dropped by a dead monster, rather than placed in the map.
+        const DROPPED = 0x20000;
+        /// Drawn with the fuzz effect.
+        const SHADOW = 0x40000;
+        /// Puffs instead of bleeding when hit.
+        const NOBLOOD = 0x80000;
+        const CORPSE = 0x100000;
+        const INFLOAT = 0x200000;
+        /// Counted in the kill percentage.
+        const COUNTKILL = 0x400000;
+        /// Counted in the item percentage.
+        const COUNTITEM = 0x800000;
+        const SKULLFLY = 0x1000000;
+        /// Not spawned in deathmatch, e.g. the keys.
+        const NOTDMATCH = 0x2000000;
+        /// Player colors, in multiplayer.
+        const TRANSLATION = 0xc000000;
+    }
+}
+
+/// Combines flags in a constant, where `|` can't be used.
+macro_rules! flags {
+    ($($flag:ident)|+) => {
+        MobjFlags::from_bits_retain(0 $(| MobjFlags::$flag.bits())+)
+    };
+}
+
+/// Properties of a kind of thing, i.e. an entry of `mobjinfo`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MobjInfo {
+    /// Number identifying the thing in the maps, -1 for the
+    /// ones only spawned by the game.
+    pub doomed_num: i32,
+    pub spawn_state: StateNum,
+    pub spawn_health: i32,
+    pub see_state: StateNum,
+    pub see_sound: Sfx,
+    pub reaction_time: i32,
+    pub attack_sound: Sfx,
+    pub pain_state: StateNum,
+    /// Chance out of 256 to enter the pain state when hurt.
+    pub pain_chance: i32,
+    pub pain_sound: Sfx,
+    pub melee_state: StateNum,
+    pub missile_state: StateNum,
+    pub death_state: StateNum,
+    /// Death state when gibbed.
+    pub xdeath_state: StateNum,
+    pub death_sound: Sfx,
+    /// Units per step for monsters, fixed point units per tic for missiles.
+    pub speed: i32,
+    /// Fixed point.
+    pub radius: i32,
+    /// Fixed point.
+    pub height: i32,
+    pub mass: i32,
+    pub damage: i32,
+    pub active_sound: Sfx,
+    pub flags: MobjFlags,
+    /// State of a corpse raised by an Arch-vile.
+    pub raise_state: StateNum,
+}
+
+impl MobjInfo {
+    /// Values shared by most entries of the table.
+    const DEFAULT: MobjInfo = MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_NULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: sfx_None,
+        reaction_time: 8,
+        attack_sound: sfx_None,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: sfx_None,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: sfx_None,
+        speed: 0,
+        radius: 20 * FRACUNIT,
+        height: 16 * FRACUNIT,
+        mass: 100,
+        damage: 0,
+        active_sound: sfx_None,
+        flags: MobjFlags::empty(),
+        raise_state: S_NULL,
+    };
+}
+
+table! {
+    /// Kinds of things, numbered like `mobjtype_t`.
+    pub enum MobjType => pub(crate) const MOBJ_INFO: [MobjInfo] {
+        MT_PLAYER => MobjInfo {
+            spawn_state: S_PLAY,
+            spawn_health: 100,
+            see_state: S_PLAY_RUN1,
+            reaction_time: 0,
+            pain_state: S_PLAY_PAIN,
+            pain_chance: 255,
+            pain_sound: sfx_plpain,
+            missile_state: S_PLAY_ATK1,
+            death_state: S_PLAY_DIE1,
+            xdeath_state: S_PLAY_XDIE1,
+            death_sound: sfx_pldeth,
+            radius: 16 * FRACUNIT,
+            height: 56 * FRACUNIT,
+            flags: flags!(SOLID | SHOOTABLE | DROPOFF | PICKUP | NOTDMATCH),
+            ..MobjInfo::DEFAULT
+        },
+        MT_POSSESSED => MobjInfo {
+            doomed_num: 3004,
+            spawn_state: S_POSS_STND,
+            spawn_health: 20,
+            see_state: S_POSS_RUN1,
+            see_sound: sfx_posit1,
+            attack_sound: sfx_pistol,
+            pain_state: S_POSS_PAIN,
+            pain_chance: 200,
+            pain_sound: sfx_popain,
+            missile_state: S_POSS_ATK1,
+            death_state: S_POSS_DIE1,
+            xdeath_state: S_POSS_XDIE1,
+            death_sound: sfx_podth1,
+            speed: 8,
+            height: 56 * FRACUNIT,
+            active_sound: sfx_posact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_POSS_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_SHOTGUY => MobjInfo {
+            doomed_num: 9,
+            spawn_state: S_SPOS_STND,
+            spawn_health: 30,
+            see_state: S_SPOS_RUN1,
+            see_sound: sfx_posit2,
+            pain_state: S_SPOS_PAIN,
+            pain_chance: 170,
+            pain_sound: sfx_popain,
+            missile_state: S_SPOS_ATK1,
+            death_state: S_SPOS_DIE1,
+            xdeath_state: S_SPOS_XDIE1,
+            death_sound: sfx_podth2,
+            speed: 8,
+            height: 56 * FRACUNIT,
+            active_sound: sfx_posact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_SPOS_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_VILE => MobjInfo {
+            doomed_num: 64,
+            spawn_state: S_VILE_STND,
+            spawn_health: 700,
+            see_state: S_VILE_RUN1,
+            see_sound: sfx_vilsit,
+            pain_state: S_VILE_PAIN,
+            pain_chance: 10,
+            pain_sound: sfx_vipain,
+            missile_state: S_VILE_ATK1,
+            death_state: S_VILE_DIE1,
+            death_sound: sfx_vildth,
+            speed: 15,
+            height: 56 * FRACUNIT,
+            mass: 500,
+            active_sound: sfx_vilact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            ..MobjInfo::DEFAULT
+        },
+        MT_FIRE => MobjInfo {
+            spawn_state: S_FIRE1,
+            flags: flags!(NOBLOCKMAP | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_UNDEAD => MobjInfo {
+            doomed_num: 66,
+            spawn_state: S_SKEL_STND,
+            spawn_health: 300,
+            see_state: S_SKEL_RUN1,
+            see_sound: sfx_skesit,
+            pain_state: S_SKEL_PAIN,
+            pain_chance: 100,
+            pain_sound: sfx_popain,
+            melee_state: S_SKEL_FIST1,
+            missile_state: S_SKEL_MISS1,
+            death_state: S_SKEL_DIE1,
+            death_sound: sfx_skedth,
+            speed: 10,
+            height: 56 * FRACUNIT,
+            mass: 500,
+            active_sound: sfx_skeact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_SKEL_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_TRACER => MobjInfo {
+            spawn_state: S_TRACER,
+            see_sound: sfx_skeatk,
+            death_state: S_TRACEEXP1,
+            death_sound: sfx_barexp,
+            speed: 10 * FRACUNIT,
+            radius: 11 * FRACUNIT,
+            height: 8 * FRACUNIT,
+            damage: 10,
+            flags: flags!(NOBLOCKMAP | MISSILE | DROPOFF | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_SMOKE => MobjInfo {
+            spawn_state: S_SMOKE1,
+            flags: flags!(NOBLOCKMAP | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_FATSO => MobjInfo {
+            doomed_num: 67,
+            spawn_state: S_FATT_STND,
+            spawn_health: 600,
+            see_state: S_FATT_RUN1,
+            see_sound: sfx_mansit,
+            pain_state: S_FATT_PAIN,
+            pain_chance: 80,
+            pain_sound: sfx_mnpain,
+            missile_state: S_FATT_ATK1,
+            death_state: S_FATT_DIE1,
+            death_sound: sfx_mandth,
+            speed: 8,
+            radius: 48 * FRACUNIT,
+            height: 64 * FRACUNIT,
+            mass: 1000,
+            active_sound: sfx_posact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_FATT_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_FATSHOT => MobjInfo {
+            spawn_state: S_FATSHOT1,
+            see_sound: sfx_firsht,
+            death_state: S_FATSHOTX1,
+            death_sound: sfx_firxpl,
+            speed: 20 * FRACUNIT,
+            radius: 6 * FRACUNIT,
+            height: 8 * FRACUNIT,
+            damage: 8,
+            flags: flags!(NOBLOCKMAP | MISSILE | DROPOFF | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_CHAINGUY => MobjInfo {
+            doomed_num: 65,
+            spawn_state: S_CPOS_STND,
+            spawn_health: 70,
+            see_state: S_CPOS_RUN1,
+            see_sound: sfx_posit2,
+            pain_state: S_CPOS_PAIN,
+            pain_chance: 170,
+            pain_sound: sfx_popain,
+            missile_state: S_CPOS_ATK1,
+            death_state: S_CPOS_DIE1,
+            xdeath_state: S_CPOS_XDIE1,
+            death_sound: sfx_podth2,
+            speed: 8,
+            height: 56 * FRACUNIT,
+            active_sound: sfx_posact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_CPOS_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_TROOP => MobjInfo {
+            doomed_num: 3001,
+            spawn_state: S_TROO_STND,
+            spawn_health: 60,
+            see_state: S_TROO_RUN1,
+            see_sound: sfx_bgsit1,
+            pain_state: S_TROO_PAIN,
+            pain_chance: 200,
+            pain_sound: sfx_popain,
+            melee_state: S_TROO_ATK1,
+            missile_state: S_TROO_ATK1,
+            death_state: S_TROO_DIE1,
+            xdeath_state: S_TROO_XDIE1,
+            death_sound: sfx_bgdth1,
+            speed: 8,
+            height: 56 * FRACUNIT,
+            active_sound: sfx_bgact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_TROO_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_SERGEANT => MobjInfo {
+            doomed_num: 3002,
+            spawn_state: S_SARG_STND,
+            spawn_health: 150,
+            see_state: S_SARG_RUN1,
+            see_sound: sfx_sgtsit,
+            attack_sound: sfx_sgtatk,
+            pain_state: S_SARG_PAIN,
+            pain_chance: 180,
+            pain_sound: sfx_dmpain,
+            melee_state: S_SARG_ATK1,
+            death_state: S_SARG_DIE1,
+            death_sound: sfx_sgtdth,
+            speed: 10,
+            radius: 30 * FRACUNIT,
+            height: 56 * FRACUNIT,
+            mass: 400,
+            active_sound: sfx_dmact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_SARG_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_SHADOWS => MobjInfo {
+            doomed_num: 58,
+            spawn_state: S_SARG_STND,
+            spawn_health: 150,
+            see_state: S_SARG_RUN1,
+            see_sound: sfx_sgtsit,
+            attack_sound: sfx_sgtatk,
+            pain_state: S_SARG_PAIN,
+            pain_chance: 180,
+            pain_sound: sfx_dmpain,
+            melee_state: S_SARG_ATK1,
+            death_state: S_SARG_DIE1,
+            death_sound: sfx_sgtdth,
+            speed: 10,
+            radius: 30 * FRACUNIT,
+            height: 56 * FRACUNIT,
+            mass: 400,
+            active_sound: sfx_dmact,
+            flags: flags!(SOLID | SHOOTABLE | SHADOW | COUNTKILL),
+            raise_state: S_SARG_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_HEAD => MobjInfo {
+            doomed_num: 3005,
+            spawn_state: S_HEAD_STND,
+            spawn_health: 400,
+            see_state: S_HEAD_RUN1,
+            see_sound: sfx_cacsit,
+            pain_state: S_HEAD_PAIN,
+            pain_chance: 128,
+            pain_sound: sfx_dmpain,
+            missile_state: S_HEAD_ATK1,
+            death_state: S_HEAD_DIE1,
+            death_sound: sfx_cacdth,
+            speed: 8,
+            radius: 31 * FRACUNIT,
+            height: 56 * FRACUNIT,
+            mass: 400,
+            active_sound: sfx_dmact,
+            flags: flags!(SOLID | SHOOTABLE | FLOAT | NOGRAVITY | COUNTKILL),
+            raise_state: S_HEAD_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_BRUISER => MobjInfo {
+            doomed_num: 3003,
+            spawn_state: S_BOSS_STND,
+            see_state: S_BOSS_RUN1,
+            see_sound: sfx_brssit,
+            pain_state: S_BOSS_PAIN,
+            pain_chance: 50,
+            pain_sound: sfx_dmpain,
+            melee_state: S_BOSS_ATK1,
+            missile_state: S_BOSS_ATK1,
+            death_state: S_BOSS_DIE1,
+            death_sound: sfx_brsdth,
+            speed: 8,
+            radius: 24 * FRACUNIT,
+            height: 64 * FRACUNIT,
+            mass: 1000,
+            active_sound: sfx_dmact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_BOSS_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_BRUISERSHOT => MobjInfo {
+            spawn_state: S_BRBALL1,
+            see_sound: sfx_firsht,
+            death_state: S_BRBALLX1,
+            death_sound: sfx_firxpl,
+            speed: 15 * FRACUNIT,
+            radius: 6 * FRACUNIT,
+            height: 8 * FRACUNIT,
+            damage: 8,
+            flags: flags!(NOBLOCKMAP | MISSILE | DROPOFF | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_KNIGHT => MobjInfo {
+            doomed_num: 69,
+            spawn_state: S_BOS2_STND,
+            spawn_health: 500,
+            see_state: S_BOS2_RUN1,
+            see_sound: sfx_kntsit,
+            pain_state: S_BOS2_PAIN,
+            pain_chance: 50,
+            pain_sound: sfx_dmpain,
+            melee_state: S_BOS2_ATK1,
+            missile_state: S_BOS2_ATK1,
+            death_state: S_BOS2_DIE1,
+            death_sound: sfx_kntdth,
+            speed: 8,
+            radius: 24 * FRACUNIT,
+            height: 64 * FRACUNIT,
+            mass: 1000,
+            active_sound: sfx_dmact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_BOS2_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_SKULL => MobjInfo {
+            doomed_num: 3006,
+            spawn_state: S_SKULL_STND,
+            spawn_health: 100,
+            see_state: S_SKULL_RUN1,
+            attack_sound: sfx_sklatk,
+            pain_state: S_SKULL_PAIN,
+            pain_chance: 256,
+            pain_sound: sfx_dmpain,
+            missile_state: S_SKULL_ATK1,
+            death_state: S_SKULL_DIE1,
+            death_sound: sfx_firxpl,
+            speed: 8,
+            radius: 16 * FRACUNIT,
+            height: 56 * FRACUNIT,
+            mass: 50,
+            damage: 3,
+            active_sound: sfx_dmact,
+            flags: flags!(SOLID | SHOOTABLE | FLOAT | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_SPIDER => MobjInfo {
+            doomed_num: 7,
+            spawn_state: S_SPID_STND,
+            spawn_health: 3000,
+            see_state: S_SPID_RUN1,
+            see_sound: sfx_spisit,
+            attack_sound: sfx_shotgn,
+            pain_state: S_SPID_PAIN,
+            pain_chance: 40,
+            pain_sound: sfx_dmpain,
+            missile_state: S_SPID_ATK1,
+            death_state: S_SPID_DIE1,
+            death_sound: sfx_spidth,
+            speed: 12,
+            radius: 128 * FRACUNIT,
+            height: 100 * FRACUNIT,
+            mass: 1000,
+            active_sound: sfx_dmact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            ..MobjInfo::DEFAULT
+        },
+        MT_BABY => MobjInfo {
+            doomed_num: 68,
+            spawn_state: S_BSPI_STND,
+            spawn_health: 500,
+            see_state: S_BSPI_SIGHT,
+            see_sound: sfx_bspsit,
+            pain_state: S_BSPI_PAIN,
+            pain_chance: 128,
+            pain_sound: sfx_dmpain,
+            missile_state: S_BSPI_ATK1,
+            death_state: S_BSPI_DIE1,
+            death_sound: sfx_bspdth,
+            speed: 12,
+            radius: 64 * FRACUNIT,
+            height: 64 * FRACUNIT,
+            mass: 600,
+            active_sound: sfx_bspact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_BSPI_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_CYBORG => MobjInfo {
+            doomed_num: 16,
+            spawn_state: S_CYBER_STND,
+            spawn_health: 4000,
+            see_state: S_CYBER_RUN1,
+            see_sound: sfx_cybsit,
+            pain_state: S_CYBER_PAIN,
+            pain_chance: 20,
+            pain_sound: sfx_dmpain,
+            missile_state: S_CYBER_ATK1,
+            death_state: S_CYBER_DIE1,
+            death_sound: sfx_cybdth,
+            speed: 16,
+            radius: 40 * FRACUNIT,
+            height: 110 * FRACUNIT,
+            mass: 1000,
+            active_sound: sfx_dmact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            ..MobjInfo::DEFAULT
+        },
+        MT_PAIN => MobjInfo {
+            doomed_num: 71,
+            spawn_state: S_PAIN_STND,
+            spawn_health: 400,
+            see_state: S_PAIN_RUN1,
+            see_sound: sfx_pesit,
+            pain_state: S_PAIN_PAIN,
+            pain_chance: 128,
+            pain_sound: sfx_pepain,
+            missile_state: S_PAIN_ATK1,
+            death_state: S_PAIN_DIE1,
+            death_sound: sfx_pedth,
+            speed: 8,
+            radius: 31 * FRACUNIT,
+            height: 56 * FRACUNIT,
+            mass: 400,
+            active_sound: sfx_dmact,
+            flags: flags!(SOLID | SHOOTABLE | FLOAT | NOGRAVITY | COUNTKILL),
+            raise_state: S_PAIN_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_WOLFSS => MobjInfo {
+            doomed_num: 84,
+            spawn_state: S_SSWV_STND,
+            spawn_health: 50,
+            see_state: S_SSWV_RUN1,
+            see_sound: sfx_sssit,
+            pain_state: S_SSWV_PAIN,
+            pain_chance: 170,
+            pain_sound: sfx_popain,
+            missile_state: S_SSWV_ATK1,
+            death_state: S_SSWV_DIE1,
+            xdeath_state: S_SSWV_XDIE1,
+            death_sound: sfx_ssdth,
+            speed: 8,
+            height: 56 * FRACUNIT,
+            active_sound: sfx_posact,
+            flags: flags!(SOLID | SHOOTABLE | COUNTKILL),
+            raise_state: S_SSWV_RAISE1,
+            ..MobjInfo::DEFAULT
+        },
+        MT_KEEN => MobjInfo {
+            doomed_num: 72,
+            spawn_state: S_KEENSTND,
+            spawn_health: 100,
+            pain_state: S_KEENPAIN,
+            pain_chance: 256,
+            pain_sound: sfx_keenpn,
+            death_state: S_COMMKEEN,
+            death_sound: sfx_keendt,
+            radius: 16 * FRACUNIT,
+            height: 72 * FRACUNIT,
+            mass: 10000000,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY | SHOOTABLE | COUNTKILL),
+            ..MobjInfo::DEFAULT
+        },
+        MT_BOSSBRAIN => MobjInfo {
+            doomed_num: 88,
+            spawn_state: S_BRAIN,
+            spawn_health: 250,
+            pain_state: S_BRAIN_PAIN,
+            pain_chance: 255,
+            pain_sound: sfx_bospn,
+            death_state: S_BRAIN_DIE1,
+            death_sound: sfx_bosdth,
+            radius: 16 * FRACUNIT,
+            mass: 10000000,
+            flags: flags!(SOLID | SHOOTABLE),
+            ..MobjInfo::DEFAULT
+        },
+        MT_BOSSSPIT => MobjInfo {
+            doomed_num: 89,
+            spawn_state: S_BRAINEYE,
+            see_state: S_BRAINEYESEE,
+            height: 32 * FRACUNIT,
+            flags: flags!(NOBLOCKMAP | NOSECTOR),
+            ..MobjInfo::DEFAULT
+        },
+        MT_BOSSTARGET => MobjInfo {
+            doomed_num: 87,
+            height: 32 * FRACUNIT,
+            flags: flags!(NOBLOCKMAP | NOSECTOR),
+            ..MobjInfo::DEFAULT
+        },
+        MT_SPAWNSHOT => MobjInfo {
+            spawn_state: S_SPAWN1,
+            see_sound: sfx_bospit,
+            death_sound: sfx_firxpl,
+            speed: 10 * FRACUNIT,
+            radius: 6 * FRACUNIT,
+            height: 32 * FRACUNIT,
+            damage: 3,
+            flags: flags!(NOBLOCKMAP | MISSILE | DROPOFF | NOGRAVITY | NOCLIP),
+            ..MobjInfo::DEFAULT
+        },
+        MT_SPAWNFIRE => MobjInfo {
+            spawn_state: S_SPAWNFIRE1,
+            flags: flags!(NOBLOCKMAP | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_BARREL => MobjInfo {
+            doomed_num: 2035,
+            spawn_state: S_BAR1,
+            spawn_health: 20,
+            death_state: S_BEXP,
+            death_sound: sfx_barexp,
+            radius: 10 * FRACUNIT,
+            height: 42 * FRACUNIT,
+            flags: flags!(SOLID | SHOOTABLE | NOBLOOD),
+            ..MobjInfo::DEFAULT
+        },
+        MT_TROOPSHOT => MobjInfo {
+            spawn_state: S_TBALL1,
+            see_sound: sfx_firsht,
+            death_state: S_TBALLX1,
+            death_sound: sfx_firxpl,
+            speed: 10 * FRACUNIT,
+            radius: 6 * FRACUNIT,
+            height: 8 * FRACUNIT,
+            damage: 3,
+            flags: flags!(NOBLOCKMAP | MISSILE | DROPOFF | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_HEADSHOT => MobjInfo {
+            spawn_state: S_RBALL1,
+            see_sound: sfx_firsht,
+            death_state: S_RBALLX1,
+            death_sound: sfx_firxpl,
+            speed: 10 * FRACUNIT,
+            radius: 6 * FRACUNIT,
+            height: 8 * FRACUNIT,
+            damage: 5,
+            flags: flags!(NOBLOCKMAP | MISSILE | DROPOFF | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_ROCKET => MobjInfo {
+            spawn_state: S_ROCKET,
+            see_sound: sfx_rlaunc,
+            death_state: S_EXPLODE1,
+            death_sound: sfx_barexp,
+            speed: 20 * FRACUNIT,
+            radius: 11 * FRACUNIT,
+            height: 8 * FRACUNIT,
+            damage: 20,
+            flags: flags!(NOBLOCKMAP | MISSILE | DROPOFF | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_PLASMA => MobjInfo {
+            spawn_state: S_PLASBALL,
+            see_sound: sfx_plasma,
+            death_state: S_PLASEXP,
+            death_sound: sfx_firxpl,
+            speed: 25 * FRACUNIT,
+            radius: 13 * FRACUNIT,
+            height: 8 * FRACUNIT,
+            damage: 5,
+            flags: flags!(NOBLOCKMAP | MISSILE | DROPOFF | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_BFG => MobjInfo {
+            spawn_state: S_BFGSHOT,
+            death_state: S_BFGLAND,
+            death_sound: sfx_rxplod,
+            speed: 25 * FRACUNIT,
+            radius: 13 * FRACUNIT,
+            height: 8 * FRACUNIT,
+            damage: 100,
+            flags: flags!(NOBLOCKMAP | MISSILE | DROPOFF | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_ARACHPLAZ => MobjInfo {
+            spawn_state: S_ARACH_PLAZ,
+            see_sound: sfx_plasma,
+            death_state: S_ARACH_PLEX,
+            death_sound: sfx_firxpl,
+            speed: 25 * FRACUNIT,
+            radius: 13 * FRACUNIT,
+            height: 8 * FRACUNIT,
+            damage: 5,
+            flags: flags!(NOBLOCKMAP | MISSILE | DROPOFF | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_PUFF => MobjInfo {
+            spawn_state: S_PUFF1,
+            flags: flags!(NOBLOCKMAP | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_BLOOD => MobjInfo {
+            spawn_state: S_BLOOD1,
+            flags: flags!(NOBLOCKMAP),
+            ..MobjInfo::DEFAULT
+        },
+        MT_TFOG => MobjInfo {
+            spawn_state: S_TFOG,
+            flags: flags!(NOBLOCKMAP | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_IFOG => MobjInfo {
+            spawn_state: S_IFOG,
+            flags: flags!(NOBLOCKMAP | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        MT_TELEPORTMAN => MobjInfo {
+            doomed_num: 14,
+            flags: flags!(NOBLOCKMAP | NOSECTOR),
+            ..MobjInfo::DEFAULT
+        },
+        MT_EXTRABFG => MobjInfo {
+            spawn_state: S_BFGEXP,
+            flags: flags!(NOBLOCKMAP | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Green armor.
+        MT_MISC0 => MobjInfo {
+            doomed_num: 2018,
+            spawn_state: S_ARM1,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Blue armor.
+        MT_MISC1 => MobjInfo {
+            doomed_num: 2019,
+            spawn_state: S_ARM2,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Health bonus.
+        MT_MISC2 => MobjInfo {
+            doomed_num: 2014,
+            spawn_state: S_BON1,
+            flags: flags!(SPECIAL | COUNTITEM),
+            ..MobjInfo::DEFAULT
+        },
+        // Armor bonus.
+        MT_MISC3 => MobjInfo {
+            doomed_num: 2015,
+            spawn_state: S_BON2,
+            flags: flags!(SPECIAL | COUNTITEM),
+            ..MobjInfo::DEFAULT
+        },
+        // Blue keycard.
+        MT_MISC4 => MobjInfo {
+            doomed_num: 5,
+            spawn_state: S_BKEY,
+            flags: flags!(SPECIAL | NOTDMATCH),
+            ..MobjInfo::DEFAULT
+        },
+        // Red keycard.
+        MT_MISC5 => MobjInfo {
+            doomed_num: 13,
+            spawn_state: S_RKEY,
+            flags: flags!(SPECIAL | NOTDMATCH),
+            ..MobjInfo::DEFAULT
+        },
+        // Yellow keycard.
+        MT_MISC6 => MobjInfo {
+            doomed_num: 6,
+            spawn_state: S_YKEY,
+            flags: flags!(SPECIAL | NOTDMATCH),
+            ..MobjInfo::DEFAULT
+        },
+        // Yellow skull key.
+        MT_MISC7 => MobjInfo {
+            doomed_num: 39,
+            spawn_state: S_YSKULL,
+            flags: flags!(SPECIAL | NOTDMATCH),
+            ..MobjInfo::DEFAULT
+        },
+        // Red skull key.
+        MT_MISC8 => MobjInfo {
+            doomed_num: 38,
+            spawn_state: S_RSKULL,
+            flags: flags!(SPECIAL | NOTDMATCH),
+            ..MobjInfo::DEFAULT
+        },
+        // Blue skull key.
+        MT_MISC9 => MobjInfo {
+            doomed_num: 40,
+            spawn_state: S_BSKULL,
+            flags: flags!(SPECIAL | NOTDMATCH),
+            ..MobjInfo::DEFAULT
+        },
+        // Stimpack.
+        MT_MISC10 => MobjInfo {
+            doomed_num: 2011,
+            spawn_state: S_STIM,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Medikit.
+        MT_MISC11 => MobjInfo {
+            doomed_num: 2012,
+            spawn_state: S_MEDI,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Soul sphere.
+        MT_MISC12 => MobjInfo {
+            doomed_num: 2013,
+            spawn_state: S_SOUL,
+            flags: flags!(SPECIAL | COUNTITEM),
+            ..MobjInfo::DEFAULT
+        },
+        MT_INV => MobjInfo {
+            doomed_num: 2022,
+            spawn_state: S_PINV,
+            flags: flags!(SPECIAL | COUNTITEM),
+            ..MobjInfo::DEFAULT
+        },
+        // Berserk.
+        MT_MISC13 => MobjInfo {
+            doomed_num: 2023,
+            spawn_state: S_PSTR,
+            flags: flags!(SPECIAL | COUNTITEM),
+            ..MobjInfo::DEFAULT
+        },
+        MT_INS => MobjInfo {
+            doomed_num: 2024,
+            spawn_state: S_PINS,
+            flags: flags!(SPECIAL | COUNTITEM),
+            ..MobjInfo::DEFAULT
+        },
+        // Radiation suit.
+        MT_MISC14 => MobjInfo {
+            doomed_num: 2025,
+            spawn_state: S_SUIT,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Computer area map.
+        MT_MISC15 => MobjInfo {
+            doomed_num: 2026,
+            spawn_state: S_PMAP,
+            flags: flags!(SPECIAL | COUNTITEM),
+            ..MobjInfo::DEFAULT
+        },
+        // Light amplification visor.
+        MT_MISC16 => MobjInfo {
+            doomed_num: 2045,
+            spawn_state: S_PVIS,
+            flags: flags!(SPECIAL | COUNTITEM),
+            ..MobjInfo::DEFAULT
+        },
+        MT_MEGA => MobjInfo {
+            doomed_num: 83,
+            spawn_state: S_MEGA,
+            flags: flags!(SPECIAL | COUNTITEM),
+            ..MobjInfo::DEFAULT
+        },
+        MT_CLIP => MobjInfo {
+            doomed_num: 2007,
+            spawn_state: S_CLIP,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Box of bullets.
+        MT_MISC17 => MobjInfo {
+            doomed_num: 2048,
+            spawn_state: S_AMMO,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Rocket.
+        MT_MISC18 => MobjInfo {
+            doomed_num: 2010,
+            spawn_state: S_ROCK,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Box of rockets.
+        MT_MISC19 => MobjInfo {
+            doomed_num: 2046,
+            spawn_state: S_BROK,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Energy cell.
+        MT_MISC20 => MobjInfo {
+            doomed_num: 2047,
+            spawn_state: S_CELL,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Energy cell pack.
+        MT_MISC21 => MobjInfo {
+            doomed_num: 17,
+            spawn_state: S_CELP,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Shotgun shells.
+        MT_MISC22 => MobjInfo {
+            doomed_num: 2008,
+            spawn_state: S_SHEL,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Box of shotgun shells.
+        MT_MISC23 => MobjInfo {
+            doomed_num: 2049,
+            spawn_state: S_SBOX,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Backpack.
+        MT_MISC24 => MobjInfo {
+            doomed_num: 8,
+            spawn_state: S_BPAK,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // BFG9000.
+        MT_MISC25 => MobjInfo {
+            doomed_num: 2006,
+            spawn_state: S_BFUG,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        MT_CHAINGUN => MobjInfo {
+            doomed_num: 2002,
+            spawn_state: S_MGUN,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Chainsaw.
+        MT_MISC26 => MobjInfo {
+            doomed_num: 2005,
+            spawn_state: S_CSAW,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Rocket launcher.
+        MT_MISC27 => MobjInfo {
+            doomed_num: 2003,
+            spawn_state: S_LAUN,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Plasma gun.
+        MT_MISC28 => MobjInfo {
+            doomed_num: 2004,
+            spawn_state: S_PLAS,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        MT_SHOTGUN => MobjInfo {
+            doomed_num: 2001,
+            spawn_state: S_SHOT,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        MT_SUPERSHOTGUN => MobjInfo {
+            doomed_num: 82,
+            spawn_state: S_SHOT2,
+            flags: flags!(SPECIAL),
+            ..MobjInfo::DEFAULT
+        },
+        // Tall techno floor lamp.
+        MT_MISC29 => MobjInfo {
+            doomed_num: 85,
+            spawn_state: S_TECHLAMP,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Short techno floor lamp.
+        MT_MISC30 => MobjInfo {
+            doomed_num: 86,
+            spawn_state: S_TECH2LAMP,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Floor lamp.
+        MT_MISC31 => MobjInfo {
+            doomed_num: 2028,
+            spawn_state: S_COLU,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Tall green pillar.
+        MT_MISC32 => MobjInfo {
+            doomed_num: 30,
+            spawn_state: S_TALLGRNCOL,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Short green pillar.
+        MT_MISC33 => MobjInfo {
+            doomed_num: 31,
+            spawn_state: S_SHRTGRNCOL,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Tall red pillar.
+        MT_MISC34 => MobjInfo {
+            doomed_num: 32,
+            spawn_state: S_TALLREDCOL,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Short red pillar.
+        MT_MISC35 => MobjInfo {
+            doomed_num: 33,
+            spawn_state: S_SHRTREDCOL,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Short red pillar with skull.
+        MT_MISC36 => MobjInfo {
+            doomed_num: 37,
+            spawn_state: S_SKULLCOL,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Short green pillar with beating heart.
+        MT_MISC37 => MobjInfo {
+            doomed_num: 36,
+            spawn_state: S_HEARTCOL,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Evil eye.
+        MT_MISC38 => MobjInfo {
+            doomed_num: 41,
+            spawn_state: S_EVILEYE,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Floating skull rock.
+        MT_MISC39 => MobjInfo {
+            doomed_num: 42,
+            spawn_state: S_FLOATSKULL,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Burnt tree.
+        MT_MISC40 => MobjInfo {
+            doomed_num: 43,
+            spawn_state: S_TORCHTREE,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Tall blue firestick.
+        MT_MISC41 => MobjInfo {
+            doomed_num: 44,
+            spawn_state: S_BLUETORCH,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Tall green firestick.
+        MT_MISC42 => MobjInfo {
+            doomed_num: 45,
+            spawn_state: S_GREENTORCH,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Tall red firestick.
+        MT_MISC43 => MobjInfo {
+            doomed_num: 46,
+            spawn_state: S_REDTORCH,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Short blue firestick.
+        MT_MISC44 => MobjInfo {
+            doomed_num: 55,
+            spawn_state: S_BTORCHSHRT,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Short green firestick.
+        MT_MISC45 => MobjInfo {
+            doomed_num: 56,
+            spawn_state: S_GTORCHSHRT,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Short red firestick.
+        MT_MISC46 => MobjInfo {
+            doomed_num: 57,
+            spawn_state: S_RTORCHSHRT,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Stalagmite.
+        MT_MISC47 => MobjInfo {
+            doomed_num: 47,
+            spawn_state: S_STALAGTITE,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Tall techno column.
+        MT_MISC48 => MobjInfo {
+            doomed_num: 48,
+            spawn_state: S_TECHPILLAR,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Candle.
+        MT_MISC49 => MobjInfo {
+            doomed_num: 34,
+            spawn_state: S_CANDLESTIK,
+            ..MobjInfo::DEFAULT
+        },
+        // Candelabra.
+        MT_MISC50 => MobjInfo {
+            doomed_num: 35,
+            spawn_state: S_CANDELABRA,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging victim, twitching.
+        MT_MISC51 => MobjInfo {
+            doomed_num: 49,
+            spawn_state: S_BLOODYTWITCH,
+            radius: 16 * FRACUNIT,
+            height: 68 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging victim, arms out.
+        MT_MISC52 => MobjInfo {
+            doomed_num: 50,
+            spawn_state: S_MEAT2,
+            radius: 16 * FRACUNIT,
+            height: 84 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging victim, one-legged.
+        MT_MISC53 => MobjInfo {
+            doomed_num: 51,
+            spawn_state: S_MEAT3,
+            radius: 16 * FRACUNIT,
+            height: 84 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging pair of legs.
+        MT_MISC54 => MobjInfo {
+            doomed_num: 52,
+            spawn_state: S_MEAT4,
+            radius: 16 * FRACUNIT,
+            height: 68 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging leg.
+        MT_MISC55 => MobjInfo {
+            doomed_num: 53,
+            spawn_state: S_MEAT5,
+            radius: 16 * FRACUNIT,
+            height: 52 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging victim, arms out, not blocking.
+        MT_MISC56 => MobjInfo {
+            doomed_num: 59,
+            spawn_state: S_MEAT2,
+            height: 84 * FRACUNIT,
+            flags: flags!(SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging pair of legs, not blocking.
+        MT_MISC57 => MobjInfo {
+            doomed_num: 60,
+            spawn_state: S_MEAT4,
+            height: 68 * FRACUNIT,
+            flags: flags!(SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging victim, one-legged, not blocking.
+        MT_MISC58 => MobjInfo {
+            doomed_num: 61,
+            spawn_state: S_MEAT3,
+            height: 52 * FRACUNIT,
+            flags: flags!(SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging leg, not blocking.
+        MT_MISC59 => MobjInfo {
+            doomed_num: 62,
+            spawn_state: S_MEAT5,
+            height: 52 * FRACUNIT,
+            flags: flags!(SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging victim, twitching, not blocking.
+        MT_MISC60 => MobjInfo {
+            doomed_num: 63,
+            spawn_state: S_BLOODYTWITCH,
+            height: 68 * FRACUNIT,
+            flags: flags!(SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Dead cacodemon.
+        MT_MISC61 => MobjInfo {
+            doomed_num: 22,
+            spawn_state: S_HEAD_DIE6,
+            ..MobjInfo::DEFAULT
+        },
+        // Dead player.
+        MT_MISC62 => MobjInfo {
+            doomed_num: 15,
+            spawn_state: S_PLAY_DIE7,
+            ..MobjInfo::DEFAULT
+        },
+        // Dead zombieman.
+        MT_MISC63 => MobjInfo {
+            doomed_num: 18,
+            spawn_state: S_POSS_DIE5,
+            ..MobjInfo::DEFAULT
+        },
+        // Dead demon.
+        MT_MISC64 => MobjInfo {
+            doomed_num: 21,
+            spawn_state: S_SARG_DIE6,
+            ..MobjInfo::DEFAULT
+        },
+        // Dead lost soul, which vanishes after a few tics.
+        MT_MISC65 => MobjInfo {
+            doomed_num: 23,
+            spawn_state: S_SKULL_DIE6,
+            ..MobjInfo::DEFAULT
+        },
+        // Dead imp.
+        MT_MISC66 => MobjInfo {
+            doomed_num: 20,
+            spawn_state: S_TROO_DIE5,
+            ..MobjInfo::DEFAULT
+        },
+        // Dead shotgun guy.
+        MT_MISC67 => MobjInfo {
+            doomed_num: 19,
+            spawn_state: S_SPOS_DIE5,
+            ..MobjInfo::DEFAULT
+        },
+        // Bloody mess.
+        MT_MISC68 => MobjInfo {
+            doomed_num: 10,
+            spawn_state: S_PLAY_XDIE9,
+            ..MobjInfo::DEFAULT
+        },
+        // Bloody mess.
+        MT_MISC69 => MobjInfo {
+            doomed_num: 12,
+            spawn_state: S_PLAY_XDIE9,
+            ..MobjInfo::DEFAULT
+        },
+        // Five skulls "shish kebab".
+        MT_MISC70 => MobjInfo {
+            doomed_num: 28,
+            spawn_state: S_HEADSONSTICK,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Pool of blood and flesh.
+        MT_MISC71 => MobjInfo {
+            doomed_num: 24,
+            spawn_state: S_GIBS,
+            ..MobjInfo::DEFAULT
+        },
+        // Skull on a pole.
+        MT_MISC72 => MobjInfo {
+            doomed_num: 27,
+            spawn_state: S_HEADONASTICK,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Pile of skulls and candles.
+        MT_MISC73 => MobjInfo {
+            doomed_num: 29,
+            spawn_state: S_HEADCANDLES,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Impaled human.
+        MT_MISC74 => MobjInfo {
+            doomed_num: 25,
+            spawn_state: S_DEADSTICK,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Twitching impaled human.
+        MT_MISC75 => MobjInfo {
+            doomed_num: 26,
+            spawn_state: S_LIVESTICK,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Large brown tree.
+        MT_MISC76 => MobjInfo {
+            doomed_num: 54,
+            spawn_state: S_BIGTREE,
+            radius: 32 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Burning barrel.
+        MT_MISC77 => MobjInfo {
+            doomed_num: 70,
+            spawn_state: S_BBAR1,
+            radius: 16 * FRACUNIT,
+            flags: flags!(SOLID),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging victim, guts removed.
+        MT_MISC78 => MobjInfo {
+            doomed_num: 73,
+            spawn_state: S_HANGNOGUTS,
+            radius: 16 * FRACUNIT,
+            height: 88 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging victim, guts and brain removed.
+        MT_MISC79 => MobjInfo {
+            doomed_num: 74,
+            spawn_state: S_HANGBNOBRAIN,
+            radius: 16 * FRACUNIT,
+            height: 88 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging torso, looking down.
+        MT_MISC80 => MobjInfo {
+            doomed_num: 75,
+            spawn_state: S_HANGTLOOKDN,
+            radius: 16 * FRACUNIT,
+            height: 64 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging torso, open skull.
+        MT_MISC81 => MobjInfo {
+            doomed_num: 76,
+            spawn_state: S_HANGTSKULL,
+            radius: 16 * FRACUNIT,
+            height: 64 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging torso, looking up.
+        MT_MISC82 => MobjInfo {
+            doomed_num: 77,
+            spawn_state: S_HANGTLOOKUP,
+            radius: 16 * FRACUNIT,
+            height: 64 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Hanging torso, brain removed.
+        MT_MISC83 => MobjInfo {
+            doomed_num: 78,
+            spawn_state: S_HANGTNOBRAIN,
+            radius: 16 * FRACUNIT,
+            height: 64 * FRACUNIT,
+            flags: flags!(SOLID | SPAWNCEILING | NOGRAVITY),
+            ..MobjInfo::DEFAULT
+        },
+        // Pool of blood.
+        MT_MISC84 => MobjInfo {
+            doomed_num: 79,
+            spawn_state: S_COLONGIBS,
+            flags: flags!(NOBLOCKMAP),
+            ..MobjInfo::DEFAULT
+        },
+        // Pool of blood.
+        MT_MISC85 => MobjInfo {
+            doomed_num: 80,
+            spawn_state: S_SMALLPOOL,
+            flags: flags!(NOBLOCKMAP),
+            ..MobjInfo::DEFAULT
+        },
+        // Pool of brains.
+        MT_MISC86 => MobjInfo {
+            doomed_num: 81,
+            spawn_state: S_BRAINSTEM,
+            flags: flags!(NOBLOCKMAP),
+            ..MobjInfo::DEFAULT
+        },
+    }
+}
@@ -0,0 +1,115 @@
+table! {
+    /// Sound effects, numbered like `sfxenum_t`. Their lumps are named
+    /// after them, prefixed with `DS`.
+    pub enum Sfx => pub(crate) const SFX_NAMES: [&str] {
+        sfx_None => "none",
+        sfx_pistol => "pistol",
+        sfx_shotgn => "shotgn",
+        sfx_sgcock => "sgcock",
+        sfx_dshtgn => "dshtgn",
+        sfx_dbopn => "dbopn",
+        sfx_dbcls => "dbcls",
+        sfx_dbload => "dbload",
+        sfx_plasma => "plasma",
+        sfx_bfg => "bfg",
+        sfx_sawup => "sawup",
+        sfx_sawidl => "sawidl",
+        sfx_sawful => "sawful",
+        sfx_sawhit => "sawhit",
+        sfx_rlaunc => "rlaunc",
+        sfx_rxplod => "rxplod",
+        sfx_firsht => "firsht",
+        sfx_firxpl => "firxpl",
+        sfx_pstart => "pstart",
+        sfx_pstop => "pstop",
+        sfx_doropn => "doropn",
+        sfx_dorcls => "dorcls",
+        sfx_stnmov => "stnmov",
+        sfx_swtchn => "swtchn",
+        sfx_swtchx => "swtchx",
+        sfx_plpain => "plpain",
+        sfx_dmpain => "dmpain",
+        sfx_popain => "popain",
+        sfx_vipain => "vipain",
+        sfx_mnpain => "mnpain",
+        sfx_pepain => "pepain",
+        sfx_slop => "slop",
+        sfx_itemup => "itemup",
+        sfx_wpnup => "wpnup",
+        sfx_oof => "oof",
+        sfx_telept => "telept",
+        sfx_posit1 => "posit1",
+        sfx_posit2 => "posit2",
+        sfx_posit3 => "posit3",
+        sfx_bgsit1 => "bgsit1",
+        sfx_bgsit2 => "bgsit2",
+        sfx_sgtsit => "sgtsit",
+        sfx_cacsit => "cacsit",
+        sfx_brssit => "brssit",
+        sfx_cybsit => "cybsit",
+        sfx_spisit => "spisit",
+        sfx_bspsit => "bspsit",
+        sfx_kntsit => "kntsit",
+        sfx_vilsit => "vilsit",
+        sfx_mansit => "mansit",
+        sfx_pesit => "pesit",
+        sfx_sklatk => "sklatk",
+        sfx_sgtatk => "sgtatk",
+        sfx_skepch => "skepch",
+        sfx_vilatk => "vilatk",
+        sfx_claw => "claw",
+        sfx_skeswg => "skeswg",
+        sfx_pldeth => "pldeth",
+        sfx_pdiehi => "pdiehi",
+        sfx_podth1 => "podth1",
+        sfx_podth2 => "podth2",
+        sfx_podth3 => "podth3",
+        sfx_bgdth1 => "bgdth1",
+        sfx_bgdth2 => "bgdth2",
+        sfx_sgtdth => "sgtdth",
+        sfx_cacdth => "cacdth",
+        sfx_skldth => "skldth",
+        sfx_brsdth => "brsdth",
+        sfx_cybdth => "cybdth",
+        sfx_spidth => "spidth",
+        sfx_bspdth => "bspdth",
+        sfx_vildth => "vildth",
+        sfx_kntdth => "kntdth",
+        sfx_pedth => "pedth",
+        sfx_skedth => "skedth",
+        sfx_posact => "posact",
+        sfx_bgact => "bgact",
+        sfx_dmact => "dmact",
+        sfx_bspact => "bspact",
+        sfx_bspwlk => "bspwlk",
+        sfx_vilact => "vilact",
+        sfx_noway => "noway",
+        sfx_barexp => "barexp",
+        sfx_punch => "punch",
+        sfx_hoof => "hoof",
+        sfx_metal => "metal",
+        sfx_chgun => "chgun",
+        sfx_tink => "tink",
+        sfx_bdopn => "bdopn",
+        sfx_bdcls => "bdcls",
+        sfx_itmbk => "itmbk",
+        sfx_flame => "flame",
+        sfx_flamst => "flamst",
+        sfx_getpow => "getpow",
+        sfx_bospit => "bospit",
+        sfx_boscub => "boscub",
+        sfx_bossit => "bossit",
+        sfx_bospn => "bospn",
+        sfx_bosdth => "bosdth",
+        sfx_manatk => "manatk",
+        sfx_mandth => "mandth",
+        sfx_sssit => "sssit",
+        sfx_ssdth => "ssdth",
+        sfx_keenpn => "keenpn",
+        sfx_keendt => "keendt",
+        sfx_skeact => "skeact",
+        sfx_skesit => "skesit",
+        sfx_skeatk => "skeatk",
+        sfx_radio => "radio",
+    }
+}
@@ -0,0 +1,143 @@
+table! {
+    /// Sprites the states can show, numbered like `sprnames`.
+    pub enum SpriteNum => pub(crate) const SPRITE_NAMES: [&str] {
+        SPR_TROO => "TROO",
+        SPR_SHTG => "SHTG",
+        SPR_PUNG => "PUNG",
+        SPR_PISG => "PISG",
+        SPR_PISF => "PISF",
+        SPR_SHTF => "SHTF",
+        SPR_SHT2 => "SHT2",
+        SPR_CHGG => "CHGG",
+        SPR_CHGF => "CHGF",
+        SPR_MISG => "MISG",
+        SPR_MISF => "MISF",
+        SPR_SAWG => "SAWG",
+        SPR_PLSG => "PLSG",
+        SPR_PLSF => "PLSF",
+        SPR_BFGG => "BFGG",
+        SPR_BFGF => "BFGF",
+        SPR_BLUD => "BLUD",
+        SPR_PUFF => "PUFF",
+        SPR_BAL1 => "BAL1",
+        SPR_BAL2 => "BAL2",
+        SPR_PLSS => "PLSS",
+        SPR_PLSE => "PLSE",
+        SPR_MISL => "MISL",
+        SPR_BFS1 => "BFS1",
+        SPR_BFE1 => "BFE1",
+        SPR_BFE2 => "BFE2",
+        SPR_TFOG => "TFOG",
+        SPR_IFOG => "IFOG",
+        SPR_PLAY => "PLAY",
+        SPR_POSS => "POSS",
+        SPR_SPOS => "SPOS",
+        SPR_VILE => "VILE",
+        SPR_FIRE => "FIRE",
+        SPR_FATB => "FATB",
+        SPR_FBXP => "FBXP",
+        SPR_SKEL => "SKEL",
+        SPR_MANF => "MANF",
+        SPR_FATT => "FATT",
+        SPR_CPOS => "CPOS",
+        SPR_SARG => "SARG",
+        SPR_HEAD => "HEAD",
+        SPR_BAL7 => "BAL7",
+        SPR_BOSS => "BOSS",
+        SPR_BOS2 => "BOS2",
+        SPR_SKUL => "SKUL",
+        SPR_SPID => "SPID",
+        SPR_BSPI => "BSPI",
+        SPR_APLS => "APLS",
+        SPR_APBX => "APBX",
+        SPR_CYBR => "CYBR",
+        SPR_PAIN => "PAIN",
+        SPR_SSWV => "SSWV",
+        SPR_KEEN => "KEEN",
+        SPR_BBRN => "BBRN",
+        SPR_BOSF => "BOSF",
+        SPR_ARM1 => "ARM1",
+        SPR_ARM2 => "ARM2",
+        SPR_BAR1 => "BAR1",
+        SPR_BEXP => "BEXP",
+        SPR_FCAN => "FCAN",
+        SPR_BON1 => "BON1",
+        SPR_BON2 => "BON2",
+        SPR_BKEY => "BKEY",
+        SPR_RKEY => "RKEY",
+        SPR_YKEY => "YKEY",
+        SPR_BSKU => "BSKU",
+        SPR_RSKU => "RSKU",
+        SPR_YSKU => "YSKU",
+        SPR_STIM => "STIM",
+        SPR_MEDI => "MEDI",
+        SPR_SOUL => "SOUL",
+        SPR_PINV => "PINV",
+        SPR_PSTR => "PSTR",
+        SPR_PINS => "PINS",
+        SPR_MEGA => "MEGA",
+        SPR_SUIT => "SUIT",
+        SPR_PMAP => "PMAP",
+        SPR_PVIS => "PVIS",
+        SPR_CLIP => "CLIP",
+        SPR_AMMO => "AMMO",
+        SPR_ROCK => "ROCK",
+        SPR_BROK => "BROK",
+        SPR_CELL => "CELL",
+        SPR_CELP => "CELP",
+        SPR_SHEL => "SHEL",
+        SPR_SBOX => "SBOX",
+        SPR_BPAK => "BPAK",
+        SPR_BFUG => "BFUG",
+        SPR_MGUN => "MGUN",
+        SPR_CSAW => "CSAW",
+        SPR_LAUN => "LAUN",
+        SPR_PLAS => "PLAS",
+        SPR_SHOT => "SHOT",
+        SPR_SGN2 => "SGN2",
+        SPR_COLU => "COLU",
+        SPR_SMT2 => "SMT2",
+        SPR_GOR1 => "GOR1",
+        SPR_POL2 => "POL2",
+        SPR_POL5 => "POL5",
+        SPR_POL4 => "POL4",
+        SPR_POL3 => "POL3",
+        SPR_POL1 => "POL1",
+        SPR_POL6 => "POL6",
+        SPR_GOR2 => "GOR2",
+        SPR_GOR3 => "GOR3",
+        SPR_GOR4 => "GOR4",
+        SPR_GOR5 => "GOR5",
+        SPR_SMIT => "SMIT",
+        SPR_COL1 => "COL1",
+        SPR_COL2 => "COL2",
+        SPR_COL3 => "COL3",
+        SPR_COL4 => "COL4",
+        SPR_CAND => "CAND",
+        SPR_CBRA => "CBRA",
+        SPR_COL6 => "COL6",
+        SPR_TRE1 => "TRE1",
+        SPR_TRE2 => "TRE2",
+        SPR_ELEC => "ELEC",
+        SPR_CEYE => "CEYE",
+        SPR_FSKU => "FSKU",
+        SPR_COL5 => "COL5",
+        SPR_TBLU => "TBLU",
+        SPR_TGRN => "TGRN",
+        SPR_TRED => "TRED",
+        SPR_SMBT => "SMBT",
+        SPR_SMGT => "SMGT",
+        SPR_SMRT => "SMRT",
+        SPR_HDB1 => "HDB1",
+        SPR_HDB2 => "HDB2",
+        SPR_HDB3 => "HDB3",
+        SPR_HDB4 => "HDB4",
+        SPR_HDB5 => "HDB5",
+        SPR_HDB6 => "HDB6",
+        SPR_POB1 => "POB1",
+        SPR_POB2 => "POB2",
+        SPR_BRS1 => "BRS1",
+        SPR_TLMP => "TLMP",
+        SPR_TLP2 => "TLP2",
+    }
+}
@@ -0,0 +1,1115 @@
+use crate::sprites::SpriteNum::{self, *};
+use Action::*;
+use StateNum::*;
+
+/// Bit of [`State::frame`] drawing the sprite at full brightness.
+pub const FF_FULLBRIGHT: u32 = 0x8000;
+/// Bits of [`State::frame`] holding the frame of the sprite.
+pub const FF_FRAMEMASK: u32 = 0x7fff;
+
+/// A step of the animation of a thing or a weapon, i.e. an entry
+/// of `states`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct State {
+    pub sprite: SpriteNum,
+    /// Frame of the sprite, with the [`FF_FULLBRIGHT`] bit.
+    pub frame: u32,
+    /// Tics before moving to the next state, -1 to stay forever.
+    pub tics: i32,
+    /// Called when entering the state.
+    pub action: Option<Action>,
+    pub next_state: StateNum,
+    /// Only used by weapons, to offset their sprites.
+    pub misc1: i32,
+    pub misc2: i32,
+}
+
+impl State {
+    /// Index of the frame of the sprite, without the brightness bit.
+    pub fn frame_index(&self) -> usize {
+        (self.frame & FF_FRAMEMASK) as usize
+    }
+
+    /// Drawn at full brightness, regardless of the light level of the sector.
+    pub fn is_full_bright(&self) -> bool {
+        self.frame & FF_FULLBRIGHT != 0
+    }
+}
+
+/// The code pointers of `doom.exe`, called by the states. Their names are
+/// the ones of the functions of the original code.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Action {
+    A_Light0,
+    A_WeaponReady,
+    A_Lower,
+    A_Raise,
+    A_Punch,
+    A_ReFire,
+    A_FirePistol,
+    A_Light1,
+    A_FireShotgun,
+    A_Light2,
+    A_FireShotgun2,
+    A_CheckReload,
+    A_OpenShotgun2,
+    A_LoadShotgun2,
+    A_CloseShotgun2,
+    A_FireCGun,
+    A_GunFlash,
+    A_FireMissile,
+    A_Saw,
+    A_FirePlasma,
+    A_BFGsound,
+    A_FireBFG,
+    A_BFGSpray,
+    A_Explode,
+    A_Pain,
+    A_PlayerScream,
+    A_Fall,
+    A_XScream,
+    A_Look,
+    A_Chase,
+    A_FaceTarget,
+    A_PosAttack,
+    A_Scream,
+    A_SPosAttack,
+    A_VileChase,
+    A_VileStart,
+    A_VileTarget,
+    A_VileAttack,
+    A_StartFire,
+    A_Fire,
+    A_FireCrackle,
+    A_Tracer,
+    A_SkelWhoosh,
+    A_SkelFist,
+    A_SkelMissile,
+    A_FatRaise,
+    A_FatAttack1,
+    A_FatAttack2,
+    A_FatAttack3,
+    A_BossDeath,
+    A_CPosAttack,
+    A_CPosRefire,
+    A_TroopAttack,
+    A_SargAttack,
+    A_HeadAttack,
+    A_BruisAttack,
+    A_SkullAttack,
+    A_Metal,
+    A_SpidRefire,
+    A_BabyMetal,
+    A_BspiAttack,
+    A_Hoof,
+    A_CyberAttack,
+    A_PainAttack,
+    A_PainDie,
+    A_KeenDie,
+    A_BrainPain,
+    A_BrainScream,
+    A_BrainDie,
+    A_BrainAwake,
+    A_BrainSpit,
+    A_SpawnSound,
+    A_SpawnFly,
+    A_BrainExplode,
+}
+
+const fn bright(frame: u32) -> u32 {
+    frame | FF_FULLBRIGHT
+}
+
+macro_rules! state {
+    ($sprite:ident, $frame:expr, $tics:expr, NULL, $next_state:ident) => {
+        state!(@ $sprite, $frame, $tics, None, $next_state)
+    };
+    ($sprite:ident, $frame:expr, $tics:expr, $action:ident, $next_state:ident) => {
+        state!(@ $sprite, $frame, $tics, Some($action), $next_state)
+    };
+    (@ $sprite:ident, $frame:expr, $tics:expr, $action:expr, $next_state:ident) => {
+        State {
+            sprite: $sprite,
+            frame: $frame,
+            tics: $tics,
+            action: $action,
+            next_state: $next_state,
+            misc1: 0,
+            misc2: 0,
+        }
+    };
+}
+
+table! {
+    /// States of the things and weapons, numbered like `statenum_t`.
+    pub enum StateNum => pub(crate) const STATES: [State] {
+        S_NULL => state!(SPR_TROO, 0, -1, NULL, S_NULL),
+        S_LIGHTDONE => state!(SPR_SHTG, 4, 0, A_Light0, S_NULL),
+        S_PUNCH => state!(SPR_PUNG, 0, 1, A_WeaponReady, S_PUNCH),
+        S_PUNCHDOWN => state!(SPR_PUNG, 0, 1, A_Lower, S_PUNCHDOWN),
+        S_PUNCHUP => state!(SPR_PUNG, 0, 1, A_Raise, S_PUNCHUP),
+        S_PUNCH1 => state!(SPR_PUNG, 1, 4, NULL, S_PUNCH2),
+        S_PUNCH2 => state!(SPR_PUNG, 2, 4, A_Punch, S_PUNCH3),
+        S_PUNCH3 => state!(SPR_PUNG, 3, 5, NULL, S_PUNCH4),
+        S_PUNCH4 => state!(SPR_PUNG, 2, 4, NULL, S_PUNCH5),
+        S_PUNCH5 => state!(SPR_PUNG, 1, 5, A_ReFire, S_PUNCH),
+        S_PISTOL => state!(SPR_PISG, 0, 1, A_WeaponReady, S_PISTOL),
+        S_PISTOLDOWN => state!(SPR_PISG, 0, 1, A_Lower, S_PISTOLDOWN),
+        S_PISTOLUP => state!(SPR_PISG, 0, 1, A_Raise, S_PISTOLUP),
+        S_PISTOL1 => state!(SPR_PISG, 0, 4, NULL, S_PISTOL2),
+        S_PISTOL2 => state!(SPR_PISG, 1, 6, A_FirePistol, S_PISTOL3),
+        S_PISTOL3 => state!(SPR_PISG, 2, 4, NULL, S_PISTOL4),
+        S_PISTOL4 => state!(SPR_PISG, 1, 5, A_ReFire, S_PISTOL),
+        S_PISTOLFLASH => state!(SPR_PISF, bright(0), 7, A_Light1, S_LIGHTDONE),
+        S_SGUN => state!(SPR_SHTG, 0, 1, A_WeaponReady, S_SGUN),
+        S_SGUNDOWN => state!(SPR_SHTG, 0, 1, A_Lower, S_SGUNDOWN),
+        S_SGUNUP => state!(SPR_SHTG, 0, 1, A_Raise, S_SGUNUP),
+        S_SGUN1 => state!(SPR_SHTG, 0, 3, NULL, S_SGUN2),
+        S_SGUN2 => state!(SPR_SHTG, 0, 7, A_FireShotgun, S_SGUN3),
+        S_SGUN3 => state!(SPR_SHTG, 1, 5, NULL, S_SGUN4),
+        S_SGUN4 => state!(SPR_SHTG, 2, 5, NULL, S_SGUN5),
+        S_SGUN5 => state!(SPR_SHTG, 3, 4, NULL, S_SGUN6),
+        S_SGUN6 => state!(SPR_SHTG, 2, 5, NULL, S_SGUN7),
+        S_SGUN7 => state!(SPR_SHTG, 1, 5, NULL, S_SGUN8),
+        S_SGUN8 => state!(SPR_SHTG, 0, 3, NULL, S_SGUN9),
+        S_SGUN9 => state!(SPR_SHTG, 0, 7, A_ReFire, S_SGUN),
+        S_SGUNFLASH1 => state!(SPR_SHTF, bright(0), 4, A_Light1, S_SGUNFLASH2),
+        S_SGUNFLASH2 => state!(SPR_SHTF, bright(1), 3, A_Light2, S_LIGHTDONE),
+        S_DSGUN => state!(SPR_SHT2, 0, 1, A_WeaponReady, S_DSGUN),
+        S_DSGUNDOWN => state!(SPR_SHT2, 0, 1, A_Lower, S_DSGUNDOWN),
+        S_DSGUNUP => state!(SPR_SHT2, 0, 1, A_Raise, S_DSGUNUP),
+        S_DSGUN1 => state!(SPR_SHT2, 0, 3, NULL, S_DSGUN2),
+        S_DSGUN2 => state!(SPR_SHT2, 0, 7, A_FireShotgun2, S_DSGUN3),
+        S_DSGUN3 => state!(SPR_SHT2, 1, 7, NULL, S_DSGUN4),
+        S_DSGUN4 => state!(SPR_SHT2, 2, 7, A_CheckReload, S_DSGUN5),
+        S_DSGUN5 => state!(SPR_SHT2, 3, 7, A_OpenShotgun2, S_DSGUN6),
+        S_DSGUN6 => state!(SPR_SHT2, 4, 7, NULL, S_DSGUN7),
+        S_DSGUN7 => state!(SPR_SHT2, 5, 7, A_LoadShotgun2, S_DSGUN8),
+        S_DSGUN8 => state!(SPR_SHT2, 6, 6, NULL, S_DSGUN9),
+        S_DSGUN9 => state!(SPR_SHT2, 7, 6, A_CloseShotgun2, S_DSGUN10),
+        S_DSGUN10 => state!(SPR_SHT2, 0, 5, A_ReFire, S_DSGUN),
+        S_DSNR1 => state!(SPR_SHT2, 1, 7, NULL, S_DSNR2),
+        S_DSNR2 => state!(SPR_SHT2, 0, 3, NULL, S_DSGUNDOWN),
+        S_DSGUNFLASH1 => state!(SPR_SHT2, bright(8), 5, A_Light1, S_DSGUNFLASH2),
+        S_DSGUNFLASH2 => state!(SPR_SHT2, bright(9), 4, A_Light2, S_LIGHTDONE),
+        S_CHAIN => state!(SPR_CHGG, 0, 1, A_WeaponReady, S_CHAIN),
+        S_CHAINDOWN => state!(SPR_CHGG, 0, 1, A_Lower, S_CHAINDOWN),
+        S_CHAINUP => state!(SPR_CHGG, 0, 1, A_Raise, S_CHAINUP),
+        S_CHAIN1 => state!(SPR_CHGG, 0, 4, A_FireCGun, S_CHAIN2),
+        S_CHAIN2 => state!(SPR_CHGG, 1, 4, A_FireCGun, S_CHAIN3),
+        S_CHAIN3 => state!(SPR_CHGG, 1, 0, A_ReFire, S_CHAIN),
+        S_CHAINFLASH1 => state!(SPR_CHGF, bright(0), 5, A_Light1, S_LIGHTDONE),
+        S_CHAINFLASH2 => state!(SPR_CHGF, bright(1), 5, A_Light2, S_LIGHTDONE),
+        S_MISSILE => state!(SPR_MISG, 0, 1, A_WeaponReady, S_MISSILE),
+        S_MISSILEDOWN => state!(SPR_MISG, 0, 1, A_Lower, S_MISSILEDOWN),
+        S_MISSILEUP => state!(SPR_MISG, 0, 1, A_Raise, S_MISSILEUP),
+        S_MISSILE1 => state!(SPR_MISG, 1, 8, A_GunFlash, S_MISSILE2),
+        S_MISSILE2 => state!(SPR_MISG, 1, 12, A_FireMissile, S_MISSILE3),
+        S_MISSILE3 => state!(SPR_MISG, 1, 0, A_ReFire, S_MISSILE),
+        S_MISSILEFLASH1 => state!(SPR_MISF, bright(0), 3, A_Light1, S_MISSILEFLASH2),
+        S_MISSILEFLASH2 => state!(SPR_MISF, bright(1), 4, NULL, S_MISSILEFLASH3),
+        S_MISSILEFLASH3 => state!(SPR_MISF, bright(2), 4, A_Light2, S_MISSILEFLASH4),
+        S_MISSILEFLASH4 => state!(SPR_MISF, bright(3), 4, A_Light2, S_LIGHTDONE),
+        S_SAW => state!(SPR_SAWG, 2, 4, A_WeaponReady, S_SAWB),
+        S_SAWB => state!(SPR_SAWG, 3, 4, A_WeaponReady, S_SAW),
+        S_SAWDOWN => state!(SPR_SAWG, 2, 1, A_Lower, S_SAWDOWN),
+        S_SAWUP => state!(SPR_SAWG, 2, 1, A_Raise, S_SAWUP),
+        S_SAW1 => state!(SPR_SAWG, 0, 4, A_Saw, S_SAW2),
+        S_SAW2 => state!(SPR_SAWG, 1, 4, A_Saw, S_SAW3),
+        S_SAW3 => state!(SPR_SAWG, 1, 0, A_ReFire, S_SAW),
+        S_PLASMA => state!(SPR_PLSG, 0, 1, A_WeaponReady, S_PLASMA),
+        S_PLASMADOWN => state!(SPR_PLSG, 0, 1, A_Lower, S_PLASMADOWN),
+        S_PLASMAUP => state!(SPR_PLSG, 0, 1, A_Raise, S_PLASMAUP),
+        S_PLASMA1 => state!(SPR_PLSG, 0, 3, A_FirePlasma, S_PLASMA2),
+        S_PLASMA2 => state!(SPR_PLSG, 1, 20, A_ReFire, S_PLASMA),
+        S_PLASMAFLASH1 => state!(SPR_PLSF, bright(0), 4, A_Light1, S_LIGHTDONE),
+        S_PLASMAFLASH2 => state!(SPR_PLSF, bright(1), 4, A_Light1, S_LIGHTDONE),
+        S_BFG => state!(SPR_BFGG, 0, 1, A_WeaponReady, S_BFG),
+        S_BFGDOWN => state!(SPR_BFGG, 0, 1, A_Lower, S_BFGDOWN),
+        S_BFGUP => state!(SPR_BFGG, 0, 1, A_Raise, S_BFGUP),
+        S_BFG1 => state!(SPR_BFGG, 0, 20, A_BFGsound, S_BFG2),
+        S_BFG2 => state!(SPR_BFGG, 1, 10, A_GunFlash, S_BFG3),
+        S_BFG3 => state!(SPR_BFGG, 1, 10, A_FireBFG, S_BFG4),
+        S_BFG4 => state!(SPR_BFGG, 1, 20, A_ReFire, S_BFG),
+        S_BFGFLASH1 => state!(SPR_BFGF, bright(0), 11, A_Light1, S_BFGFLASH2),
+        S_BFGFLASH2 => state!(SPR_BFGF, bright(1), 6, A_Light2, S_LIGHTDONE),
+        S_BLOOD1 => state!(SPR_BLUD, 2, 8, NULL, S_BLOOD2),
+        S_BLOOD2 => state!(SPR_BLUD, 1, 8, NULL, S_BLOOD3),
+        S_BLOOD3 => state!(SPR_BLUD, 0, 8, NULL, S_NULL),
+        S_PUFF1 => state!(SPR_PUFF, bright(0), 4, NULL, S_PUFF2),
+        S_PUFF2 => state!(SPR_PUFF, 1, 4, NULL, S_PUFF3),
+        S_PUFF3 => state!(SPR_PUFF, 2, 4, NULL, S_PUFF4),
+        S_PUFF4 => state!(SPR_PUFF, 3, 4, NULL, S_NULL),
+        S_TBALL1 => state!(SPR_BAL1, bright(0), 4, NULL, S_TBALL2),
+        S_TBALL2 => state!(SPR_BAL1, bright(1), 4, NULL, S_TBALL1),
+        S_TBALLX1 => state!(SPR_BAL1, bright(2), 6, NULL, S_TBALLX2),
+        S_TBALLX2 => state!(SPR_BAL1, bright(3), 6, NULL, S_TBALLX3),
+        S_TBALLX3 => state!(SPR_BAL1, bright(4), 6, NULL, S_NULL),
+        S_RBALL1 => state!(SPR_BAL2, bright(0), 4, NULL, S_RBALL2),
+        S_RBALL2 => state!(SPR_BAL2, bright(1), 4, NULL, S_RBALL1),
+        S_RBALLX1 => state!(SPR_BAL2, bright(2), 6, NULL, S_RBALLX2),
+        S_RBALLX2 => state!(SPR_BAL2, bright(3), 6, NULL, S_RBALLX3),
+        S_RBALLX3 => state!(SPR_BAL2, bright(4), 6, NULL, S_NULL),
+        S_PLASBALL => state!(SPR_PLSS, bright(0), 6, NULL, S_PLASBALL2),
+        S_PLASBALL2 => state!(SPR_PLSS, bright(1), 6, NULL, S_PLASBALL),
+        S_PLASEXP => state!(SPR_PLSE, bright(0), 4, NULL, S_PLASEXP2),
+        S_PLASEXP2 => state!(SPR_PLSE, bright(1), 4, NULL, S_PLASEXP3),
+        S_PLASEXP3 => state!(SPR_PLSE, bright(2), 4, NULL, S_PLASEXP4),
+        S_PLASEXP4 => state!(SPR_PLSE, bright(3), 4, NULL, S_PLASEXP5),
+        S_PLASEXP5 => state!(SPR_PLSE, bright(4), 4, NULL, S_NULL),
+        S_ROCKET => state!(SPR_MISL, bright(0), 1, NULL, S_ROCKET),
+        S_BFGSHOT => state!(SPR_BFS1, bright(0), 4, NULL, S_BFGSHOT2),
+        S_BFGSHOT2 => state!(SPR_BFS1, bright(1), 4, NULL, S_BFGSHOT),
+        S_BFGLAND => state!(SPR_BFE1, bright(0), 8, NULL, S_BFGLAND2),
+        S_BFGLAND2 => state!(SPR_BFE1, bright(1), 8, NULL, S_BFGLAND3),
+        S_BFGLAND3 => state!(SPR_BFE1, bright(2), 8, A_BFGSpray, S_BFGLAND4),
+        S_BFGLAND4 => state!(SPR_BFE1, bright(3), 8, NULL, S_BFGLAND5),
+        S_BFGLAND5 => state!(SPR_BFE1, bright(4), 8, NULL, S_BFGLAND6),
+        S_BFGLAND6 => state!(SPR_BFE1, bright(5), 8, NULL, S_NULL),
+        S_BFGEXP => state!(SPR_BFE2, bright(0), 8, NULL, S_BFGEXP2),
+        S_BFGEXP2 => state!(SPR_BFE2, bright(1), 8, NULL, S_BFGEXP3),
+        S_BFGEXP3 => state!(SPR_BFE2, bright(2), 8, NULL, S_BFGEXP4),
+        S_BFGEXP4 => state!(SPR_BFE2, bright(3), 8, NULL, S_NULL),
+        S_EXPLODE1 => state!(SPR_MISL, bright(1), 8, A_Explode, S_EXPLODE2),
+        S_EXPLODE2 => state!(SPR_MISL, bright(2), 6, NULL, S_EXPLODE3),
+        S_EXPLODE3 => state!(SPR_MISL, bright(3), 4, NULL, S_NULL),
+        S_TFOG => state!(SPR_TFOG, bright(0), 6, NULL, S_TFOG01),
+        S_TFOG01 => state!(SPR_TFOG, bright(1), 6, NULL, S_TFOG02),
+        S_TFOG02 => state!(SPR_TFOG, bright(0), 6, NULL, S_TFOG2),
+        S_TFOG2 => state!(SPR_TFOG, bright(1), 6, NULL, S_TFOG3),
+        S_TFOG3 => state!(SPR_TFOG, bright(2), 6, NULL, S_TFOG4),
+        S_TFOG4 => state!(SPR_TFOG, bright(3), 6, NULL, S_TFOG5),
+        S_TFOG5 => state!(SPR_TFOG, bright(4), 6, NULL, S_TFOG6),
+        S_TFOG6 => state!(SPR_TFOG, bright(5), 6, NULL, S_TFOG7),
+        S_TFOG7 => state!(SPR_TFOG, bright(6), 6, NULL, S_TFOG8),
+        S_TFOG8 => state!(SPR_TFOG, bright(7), 6, NULL, S_TFOG9),
+        S_TFOG9 => state!(SPR_TFOG, bright(8), 6, NULL, S_TFOG10),
+        S_TFOG10 => state!(SPR_TFOG, bright(9), 6, NULL, S_NULL),
+        S_IFOG => state!(SPR_IFOG, bright(0), 6, NULL, S_IFOG01),
+        S_IFOG01 => state!(SPR_IFOG, bright(1), 6, NULL, S_IFOG02),
+        S_IFOG02 => state!(SPR_IFOG, bright(0), 6, NULL, S_IFOG2),
+        S_IFOG2 => state!(SPR_IFOG, bright(1), 6, NULL, S_IFOG3),
+        S_IFOG3 => state!(SPR_IFOG, bright(2), 6, NULL, S_IFOG4),
+        S_IFOG4 => state!(SPR_IFOG, bright(3), 6, NULL, S_IFOG5),
+        S_IFOG5 => state!(SPR_IFOG, bright(4), 6, NULL, S_NULL),
+        S_PLAY => state!(SPR_PLAY, 0, -1, NULL, S_NULL),
+        S_PLAY_RUN1 => state!(SPR_PLAY, 0, 4, NULL, S_PLAY_RUN2),
+        S_PLAY_RUN2 => state!(SPR_PLAY, 1, 4, NULL, S_PLAY_RUN3),
+        S_PLAY_RUN3 => state!(SPR_PLAY, 2, 4, NULL, S_PLAY_RUN4),
+        S_PLAY_RUN4 => state!(SPR_PLAY, 3, 4, NULL, S_PLAY_RUN1),
+        S_PLAY_ATK1 => state!(SPR_PLAY, 4, 12, NULL, S_PLAY),
+        S_PLAY_ATK2 => state!(SPR_PLAY, bright(5), 6, NULL, S_PLAY_ATK1),
+        S_PLAY_PAIN => state!(SPR_PLAY, 6, 4, NULL, S_PLAY_PAIN2),
+        S_PLAY_PAIN2 => state!(SPR_PLAY, 6, 4, A_Pain, S_PLAY),
+        S_PLAY_DIE1 => state!(SPR_PLAY, 7, 10, NULL, S_PLAY_DIE2),
+        S_PLAY_DIE2 => state!(SPR_PLAY, 8, 10, A_PlayerScream, S_PLAY_DIE3),
+        S_PLAY_DIE3 => state!(SPR_PLAY, 9, 10, A_Fall, S_PLAY_DIE4),
+        S_PLAY_DIE4 => state!(SPR_PLAY, 10, 10, NULL, S_PLAY_DIE5),
+        S_PLAY_DIE5 => state!(SPR_PLAY, 11, 10, NULL, S_PLAY_DIE6),
+        S_PLAY_DIE6 => state!(SPR_PLAY, 12, 10, NULL, S_PLAY_DIE7),
+        S_PLAY_DIE7 => state!(SPR_PLAY, 13, -1, NULL, S_NULL),
+        S_PLAY_XDIE1 => state!(SPR_PLAY, 14, 5, NULL, S_PLAY_XDIE2),
+        S_PLAY_XDIE2 => state!(SPR_PLAY, 15, 5, A_XScream, S_PLAY_XDIE3),
+        S_PLAY_XDIE3 => state!(SPR_PLAY, 16, 5, A_Fall, S_PLAY_XDIE4),
+        S_PLAY_XDIE4 => state!(SPR_PLAY, 17, 5, NULL, S_PLAY_XDIE5),
+        S_PLAY_XDIE5 => state!(SPR_PLAY, 18, 5, NULL, S_PLAY_XDIE6),
+        S_PLAY_XDIE6 => state!(SPR_PLAY, 19, 5, NULL, S_PLAY_XDIE7),
+        S_PLAY_XDIE7 => state!(SPR_PLAY, 20, 5, NULL, S_PLAY_XDIE8),
+        S_PLAY_XDIE8 => state!(SPR_PLAY, 21, 5, NULL, S_PLAY_XDIE9),
+        S_PLAY_XDIE9 => state!(SPR_PLAY, 22, -1, NULL, S_NULL),
+        S_POSS_STND => state!(SPR_POSS, 0, 10, A_Look, S_POSS_STND2),
+        S_POSS_STND2 => state!(SPR_POSS, 1, 10, A_Look, S_POSS_STND),
+        S_POSS_RUN1 => state!(SPR_POSS, 0, 4, A_Chase, S_POSS_RUN2),
+        S_POSS_RUN2 => state!(SPR_POSS, 0, 4, A_Chase, S_POSS_RUN3),
+        S_POSS_RUN3 => state!(SPR_POSS, 1, 4, A_Chase, S_POSS_RUN4),
+        S_POSS_RUN4 => state!(SPR_POSS, 1, 4, A_Chase, S_POSS_RUN5),
+        S_POSS_RUN5 => state!(SPR_POSS, 2, 4, A_Chase, S_POSS_RUN6),
+        S_POSS_RUN6 => state!(SPR_POSS, 2, 4, A_Chase, S_POSS_RUN7),
+        S_POSS_RUN7 => state!(SPR_POSS, 3, 4, A_Chase, S_POSS_RUN8),
+        S_POSS_RUN8 => state!(SPR_POSS, 3, 4, A_Chase, S_POSS_RUN1),
+        S_POSS_ATK1 => state!(SPR_POSS, 4, 10, A_FaceTarget, S_POSS_ATK2),
+        S_POSS_ATK2 => state!(SPR_POSS, 5, 8, A_PosAttack, S_POSS_ATK3),
+        S_POSS_ATK3 => state!(SPR_POSS, 4, 8, NULL, S_POSS_RUN1),
+        S_POSS_PAIN => state!(SPR_POSS, 6, 3, NULL, S_POSS_PAIN2),
+        S_POSS_PAIN2 => state!(SPR_POSS, 6, 3, A_Pain, S_POSS_RUN1),
+        S_POSS_DIE1 => state!(SPR_POSS, 7, 5, NULL, S_POSS_DIE2),
+        S_POSS_DIE2 => state!(SPR_POSS, 8, 5, A_Scream, S_POSS_DIE3),
+        S_POSS_DIE3 => state!(SPR_POSS, 9, 5, A_Fall, S_POSS_DIE4),
+        S_POSS_DIE4 => state!(SPR_POSS, 10, 5, NULL, S_POSS_DIE5),
+        S_POSS_DIE5 => state!(SPR_POSS, 11, -1, NULL, S_NULL),
+        S_POSS_XDIE1 => state!(SPR_POSS, 12, 5, NULL, S_POSS_XDIE2),
+        S_POSS_XDIE2 => state!(SPR_POSS, 13, 5, A_XScream, S_POSS_XDIE3),
+        S_POSS_XDIE3 => state!(SPR_POSS, 14, 5, A_Fall, S_POSS_XDIE4),
+        S_POSS_XDIE4 => state!(SPR_POSS, 15, 5, NULL, S_POSS_XDIE5),
+        S_POSS_XDIE5 => state!(SPR_POSS, 16, 5, NULL, S_POSS_XDIE6),
+        S_POSS_XDIE6 => state!(SPR_POSS, 17, 5, NULL, S_POSS_XDIE7),
+        S_POSS_XDIE7 => state!(SPR_POSS, 18, 5, NULL, S_POSS_XDIE8),
+        S_POSS_XDIE8 => state!(SPR_POSS, 19, 5, NULL, S_POSS_XDIE9),
+        S_POSS_XDIE9 => state!(SPR_POSS, 20, -1, NULL, S_NULL),
+        S_POSS_RAISE1 => state!(SPR_POSS, 10, 5, NULL, S_POSS_RAISE2),
+        S_POSS_RAISE2 => state!(SPR_POSS, 9, 5, NULL, S_POSS_RAISE3),
+        S_POSS_RAISE3 => state!(SPR_POSS, 8, 5, NULL, S_POSS_RAISE4),
+        S_POSS_RAISE4 => state!(SPR_POSS, 7, 5, NULL, S_POSS_RUN1),
+        S_SPOS_STND => state!(SPR_SPOS, 0, 10, A_Look, S_SPOS_STND2),
+        S_SPOS_STND2 => state!(SPR_SPOS, 1, 10, A_Look, S_SPOS_STND),
+        S_SPOS_RUN1 => state!(SPR_SPOS, 0, 3, A_Chase, S_SPOS_RUN2),
+        S_SPOS_RUN2 => state!(SPR_SPOS, 0, 3, A_Chase, S_SPOS_RUN3),
+        S_SPOS_RUN3 => state!(SPR_SPOS, 1, 3, A_Chase, S_SPOS_RUN4),
+        S_SPOS_RUN4 => state!(SPR_SPOS, 1, 3, A_Chase, S_SPOS_RUN5),
+        S_SPOS_RUN5 => state!(SPR_SPOS, 2, 3, A_Chase, S_SPOS_RUN6),
+        S_SPOS_RUN6 => state!(SPR_SPOS, 2, 3, A_Chase, S_SPOS_RUN7),
+        S_SPOS_RUN7 => state!(SPR_SPOS, 3, 3, A_Chase, S_SPOS_RUN8),
+        S_SPOS_RUN8 => state!(SPR_SPOS, 3, 3, A_Chase, S_SPOS_RUN1),
+        S_SPOS_ATK1 => state!(SPR_SPOS, 4, 10, A_FaceTarget, S_SPOS_ATK2),
+        S_SPOS_ATK2 => state!(SPR_SPOS, bright(5), 10, A_SPosAttack, S_SPOS_ATK3),
+        S_SPOS_ATK3 => state!(SPR_SPOS, 4, 10, NULL, S_SPOS_RUN1),
+        S_SPOS_PAIN => state!(SPR_SPOS, 6, 3, NULL, S_SPOS_PAIN2),
+        S_SPOS_PAIN2 => state!(SPR_SPOS, 6, 3, A_Pain, S_SPOS_RUN1),
+        S_SPOS_DIE1 => state!(SPR_SPOS, 7, 5, NULL, S_SPOS_DIE2),
+        S_SPOS_DIE2 => state!(SPR_SPOS, 8, 5, A_Scream, S_SPOS_DIE3),
+        S_SPOS_DIE3 => state!(SPR_SPOS, 9, 5, A_Fall, S_SPOS_DIE4),
+        S_SPOS_DIE4 => state!(SPR_SPOS, 10, 5, NULL, S_SPOS_DIE5),
+        S_SPOS_DIE5 => state!(SPR_SPOS, 11, -1, NULL, S_NULL),
+        S_SPOS_XDIE1 => state!(SPR_SPOS, 12, 5, NULL, S_SPOS_XDIE2),
+        S_SPOS_XDIE2 => state!(SPR_SPOS, 13, 5, A_XScream, S_SPOS_XDIE3),
+        S_SPOS_XDIE3 => state!(SPR_SPOS, 14, 5, A_Fall, S_SPOS_XDIE4),
+        S_SPOS_XDIE4 => state!(SPR_SPOS, 15, 5, NULL, S_SPOS_XDIE5),
+        S_SPOS_XDIE5 => state!(SPR_SPOS, 16, 5, NULL, S_SPOS_XDIE6),
+        S_SPOS_XDIE6 => state!(SPR_SPOS, 17, 5, NULL, S_SPOS_XDIE7),
+        S_SPOS_XDIE7 => state!(SPR_SPOS, 18, 5, NULL, S_SPOS_XDIE8),
+        S_SPOS_XDIE8 => state!(SPR_SPOS, 19, 5, NULL, S_SPOS_XDIE9),
+        S_SPOS_XDIE9 => state!(SPR_SPOS, 20, -1, NULL, S_NULL),
+        S_SPOS_RAISE1 => state!(SPR_SPOS, 11, 5, NULL, S_SPOS_RAISE2),
+        S_SPOS_RAISE2 => state!(SPR_SPOS, 10, 5, NULL, S_SPOS_RAISE3),
+        S_SPOS_RAISE3 => state!(SPR_SPOS, 9, 5, NULL, S_SPOS_RAISE4),
+        S_SPOS_RAISE4 => state!(SPR_SPOS, 8, 5, NULL, S_SPOS_RAISE5),
+        S_SPOS_RAISE5 => state!(SPR_SPOS, 7, 5, NULL, S_SPOS_RUN1),
+        S_VILE_STND => state!(SPR_VILE, 0, 10, A_Look, S_VILE_STND2),
+        S_VILE_STND2 => state!(SPR_VILE, 1, 10, A_Look, S_VILE_STND),
+        S_VILE_RUN1 => state!(SPR_VILE, 0, 2, A_VileChase, S_VILE_RUN2),
+        S_VILE_RUN2 => state!(SPR_VILE, 0, 2, A_VileChase, S_VILE_RUN3),
+        S_VILE_RUN3 => state!(SPR_VILE, 1, 2, A_VileChase, S_VILE_RUN4),
+        S_VILE_RUN4 => state!(SPR_VILE, 1, 2, A_VileChase, S_VILE_RUN5),
+        S_VILE_RUN5 => state!(SPR_VILE, 2, 2, A_VileChase, S_VILE_RUN6),
+        S_VILE_RUN6 => state!(SPR_VILE, 2, 2, A_VileChase, S_VILE_RUN7),
+        S_VILE_RUN7 => state!(SPR_VILE, 3, 2, A_VileChase, S_VILE_RUN8),
+        S_VILE_RUN8 => state!(SPR_VILE, 3, 2, A_VileChase, S_VILE_RUN9),
+        S_VILE_RUN9 => state!(SPR_VILE, 4, 2, A_VileChase, S_VILE_RUN10),
+        S_VILE_RUN10 => state!(SPR_VILE, 4, 2, A_VileChase, S_VILE_RUN11),
+        S_VILE_RUN11 => state!(SPR_VILE, 5, 2, A_VileChase, S_VILE_RUN12),
+        S_VILE_RUN12 => state!(SPR_VILE, 5, 2, A_VileChase, S_VILE_RUN1),
+        S_VILE_ATK1 => state!(SPR_VILE, bright(6), 0, A_VileStart, S_VILE_ATK2),
+        S_VILE_ATK2 => state!(SPR_VILE, bright(6), 10, A_FaceTarget, S_VILE_ATK3),
+        S_VILE_ATK3 => state!(SPR_VILE, bright(7), 8, A_VileTarget, S_VILE_ATK4),
+        S_VILE_ATK4 => state!(SPR_VILE, bright(8), 8, A_FaceTarget, S_VILE_ATK5),
+        S_VILE_ATK5 => state!(SPR_VILE, bright(9), 8, A_FaceTarget, S_VILE_ATK6),
+        S_VILE_ATK6 => state!(SPR_VILE, bright(10), 8, A_FaceTarget, S_VILE_ATK7),
+        S_VILE_ATK7 => state!(SPR_VILE, bright(11), 8, A_FaceTarget, S_VILE_ATK8),
+        S_VILE_ATK8 => state!(SPR_VILE, bright(12), 8, A_FaceTarget, S_VILE_ATK9),
+        S_VILE_ATK9 => state!(SPR_VILE, bright(13), 8, A_FaceTarget, S_VILE_ATK10),
+        S_VILE_ATK10 => state!(SPR_VILE, bright(14), 8, A_VileAttack, S_VILE_ATK11),
+        S_VILE_ATK11 => state!(SPR_VILE, bright(15), 20, NULL, S_VILE_RUN1),
+        S_VILE_HEAL1 => state!(SPR_VILE, bright(26), 10, NULL, S_VILE_HEAL2),
+        S_VILE_HEAL2 => state!(SPR_VILE, bright(27), 10, NULL, S_VILE_HEAL3),
+        S_VILE_HEAL3 => state!(SPR_VILE, bright(28), 10, NULL, S_VILE_RUN1),
+        S_VILE_PAIN => state!(SPR_VILE, 16, 5, NULL, S_VILE_PAIN2),
+        S_VILE_PAIN2 => state!(SPR_VILE, 16, 5, A_Pain, S_VILE_RUN1),
+        S_VILE_DIE1 => state!(SPR_VILE, 16, 7, NULL, S_VILE_DIE2),
+        S_VILE_DIE2 => state!(SPR_VILE, 17, 7, A_Scream, S_VILE_DIE3),
+        S_VILE_DIE3 => state!(SPR_VILE, 18, 7, A_Fall, S_VILE_DIE4),
+        S_VILE_DIE4 => state!(SPR_VILE, 19, 7, NULL, S_VILE_DIE5),
+        S_VILE_DIE5 => state!(SPR_VILE, 20, 7, NULL, S_VILE_DIE6),
+        S_VILE_DIE6 => state!(SPR_VILE, 21, 7, NULL, S_VILE_DIE7),
+        S_VILE_DIE7 => state!(SPR_VILE, 22, 7, NULL, S_VILE_DIE8),
+        S_VILE_DIE8 => state!(SPR_VILE, 23, 5, NULL, S_VILE_DIE9),
+        S_VILE_DIE9 => state!(SPR_VILE, 24, 5, NULL, S_VILE_DIE10),
+        S_VILE_DIE10 => state!(SPR_VILE, 25, -1, NULL, S_NULL),
+        S_FIRE1 => state!(SPR_FIRE, bright(0), 2, A_StartFire, S_FIRE2),
+        S_FIRE2 => state!(SPR_FIRE, bright(1), 2, A_Fire, S_FIRE3),
+        S_FIRE3 => state!(SPR_FIRE, bright(0), 2, A_Fire, S_FIRE4),
+        S_FIRE4 => state!(SPR_FIRE, bright(1), 2, A_Fire, S_FIRE5),
+        S_FIRE5 => state!(SPR_FIRE, bright(2), 2, A_FireCrackle, S_FIRE6),
+        S_FIRE6 => state!(SPR_FIRE, bright(1), 2, A_Fire, S_FIRE7),
+        S_FIRE7 => state!(SPR_FIRE, bright(2), 2, A_Fire, S_FIRE8),
+        S_FIRE8 => state!(SPR_FIRE, bright(1), 2, A_Fire, S_FIRE9),
+        S_FIRE9 => state!(SPR_FIRE, bright(2), 2, A_Fire, S_FIRE10),
+        S_FIRE10 => state!(SPR_FIRE, bright(3), 2, A_Fire, S_FIRE11),
+        S_FIRE11 => state!(SPR_FIRE, bright(2), 2, A_Fire, S_FIRE12),
+        S_FIRE12 => state!(SPR_FIRE, bright(3), 2, A_Fire, S_FIRE13),
+        S_FIRE13 => state!(SPR_FIRE, bright(2), 2, A_Fire, S_FIRE14),
+        S_FIRE14 => state!(SPR_FIRE, bright(3), 2, A_Fire, S_FIRE15),
+        S_FIRE15 => state!(SPR_FIRE, bright(4), 2, A_Fire, S_FIRE16),
+        S_FIRE16 => state!(SPR_FIRE, bright(3), 2, A_Fire, S_FIRE17),
+        S_FIRE17 => state!(SPR_FIRE, bright(4), 2, A_Fire, S_FIRE18),
+        S_FIRE18 => state!(SPR_FIRE, bright(3), 2, A_Fire, S_FIRE19),
+        S_FIRE19 => state!(SPR_FIRE, bright(4), 2, A_FireCrackle, S_FIRE20),
+        S_FIRE20 => state!(SPR_FIRE, bright(5), 2, A_Fire, S_FIRE21),
+        S_FIRE21 => state!(SPR_FIRE, bright(4), 2, A_Fire, S_FIRE22),
+        S_FIRE22 => state!(SPR_FIRE, bright(5), 2, A_Fire, S_FIRE23),
+        S_FIRE23 => state!(SPR_FIRE, bright(4), 2, A_Fire, S_FIRE24),
+        S_FIRE24 => state!(SPR_FIRE, bright(5), 2, A_Fire, S_FIRE25),
+        S_FIRE25 => state!(SPR_FIRE, bright(6), 2, A_Fire, S_FIRE26),
+        S_FIRE26 => state!(SPR_FIRE, bright(7), 2, A_Fire, S_FIRE27),
+        S_FIRE27 => state!(SPR_FIRE, bright(6), 2, A_Fire, S_FIRE28),
+        S_FIRE28 => state!(SPR_FIRE, bright(7), 2, A_Fire, S_FIRE29),
+        S_FIRE29 => state!(SPR_FIRE, bright(6), 2, A_Fire, S_FIRE30),
+        S_FIRE30 => state!(SPR_FIRE, bright(7), 2, A_Fire, S_NULL),
+        S_SMOKE1 => state!(SPR_PUFF, 1, 4, NULL, S_SMOKE2),
+        S_SMOKE2 => state!(SPR_PUFF, 2, 4, NULL, S_SMOKE3),
+        S_SMOKE3 => state!(SPR_PUFF, 1, 4, NULL, S_SMOKE4),
+        S_SMOKE4 => state!(SPR_PUFF, 2, 4, NULL, S_SMOKE5),
+        S_SMOKE5 => state!(SPR_PUFF, 3, 4, NULL, S_NULL),
+        S_TRACER => state!(SPR_FATB, bright(0), 2, A_Tracer, S_TRACER2),
+        S_TRACER2 => state!(SPR_FATB, bright(1), 2, A_Tracer, S_TRACER),
+        S_TRACEEXP1 => state!(SPR_FBXP, bright(0), 8, NULL, S_TRACEEXP2),
+        S_TRACEEXP2 => state!(SPR_FBXP, bright(1), 6, NULL, S_TRACEEXP3),
+        S_TRACEEXP3 => state!(SPR_FBXP, bright(2), 4, NULL, S_NULL),
+        S_SKEL_STND => state!(SPR_SKEL, 0, 10, A_Look, S_SKEL_STND2),
+        S_SKEL_STND2 => state!(SPR_SKEL, 1, 10, A_Look, S_SKEL_STND),
+        S_SKEL_RUN1 => state!(SPR_SKEL, 0, 2, A_Chase, S_SKEL_RUN2),
+        S_SKEL_RUN2 => state!(SPR_SKEL, 0, 2, A_Chase, S_SKEL_RUN3),
+        S_SKEL_RUN3 => state!(SPR_SKEL, 1, 2, A_Chase, S_SKEL_RUN4),
+        S_SKEL_RUN4 => state!(SPR_SKEL, 1, 2, A_Chase, S_SKEL_RUN5),
+        S_SKEL_RUN5 => state!(SPR_SKEL, 2, 2, A_Chase, S_SKEL_RUN6),
+        S_SKEL_RUN6 => state!(SPR_SKEL, 2, 2, A_Chase, S_SKEL_RUN7),
+        S_SKEL_RUN7 => state!(SPR_SKEL, 3, 2, A_Chase, S_SKEL_RUN8),
+        S_SKEL_RUN8 => state!(SPR_SKEL, 3, 2, A_Chase, S_SKEL_RUN9),
+        S_SKEL_RUN9 => state!(SPR_SKEL, 4, 2, A_Chase, S_SKEL_RUN10),
+        S_SKEL_RUN10 => state!(SPR_SKEL, 4, 2, A_Chase, S_SKEL_RUN11),
+        S_SKEL_RUN11 => state!(SPR_SKEL, 5, 2, A_Chase, S_SKEL_RUN12),
+        S_SKEL_RUN12 => state!(SPR_SKEL, 5, 2, A_Chase, S_SKEL_RUN1),
+        S_SKEL_FIST1 => state!(SPR_SKEL, 6, 0, A_FaceTarget, S_SKEL_FIST2),
+        S_SKEL_FIST2 => state!(SPR_SKEL, 6, 6, A_SkelWhoosh, S_SKEL_FIST3),
+        S_SKEL_FIST3 => state!(SPR_SKEL, 7, 6, A_FaceTarget, S_SKEL_FIST4),
+        S_SKEL_FIST4 => state!(SPR_SKEL, 8, 6, A_SkelFist, S_SKEL_RUN1),
+        S_SKEL_MISS1 => state!(SPR_SKEL, bright(9), 0, A_FaceTarget, S_SKEL_MISS2),
+        S_SKEL_MISS2 => state!(SPR_SKEL, bright(9), 10, A_FaceTarget, S_SKEL_MISS3),
+        S_SKEL_MISS3 => state!(SPR_SKEL, 10, 10, A_SkelMissile, S_SKEL_MISS4),
+        S_SKEL_MISS4 => state!(SPR_SKEL, 10, 10, A_FaceTarget, S_SKEL_RUN1),
+        S_SKEL_PAIN => state!(SPR_SKEL, 11, 5, NULL, S_SKEL_PAIN2),
+        S_SKEL_PAIN2 => state!(SPR_SKEL, 11, 5, A_Pain, S_SKEL_RUN1),
+        S_SKEL_DIE1 => state!(SPR_SKEL, 11, 7, NULL, S_SKEL_DIE2),
+        S_SKEL_DIE2 => state!(SPR_SKEL, 12, 7, NULL, S_SKEL_DIE3),
+        S_SKEL_DIE3 => state!(SPR_SKEL, 13, 7, A_Scream, S_SKEL_DIE4),
+        S_SKEL_DIE4 => state!(SPR_SKEL, 14, 7, A_Fall, S_SKEL_DIE5),
+        S_SKEL_DIE5 => state!(SPR_SKEL, 15, 7, NULL, S_SKEL_DIE6),
+        S_SKEL_DIE6 => state!(SPR_SKEL, 16, -1, NULL, S_NULL),
+        S_SKEL_RAISE1 => state!(SPR_SKEL, 16, 5, NULL, S_SKEL_RAISE2),
+        S_SKEL_RAISE2 => state!(SPR_SKEL, 15, 5, NULL, S_SKEL_RAISE3),
+        S_SKEL_RAISE3 => state!(SPR_SKEL, 14, 5, NULL, S_SKEL_RAISE4),
+        S_SKEL_RAISE4 => state!(SPR_SKEL, 13, 5, NULL, S_SKEL_RAISE5),
+        S_SKEL_RAISE5 => state!(SPR_SKEL, 12, 5, NULL, S_SKEL_RAISE6),
+        S_SKEL_RAISE6 => state!(SPR_SKEL, 11, 5, NULL, S_SKEL_RUN1),
+        S_FATSHOT1 => state!(SPR_MANF, bright(0), 4, NULL, S_FATSHOT2),
+        S_FATSHOT2 => state!(SPR_MANF, bright(1), 4, NULL, S_FATSHOT1),
+        S_FATSHOTX1 => state!(SPR_MISL, bright(1), 8, NULL, S_FATSHOTX2),
+        S_FATSHOTX2 => state!(SPR_MISL, bright(2), 6, NULL, S_FATSHOTX3),
+        S_FATSHOTX3 => state!(SPR_MISL, bright(3), 4, NULL, S_NULL),
+        S_FATT_STND => state!(SPR_FATT, 0, 15, A_Look, S_FATT_STND2),
+        S_FATT_STND2 => state!(SPR_FATT, 1, 15, A_Look, S_FATT_STND),
+        S_FATT_RUN1 => state!(SPR_FATT, 0, 4, A_Chase, S_FATT_RUN2),
+        S_FATT_RUN2 => state!(SPR_FATT, 0, 4, A_Chase, S_FATT_RUN3),
+        S_FATT_RUN3 => state!(SPR_FATT, 1, 4, A_Chase, S_FATT_RUN4),
+        S_FATT_RUN4 => state!(SPR_FATT, 1, 4, A_Chase, S_FATT_RUN5),
+        S_FATT_RUN5 => state!(SPR_FATT, 2, 4, A_Chase, S_FATT_RUN6),
+        S_FATT_RUN6 => state!(SPR_FATT, 2, 4, A_Chase, S_FATT_RUN7),
+        S_FATT_RUN7 => state!(SPR_FATT, 3, 4, A_Chase, S_FATT_RUN8),
+        S_FATT_RUN8 => state!(SPR_FATT, 3, 4, A_Chase, S_FATT_RUN9),
+        S_FATT_RUN9 => state!(SPR_FATT, 4, 4, A_Chase, S_FATT_RUN10),
+        S_FATT_RUN10 => state!(SPR_FATT, 4, 4, A_Chase, S_FATT_RUN11),
+        S_FATT_RUN11 => state!(SPR_FATT, 5, 4, A_Chase, S_FATT_RUN12),
+        S_FATT_RUN12 => state!(SPR_FATT, 5, 4, A_Chase, S_FATT_RUN1),
+        S_FATT_ATK1 => state!(SPR_FATT, 6, 20, A_FatRaise, S_FATT_ATK2),
+        S_FATT_ATK2 => state!(SPR_FATT, bright(7), 10, A_FatAttack1, S_FATT_ATK3),
+        S_FATT_ATK3 => state!(SPR_FATT, 8, 5, A_FaceTarget, S_FATT_ATK4),
+        S_FATT_ATK4 => state!(SPR_FATT, 6, 5, A_FaceTarget, S_FATT_ATK5),
+        S_FATT_ATK5 => state!(SPR_FATT, bright(7), 10, A_FatAttack2, S_FATT_ATK6),
+        S_FATT_ATK6 => state!(SPR_FATT, 8, 5, A_FaceTarget, S_FATT_ATK7),
+        S_FATT_ATK7 => state!(SPR_FATT, 6, 5, A_FaceTarget, S_FATT_ATK8),
+        S_FATT_ATK8 => state!(SPR_FATT, bright(7), 10, A_FatAttack3, S_FATT_ATK9),
+        S_FATT_ATK9 => state!(SPR_FATT, 8, 5, A_FaceTarget, S_FATT_ATK10),
+        S_FATT_ATK10 => state!(SPR_FATT, 6, 5, A_FaceTarget, S_FATT_RUN1),
+        S_FATT_PAIN => state!(SPR_FATT, 9, 3, NULL, S_FATT_PAIN2),
+        S_FATT_PAIN2 => state!(SPR_FATT, 9, 3, A_Pain, S_FATT_RUN1),
+        S_FATT_DIE1 => state!(SPR_FATT, 10, 6, NULL, S_FATT_DIE2),
+        S_FATT_DIE2 => state!(SPR_FATT, 11, 6, A_Scream, S_FATT_DIE3),
+        S_FATT_DIE3 => state!(SPR_FATT, 12, 6, A_Fall, S_FATT_DIE4),
+        S_FATT_DIE4 => state!(SPR_FATT, 13, 6, NULL, S_FATT_DIE5),
+        S_FATT_DIE5 => state!(SPR_FATT, 14, 6, NULL, S_FATT_DIE6),
+        S_FATT_DIE6 => state!(SPR_FATT, 15, 6, NULL, S_FATT_DIE7),
+        S_FATT_DIE7 => state!(SPR_FATT, 16, 6, NULL, S_FATT_DIE8),
+        S_FATT_DIE8 => state!(SPR_FATT, 17, 6, NULL, S_FATT_DIE9),
+        S_FATT_DIE9 => state!(SPR_FATT, 18, 6, NULL, S_FATT_DIE10),
+        S_FATT_DIE10 => state!(SPR_FATT, 19, -1, A_BossDeath, S_NULL),
+        S_FATT_RAISE1 => state!(SPR_FATT, 17, 5, NULL, S_FATT_RAISE2),
+        S_FATT_RAISE2 => state!(SPR_FATT, 16, 5, NULL, S_FATT_RAISE3),
+        S_FATT_RAISE3 => state!(SPR_FATT, 15, 5, NULL, S_FATT_RAISE4),
+        S_FATT_RAISE4 => state!(SPR_FATT, 14, 5, NULL, S_FATT_RAISE5),
+        S_FATT_RAISE5 => state!(SPR_FATT, 13, 5, NULL, S_FATT_RAISE6),
+        S_FATT_RAISE6 => state!(SPR_FATT, 12, 5, NULL, S_FATT_RAISE7),
+        S_FATT_RAISE7 => state!(SPR_FATT, 11, 5, NULL, S_FATT_RAISE8),
+        S_FATT_RAISE8 => state!(SPR_FATT, 10, 5, NULL, S_FATT_RUN1),
+        S_CPOS_STND => state!(SPR_CPOS, 0, 10, A_Look, S_CPOS_STND2),
+        S_CPOS_STND2 => state!(SPR_CPOS, 1, 10, A_Look, S_CPOS_STND),
+        S_CPOS_RUN1 => state!(SPR_CPOS, 0, 3, A_Chase, S_CPOS_RUN2),
+        S_CPOS_RUN2 => state!(SPR_CPOS, 0, 3, A_Chase, S_CPOS_RUN3),
+        S_CPOS_RUN3 => state!(SPR_CPOS, 1, 3, A_Chase, S_CPOS_RUN4),
+        S_CPOS_RUN4 => state!(SPR_CPOS, 1, 3, A_Chase, S_CPOS_RUN5),
+        S_CPOS_RUN5 => state!(SPR_CPOS, 2, 3, A_Chase, S_CPOS_RUN6),
+        S_CPOS_RUN6 => state!(SPR_CPOS, 2, 3, A_Chase, S_CPOS_RUN7),
+        S_CPOS_RUN7 => state!(SPR_CPOS, 3, 3, A_Chase, S_CPOS_RUN8),
+        S_CPOS_RUN8 => state!(SPR_CPOS, 3, 3, A_Chase, S_CPOS_RUN1),
+        S_CPOS_ATK1 => state!(SPR_CPOS, 4, 10, A_FaceTarget, S_CPOS_ATK2),
+        S_CPOS_ATK2 => state!(SPR_CPOS, bright(4), 4, A_CPosAttack, S_CPOS_ATK3),
+        S_CPOS_ATK3 => state!(SPR_CPOS, bright(5), 4, A_CPosAttack, S_CPOS_ATK4),
+        S_CPOS_ATK4 => state!(SPR_CPOS, 5, 1, A_CPosRefire, S_CPOS_ATK2),
+        S_CPOS_PAIN => state!(SPR_CPOS, 6, 3, NULL, S_CPOS_PAIN2),
+        S_CPOS_PAIN2 => state!(SPR_CPOS, 6, 3, A_Pain, S_CPOS_RUN1),
+        S_CPOS_DIE1 => state!(SPR_CPOS, 7, 5, NULL, S_CPOS_DIE2),
+        S_CPOS_DIE2 => state!(SPR_CPOS, 8, 5, A_Scream, S_CPOS_DIE3),
+        S_CPOS_DIE3 => state!(SPR_CPOS, 9, 5, A_Fall, S_CPOS_DIE4),
+        S_CPOS_DIE4 => state!(SPR_CPOS, 10, 5, NULL, S_CPOS_DIE5),
+        S_CPOS_DIE5 => state!(SPR_CPOS, 11, 5, NULL, S_CPOS_DIE6),
+        S_CPOS_DIE6 => state!(SPR_CPOS, 12, 5, NULL, S_CPOS_DIE7),
+        S_CPOS_DIE7 => state!(SPR_CPOS, 13, -1, NULL, S_NULL),
+        S_CPOS_XDIE1 => state!(SPR_CPOS, 14, 5, NULL, S_CPOS_XDIE2),
+        S_CPOS_XDIE2 => state!(SPR_CPOS, 15, 5, A_XScream, S_CPOS_XDIE3),
+        S_CPOS_XDIE3 => state!(SPR_CPOS, 16, 5, A_Fall, S_CPOS_XDIE4),
+        S_CPOS_XDIE4 => state!(SPR_CPOS, 17, 5, NULL, S_CPOS_XDIE5),
+        S_CPOS_XDIE5 => state!(SPR_CPOS, 18, 5, NULL, S_CPOS_XDIE6),
+        S_CPOS_XDIE6 => state!(SPR_CPOS, 19, -1, NULL, S_NULL),
+        S_CPOS_RAISE1 => state!(SPR_CPOS, 13, 5, NULL, S_CPOS_RAISE2),
+        S_CPOS_RAISE2 => state!(SPR_CPOS, 12, 5, NULL, S_CPOS_RAISE3),
+        S_CPOS_RAISE3 => state!(SPR_CPOS, 11, 5, NULL, S_CPOS_RAISE4),
+        S_CPOS_RAISE4 => state!(SPR_CPOS, 10, 5, NULL, S_CPOS_RAISE5),
+        S_CPOS_RAISE5 => state!(SPR_CPOS, 9, 5, NULL, S_CPOS_RAISE6),
+        S_CPOS_RAISE6 => state!(SPR_CPOS, 8, 5, NULL, S_CPOS_RAISE7),
+        S_CPOS_RAISE7 => state!(SPR_CPOS, 7, 5, NULL, S_CPOS_RUN1),
+        S_TROO_STND => state!(SPR_TROO, 0, 10, A_Look, S_TROO_STND2),
+        S_TROO_STND2 => state!(SPR_TROO, 1, 10, A_Look, S_TROO_STND),
+        S_TROO_RUN1 => state!(SPR_TROO, 0, 3, A_Chase, S_TROO_RUN2),
+        S_TROO_RUN2 => state!(SPR_TROO, 0, 3, A_Chase, S_TROO_RUN3),
+        S_TROO_RUN3 => state!(SPR_TROO, 1, 3, A_Chase, S_TROO_RUN4),
+        S_TROO_RUN4 => state!(SPR_TROO, 1, 3, A_Chase, S_TROO_RUN5),
+        S_TROO_RUN5 => state!(SPR_TROO, 2, 3, A_Chase, S_TROO_RUN6),
+        S_TROO_RUN6 => state!(SPR_TROO, 2, 3, A_Chase, S_TROO_RUN7),
+        S_TROO_RUN7 => state!(SPR_TROO, 3, 3, A_Chase, S_TROO_RUN8),
+        S_TROO_RUN8 => state!(SPR_TROO, 3, 3, A_Chase, S_TROO_RUN1),
+        S_TROO_ATK1 => state!(SPR_TROO, 4, 8, A_FaceTarget, S_TROO_ATK2),
+        S_TROO_ATK2 => state!(SPR_TROO, 5, 8, A_FaceTarget, S_TROO_ATK3),
+        S_TROO_ATK3 => state!(SPR_TROO, 6, 6, A_TroopAttack, S_TROO_RUN1),
+        S_TROO_PAIN => state!(SPR_TROO, 7, 2, NULL, S_TROO_PAIN2),
+        S_TROO_PAIN2 => state!(SPR_TROO, 7, 2, A_Pain, S_TROO_RUN1),
+        S_TROO_DIE1 => state!(SPR_TROO, 8, 8, NULL, S_TROO_DIE2),
+        S_TROO_DIE2 => state!(SPR_TROO, 9, 8, A_Scream, S_TROO_DIE3),
+        S_TROO_DIE3 => state!(SPR_TROO, 10, 6, NULL, S_TROO_DIE4),
+        S_TROO_DIE4 => state!(SPR_TROO, 11, 6, A_Fall, S_TROO_DIE5),
+        S_TROO_DIE5 => state!(SPR_TROO, 12, -1, NULL, S_NULL),
+        S_TROO_XDIE1 => state!(SPR_TROO, 13, 5, NULL, S_TROO_XDIE2),
+        S_TROO_XDIE2 => state!(SPR_TROO, 14, 5, A_XScream, S_TROO_XDIE3),
+        S_TROO_XDIE3 => state!(SPR_TROO, 15, 5, NULL, S_TROO_XDIE4),
+        S_TROO_XDIE4 => state!(SPR_TROO, 16, 5, A_Fall, S_TROO_XDIE5),
+        S_TROO_XDIE5 => state!(SPR_TROO, 17, 5, NULL, S_TROO_XDIE6),
+        S_TROO_XDIE6 => state!(SPR_TROO, 18, 5, NULL, S_TROO_XDIE7),
+        S_TROO_XDIE7 => state!(SPR_TROO, 19, 5, NULL, S_TROO_XDIE8),
+        S_TROO_XDIE8 => state!(SPR_TROO, 20, -1, NULL, S_NULL),
+        S_TROO_RAISE1 => state!(SPR_TROO, 12, 8, NULL, S_TROO_RAISE2),
+        S_TROO_RAISE2 => state!(SPR_TROO, 11, 8, NULL, S_TROO_RAISE3),
+        S_TROO_RAISE3 => state!(SPR_TROO, 10, 6, NULL, S_TROO_RAISE4),
+        S_TROO_RAISE4 => state!(SPR_TROO, 9, 6, NULL, S_TROO_RAISE5),
+        S_TROO_RAISE5 => state!(SPR_TROO, 8, 6, NULL, S_TROO_RUN1),
+        S_SARG_STND => state!(SPR_SARG, 0, 10, A_Look, S_SARG_STND2),
+        S_SARG_STND2 => state!(SPR_SARG, 1, 10, A_Look, S_SARG_STND),
+        S_SARG_RUN1 => state!(SPR_SARG, 0, 2, A_Chase, S_SARG_RUN2),
+        S_SARG_RUN2 => state!(SPR_SARG, 0, 2, A_Chase, S_SARG_RUN3),
+        S_SARG_RUN3 => state!(SPR_SARG, 1, 2, A_Chase, S_SARG_RUN4),
+        S_SARG_RUN4 => state!(SPR_SARG, 1, 2, A_Chase, S_SARG_RUN5),
+        S_SARG_RUN5 => state!(SPR_SARG, 2, 2, A_Chase, S_SARG_RUN6),
+        S_SARG_RUN6 => state!(SPR_SARG, 2, 2, A_Chase, S_SARG_RUN7),
+        S_SARG_RUN7 => state!(SPR_SARG, 3, 2, A_Chase, S_SARG_RUN8),
+        S_SARG_RUN8 => state!(SPR_SARG, 3, 2, A_Chase, S_SARG_RUN1),
+        S_SARG_ATK1 => state!(SPR_SARG, 4, 8, A_FaceTarget, S_SARG_ATK2),
+        S_SARG_ATK2 => state!(SPR_SARG, 5, 8, A_FaceTarget, S_SARG_ATK3),
+        S_SARG_ATK3 => state!(SPR_SARG, 6, 8, A_SargAttack, S_SARG_RUN1),
+        S_SARG_PAIN => state!(SPR_SARG, 7, 2, NULL, S_SARG_PAIN2),
+        S_SARG_PAIN2 => state!(SPR_SARG, 7, 2, A_Pain, S_SARG_RUN1),
+        S_SARG_DIE1 => state!(SPR_SARG, 8, 8, NULL, S_SARG_DIE2),
+        S_SARG_DIE2 => state!(SPR_SARG, 9, 8, A_Scream, S_SARG_DIE3),
+        S_SARG_DIE3 => state!(SPR_SARG, 10, 4, NULL, S_SARG_DIE4),
+        S_SARG_DIE4 => state!(SPR_SARG, 11, 4, A_Fall, S_SARG_DIE5),
+        S_SARG_DIE5 => state!(SPR_SARG, 12, 4, NULL, S_SARG_DIE6),
+        S_SARG_DIE6 => state!(SPR_SARG, 13, -1, NULL, S_NULL),
+        S_SARG_RAISE1 => state!(SPR_SARG, 13, 5, NULL, S_SARG_RAISE2),
+        S_SARG_RAISE2 => state!(SPR_SARG, 12, 5, NULL, S_SARG_RAISE3),
+        S_SARG_RAISE3 => state!(SPR_SARG, 11, 5, NULL, S_SARG_RAISE4),
+        S_SARG_RAISE4 => state!(SPR_SARG, 10, 5, NULL, S_SARG_RAISE5),
+        S_SARG_RAISE5 => state!(SPR_SARG, 9, 5, NULL, S_SARG_RAISE6),
+        S_SARG_RAISE6 => state!(SPR_SARG, 8, 5, NULL, S_SARG_RUN1),
+        S_HEAD_STND => state!(SPR_HEAD, 0, 10, A_Look, S_HEAD_STND),
+        S_HEAD_RUN1 => state!(SPR_HEAD, 0, 3, A_Chase, S_HEAD_RUN1),
+        S_HEAD_ATK1 => state!(SPR_HEAD, 1, 5, A_FaceTarget, S_HEAD_ATK2),
+        S_HEAD_ATK2 => state!(SPR_HEAD, 2, 5, A_FaceTarget, S_HEAD_ATK3),
+        S_HEAD_ATK3 => state!(SPR_HEAD, bright(3), 5, A_HeadAttack, S_HEAD_RUN1),
+        S_HEAD_PAIN => state!(SPR_HEAD, 4, 3, NULL, S_HEAD_PAIN2),
+        S_HEAD_PAIN2 => state!(SPR_HEAD, 4, 3, A_Pain, S_HEAD_PAIN3),
+        S_HEAD_PAIN3 => state!(SPR_HEAD, 5, 6, NULL, S_HEAD_RUN1),
+        S_HEAD_DIE1 => state!(SPR_HEAD, 6, 8, NULL, S_HEAD_DIE2),
+        S_HEAD_DIE2 => state!(SPR_HEAD, 7, 8, A_Scream, S_HEAD_DIE3),
+        S_HEAD_DIE3 => state!(SPR_HEAD, 8, 8, NULL, S_HEAD_DIE4),
+        S_HEAD_DIE4 => state!(SPR_HEAD, 9, 8, NULL, S_HEAD_DIE5),
+        S_HEAD_DIE5 => state!(SPR_HEAD, 10, 8, A_Fall, S_HEAD_DIE6),
+        S_HEAD_DIE6 => state!(SPR_HEAD, 11, -1, NULL, S_NULL),
+        S_HEAD_RAISE1 => state!(SPR_HEAD, 11, 8, NULL, S_HEAD_RAISE2),
+        S_HEAD_RAISE2 => state!(SPR_HEAD, 10, 8, NULL, S_HEAD_RAISE3),
+        S_HEAD_RAISE3 => state!(SPR_HEAD, 9, 8, NULL, S_HEAD_RAISE4),
+        S_HEAD_RAISE4 => state!(SPR_HEAD, 8, 8, NULL, S_HEAD_RAISE5),
+        S_HEAD_RAISE5 => state!(SPR_HEAD, 7, 8, NULL, S_HEAD_RAISE6),
+        S_HEAD_RAISE6 => state!(SPR_HEAD, 6, 8, NULL, S_HEAD_RUN1),
+        S_BRBALL1 => state!(SPR_BAL7, bright(0), 4, NULL, S_BRBALL2),
+        S_BRBALL2 => state!(SPR_BAL7, bright(1), 4, NULL, S_BRBALL1),
+        S_BRBALLX1 => state!(SPR_BAL7, bright(2), 6, NULL, S_BRBALLX2),
+        S_BRBALLX2 => state!(SPR_BAL7, bright(3), 6, NULL, S_BRBALLX3),
+        S_BRBALLX3 => state!(SPR_BAL7, bright(4), 6, NULL, S_NULL),
+        S_BOSS_STND => state!(SPR_BOSS, 0, 10, A_Look, S_BOSS_STND2),
+        S_BOSS_STND2 => state!(SPR_BOSS, 1, 10, A_Look, S_BOSS_STND),
+        S_BOSS_RUN1 => state!(SPR_BOSS, 0, 3, A_Chase, S_BOSS_RUN2),
+        S_BOSS_RUN2 => state!(SPR_BOSS, 0, 3, A_Chase, S_BOSS_RUN3),
+        S_BOSS_RUN3 => state!(SPR_BOSS, 1, 3, A_Chase, S_BOSS_RUN4),
+        S_BOSS_RUN4 => state!(SPR_BOSS, 1, 3, A_Chase, S_BOSS_RUN5),
+        S_BOSS_RUN5 => state!(SPR_BOSS, 2, 3, A_Chase, S_BOSS_RUN6),
+        S_BOSS_RUN6 => state!(SPR_BOSS, 2, 3, A_Chase, S_BOSS_RUN7),
+        S_BOSS_RUN7 => state!(SPR_BOSS, 3, 3, A_Chase, S_BOSS_RUN8),
+        S_BOSS_RUN8 => state!(SPR_BOSS, 3, 3, A_Chase, S_BOSS_RUN1),
+        S_BOSS_ATK1 => state!(SPR_BOSS, 4, 8, A_FaceTarget, S_BOSS_ATK2),
+        S_BOSS_ATK2 => state!(SPR_BOSS, 5, 8, A_FaceTarget, S_BOSS_ATK3),
+        S_BOSS_ATK3 => state!(SPR_BOSS, 6, 8, A_BruisAttack, S_BOSS_RUN1),
+        S_BOSS_PAIN => state!(SPR_BOSS, 7, 2, NULL, S_BOSS_PAIN2),
+        S_BOSS_PAIN2 => state!(SPR_BOSS, 7, 2, A_Pain, S_BOSS_RUN1),
+        S_BOSS_DIE1 => state!(SPR_BOSS, 8, 8, NULL, S_BOSS_DIE2),
+        S_BOSS_DIE2 => state!(SPR_BOSS, 9, 8, A_Scream, S_BOSS_DIE3),
+        S_BOSS_DIE3 => state!(SPR_BOSS, 10, 8, NULL, S_BOSS_DIE4),
+        S_BOSS_DIE4 => state!(SPR_BOSS, 11, 8, A_Fall, S_BOSS_DIE5),
+        S_BOSS_DIE5 => state!(SPR_BOSS, 12, 8, NULL, S_BOSS_DIE6),
+        S_BOSS_DIE6 => state!(SPR_BOSS, 13, 8, NULL, S_BOSS_DIE7),
+        S_BOSS_DIE7 => state!(SPR_BOSS, 14, -1, A_BossDeath, S_NULL),
+        S_BOSS_RAISE1 => state!(SPR_BOSS, 14, 8, NULL, S_BOSS_RAISE2),
+        S_BOSS_RAISE2 => state!(SPR_BOSS, 13, 8, NULL, S_BOSS_RAISE3),
+        S_BOSS_RAISE3 => state!(SPR_BOSS, 12, 8, NULL, S_BOSS_RAISE4),
+        S_BOSS_RAISE4 => state!(SPR_BOSS, 11, 8, NULL, S_BOSS_RAISE5),
+        S_BOSS_RAISE5 => state!(SPR_BOSS, 10, 8, NULL, S_BOSS_RAISE6),
+        S_BOSS_RAISE6 => state!(SPR_BOSS, 9, 8, NULL, S_BOSS_RAISE7),
+        S_BOSS_RAISE7 => state!(SPR_BOSS, 8, 8, NULL, S_BOSS_RUN1),
+        S_BOS2_STND => state!(SPR_BOS2, 0, 10, A_Look, S_BOS2_STND2),
+        S_BOS2_STND2 => state!(SPR_BOS2, 1, 10, A_Look, S_BOS2_STND),
+        S_BOS2_RUN1 => state!(SPR_BOS2, 0, 3, A_Chase, S_BOS2_RUN2),
+        S_BOS2_RUN2 => state!(SPR_BOS2, 0, 3, A_Chase, S_BOS2_RUN3),
+        S_BOS2_RUN3 => state!(SPR_BOS2, 1, 3, A_Chase, S_BOS2_RUN4),
+        S_BOS2_RUN4 => state!(SPR_BOS2, 1, 3, A_Chase, S_BOS2_RUN5),
+        S_BOS2_RUN5 => state!(SPR_BOS2, 2, 3, A_Chase, S_BOS2_RUN6),
+        S_BOS2_RUN6 => state!(SPR_BOS2, 2, 3, A_Chase, S_BOS2_RUN7),
+        S_BOS2_RUN7 => state!(SPR_BOS2, 3, 3, A_Chase, S_BOS2_RUN8),
+        S_BOS2_RUN8 => state!(SPR_BOS2, 3, 3, A_Chase, S_BOS2_RUN1),
+        S_BOS2_ATK1 => state!(SPR_BOS2, 4, 8, A_FaceTarget, S_BOS2_ATK2),
+        S_BOS2_ATK2 => state!(SPR_BOS2, 5, 8, A_FaceTarget, S_BOS2_ATK3),
+        S_BOS2_ATK3 => state!(SPR_BOS2, 6, 8, A_BruisAttack, S_BOS2_RUN1),
+        S_BOS2_PAIN => state!(SPR_BOS2, 7, 2, NULL, S_BOS2_PAIN2),
+        S_BOS2_PAIN2 => state!(SPR_BOS2, 7, 2, A_Pain, S_BOS2_RUN1),
+        S_BOS2_DIE1 => state!(SPR_BOS2, 8, 8, NULL, S_BOS2_DIE2),
+        S_BOS2_DIE2 => state!(SPR_BOS2, 9, 8, A_Scream, S_BOS2_DIE3),
+        S_BOS2_DIE3 => state!(SPR_BOS2, 10, 8, NULL, S_BOS2_DIE4),
+        S_BOS2_DIE4 => state!(SPR_BOS2, 11, 8, A_Fall, S_BOS2_DIE5),
+        S_BOS2_DIE5 => state!(SPR_BOS2, 12, 8, NULL, S_BOS2_DIE6),
+        S_BOS2_DIE6 => state!(SPR_BOS2, 13, 8, NULL, S_BOS2_DIE7),
+        S_BOS2_DIE7 => state!(SPR_BOS2, 14, -1, NULL, S_NULL),
+        S_BOS2_RAISE1 => state!(SPR_BOS2, 14, 8, NULL, S_BOS2_RAISE2),
+        S_BOS2_RAISE2 => state!(SPR_BOS2, 13, 8, NULL, S_BOS2_RAISE3),
+        S_BOS2_RAISE3 => state!(SPR_BOS2, 12, 8, NULL, S_BOS2_RAISE4),
+        S_BOS2_RAISE4 => state!(SPR_BOS2, 11, 8, NULL, S_BOS2_RAISE5),
+        S_BOS2_RAISE5 => state!(SPR_BOS2, 10, 8, NULL, S_BOS2_RAISE6),
+        S_BOS2_RAISE6 => state!(SPR_BOS2, 9, 8, NULL, S_BOS2_RAISE7),
+        S_BOS2_RAISE7 => state!(SPR_BOS2, 8, 8, NULL, S_BOS2_RUN1),
+        S_SKULL_STND => state!(SPR_SKUL, bright(0), 10, A_Look, S_SKULL_STND2),
+        S_SKULL_STND2 => state!(SPR_SKUL, bright(1), 10, A_Look, S_SKULL_STND),
+        S_SKULL_RUN1 => state!(SPR_SKUL, bright(0), 6, A_Chase, S_SKULL_RUN2),
+        S_SKULL_RUN2 => state!(SPR_SKUL, bright(1), 6, A_Chase, S_SKULL_RUN1),
+        S_SKULL_ATK1 => state!(SPR_SKUL, bright(2), 10, A_FaceTarget, S_SKULL_ATK2),
+        S_SKULL_ATK2 => state!(SPR_SKUL, bright(3), 4, A_SkullAttack, S_SKULL_ATK3),
+        S_SKULL_ATK3 => state!(SPR_SKUL, bright(2), 4, NULL, S_SKULL_ATK4),
+        S_SKULL_ATK4 => state!(SPR_SKUL, bright(3), 4, NULL, S_SKULL_ATK3),
+        S_SKULL_PAIN => state!(SPR_SKUL, bright(4), 3, NULL, S_SKULL_PAIN2),
+        S_SKULL_PAIN2 => state!(SPR_SKUL, bright(4), 6, A_Pain, S_SKULL_RUN1),
+        S_SKULL_DIE1 => state!(SPR_SKUL, bright(5), 6, NULL, S_SKULL_DIE2),
+        S_SKULL_DIE2 => state!(SPR_SKUL, bright(6), 6, A_Scream, S_SKULL_DIE3),
+        S_SKULL_DIE3 => state!(SPR_SKUL, bright(7), 6, NULL, S_SKULL_DIE4),
+        S_SKULL_DIE4 => state!(SPR_SKUL, bright(8), 6, A_Fall, S_SKULL_DIE5),
+        S_SKULL_DIE5 => state!(SPR_SKUL, 9, 6, NULL, S_SKULL_DIE6),
+        S_SKULL_DIE6 => state!(SPR_SKUL, 10, 6, NULL, S_NULL),
+        S_SPID_STND => state!(SPR_SPID, 0, 10, A_Look, S_SPID_STND2),
+        S_SPID_STND2 => state!(SPR_SPID, 1, 10, A_Look, S_SPID_STND),
+        S_SPID_RUN1 => state!(SPR_SPID, 0, 3, A_Metal, S_SPID_RUN2),
+        S_SPID_RUN2 => state!(SPR_SPID, 0, 3, A_Chase, S_SPID_RUN3),
+        S_SPID_RUN3 => state!(SPR_SPID, 1, 3, A_Chase, S_SPID_RUN4),
+        S_SPID_RUN4 => state!(SPR_SPID, 1, 3, A_Chase, S_SPID_RUN5),
+        S_SPID_RUN5 => state!(SPR_SPID, 2, 3, A_Metal, S_SPID_RUN6),
+        S_SPID_RUN6 => state!(SPR_SPID, 2, 3, A_Chase, S_SPID_RUN7),
+        S_SPID_RUN7 => state!(SPR_SPID, 3, 3, A_Chase, S_SPID_RUN8),
+        S_SPID_RUN8 => state!(SPR_SPID, 3, 3, A_Chase, S_SPID_RUN9),
+        S_SPID_RUN9 => state!(SPR_SPID, 4, 3, A_Metal, S_SPID_RUN10),
+        S_SPID_RUN10 => state!(SPR_SPID, 4, 3, A_Chase, S_SPID_RUN11),
+        S_SPID_RUN11 => state!(SPR_SPID, 5, 3, A_Chase, S_SPID_RUN12),
+        S_SPID_RUN12 => state!(SPR_SPID, 5, 3, A_Chase, S_SPID_RUN1),
+        S_SPID_ATK1 => state!(SPR_SPID, bright(0), 20, A_FaceTarget, S_SPID_ATK2),
+        S_SPID_ATK2 => state!(SPR_SPID, bright(6), 4, A_SPosAttack, S_SPID_ATK3),
+        S_SPID_ATK3 => state!(SPR_SPID, bright(7), 4, A_SPosAttack, S_SPID_ATK4),
+        S_SPID_ATK4 => state!(SPR_SPID, bright(7), 1, A_SpidRefire, S_SPID_ATK2),
+        S_SPID_PAIN => state!(SPR_SPID, 8, 3, NULL, S_SPID_PAIN2),
+        S_SPID_PAIN2 => state!(SPR_SPID, 8, 3, A_Pain, S_SPID_RUN1),
+        S_SPID_DIE1 => state!(SPR_SPID, 9, 20, A_Scream, S_SPID_DIE2),
+        S_SPID_DIE2 => state!(SPR_SPID, 10, 10, A_Fall, S_SPID_DIE3),
+        S_SPID_DIE3 => state!(SPR_SPID, 11, 10, NULL, S_SPID_DIE4),
+        S_SPID_DIE4 => state!(SPR_SPID, 12, 10, NULL, S_SPID_DIE5),
+        S_SPID_DIE5 => state!(SPR_SPID, 13, 10, NULL, S_SPID_DIE6),
+        S_SPID_DIE6 => state!(SPR_SPID, 14, 10, NULL, S_SPID_DIE7),
+        S_SPID_DIE7 => state!(SPR_SPID, 15, 10, NULL, S_SPID_DIE8),
+        S_SPID_DIE8 => state!(SPR_SPID, 16, 10, NULL, S_SPID_DIE9),
+        S_SPID_DIE9 => state!(SPR_SPID, 17, 10, NULL, S_SPID_DIE10),
+        S_SPID_DIE10 => state!(SPR_SPID, 18, 30, NULL, S_SPID_DIE11),
+        S_SPID_DIE11 => state!(SPR_SPID, 18, -1, A_BossDeath, S_NULL),
+        S_BSPI_STND => state!(SPR_BSPI, 0, 10, A_Look, S_BSPI_STND2),
+        S_BSPI_STND2 => state!(SPR_BSPI, 1, 10, A_Look, S_BSPI_STND),
+        S_BSPI_SIGHT => state!(SPR_BSPI, 0, 20, NULL, S_BSPI_RUN1),
+        S_BSPI_RUN1 => state!(SPR_BSPI, 0, 3, A_BabyMetal, S_BSPI_RUN2),
+        S_BSPI_RUN2 => state!(SPR_BSPI, 0, 3, A_Chase, S_BSPI_RUN3),
+        S_BSPI_RUN3 => state!(SPR_BSPI, 1, 3, A_Chase, S_BSPI_RUN4),
+        S_BSPI_RUN4 => state!(SPR_BSPI, 1, 3, A_Chase, S_BSPI_RUN5),
+        S_BSPI_RUN5 => state!(SPR_BSPI, 2, 3, A_Chase, S_BSPI_RUN6),
+        S_BSPI_RUN6 => state!(SPR_BSPI, 2, 3, A_Chase, S_BSPI_RUN7),
+        S_BSPI_RUN7 => state!(SPR_BSPI, 3, 3, A_BabyMetal, S_BSPI_RUN8),
+        S_BSPI_RUN8 => state!(SPR_BSPI, 3, 3, A_Chase, S_BSPI_RUN9),
+        S_BSPI_RUN9 => state!(SPR_BSPI, 4, 3, A_Chase, S_BSPI_RUN10),
+        S_BSPI_RUN10 => state!(SPR_BSPI, 4, 3, A_Chase, S_BSPI_RUN11),
+        S_BSPI_RUN11 => state!(SPR_BSPI, 5, 3, A_Chase, S_BSPI_RUN12),
+        S_BSPI_RUN12 => state!(SPR_BSPI, 5, 3, A_Chase, S_BSPI_RUN1),
+        S_BSPI_ATK1 => state!(SPR_BSPI, bright(0), 20, A_FaceTarget, S_BSPI_ATK2),
+        S_BSPI_ATK2 => state!(SPR_BSPI, bright(6), 4, A_BspiAttack, S_BSPI_ATK3),
+        S_BSPI_ATK3 => state!(SPR_BSPI, bright(7), 4, NULL, S_BSPI_ATK4),
+        S_BSPI_ATK4 => state!(SPR_BSPI, bright(7), 1, A_SpidRefire, S_BSPI_ATK2),
+        S_BSPI_PAIN => state!(SPR_BSPI, 8, 3, NULL, S_BSPI_PAIN2),
+        S_BSPI_PAIN2 => state!(SPR_BSPI, 8, 3, A_Pain, S_BSPI_RUN1),
+        S_BSPI_DIE1 => state!(SPR_BSPI, 9, 20, A_Scream, S_BSPI_DIE2),
+        S_BSPI_DIE2 => state!(SPR_BSPI, 10, 7, A_Fall, S_BSPI_DIE3),
+        S_BSPI_DIE3 => state!(SPR_BSPI, 11, 7, NULL, S_BSPI_DIE4),
+        S_BSPI_DIE4 => state!(SPR_BSPI, 12, 7, NULL, S_BSPI_DIE5),
+        S_BSPI_DIE5 => state!(SPR_BSPI, 13, 7, NULL, S_BSPI_DIE6),
+        S_BSPI_DIE6 => state!(SPR_BSPI, 14, 7, NULL, S_BSPI_DIE7),
+        S_BSPI_DIE7 => state!(SPR_BSPI, 15, -1, A_BossDeath, S_NULL),
+        S_BSPI_RAISE1 => state!(SPR_BSPI, 15, 5, NULL, S_BSPI_RAISE2),
+        S_BSPI_RAISE2 => state!(SPR_BSPI, 14, 5, NULL, S_BSPI_RAISE3),
+        S_BSPI_RAISE3 => state!(SPR_BSPI, 13, 5, NULL, S_BSPI_RAISE4),
+        S_BSPI_RAISE4 => state!(SPR_BSPI, 12, 5, NULL, S_BSPI_RAISE5),
+        S_BSPI_RAISE5 => state!(SPR_BSPI, 11, 5, NULL, S_BSPI_RAISE6),
+        S_BSPI_RAISE6 => state!(SPR_BSPI, 10, 5, NULL, S_BSPI_RAISE7),
+        S_BSPI_RAISE7 => state!(SPR_BSPI, 9, 5, NULL, S_BSPI_RUN1),
+        S_ARACH_PLAZ => state!(SPR_APLS, bright(0), 5, NULL, S_ARACH_PLAZ2),
+        S_ARACH_PLAZ2 => state!(SPR_APLS, bright(1), 5, NULL, S_ARACH_PLAZ),
+        S_ARACH_PLEX => state!(SPR_APBX, bright(0), 5, NULL, S_ARACH_PLEX2),
+        S_ARACH_PLEX2 => state!(SPR_APBX, bright(1), 5, NULL, S_ARACH_PLEX3),
+        S_ARACH_PLEX3 => state!(SPR_APBX, bright(2), 5, NULL, S_ARACH_PLEX4),
+        S_ARACH_PLEX4 => state!(SPR_APBX, bright(3), 5, NULL, S_ARACH_PLEX5),
+        S_ARACH_PLEX5 => state!(SPR_APBX, bright(4), 5, NULL, S_NULL),
+        S_CYBER_STND => state!(SPR_CYBR, 0, 10, A_Look, S_CYBER_STND2),
+        S_CYBER_STND2 => state!(SPR_CYBR, 1, 10, A_Look, S_CYBER_STND),
+        S_CYBER_RUN1 => state!(SPR_CYBR, 0, 3, A_Hoof, S_CYBER_RUN2),
+        S_CYBER_RUN2 => state!(SPR_CYBR, 0, 3, A_Chase, S_CYBER_RUN3),
+        S_CYBER_RUN3 => state!(SPR_CYBR, 1, 3, A_Chase, S_CYBER_RUN4),
+        S_CYBER_RUN4 => state!(SPR_CYBR, 1, 3, A_Chase, S_CYBER_RUN5),
+        S_CYBER_RUN5 => state!(SPR_CYBR, 2, 3, A_Chase, S_CYBER_RUN6),
+        S_CYBER_RUN6 => state!(SPR_CYBR, 2, 3, A_Chase, S_CYBER_RUN7),
+        S_CYBER_RUN7 => state!(SPR_CYBR, 3, 3, A_Metal, S_CYBER_RUN8),
+        S_CYBER_RUN8 => state!(SPR_CYBR, 3, 3, A_Chase, S_CYBER_RUN1),
+        S_CYBER_ATK1 => state!(SPR_CYBR, 4, 6, A_FaceTarget, S_CYBER_ATK2),
+        S_CYBER_ATK2 => state!(SPR_CYBR, 5, 12, A_CyberAttack, S_CYBER_ATK3),
+        S_CYBER_ATK3 => state!(SPR_CYBR, 4, 12, A_FaceTarget, S_CYBER_ATK4),
+        S_CYBER_ATK4 => state!(SPR_CYBR, 5, 12, A_CyberAttack, S_CYBER_ATK5),
+        S_CYBER_ATK5 => state!(SPR_CYBR, 4, 12, A_FaceTarget, S_CYBER_ATK6),
+        S_CYBER_ATK6 => state!(SPR_CYBR, 5, 12, A_CyberAttack, S_CYBER_RUN1),
+        S_CYBER_PAIN => state!(SPR_CYBR, 6, 10, A_Pain, S_CYBER_RUN1),
+        S_CYBER_DIE1 => state!(SPR_CYBR, 7, 10, NULL, S_CYBER_DIE2),
+        S_CYBER_DIE2 => state!(SPR_CYBR, 8, 10, A_Scream, S_CYBER_DIE3),
+        S_CYBER_DIE3 => state!(SPR_CYBR, 9, 10, NULL, S_CYBER_DIE4),
+        S_CYBER_DIE4 => state!(SPR_CYBR, 10, 10, NULL, S_CYBER_DIE5),
+        S_CYBER_DIE5 => state!(SPR_CYBR, 11, 10, NULL, S_CYBER_DIE6),
+        S_CYBER_DIE6 => state!(SPR_CYBR, 12, 10, A_Fall, S_CYBER_DIE7),
+        S_CYBER_DIE7 => state!(SPR_CYBR, 13, 10, NULL, S_CYBER_DIE8),
+        S_CYBER_DIE8 => state!(SPR_CYBR, 14, 10, NULL, S_CYBER_DIE9),
+        S_CYBER_DIE9 => state!(SPR_CYBR, 15, 30, NULL, S_CYBER_DIE10),
+        S_CYBER_DIE10 => state!(SPR_CYBR, 15, -1, A_BossDeath, S_NULL),
+        S_PAIN_STND => state!(SPR_PAIN, 0, 10, A_Look, S_PAIN_STND),
+        S_PAIN_RUN1 => state!(SPR_PAIN, 0, 3, A_Chase, S_PAIN_RUN2),
+        S_PAIN_RUN2 => state!(SPR_PAIN, 0, 3, A_Chase, S_PAIN_RUN3),
+        S_PAIN_RUN3 => state!(SPR_PAIN, 1, 3, A_Chase, S_PAIN_RUN4),
+        S_PAIN_RUN4 => state!(SPR_PAIN, 1, 3, A_Chase, S_PAIN_RUN5),
+        S_PAIN_RUN5 => state!(SPR_PAIN, 2, 3, A_Chase, S_PAIN_RUN6),
+        S_PAIN_RUN6 => state!(SPR_PAIN, 2, 3, A_Chase, S_PAIN_RUN1),
+        S_PAIN_ATK1 => state!(SPR_PAIN, 3, 5, A_FaceTarget, S_PAIN_ATK2),
+        S_PAIN_ATK2 => state!(SPR_PAIN, 4, 5, A_FaceTarget, S_PAIN_ATK3),
+        S_PAIN_ATK3 => state!(SPR_PAIN, bright(5), 5, A_FaceTarget, S_PAIN_ATK4),
+        S_PAIN_ATK4 => state!(SPR_PAIN, bright(5), 0, A_PainAttack, S_PAIN_RUN1),
+        S_PAIN_PAIN => state!(SPR_PAIN, 6, 6, NULL, S_PAIN_PAIN2),
+        S_PAIN_PAIN2 => state!(SPR_PAIN, 6, 6, A_Pain, S_PAIN_RUN1),
+        S_PAIN_DIE1 => state!(SPR_PAIN, bright(7), 8, NULL, S_PAIN_DIE2),
+        S_PAIN_DIE2 => state!(SPR_PAIN, bright(8), 8, A_Scream, S_PAIN_DIE3),
+        S_PAIN_DIE3 => state!(SPR_PAIN, bright(9), 8, NULL, S_PAIN_DIE4),
+        S_PAIN_DIE4 => state!(SPR_PAIN, bright(10), 8, NULL, S_PAIN_DIE5),
+        S_PAIN_DIE5 => state!(SPR_PAIN, bright(11), 8, A_PainDie, S_PAIN_DIE6),
+        S_PAIN_DIE6 => state!(SPR_PAIN, bright(12), 8, NULL, S_NULL),
+        S_PAIN_RAISE1 => state!(SPR_PAIN, 12, 8, NULL, S_PAIN_RAISE2),
+        S_PAIN_RAISE2 => state!(SPR_PAIN, 11, 8, NULL, S_PAIN_RAISE3),
+        S_PAIN_RAISE3 => state!(SPR_PAIN, 10, 8, NULL, S_PAIN_RAISE4),
+        S_PAIN_RAISE4 => state!(SPR_PAIN, 9, 8, NULL, S_PAIN_RAISE5),
+        S_PAIN_RAISE5 => state!(SPR_PAIN, 8, 8, NULL, S_PAIN_RAISE6),
+        S_PAIN_RAISE6 => state!(SPR_PAIN, 7, 8, NULL, S_PAIN_RUN1),
+        S_SSWV_STND => state!(SPR_SSWV, 0, 10, A_Look, S_SSWV_STND2),
+        S_SSWV_STND2 => state!(SPR_SSWV, 1, 10, A_Look, S_SSWV_STND),
+        S_SSWV_RUN1 => state!(SPR_SSWV, 0, 3, A_Chase, S_SSWV_RUN2),
+        S_SSWV_RUN2 => state!(SPR_SSWV, 0, 3, A_Chase, S_SSWV_RUN3),
+        S_SSWV_RUN3 => state!(SPR_SSWV, 1, 3, A_Chase, S_SSWV_RUN4),
+        S_SSWV_RUN4 => state!(SPR_SSWV, 1, 3, A_Chase, S_SSWV_RUN5),
+        S_SSWV_RUN5 => state!(SPR_SSWV, 2, 3, A_Chase, S_SSWV_RUN6),
+        S_SSWV_RUN6 => state!(SPR_SSWV, 2, 3, A_Chase, S_SSWV_RUN7),
+        S_SSWV_RUN7 => state!(SPR_SSWV, 3, 3, A_Chase, S_SSWV_RUN8),
+        S_SSWV_RUN8 => state!(SPR_SSWV, 3, 3, A_Chase, S_SSWV_RUN1),
+        S_SSWV_ATK1 => state!(SPR_SSWV, 4, 10, A_FaceTarget, S_SSWV_ATK2),
+        S_SSWV_ATK2 => state!(SPR_SSWV, 5, 10, A_FaceTarget, S_SSWV_ATK3),
+        S_SSWV_ATK3 => state!(SPR_SSWV, bright(6), 4, A_CPosAttack, S_SSWV_ATK4),
+        S_SSWV_ATK4 => state!(SPR_SSWV, 5, 6, A_FaceTarget, S_SSWV_ATK5),
+        S_SSWV_ATK5 => state!(SPR_SSWV, bright(6), 4, A_CPosAttack, S_SSWV_ATK6),
+        S_SSWV_ATK6 => state!(SPR_SSWV, 5, 1, A_CPosRefire, S_SSWV_ATK2),
+        S_SSWV_PAIN => state!(SPR_SSWV, 7, 3, NULL, S_SSWV_PAIN2),
+        S_SSWV_PAIN2 => state!(SPR_SSWV, 7, 3, A_Pain, S_SSWV_RUN1),
+        S_SSWV_DIE1 => state!(SPR_SSWV, 8, 5, NULL, S_SSWV_DIE2),
+        S_SSWV_DIE2 => state!(SPR_SSWV, 9, 5, A_Scream, S_SSWV_DIE3),
+        S_SSWV_DIE3 => state!(SPR_SSWV, 10, 5, A_Fall, S_SSWV_DIE4),
+        S_SSWV_DIE4 => state!(SPR_SSWV, 11, 5, NULL, S_SSWV_DIE5),
+        S_SSWV_DIE5 => state!(SPR_SSWV, 12, -1, NULL, S_NULL),
+        S_SSWV_XDIE1 => state!(SPR_SSWV, 13, 5, NULL, S_SSWV_XDIE2),
+        S_SSWV_XDIE2 => state!(SPR_SSWV, 14, 5, A_XScream, S_SSWV_XDIE3),
+        S_SSWV_XDIE3 => state!(SPR_SSWV, 15, 5, A_Fall, S_SSWV_XDIE4),
+        S_SSWV_XDIE4 => state!(SPR_SSWV, 16, 5, NULL, S_SSWV_XDIE5),
+        S_SSWV_XDIE5 => state!(SPR_SSWV, 17, 5, NULL, S_SSWV_XDIE6),
+        S_SSWV_XDIE6 => state!(SPR_SSWV, 18, 5, NULL, S_SSWV_XDIE7),
+        S_SSWV_XDIE7 => state!(SPR_SSWV, 19, 5, NULL, S_SSWV_XDIE8),
+        S_SSWV_XDIE8 => state!(SPR_SSWV, 20, 5, NULL, S_SSWV_XDIE9),
+        S_SSWV_XDIE9 => state!(SPR_SSWV, 21, -1, NULL, S_NULL),
+        S_SSWV_RAISE1 => state!(SPR_SSWV, 12, 5, NULL, S_SSWV_RAISE2),
+        S_SSWV_RAISE2 => state!(SPR_SSWV, 11, 5, NULL, S_SSWV_RAISE3),
+        S_SSWV_RAISE3 => state!(SPR_SSWV, 10, 5, NULL, S_SSWV_RAISE4),
+        S_SSWV_RAISE4 => state!(SPR_SSWV, 9, 5, NULL, S_SSWV_RAISE5),
+        S_SSWV_RAISE5 => state!(SPR_SSWV, 8, 5, NULL, S_SSWV_RUN1),
+        S_KEENSTND => state!(SPR_KEEN, 0, -1, NULL, S_KEENSTND),
+        S_COMMKEEN => state!(SPR_KEEN, 0, 6, NULL, S_COMMKEEN2),
+        S_COMMKEEN2 => state!(SPR_KEEN, 1, 6, NULL, S_COMMKEEN3),
+        S_COMMKEEN3 => state!(SPR_KEEN, 2, 6, A_Scream, S_COMMKEEN4),
+        S_COMMKEEN4 => state!(SPR_KEEN, 3, 6, NULL, S_COMMKEEN5),
+        S_COMMKEEN5 => state!(SPR_KEEN, 4, 6, NULL, S_COMMKEEN6),
+        S_COMMKEEN6 => state!(SPR_KEEN, 5, 6, NULL, S_COMMKEEN7),
+        S_COMMKEEN7 => state!(SPR_KEEN, 6, 6, NULL, S_COMMKEEN8),
+        S_COMMKEEN8 => state!(SPR_KEEN, 7, 6, NULL, S_COMMKEEN9),
+        S_COMMKEEN9 => state!(SPR_KEEN, 8, 6, NULL, S_COMMKEEN10),
+        S_COMMKEEN10 => state!(SPR_KEEN, 9, 6, NULL, S_COMMKEEN11),
+        S_COMMKEEN11 => state!(SPR_KEEN, 10, 6, A_KeenDie, S_COMMKEEN12),
+        S_COMMKEEN12 => state!(SPR_KEEN, 11, -1, NULL, S_NULL),
+        S_KEENPAIN => state!(SPR_KEEN, 12, 4, NULL, S_KEENPAIN2),
+        S_KEENPAIN2 => state!(SPR_KEEN, 12, 8, A_Pain, S_KEENSTND),
+        S_BRAIN => state!(SPR_BBRN, 0, -1, NULL, S_NULL),
+        S_BRAIN_PAIN => state!(SPR_BBRN, 1, 36, A_BrainPain, S_BRAIN),
+        S_BRAIN_DIE1 => state!(SPR_BBRN, 0, 100, A_BrainScream, S_BRAIN_DIE2),
+        S_BRAIN_DIE2 => state!(SPR_BBRN, 0, 10, NULL, S_BRAIN_DIE3),
+        S_BRAIN_DIE3 => state!(SPR_BBRN, 0, 10, NULL, S_BRAIN_DIE4),
+        S_BRAIN_DIE4 => state!(SPR_BBRN, 0, -1, A_BrainDie, S_NULL),
+        S_BRAINEYE => state!(SPR_SSWV, 0, 10, A_Look, S_BRAINEYE),
+        S_BRAINEYESEE => state!(SPR_SSWV, 0, 181, A_BrainAwake, S_BRAINEYE1),
+        S_BRAINEYE1 => state!(SPR_SSWV, 0, 150, A_BrainSpit, S_BRAINEYE1),
+        S_SPAWN1 => state!(SPR_BOSF, bright(0), 3, A_SpawnSound, S_SPAWN2),
+        S_SPAWN2 => state!(SPR_BOSF, bright(1), 3, A_SpawnFly, S_SPAWN3),
+        S_SPAWN3 => state!(SPR_BOSF, bright(2), 3, A_SpawnFly, S_SPAWN4),
+        S_SPAWN4 => state!(SPR_BOSF, bright(3), 3, A_SpawnFly, S_SPAWN1),
+        S_SPAWNFIRE1 => state!(SPR_FIRE, bright(0), 4, A_Fire, S_SPAWNFIRE2),
+        S_SPAWNFIRE2 => state!(SPR_FIRE, bright(1), 4, A_Fire, S_SPAWNFIRE3),
+        S_SPAWNFIRE3 => state!(SPR_FIRE, bright(2), 4, A_Fire, S_SPAWNFIRE4),
+        S_SPAWNFIRE4 => state!(SPR_FIRE, bright(3), 4, A_Fire, S_SPAWNFIRE5),
+        S_SPAWNFIRE5 => state!(SPR_FIRE, bright(4), 4, A_Fire, S_SPAWNFIRE6),
+        S_SPAWNFIRE6 => state!(SPR_FIRE, bright(5), 4, A_Fire, S_SPAWNFIRE7),
+        S_SPAWNFIRE7 => state!(SPR_FIRE, bright(6), 4, A_Fire, S_SPAWNFIRE8),
+        S_SPAWNFIRE8 => state!(SPR_FIRE, bright(7), 4, A_Fire, S_NULL),
+        S_BRAINEXPLODE1 => state!(SPR_MISL, bright(1), 10, NULL, S_BRAINEXPLODE2),
+        S_BRAINEXPLODE2 => state!(SPR_MISL, bright(2), 10, NULL, S_BRAINEXPLODE3),
+        S_BRAINEXPLODE3 => state!(SPR_MISL, bright(3), 10, A_BrainExplode, S_NULL),
+        S_ARM1 => state!(SPR_ARM1, 0, 6, NULL, S_ARM1A),
+        S_ARM1A => state!(SPR_ARM1, bright(1), 7, NULL, S_ARM1),
+        S_ARM2 => state!(SPR_ARM2, 0, 6, NULL, S_ARM2A),
+        S_ARM2A => state!(SPR_ARM2, bright(1), 6, NULL, S_ARM2),
+        S_BAR1 => state!(SPR_BAR1, 0, 6, NULL, S_BAR2),
+        S_BAR2 => state!(SPR_BAR1, 1, 6, NULL, S_BAR1),
+        S_BEXP => state!(SPR_BEXP, bright(0), 5, NULL, S_BEXP2),
+        S_BEXP2 => state!(SPR_BEXP, bright(1), 5, A_Scream, S_BEXP3),
+        S_BEXP3 => state!(SPR_BEXP, bright(2), 5, NULL, S_BEXP4),
+        S_BEXP4 => state!(SPR_BEXP, bright(3), 10, A_Explode, S_BEXP5),
+        S_BEXP5 => state!(SPR_BEXP, bright(4), 10, NULL, S_NULL),
+        S_BBAR1 => state!(SPR_FCAN, bright(0), 4, NULL, S_BBAR2),
+        S_BBAR2 => state!(SPR_FCAN, bright(1), 4, NULL, S_BBAR3),
+        S_BBAR3 => state!(SPR_FCAN, bright(2), 4, NULL, S_BBAR1),
+        S_BON1 => state!(SPR_BON1, 0, 6, NULL, S_BON1A),
+        S_BON1A => state!(SPR_BON1, 1, 6, NULL, S_BON1B),
+        S_BON1B => state!(SPR_BON1, 2, 6, NULL, S_BON1C),
+        S_BON1C => state!(SPR_BON1, 3, 6, NULL, S_BON1D),
+        S_BON1D => state!(SPR_BON1, 2, 6, NULL, S_BON1E),
+        S_BON1E => state!(SPR_BON1, 1, 6, NULL, S_BON1),
+        S_BON2 => state!(SPR_BON2, 0, 6, NULL, S_BON2A),
+        S_BON2A => state!(SPR_BON2, 1, 6, NULL, S_BON2B),
+        S_BON2B => state!(SPR_BON2, 2, 6, NULL, S_BON2C),
+        S_BON2C => state!(SPR_BON2, 3, 6, NULL, S_BON2D),
+        S_BON2D => state!(SPR_BON2, 2, 6, NULL, S_BON2E),
+        S_BON2E => state!(SPR_BON2, 1, 6, NULL, S_BON2),
+        S_BKEY => state!(SPR_BKEY, 0, 10, NULL, S_BKEY2),
+        S_BKEY2 => state!(SPR_BKEY, bright(1), 10, NULL, S_BKEY),
+        S_RKEY => state!(SPR_RKEY, 0, 10, NULL, S_RKEY2),
+        S_RKEY2 => state!(SPR_RKEY, bright(1), 10, NULL, S_RKEY),
+        S_YKEY => state!(SPR_YKEY, 0, 10, NULL, S_YKEY2),
+        S_YKEY2 => state!(SPR_YKEY, bright(1), 10, NULL, S_YKEY),
+        S_BSKULL => state!(SPR_BSKU, 0, 10, NULL, S_BSKULL2),
+        S_BSKULL2 => state!(SPR_BSKU, bright(1), 10, NULL, S_BSKULL),
+        S_RSKULL => state!(SPR_RSKU, 0, 10, NULL, S_RSKULL2),
+        S_RSKULL2 => state!(SPR_RSKU, bright(1), 10, NULL, S_RSKULL),
+        S_YSKULL => state!(SPR_YSKU, 0, 10, NULL, S_YSKULL2),
+        S_YSKULL2 => state!(SPR_YSKU, bright(1), 10, NULL, S_YSKULL),
+        S_STIM => state!(SPR_STIM, 0, -1, NULL, S_NULL),
+        S_MEDI => state!(SPR_MEDI, 0, -1, NULL, S_NULL),
+        S_SOUL => state!(SPR_SOUL, bright(0), 6, NULL, S_SOUL2),
+        S_SOUL2 => state!(SPR_SOUL, bright(1), 6, NULL, S_SOUL3),
+        S_SOUL3 => state!(SPR_SOUL, bright(2), 6, NULL, S_SOUL4),
+        S_SOUL4 => state!(SPR_SOUL, bright(3), 6, NULL, S_SOUL5),
+        S_SOUL5 => state!(SPR_SOUL, bright(2), 6, NULL, S_SOUL6),
+        S_SOUL6 => state!(SPR_SOUL, bright(1), 6, NULL, S_SOUL),
+        S_PINV => state!(SPR_PINV, bright(0), 6, NULL, S_PINV2),
+        S_PINV2 => state!(SPR_PINV, bright(1), 6, NULL, S_PINV3),
+        S_PINV3 => state!(SPR_PINV, bright(2), 6, NULL, S_PINV4),
+        S_PINV4 => state!(SPR_PINV, bright(3), 6, NULL, S_PINV),
+        S_PSTR => state!(SPR_PSTR, bright(0), -1, NULL, S_NULL),
+        S_PINS => state!(SPR_PINS, bright(0), 6, NULL, S_PINS2),
+        S_PINS2 => state!(SPR_PINS, bright(1), 6, NULL, S_PINS3),
+        S_PINS3 => state!(SPR_PINS, bright(2), 6, NULL, S_PINS4),
+        S_PINS4 => state!(SPR_PINS, bright(3), 6, NULL, S_PINS),
+        S_MEGA => state!(SPR_MEGA, bright(0), 6, NULL, S_MEGA2),
+        S_MEGA2 => state!(SPR_MEGA, bright(1), 6, NULL, S_MEGA3),
+        S_MEGA3 => state!(SPR_MEGA, bright(2), 6, NULL, S_MEGA4),
+        S_MEGA4 => state!(SPR_MEGA, bright(3), 6, NULL, S_MEGA),
+        S_SUIT => state!(SPR_SUIT, bright(0), -1, NULL, S_NULL),
+        S_PMAP => state!(SPR_PMAP, bright(0), 6, NULL, S_PMAP2),
+        S_PMAP2 => state!(SPR_PMAP, bright(1), 6, NULL, S_PMAP3),
+        S_PMAP3 => state!(SPR_PMAP, bright(2), 6, NULL, S_PMAP4),
+        S_PMAP4 => state!(SPR_PMAP, bright(3), 6, NULL, S_PMAP5),
+        S_PMAP5 => state!(SPR_PMAP, bright(2), 6, NULL, S_PMAP6),
+        S_PMAP6 => state!(SPR_PMAP, bright(1), 6, NULL, S_PMAP),
+        S_PVIS => state!(SPR_PVIS, bright(0), 6, NULL, S_PVIS2),
+        S_PVIS2 => state!(SPR_PVIS, 1, 6, NULL, S_PVIS),
+        S_CLIP => state!(SPR_CLIP, 0, -1, NULL, S_NULL),
+        S_AMMO => state!(SPR_AMMO, 0, -1, NULL, S_NULL),
+        S_ROCK => state!(SPR_ROCK, 0, -1, NULL, S_NULL),
+        S_BROK => state!(SPR_BROK, 0, -1, NULL, S_NULL),
+        S_CELL => state!(SPR_CELL, 0, -1, NULL, S_NULL),
+        S_CELP => state!(SPR_CELP, 0, -1, NULL, S_NULL),
+        S_SHEL => state!(SPR_SHEL, 0, -1, NULL, S_NULL),
+        S_SBOX => state!(SPR_SBOX, 0, -1, NULL, S_NULL),
+        S_BPAK => state!(SPR_BPAK, 0, -1, NULL, S_NULL),
+        S_BFUG => state!(SPR_BFUG, 0, -1, NULL, S_NULL),
+        S_MGUN => state!(SPR_MGUN, 0, -1, NULL, S_NULL),
+        S_CSAW => state!(SPR_CSAW, 0, -1, NULL, S_NULL),
+        S_LAUN => state!(SPR_LAUN, 0, -1, NULL, S_NULL),
+        S_PLAS => state!(SPR_PLAS, 0, -1, NULL, S_NULL),
+        S_SHOT => state!(SPR_SHOT, 0, -1, NULL, S_NULL),
+        S_SHOT2 => state!(SPR_SGN2, 0, -1, NULL, S_NULL),
+        S_COLU => state!(SPR_COLU, bright(0), -1, NULL, S_NULL),
+        S_STALAG => state!(SPR_SMT2, 0, -1, NULL, S_NULL),
+        S_BLOODYTWITCH => state!(SPR_GOR1, 0, 10, NULL, S_BLOODYTWITCH2),
+        S_BLOODYTWITCH2 => state!(SPR_GOR1, 1, 15, NULL, S_BLOODYTWITCH3),
+        S_BLOODYTWITCH3 => state!(SPR_GOR1, 2, 8, NULL, S_BLOODYTWITCH4),
+        S_BLOODYTWITCH4 => state!(SPR_GOR1, 1, 6, NULL, S_BLOODYTWITCH),
+        S_DEADTORSO => state!(SPR_PLAY, 13, -1, NULL, S_NULL),
+        S_DEADBOTTOM => state!(SPR_PLAY, 18, -1, NULL, S_NULL),
+        S_HEADSONSTICK => state!(SPR_POL2, 0, -1, NULL, S_NULL),
+        S_GIBS => state!(SPR_POL5, 0, -1, NULL, S_NULL),
+        S_HEADONASTICK => state!(SPR_POL4, 0, -1, NULL, S_NULL),
+        S_HEADCANDLES => state!(SPR_POL3, bright(0), 6, NULL, S_HEADCANDLES2),
+        S_HEADCANDLES2 => state!(SPR_POL3, bright(1), 6, NULL, S_HEADCANDLES),
+        S_DEADSTICK => state!(SPR_POL1, 0, -1, NULL, S_NULL),
+        S_LIVESTICK => state!(SPR_POL6, 0, 6, NULL, S_LIVESTICK2),
+        S_LIVESTICK2 => state!(SPR_POL6, 1, 8, NULL, S_LIVESTICK),
+        S_MEAT2 => state!(SPR_GOR2, 0, -1, NULL, S_NULL),
+        S_MEAT3 => state!(SPR_GOR3, 0, -1, NULL, S_NULL),
+        S_MEAT4 => state!(SPR_GOR4, 0, -1, NULL, S_NULL),
+        S_MEAT5 => state!(SPR_GOR5, 0, -1, NULL, S_NULL),
+        S_STALAGTITE => state!(SPR_SMIT, 0, -1, NULL, S_NULL),
+        S_TALLGRNCOL => state!(SPR_COL1, 0, -1, NULL, S_NULL),
+        S_SHRTGRNCOL => state!(SPR_COL2, 0, -1, NULL, S_NULL),
+        S_TALLREDCOL => state!(SPR_COL3, 0, -1, NULL, S_NULL),
+        S_SHRTREDCOL => state!(SPR_COL4, 0, -1, NULL, S_NULL),
+        S_CANDLESTIK => state!(SPR_CAND, bright(0), -1, NULL, S_NULL),
+        S_CANDELABRA => state!(SPR_CBRA, bright(0), -1, NULL, S_NULL),
+        S_SKULLCOL => state!(SPR_COL6, 0, -1, NULL, S_NULL),
+        S_TORCHTREE => state!(SPR_TRE1, 0, -1, NULL, S_NULL),
+        S_BIGTREE => state!(SPR_TRE2, 0, -1, NULL, S_NULL),
+        S_TECHPILLAR => state!(SPR_ELEC, 0, -1, NULL, S_NULL),
+        S_EVILEYE => state!(SPR_CEYE, bright(0), 6, NULL, S_EVILEYE2),
+        S_EVILEYE2 => state!(SPR_CEYE, bright(1), 6, NULL, S_EVILEYE3),
+        S_EVILEYE3 => state!(SPR_CEYE, bright(2), 6, NULL, S_EVILEYE4),
+        S_EVILEYE4 => state!(SPR_CEYE, bright(1), 6, NULL, S_EVILEYE),
+        S_FLOATSKULL => state!(SPR_FSKU, bright(0), 6, NULL, S_FLOATSKULL2),
+        S_FLOATSKULL2 => state!(SPR_FSKU, bright(1), 6, NULL, S_FLOATSKULL3),
+        S_FLOATSKULL3 => state!(SPR_FSKU, bright(2), 6, NULL, S_FLOATSKULL),
+        S_HEARTCOL => state!(SPR_COL5, 0, 14, NULL, S_HEARTCOL2),
+        S_HEARTCOL2 => state!(SPR_COL5, 1, 14, NULL, S_HEARTCOL),
+        S_BLUETORCH => state!(SPR_TBLU, bright(0), 4, NULL, S_BLUETORCH2),
+        S_BLUETORCH2 => state!(SPR_TBLU, bright(1), 4, NULL, S_BLUETORCH3),
+        S_BLUETORCH3 => state!(SPR_TBLU, bright(2), 4, NULL, S_BLUETORCH4),
+        S_BLUETORCH4 => state!(SPR_TBLU, bright(3), 4, NULL, S_BLUETORCH),
+        S_GREENTORCH => state!(SPR_TGRN, bright(0), 4, NULL, S_GREENTORCH2),
+        S_GREENTORCH2 => state!(SPR_TGRN, bright(1), 4, NULL, S_GREENTORCH3),
+        S_GREENTORCH3 => state!(SPR_TGRN, bright(2), 4, NULL, S_GREENTORCH4),
+        S_GREENTORCH4 => state!(SPR_TGRN, bright(3), 4, NULL, S_GREENTORCH),
+        S_REDTORCH => state!(SPR_TRED, bright(0), 4, NULL, S_REDTORCH2),
+        S_REDTORCH2 => state!(SPR_TRED, bright(1), 4, NULL, S_REDTORCH3),
+        S_REDTORCH3 => state!(SPR_TRED, bright(2), 4, NULL, S_REDTORCH4),
+        S_REDTORCH4 => state!(SPR_TRED, bright(3), 4, NULL, S_REDTORCH),
+        S_BTORCHSHRT => state!(SPR_SMBT, bright(0), 4, NULL, S_BTORCHSHRT2),
+        S_BTORCHSHRT2 => state!(SPR_SMBT, bright(1), 4, NULL, S_BTORCHSHRT3),
+        S_BTORCHSHRT3 => state!(SPR_SMBT, bright(2), 4, NULL, S_BTORCHSHRT4),
+        S_BTORCHSHRT4 => state!(SPR_SMBT, bright(3), 4, NULL, S_BTORCHSHRT),
+        S_GTORCHSHRT => state!(SPR_SMGT, bright(0), 4, NULL, S_GTORCHSHRT2),
+        S_GTORCHSHRT2 => state!(SPR_SMGT, bright(1), 4, NULL, S_GTORCHSHRT3),
+        S_GTORCHSHRT3 => state!(SPR_SMGT, bright(2), 4, NULL, S_GTORCHSHRT4),
+        S_GTORCHSHRT4 => state!(SPR_SMGT, bright(3), 4, NULL, S_GTORCHSHRT),
+        S_RTORCHSHRT => state!(SPR_SMRT, bright(0), 4, NULL, S_RTORCHSHRT2),
+        S_RTORCHSHRT2 => state!(SPR_SMRT, bright(1), 4, NULL, S_RTORCHSHRT3),
+        S_RTORCHSHRT3 => state!(SPR_SMRT, bright(2), 4, NULL, S_RTORCHSHRT4),
+        S_RTORCHSHRT4 => state!(SPR_SMRT, bright(3), 4, NULL, S_RTORCHSHRT),
+        S_HANGNOGUTS => state!(SPR_HDB1, 0, -1, NULL, S_NULL),
+        S_HANGBNOBRAIN => state!(SPR_HDB2, 0, -1, NULL, S_NULL),
+        S_HANGTLOOKDN => state!(SPR_HDB3, 0, -1, NULL, S_NULL),
+        S_HANGTSKULL => state!(SPR_HDB4, 0, -1, NULL, S_NULL),
+        S_HANGTLOOKUP => state!(SPR_HDB5, 0, -1, NULL, S_NULL),
+        S_HANGTNOBRAIN => state!(SPR_HDB6, 0, -1, NULL, S_NULL),
+        S_COLONGIBS => state!(SPR_POB1, 0, -1, NULL, S_NULL),
+        S_SMALLPOOL => state!(SPR_POB2, 0, -1, NULL, S_NULL),
+        S_BRAINSTEM => state!(SPR_BRS1, 0, -1, NULL, S_NULL),
+        S_TECHLAMP => state!(SPR_TLMP, bright(0), 4, NULL, S_TECHLAMP2),
+        S_TECHLAMP2 => state!(SPR_TLMP, bright(1), 4, NULL, S_TECHLAMP3),
+        S_TECHLAMP3 => state!(SPR_TLMP, bright(2), 4, NULL, S_TECHLAMP4),
+        S_TECHLAMP4 => state!(SPR_TLMP, bright(3), 4, NULL, S_TECHLAMP),
+        S_TECH2LAMP => state!(SPR_TLP2, bright(0), 4, NULL, S_TECH2LAMP2),
+        S_TECH2LAMP2 => state!(SPR_TLP2, bright(1), 4, NULL, S_TECH2LAMP3),
+        S_TECH2LAMP3 => state!(SPR_TLP2, bright(2), 4, NULL, S_TECH2LAMP4),
+        S_TECH2LAMP4 => state!(SPR_TLP2, bright(3), 4, NULL, S_TECH2LAMP),
+    }
+}
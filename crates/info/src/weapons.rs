@@ -0,0 +1,89 @@
+use crate::states::StateNum::{self, *};
+
+table! {
+    /// Kinds of ammo, numbered like `ammotype_t`.
+    pub enum AmmoType => pub(crate) const MAX_AMMO: [i32] {
+        am_clip => 200,
+        am_shell => 50,
+        am_cell => 300,
+        am_misl => 50,
+    }
+}
+
+/// Ammo given by a clip, or a box when multiplied by 5, by ammo type.
+///
+/// Mirrors `clipammo`.
+pub(crate) const CLIP_AMMO: [i32; 4] = [10, 4, 20, 1];
+
+/// Ammo and states of a weapon, i.e. an entry of `weaponinfo`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct WeaponInfo {
+    /// `None` for the weapons that don't use any.
+    pub ammo: Option<AmmoType>,
+    pub up_state: StateNum,
+    pub down_state: StateNum,
+    pub ready_state: StateNum,
+    pub attack_state: StateNum,
+    /// Muzzle flash, [`StateNum::S_NULL`] for the melee weapons.
+    pub flash_state: StateNum,
+}
+
+impl WeaponInfo {
+    const fn new(
+        ammo: Option<AmmoType>,
+        [up_state, down_state, ready_state, attack_state, flash_state]: [StateNum; 5],
+    ) -> Self {
+        Self {
+            ammo,
+            up_state,
+            down_state,
+            ready_state,
+            attack_state,
+            flash_state,
+        }
+    }
+}
+
+table! {
+    /// Weapons of the player, numbered like `weapontype_t`.
+    pub enum WeaponType => pub(crate) const WEAPON_INFO: [WeaponInfo] {
+        wp_fist => WeaponInfo::new(None, [S_PUNCHUP, S_PUNCHDOWN, S_PUNCH, S_PUNCH1, S_NULL]),
+        wp_pistol => WeaponInfo::new(
+            Some(AmmoType::am_clip),
+            [S_PISTOLUP, S_PISTOLDOWN, S_PISTOL, S_PISTOL1, S_PISTOLFLASH],
+        ),
+        wp_shotgun => WeaponInfo::new(
+            Some(AmmoType::am_shell),
+            [S_SGUNUP, S_SGUNDOWN, S_SGUN, S_SGUN1, S_SGUNFLASH1],
+        ),
+        wp_chaingun => WeaponInfo::new(
+            Some(AmmoType::am_clip),
+            [S_CHAINUP, S_CHAINDOWN, S_CHAIN, S_CHAIN1, S_CHAINFLASH1],
+        ),
+        wp_missile => WeaponInfo::new(
+            Some(AmmoType::am_misl),
+            [S_MISSILEUP, S_MISSILEDOWN, S_MISSILE, S_MISSILE1, S_MISSILEFLASH1],
+        ),
+        wp_plasma => WeaponInfo::new(
+            Some(AmmoType::am_cell),
+            [S_PLASMAUP, S_PLASMADOWN, S_PLASMA, S_PLASMA1, S_PLASMAFLASH1],
+        ),
+        wp_bfg => WeaponInfo::new(
+            Some(AmmoType::am_cell),
+            [S_BFGUP, S_BFGDOWN, S_BFG, S_BFG1, S_BFGFLASH1],
+        ),
+        wp_chainsaw => WeaponInfo::new(None, [S_SAWUP, S_SAWDOWN, S_SAW, S_SAW1, S_NULL]),
+        wp_supershotgun => WeaponInfo::new(
+            Some(AmmoType::am_shell),
+            [S_DSGUNUP, S_DSGUNDOWN, S_DSGUN, S_DSGUN1, S_DSGUNFLASH1],
+        ),
+    }
+}
+
+impl WeaponType {
+    pub const COUNT: usize = Self::ALL.len();
+}
+
+impl AmmoType {
+    pub const COUNT: usize = Self::ALL.len();
+}
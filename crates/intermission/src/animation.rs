@@ -0,0 +1,161 @@
+use rand::{Rand, RngCore};
+
+const TIC_RATE: u32 = 35;
+
+/// Mirrors `animenum_t`.
+#[derive(Debug, Clone, Copy)]
+enum AnimType {
+    /// Loops all the time.
+    Always,
+    /// Plays once when entering the map of index `.0`, from 0.
+    Level(usize),
+}
+
+/// Mirrors the constant part of `anim_t`.
+#[derive(Debug, Clone, Copy)]
+struct AnimInfo {
+    anim_type: AnimType,
+    /// Tics between the frames.
+    period: u32,
+    frames: usize,
+    x: i32,
+    y: i32,
+}
+
+impl AnimInfo {
+    const fn always(period: u32, x: i32, y: i32) -> Self {
+        Self {
+            anim_type: AnimType::Always,
+            period,
+            frames: 3,
+            x,
+            y,
+        }
+    }
+
+    const fn level(map: usize, frames: usize, x: i32, y: i32) -> Self {
+        Self {
+            anim_type: AnimType::Level(map),
+            period: TIC_RATE / 3,
+            frames,
+            x,
+            y,
+        }
+    }
+}
+
+/// Mirrors `epsd0animinfo`, `epsd1animinfo` and `epsd2animinfo`.
+const ANIMS: [&[AnimInfo]; 3] = [
+    &[
+        AnimInfo::always(TIC_RATE / 3, 224, 104),
+        AnimInfo::always(TIC_RATE / 3, 184, 160),
+        AnimInfo::always(TIC_RATE / 3, 112, 136),
+        AnimInfo::always(TIC_RATE / 3, 72, 112),
+        AnimInfo::always(TIC_RATE / 3, 88, 96),
+        AnimInfo::always(TIC_RATE / 3, 64, 48),
+        AnimInfo::always(TIC_RATE / 3, 192, 40),
+        AnimInfo::always(TIC_RATE / 3, 136, 16),
+        AnimInfo::always(TIC_RATE / 3, 80, 16),
+        AnimInfo::always(TIC_RATE / 3, 64, 24),
+    ],
+    &[
+        AnimInfo::level(1, 1, 128, 136),
+        AnimInfo::level(2, 1, 128, 136),
+        AnimInfo::level(3, 1, 128, 136),
+        AnimInfo::level(4, 1, 128, 136),
+        AnimInfo::level(5, 1, 128, 136),
+        AnimInfo::level(6, 1, 128, 136),
+        AnimInfo::level(7, 1, 128, 136),
+        AnimInfo::level(8, 3, 192, 144),
+        AnimInfo::level(8, 1, 128, 136),
+    ],
+    &[
+        AnimInfo::always(TIC_RATE / 3, 104, 168),
+        AnimInfo::always(TIC_RATE / 3, 40, 136),
+        AnimInfo::always(TIC_RATE / 3, 160, 96),
+        AnimInfo::always(TIC_RATE / 3, 104, 80),
+        AnimInfo::always(TIC_RATE / 3, 120, 32),
+        AnimInfo::always(TIC_RATE / 4, 40, 0),
+    ],
+];
+
+/// Animation drawn over the map of an episode, e.g. the flames
+/// of episode 1.
+///
+/// Mirrors `anim_t`.
+#[derive(Debug, Clone)]
+pub struct Anim {
+    info: AnimInfo,
+    episode: usize,
+    index: usize,
+    next_tic: u32,
+    /// Frame drawn, `None` before the first one.
+    frame: Option<usize>,
+}
+
+impl Anim {
+    /// Start the animations of `episode`, from 0, at tic `tic`.
+    ///
+    /// Mirrors `WI_initAnimatedBack`.
+    pub fn start_all(episode: usize, tic: u32, rand: &mut Rand) -> Vec<Anim> {
+        let Some(anims) = ANIMS.get(episode) else {
+            return Vec::new();
+        };
+        anims
+            .iter()
+            .enumerate()
+            .map(|(index, &info)| {
+                let next_tic = match info.anim_type {
+                    AnimType::Always => tic + 1 + rand.next_u32() % info.period,
+                    AnimType::Level(_) => tic + 1,
+                };
+                Anim {
+                    info,
+                    episode,
+                    index,
+                    next_tic,
+                    frame: None,
+                }
+            })
+            .collect()
+    }
+
+    /// Move to the next frame when it's time. `next` is the index of
+    /// the map entered, and `counting` is set while counting the stats.
+    ///
+    /// Mirrors `WI_updateAnimatedBack`.
+    pub fn update(&mut self, tic: u32, next: usize, counting: bool) {
+        if tic != self.next_tic {
+            return;
+        }
+        match self.info.anim_type {
+            AnimType::Always => {
+                let frame = self.frame.map_or(0, |frame| frame + 1);
+                self.frame = Some(if frame >= self.info.frames { 0 } else { frame });
+                self.next_tic = tic + self.info.period;
+            }
+            AnimType::Level(map) => {
+                // The big animation of the secret level waits for the stats.
+                if (counting && self.index == 7) || next != map {
+                    return;
+                }
+                let frame = self.frame.map_or(0, |frame| frame + 1);
+                self.frame = Some(frame.min(self.info.frames - 1));
+                self.next_tic = tic + self.info.period;
+            }
+        }
+    }
+
+    /// Position and patch name of the frame to draw, if any.
+    pub fn patch(&self) -> Option<(i32, i32, String)> {
+        let frame = self.frame?;
+        // The last animation of episode 2 reuses the patches of the fifth.
+        let index = if self.episode == 1 && self.index == 8 {
+            4
+        } else {
+            self.index
+        };
+        let name = format!("WIA{}{index:02}{frame:02}", self.episode);
+        Some((self.info.x, self.info.y, name))
+    }
+}
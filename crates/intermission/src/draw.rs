@@ -0,0 +1,96 @@
+use anyhow::{bail, Result};
+use wad::prelude::*;
+use window::ScreenBuffer;
+
+pub const SCREEN_WIDTH: i32 = 320;
+pub const SCREEN_HEIGHT: i32 = 200;
+
+/// Draws the intermission graphics on the screen.
+pub struct Painter<'a> {
+    pub screen: &'a mut ScreenBuffer,
+    pub wad: &'a WadFile,
+}
+
+impl Painter<'_> {
+    pub fn patch(&self, name: &str) -> Result<&Patch> {
+        graphic(self.wad, name)
+    }
+
+    /// Mirrors `V_DrawPatch`.
+    pub fn draw(&mut self, x: i32, y: i32, name: &str) -> Result<()> {
+        let patch = graphic(self.wad, name)?;
+        let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+            bail!("Bad V_DrawPatch");
+        };
+        self.screen.draw_patch(x, y, patch)
+    }
+
+    /// Draw `name` centered on the screen.
+    pub fn draw_centered(&mut self, y: i32, name: &str) -> Result<()> {
+        let width = self.patch(name)?.width as i32;
+        self.draw((SCREEN_WIDTH - width) / 2, y, name)
+    }
+
+    /// Draw `n` right aligned on `x`, with `digits` digits, or as
+    /// many as needed when `None`. Returns the left of the number.
+    ///
+    /// Mirrors `WI_drawNum`.
+    pub fn draw_num(&mut self, mut x: i32, y: i32, n: i32, digits: Option<u32>) -> Result<i32> {
+        let font_width = self.patch("WINUM0")?.width as i32;
+        let digits = digits.unwrap_or_else(|| n.unsigned_abs().checked_ilog10().unwrap_or(0) + 1);
+        let mut n = n.unsigned_abs();
+        for _ in 0..digits {
+            x -= font_width;
+            self.draw(x, y, &format!("WINUM{}", n % 10))?;
+            n /= 10;
+        }
+        Ok(x)
+    }
+
+    /// Draw the percentage `p`, unless it's negative.
+    ///
+    /// Mirrors `WI_drawPercent`.
+    pub fn draw_percent(&mut self, x: i32, y: i32, p: i32) -> Result<()> {
+        if p < 0 {
+            return Ok(());
+        }
+        self.draw(x, y, "WIPCNT")?;
+        self.draw_num(x, y, p, None)?;
+        Ok(())
+    }
+
+    /// Draw the time `t` in seconds as `h:mm:ss`, right aligned on `x`,
+    /// unless it's negative.
+    ///
+    /// Mirrors `WI_drawTime`.
+    pub fn draw_time(&mut self, mut x: i32, y: i32, t: i32) -> Result<()> {
+        if t < 0 {
+            return Ok(());
+        }
+        if t > 61 * 59 {
+            let width = self.patch("WISUCKS")?.width as i32;
+            return self.draw(x - width, y, "WISUCKS");
+        }
+
+        let colon_width = self.patch("WICOLON")?.width as i32;
+        let mut div = 1;
+        loop {
+            let n = (t / div) % 60;
+            x = self.draw_num(x, y, n, Some(2))? - colon_width;
+            div *= 60;
+            if div == 60 || t / div != 0 {
+                self.draw(x, y, "WICOLON")?;
+            }
+            if t / div == 0 {
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn graphic<'a>(wad: &'a WadFile, name: &str) -> Result<&'a Patch> {
+    let Some(patch) = wad.graphic(name) else {
+        bail!("Missing intermission graphic {name}");
+    };
+    Ok(patch)
+}
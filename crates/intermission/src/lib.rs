@@ -0,0 +1,539 @@
+use animation::Anim;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use derive_more::{Deref, DerefMut};
+use draw::{Painter, SCREEN_HEIGHT, SCREEN_WIDTH};
+use exit::macros::sys_fail;
+use game_state::conditions::{in_intermission_state, in_level_state};
+use game_state::PlayingState;
+use info::Sfx;
+use leafwing_input_manager::prelude::ActionState;
+use level::prelude::{
+    CurrentMap, ExitLevel, LevelStats, LevelTime, LevelTotals, PlayerAction, StartSound,
+};
+use level::LoadLevel;
+use rand::Rand;
+use results::{next_map, par_time, LevelResults};
+use wad::prelude::*;
+use window::ScreenBuffer;
+
+mod animation;
+mod draw;
+mod results;
+
+const TIC_RATE: i32 = 35;
+/// Tics the "you are here" pointer blinks before entering the next map.
+const SHOW_NEXT_LOC_DELAY: i32 = 4 * TIC_RATE;
+const TITLE_Y: i32 = 2;
+const STATS_X: i32 = 50;
+const STATS_Y: i32 = 50;
+const TIME_X: i32 = 16;
+const TIME_Y: i32 = SCREEN_HEIGHT - 32;
+
+/// Where the maps are on the backgrounds of the first three episodes.
+///
+/// Mirrors `lnodes`.
+const MAP_NODES: [[(i32, i32); 9]; 3] = [
+    [
+        (185, 164),
+        (148, 143),
+        (69, 122),
+        (209, 102),
+        (116, 89),
+        (166, 55),
+        (71, 56),
+        (135, 29),
+        (71, 24),
+    ],
+    [
+        (254, 25),
+        (97, 50),
+        (188, 64),
+        (128, 78),
+        (214, 92),
+        (133, 130),
+        (208, 136),
+        (148, 140),
+        (235, 158),
+    ],
+    [
+        (156, 168),
+        (48, 154),
+        (174, 95),
+        (265, 75),
+        (130, 48),
+        (279, 23),
+        (198, 48),
+        (140, 25),
+        (281, 136),
+    ],
+];
+
+/// Shows the stats of the level just finished, then where the next
+/// one is, before loading it.
+#[derive(Default)]
+pub struct IntermissionPlugin;
+
+impl Plugin for IntermissionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DidSecret>()
+            .add_systems(Update, reset_did_secret.run_if(on_event::<LoadLevel>))
+            .add_systems(
+                Update,
+                start_intermission
+                    .run_if(on_event::<ExitLevel>)
+                    .run_if(in_level_state()),
+            )
+            .add_systems(
+                FixedUpdate,
+                tick_intermission.run_if(in_intermission_state()),
+            )
+            .add_systems(
+                PostUpdate,
+                draw_intermission.run_if(in_intermission_state()),
+            );
+    }
+}
+
+/// Set once the secret level of the episode is finished.
+///
+/// Mirrors `didsecret` of `player_t`.
+#[derive(Resource, Debug, Default, Deref, DerefMut)]
+pub struct DidSecret(bool);
+
+/// Mirrors `stateenum_t`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Stage {
+    /// Counting the kills, items, secrets and time.
+    StatCount,
+    /// Showing the next map on the episode background.
+    ShowNextLoc,
+    /// Waiting a bit before loading the next map.
+    NoState,
+}
+
+/// State of the intermission screen.
+#[derive(Resource, Debug)]
+struct Intermission {
+    results: LevelResults,
+    stage: Stage,
+    /// Tics left in the stage.
+    count: i32,
+    /// Tics since the intermission started.
+    tic: u32,
+    /// Set when use is pressed, to skip ahead.
+    accelerate: bool,
+    use_down: bool,
+    /// Step of the stats counting, the odd ones being pauses.
+    step: u32,
+    kills: i32,
+    items: i32,
+    secrets: i32,
+    time: i32,
+    par: i32,
+    /// Tics left in the pause between two stats.
+    pause: i32,
+    /// Whether the "you are here" pointer is shown, it blinks.
+    pointer_on: bool,
+    anims: Vec<Anim>,
+}
+
+impl Intermission {
+    /// Mirrors `WI_Start` and `WI_initStats`.
+    fn new(results: LevelResults, rand: &mut Rand) -> Self {
+        let anims = Self::start_anims(&results, 0, rand);
+        Self {
+            results,
+            stage: Stage::StatCount,
+            count: 0,
+            tic: 0,
+            accelerate: false,
+            use_down: false,
+            step: 1,
+            kills: -1,
+            items: -1,
+            secrets: -1,
+            time: -1,
+            par: -1,
+            pause: TIC_RATE,
+            pointer_on: false,
+            anims,
+        }
+    }
+
+    fn start_anims(results: &LevelResults, tic: u32, rand: &mut Rand) -> Vec<Anim> {
+        results
+            .episode()
+            .map_or_else(Vec::new, |episode| Anim::start_all(episode, tic, rand))
+    }
+
+    /// Mirrors `WI_updateAnimatedBack`.
+    fn update_anims(&mut self) {
+        let next = self.results.next.map_or(0, map_index);
+        let counting = self.stage == Stage::StatCount;
+        for anim in &mut self.anims {
+            anim.update(self.tic, next, counting);
+        }
+    }
+
+    /// Mirrors `WI_checkForAccelerate`.
+    fn check_for_accelerate(&mut self, use_pressed: bool) {
+        if use_pressed {
+            if !self.use_down {
+                self.accelerate = true;
+            }
+            self.use_down = true;
+        } else {
+            self.use_down = false;
+        }
+    }
+
+    /// Mirrors `WI_updateStats`.
+    fn update_stats(&mut self, sounds: &mut EventWriter<StartSound>, rand: &mut Rand) {
+        self.update_anims();
+        let results = self.results;
+        let seconds = results.seconds();
+        let par = results.par_time as i32;
+
+        if self.accelerate && self.step != 10 {
+            self.accelerate = false;
+            self.kills = results.kills_percent();
+            self.items = results.items_percent();
+            self.secrets = results.secrets_percent();
+            self.time = seconds;
+            self.par = par;
+            sounds.send(StartSound::new(None, Sfx::sfx_barexp));
+            self.step = 10;
+        }
+
+        match self.step {
+            2 => self.kills = self.count_up(self.kills, results.kills_percent(), sounds),
+            4 => self.items = self.count_up(self.items, results.items_percent(), sounds),
+            6 => self.secrets = self.count_up(self.secrets, results.secrets_percent(), sounds),
+            8 => {
+                if self.tic & 3 == 0 {
+                    sounds.send(StartSound::new(None, Sfx::sfx_pistol));
+                }
+                self.time = (self.time + 3).min(seconds);
+                self.par = (self.par + 3).min(par);
+                if self.par >= par && self.time >= seconds {
+                    sounds.send(StartSound::new(None, Sfx::sfx_barexp));
+                    self.step += 1;
+                }
+            }
+            10 => {
+                if !self.accelerate {
+                    return;
+                }
+                sounds.send(StartSound::new(None, Sfx::sfx_sgcock));
+                match (results.next, results.episode()) {
+                    // There's no finale yet, so the game ends on the stats.
+                    (None, _) => self.accelerate = false,
+                    (Some(_), None) => self.start_no_state(),
+                    (Some(_), Some(_)) => self.start_show_next_loc(rand),
+                }
+            }
+            _ => {
+                self.pause -= 1;
+                if self.pause == 0 {
+                    self.step += 1;
+                    self.pause = TIC_RATE;
+                }
+            }
+        }
+    }
+
+    /// Count `counter` up to `target` percents, moving to the next
+    /// step once there.
+    fn count_up(&mut self, counter: i32, target: i32, sounds: &mut EventWriter<StartSound>) -> i32 {
+        if self.tic & 3 == 0 {
+            sounds.send(StartSound::new(None, Sfx::sfx_pistol));
+        }
+        if counter + 2 < target {
+            return counter + 2;
+        }
+        sounds.send(StartSound::new(None, Sfx::sfx_barexp));
+        self.step += 1;
+        target
+    }
+
+    /// Mirrors `WI_initShowNextLoc`.
+    fn start_show_next_loc(&mut self, rand: &mut Rand) {
+        self.stage = Stage::ShowNextLoc;
+        self.accelerate = false;
+        self.count = SHOW_NEXT_LOC_DELAY;
+        self.anims = Self::start_anims(&self.results, self.tic, rand);
+    }
+
+    /// Mirrors `WI_updateShowNextLoc`.
+    fn update_show_next_loc(&mut self) {
+        self.update_anims();
+        self.count -= 1;
+        if self.count == 0 || self.accelerate {
+            self.start_no_state();
+        } else {
+            self.pointer_on = (self.count & 31) < 20;
+        }
+    }
+
+    /// Mirrors `WI_initNoState`.
+    fn start_no_state(&mut self) {
+        self.stage = Stage::NoState;
+        self.accelerate = false;
+        self.count = 10;
+        self.pointer_on = true;
+    }
+
+    /// Returns the map to load once the intermission is over.
+    ///
+    /// Mirrors `WI_updateNoState`.
+    fn update_no_state(&mut self) -> Option<MapId> {
+        self.update_anims();
+        self.count -= 1;
+        if self.count == 0 {
+            self.results.next
+        } else {
+            None
+        }
+    }
+
+    /// Mirrors `WI_slamBackground` and `WI_drawAnimatedBack`.
+    fn draw_background(&self, painter: &mut Painter) -> anyhow::Result<()> {
+        let background = match self.results.episode() {
+            Some(episode @ 0..=2) => format!("WIMAP{episode}"),
+            _ => "INTERPIC".to_string(),
+        };
+        painter.draw(0, 0, &background)?;
+        for anim in &self.anims {
+            if let Some((x, y, name)) = anim.patch() {
+                painter.draw(x, y, &name)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw the name of the map finished.
+    ///
+    /// Mirrors `WI_drawLF`.
+    fn draw_finished(&self, painter: &mut Painter) -> anyhow::Result<()> {
+        let name = level_name(self.results.last);
+        let height = painter.patch(&name)?.height as i32;
+        painter.draw_centered(TITLE_Y, &name)?;
+        painter.draw_centered(TITLE_Y + 5 * height / 4, "WIF")
+    }
+
+    /// Draw the name of the map entered.
+    ///
+    /// Mirrors `WI_drawEL`.
+    fn draw_entering(&self, painter: &mut Painter, next: MapId) -> anyhow::Result<()> {
+        let name = level_name(next);
+        let height = painter.patch(&name)?.height as i32;
+        painter.draw_centered(TITLE_Y, "WIENTER")?;
+        painter.draw_centered(TITLE_Y + 5 * height / 4, &name)
+    }
+
+    /// Mirrors `WI_drawStats`.
+    fn draw_stats(&self, painter: &mut Painter) -> anyhow::Result<()> {
+        let line_height = 3 * painter.patch("WINUM0")?.height as i32 / 2;
+        self.draw_background(painter)?;
+        self.draw_finished(painter)?;
+
+        let stats = [
+            ("WIOSTK", self.kills),
+            ("WIOSTI", self.items),
+            ("WISCRT2", self.secrets),
+        ];
+        for (line, (name, percent)) in (0..).zip(stats) {
+            let y = STATS_Y + line * line_height;
+            painter.draw(STATS_X, y, name)?;
+            painter.draw_percent(SCREEN_WIDTH - STATS_X, y, percent)?;
+        }
+
+        painter.draw(TIME_X, TIME_Y, "WITIME")?;
+        painter.draw_time(SCREEN_WIDTH / 2 - TIME_X, TIME_Y, self.time)?;
+        // Episode 4 has no par times.
+        if !matches!(self.results.episode(), Some(3..)) {
+            painter.draw(SCREEN_WIDTH / 2 + TIME_X, TIME_Y, "WIPAR")?;
+            painter.draw_time(SCREEN_WIDTH - TIME_X, TIME_Y, self.par)?;
+        }
+        Ok(())
+    }
+
+    /// Draw the maps done and the one entered on the background.
+    ///
+    /// Mirrors `WI_drawShowNextLoc`.
+    fn draw_show_next_loc(&self, painter: &mut Painter) -> anyhow::Result<()> {
+        self.draw_background(painter)?;
+        let Some(next) = self.results.next else {
+            return Ok(());
+        };
+
+        if let Some(episode) = self.results.episode() {
+            if episode > 2 {
+                return self.draw_entering(painter, next);
+            }
+            let last = match map_index(self.results.last) {
+                8 => map_index(next) - 1,
+                last => last,
+            };
+            // Splat the maps done.
+            for map in 0..=last {
+                draw_on_node(painter, episode, map, &["WISPLAT"])?;
+            }
+            if self.results.did_secret {
+                draw_on_node(painter, episode, 8, &["WISPLAT"])?;
+            }
+            if self.pointer_on {
+                draw_on_node(painter, episode, map_index(next), &["WIURH0", "WIURH1"])?;
+            }
+        }
+
+        // The secret levels of Doom II are announced by the finale texts.
+        if next != (MapId::Commercial { map: 31 }) {
+            self.draw_entering(painter, next)?;
+        }
+        Ok(())
+    }
+}
+
+/// Draw the first of `names` fitting on the screen at the
+/// place of `map` on the background of `episode`.
+///
+/// Mirrors `WI_drawOnLnode`.
+fn draw_on_node(
+    painter: &mut Painter,
+    episode: usize,
+    map: usize,
+    names: &[&str],
+) -> anyhow::Result<()> {
+    let (x, y) = MAP_NODES[episode][map];
+    for name in names {
+        let patch = painter.patch(name)?;
+        let left = x - patch.left_offset as i32;
+        let top = y - patch.top_offset as i32;
+        let right = left + patch.width as i32;
+        let bottom = top + patch.height as i32;
+        if left >= 0 && right < SCREEN_WIDTH && top >= 0 && bottom < SCREEN_HEIGHT {
+            return painter.draw(x, y, name);
+        }
+    }
+    warn!("Could not place patch on level {}", map + 1);
+    Ok(())
+}
+
+/// Index of `map` in its episode, from 0.
+fn map_index(map: MapId) -> usize {
+    match map {
+        MapId::Episode { map, .. } | MapId::Commercial { map } => map - 1,
+    }
+}
+
+/// Name of the patch with the title of `map`.
+fn level_name(map: MapId) -> String {
+    match map {
+        MapId::Episode { episode, map } => format!("WILV{}{}", episode - 1, map - 1),
+        MapId::Commercial { map } => format!("CWILV{:02}", map - 1),
+    }
+}
+
+/// The level just finished.
+#[derive(SystemParam)]
+struct FinishedLevel<'w> {
+    current_map: Res<'w, CurrentMap>,
+    totals: Res<'w, LevelTotals>,
+    stats: Res<'w, LevelStats>,
+    level_time: Res<'w, LevelTime>,
+}
+
+/// A new game starts without the secret level finished.
+///
+/// Mirrors the reset of `didsecret` by `G_PlayerReborn`.
+fn reset_did_secret(mut load: EventReader<LoadLevel>, mut did_secret: ResMut<DidSecret>) {
+    if load.read().any(|load| load.new_game) {
+        **did_secret = false;
+    }
+}
+
+/// Sum up the level just finished, and start the intermission.
+///
+/// Mirrors `G_DoCompleted`.
+fn start_intermission(
+    mut exits: EventReader<ExitLevel>,
+    level: FinishedLevel,
+    wad: Res<WadFile>,
+    mut did_secret: ResMut<DidSecret>,
+    mut rand: ResMut<Rand>,
+    mut commands: Commands,
+) {
+    let Some(exit) = exits.read().last() else {
+        return;
+    };
+    let last = **level.current_map;
+    // Doom II has no secret exits without its secret level.
+    let has_secret_level = match last {
+        MapId::Episode { .. } => true,
+        MapId::Commercial { .. } => wad.map(MapId::Commercial { map: 31 }).is_some(),
+    };
+    let secret = exit.secret && has_secret_level;
+    if let MapId::Episode { map: 9, .. } = last {
+        **did_secret = true;
+    }
+    debug!("Exiting Map {last}");
+
+    let results = LevelResults {
+        last,
+        next: next_map(last, secret),
+        did_secret: **did_secret,
+        totals: *level.totals,
+        stats: *level.stats,
+        time: **level.level_time,
+        par_time: par_time(last),
+    };
+    commands.insert_resource(Intermission::new(results, &mut rand));
+    commands.set_state(PlayingState::Intermission);
+}
+
+/// Mirrors `WI_Ticker` and `G_WorldDone`.
+fn tick_intermission(
+    mut intermission: ResMut<Intermission>,
+    action_query: Query<&ActionState<PlayerAction>>,
+    mut rand: ResMut<Rand>,
+    mut sounds: EventWriter<StartSound>,
+    mut load_level: EventWriter<LoadLevel>,
+) {
+    intermission.tic += 1;
+    let use_pressed = action_query
+        .iter()
+        .any(|action_state| action_state.pressed(&PlayerAction::Use));
+    intermission.check_for_accelerate(use_pressed);
+
+    match intermission.stage {
+        Stage::StatCount => intermission.update_stats(&mut sounds, &mut rand),
+        Stage::ShowNextLoc => intermission.update_show_next_loc(),
+        Stage::NoState => {
+            if let Some(map) = intermission.update_no_state() {
+                load_level.send(LoadLevel {
+                    map,
+                    new_game: false,
+                });
+            }
+        }
+    }
+}
+
+/// Mirrors `WI_Drawer`.
+#[sys_fail]
+fn draw_intermission(
+    mut screen: ResMut<ScreenBuffer>,
+    wad: Res<WadFile>,
+    intermission: Res<Intermission>,
+) {
+    let mut painter = Painter {
+        screen: &mut screen,
+        wad: &wad,
+    };
+    match intermission.stage {
+        Stage::StatCount => intermission.draw_stats(&mut painter)?,
+        Stage::ShowNextLoc | Stage::NoState => intermission.draw_show_next_loc(&mut painter)?,
+    }
+}
@@ -0,0 +1,153 @@
+use level::prelude::{LevelStats, LevelTotals};
+use wad::prelude::MapId;
+
+/// Par times of Doom in seconds, by episode and map.
+const PARS: [[u32; 9]; 3] = [
+    [30, 75, 120, 90, 165, 180, 180, 30, 165],
+    [90, 90, 90, 120, 90, 360, 240, 30, 170],
+    [90, 45, 90, 150, 90, 90, 165, 30, 135],
+];
+
+/// Par times of Doom II in seconds, by map.
+const CPARS: [u32; 32] = [
+    30, 90, 120, 120, 90, 150, 120, 120, 270, 90, // 1-10
+    210, 150, 150, 150, 210, 150, 420, 150, 210, 150, // 11-20
+    240, 150, 180, 150, 150, 300, 330, 420, 300, 180, // 21-30
+    120, 30, // 31-32
+];
+
+/// What the player did in the level just finished, and where
+/// the game goes next.
+///
+/// Mirrors `wbstartstruct_t`.
+#[derive(Debug, Clone, Copy)]
+pub struct LevelResults {
+    pub last: MapId,
+    /// `None` once the game is over.
+    pub next: Option<MapId>,
+    /// Whether the secret level of the episode was finished.
+    pub did_secret: bool,
+    pub totals: LevelTotals,
+    pub stats: LevelStats,
+    /// Tics spent in the level.
+    pub time: u32,
+    /// Par time in seconds.
+    pub par_time: u32,
+}
+
+impl LevelResults {
+    /// Episode of the maps, from 0, or `None` for Doom II.
+    pub fn episode(&self) -> Option<usize> {
+        match self.last {
+            MapId::Episode { episode, .. } => Some(episode - 1),
+            MapId::Commercial { .. } => None,
+        }
+    }
+
+    /// Percentage of the monsters killed.
+    pub fn kills_percent(&self) -> i32 {
+        percent(self.stats.kills, self.totals.kills)
+    }
+
+    /// Percentage of the items picked up.
+    pub fn items_percent(&self) -> i32 {
+        percent(self.stats.items, self.totals.items)
+    }
+
+    /// Percentage of the secret sectors found.
+    pub fn secrets_percent(&self) -> i32 {
+        percent(self.stats.secrets, self.totals.secrets)
+    }
+
+    /// Seconds spent in the level.
+    pub fn seconds(&self) -> i32 {
+        (self.time / 35) as i32
+    }
+}
+
+fn percent(count: u32, total: u32) -> i32 {
+    // Levels with nothing to find count as 1, so that's 0%.
+    (count * 100 / total.max(1)) as i32
+}
+
+/// Par time of `map`, in seconds. Episode 4 has none.
+pub fn par_time(map: MapId) -> u32 {
+    match map {
+        MapId::Episode { episode, map } => PARS
+            .get(episode - 1)
+            .and_then(|pars| pars.get(map - 1))
+            .copied()
+            .unwrap_or(0),
+        MapId::Commercial { map } => CPARS.get(map - 1).copied().unwrap_or(0),
+    }
+}
+
+/// Find the map following `last`, going to the secret level of the
+/// episode when `secret`. Returns `None` at the end of the game, where
+/// the finale would start.
+///
+/// Mirrors the map routing of `G_DoCompleted` and `G_WorldDone`.
+pub fn next_map(last: MapId, secret: bool) -> Option<MapId> {
+    let next = match last {
+        MapId::Episode { map: 8, .. } | MapId::Commercial { map: 30 } => return None,
+        MapId::Episode { episode, .. } if secret => MapId::Episode { episode, map: 9 },
+        // Returning from the secret level.
+        MapId::Episode { episode, map: 9 } => {
+            let map = match episode {
+                1 => 4,
+                2 => 6,
+                3 => 7,
+                _ => 3,
+            };
+            MapId::Episode { episode, map }
+        }
+        MapId::Episode { episode, map } => MapId::Episode {
+            episode,
+            map: map + 1,
+        },
+        MapId::Commercial { map: 15 } if secret => MapId::Commercial { map: 31 },
+        MapId::Commercial { map: 31 } if secret => MapId::Commercial { map: 32 },
+        MapId::Commercial { map: 31 | 32 } => MapId::Commercial { map: 16 },
+        MapId::Commercial { map } => MapId::Commercial { map: map + 1 },
+    };
+    Some(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn episode(episode: usize, map: usize) -> MapId {
+        MapId::Episode { episode, map }
+    }
+
+    fn commercial(map: usize) -> MapId {
+        MapId::Commercial { map }
+    }
+
+    #[test]
+    fn episodes_go_through_the_secret_level() {
+        assert_eq!(next_map(episode(1, 3), true), Some(episode(1, 9)));
+        assert_eq!(next_map(episode(1, 9), false), Some(episode(1, 4)));
+        assert_eq!(next_map(episode(4, 9), false), Some(episode(4, 3)));
+        assert_eq!(next_map(episode(1, 8), false), None);
+    }
+
+    #[test]
+    fn commercial_goes_through_the_secret_levels() {
+        assert_eq!(next_map(commercial(15), true), Some(commercial(31)));
+        assert_eq!(next_map(commercial(31), true), Some(commercial(32)));
+        assert_eq!(next_map(commercial(32), false), Some(commercial(16)));
+        assert_eq!(next_map(commercial(31), false), Some(commercial(16)));
+        assert_eq!(next_map(commercial(30), false), None);
+    }
+
+    #[test]
+    fn par_times() {
+        assert_eq!(par_time(episode(1, 1)), 30);
+        assert_eq!(par_time(episode(3, 9)), 135);
+        assert_eq!(par_time(episode(4, 1)), 0);
+        assert_eq!(par_time(commercial(1)), 30);
+        assert_eq!(par_time(commercial(32)), 30);
+    }
+}
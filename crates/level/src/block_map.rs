@@ -0,0 +1,221 @@
+use crate::components::Line;
+use crate::map_object::prelude::MapObject;
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use info::MobjFlags;
+use moonshine_kind::Instance;
+use wad::prelude::*;
+
+/// Size of the side of a block, in map units.
+pub const BLOCK_SIZE: f32 = 128.0;
+
+/// Lines and things of the level grouped by the 128x128 blocks they
+/// are in. Collision checks and traces only look at the lines and
+/// things of the blocks they touch, instead of going through the
+/// whole map.
+#[derive(Resource, Default)]
+pub struct BlockMap {
+    origin: Vec2,
+    width: i32,
+    height: i32,
+    blocks: Vec<Vec<Instance<Line>>>,
+    /// Things linked to each block, by the block of their center.
+    things: Vec<Vec<Entity>>,
+    /// Block each linked thing is in.
+    thing_blocks: HashMap<Entity, usize>,
+}
+
+impl BlockMap {
+    pub(crate) fn new(block_map: &MapBlockMap, lines: &[Instance<Line>]) -> Self {
+        let width = i32::from(block_map.width);
+        let height = i32::from(block_map.height);
+        let blocks = (0..(width * height) as usize)
+            .map(|block| {
+                block_map
+                    .block_lines(block)
+                    .filter_map(|line| lines.get(usize::try_from(line).ok()?).copied())
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            origin: Vec2::new(block_map.origin_x.into(), block_map.origin_y.into()),
+            width,
+            height,
+            blocks,
+            things: vec![vec![]; (width * height) as usize],
+            thing_blocks: HashMap::new(),
+        }
+    }
+
+    /// Get the block containing `point`. The block may be outside
+    /// of the map, in which case it has no lines.
+    pub fn block_of(&self, point: Vec2) -> IVec2 {
+        ((point - self.origin) / BLOCK_SIZE).floor().as_ivec2()
+    }
+
+    /// Get the lines crossing the block `block`.
+    pub fn block_lines(&self, block: IVec2) -> &[Instance<Line>] {
+        self.index(block).map_or(&[], |index| &self.blocks[index])
+    }
+
+    /// Get the things whose center is in the block `block`.
+    pub fn block_things(&self, block: IVec2) -> &[Entity] {
+        self.index(block).map_or(&[], |index| &self.things[index])
+    }
+
+    /// Iterate over the things of the blocks touched by the box going
+    /// from `min` to `max`. Things are linked to a single block, so
+    /// the box has to be grown by their radius.
+    ///
+    /// Mirrors `P_BlockThingsIterator` called on every block of a box.
+    pub fn things_in_box(&self, min: Vec2, max: Vec2) -> impl Iterator<Item = Entity> + '_ {
+        let min = self.block_of(min);
+        let max = self.block_of(max);
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+            .flat_map(|block| self.block_things(block).iter().copied())
+    }
+
+    /// Iterate over the things of the blocks crossed by the path going
+    /// from `start` to `end`, in the order the blocks are crossed. The
+    /// things may not actually cross the path.
+    ///
+    /// Mirrors the block walk of `P_PathTraverse` with `PT_ADDTHINGS`.
+    pub fn things_on_path(&self, start: Vec2, end: Vec2) -> impl Iterator<Item = Entity> + '_ {
+        self.blocks_on_path(start, end)
+            .into_iter()
+            .flat_map(|block| self.block_things(block).iter().copied())
+    }
+
+    /// Link `thing` to the block containing `pos`, or only unlink it
+    /// from its block when `pos` is `None` or outside of the map.
+    ///
+    /// Mirrors the block links of `P_UnsetThingPosition` and
+    /// `P_SetThingPosition`.
+    fn link_thing(&mut self, thing: Entity, pos: Option<Vec2>) {
+        let index = pos.and_then(|pos| self.index(self.block_of(pos)));
+        if self.thing_blocks.get(&thing).copied() == index {
+            return;
+        }
+        if let Some(old) = self.thing_blocks.remove(&thing) {
+            self.things[old].retain(|&other| other != thing);
+        }
+        if let Some(index) = index {
+            self.things[index].push(thing);
+            self.thing_blocks.insert(thing, index);
+        }
+    }
+
+    fn index(&self, block: IVec2) -> Option<usize> {
+        if block.x < 0 || block.y < 0 || block.x >= self.width || block.y >= self.height {
+            return None;
+        }
+        Some((block.y * self.width + block.x) as usize)
+    }
+
+    /// Iterate over the lines of the blocks touched by the box going
+    /// from `min` to `max`. Each line is returned once, even when it
+    /// crosses several blocks.
+    ///
+    /// Mirrors `P_BlockLinesIterator` called on every block of a box.
+    pub fn lines_in_box(&self, min: Vec2, max: Vec2) -> impl Iterator<Item = Instance<Line>> + '_ {
+        let min = self.block_of(min);
+        let max = self.block_of(max);
+        let blocks =
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)));
+        self.unique_lines(blocks)
+    }
+
+    /// Iterate over the lines of the blocks crossed by the path going
+    /// from `start` to `end`, in the order the blocks are crossed. Each
+    /// line is returned once, and may not actually cross the path.
+    ///
+    /// Mirrors the block walk of `P_PathTraverse`.
+    pub fn lines_on_path(
+        &self,
+        start: Vec2,
+        end: Vec2,
+    ) -> impl Iterator<Item = Instance<Line>> + '_ {
+        self.unique_lines(self.blocks_on_path(start, end).into_iter())
+    }
+
+    fn unique_lines<'a>(
+        &'a self,
+        blocks: impl Iterator<Item = IVec2> + 'a,
+    ) -> impl Iterator<Item = Instance<Line>> + 'a {
+        // Replaces the `validcount` of vanilla.
+        let mut checked = HashSet::new();
+        blocks
+            .flat_map(|block| self.block_lines(block).iter().copied())
+            .filter(move |line| checked.insert(line.entity()))
+    }
+
+    /// Walk the grid from the block of `start` to the block of `end`.
+    fn blocks_on_path(&self, start: Vec2, end: Vec2) -> Vec<IVec2> {
+        let start = (start - self.origin) / BLOCK_SIZE;
+        let end = (end - self.origin) / BLOCK_SIZE;
+        let delta = end - start;
+        let mut block = start.floor().as_ivec2();
+        let end_block = end.floor().as_ivec2();
+
+        let step = IVec2::new(delta.x.signum() as i32, delta.y.signum() as i32);
+        // Fraction of the path to go through a whole block, and fraction
+        // of the path at which the next block boundary is crossed.
+        let frac_delta = delta.abs().recip();
+        let mut next_frac = Vec2::new(
+            boundary_frac(start.x, delta.x, block.x),
+            boundary_frac(start.y, delta.y, block.y),
+        );
+
+        let count = (end_block - block).abs();
+        let mut blocks = Vec::with_capacity((count.x + count.y + 1) as usize);
+        blocks.push(block);
+        while block != end_block && blocks.len() <= (count.x + count.y) as usize {
+            if next_frac.x < next_frac.y {
+                block.x += step.x;
+                next_frac.x += frac_delta.x;
+            } else if next_frac.y < next_frac.x {
+                block.y += step.y;
+                next_frac.y += frac_delta.y;
+            } else {
+                // Going exactly through a corner, both
+                // neighbouring blocks are touched.
+                blocks.push(IVec2::new(block.x + step.x, block.y));
+                block += step;
+                next_frac += frac_delta;
+            }
+            blocks.push(block);
+        }
+        blocks
+    }
+}
+
+/// Link the things spawned or moved since the last run to the blocks
+/// they are in, and unlink the ones gone. Things with `NOBLOCKMAP`,
+/// e.g. missiles and puffs, aren't linked.
+pub(crate) fn link_things(
+    mut block_map: ResMut<BlockMap>,
+    query: Query<(Entity, &MapObject), Changed<MapObject>>,
+    mut removed: RemovedComponents<MapObject>,
+) {
+    for entity in removed.read() {
+        block_map.link_thing(entity, None);
+    }
+    for (entity, map_obj) in &query {
+        let pos = (!map_obj.flags.contains(MobjFlags::NOBLOCKMAP)).then_some(map_obj.pos);
+        block_map.link_thing(entity, pos);
+    }
+}
+
+/// Fraction of the path at which the first block boundary is crossed
+/// along one axis, infinite if the path never crosses one.
+fn boundary_frac(start: f32, delta: f32, block: i32) -> f32 {
+    if delta > 0.0 {
+        (block as f32 + 1.0 - start) / delta
+    } else if delta < 0.0 {
+        (start - block as f32) / -delta
+    } else {
+        f32::INFINITY
+    }
+}
@@ -1,4 +1,4 @@
-use super::utils::{get_back_sector, get_front_sector};
+use super::utils::{get_back_sector, get_back_side, get_front_sector, get_front_side};
 use crate::components::{Line, PortalLine, WallLine};
 use crate::resources::LevelMap;
 use anyhow::{bail, Result};
@@ -27,8 +27,10 @@ fn create_line(line: &MapLine, map: &Map, level_map: &LevelMap) -> Result<Line>
     let v2 = get_end_vertex(line, level_map)?;
     let front_sector = get_front_sector(line, map, level_map)?;
     let back_sector = get_back_sector(line, map, level_map)?;
+    let front_side = get_front_side(line, level_map)?;
+    let back_side = get_back_side(line, level_map)?;
 
-    let line = match back_sector {
+    let line = match back_sector.zip(back_side) {
         None => Line::Wall(WallLine {
             v1,
             v2,
@@ -36,8 +38,9 @@ fn create_line(line: &MapLine, map: &Map, level_map: &LevelMap) -> Result<Line>
             special: line.special,
             tag: line.tag,
             front_sector,
+            front_side,
         }),
-        Some(back_sector) => Line::Portal(PortalLine {
+        Some((back_sector, back_side)) => Line::Portal(PortalLine {
             v1,
             v2,
             flags: line.flags,
@@ -45,6 +48,8 @@ fn create_line(line: &MapLine, map: &Map, level_map: &LevelMap) -> Result<Line>
             tag: line.tag,
             front_sector,
             back_sector,
+            front_side,
+            back_side,
         }),
     };
 
@@ -1,44 +1,189 @@
 use crate::map_object::prelude::Camera;
 use crate::map_object::prelude::*;
+use crate::skill::Skill;
+use crate::LevelTotals;
+use anyhow::{bail, Result};
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use moonshine_kind::{Instance, SpawnInstance};
-use std::f32::consts;
+use info::{Info, MobjFlags, MobjType};
 use leafwing_input_manager::InputManagerBundle;
+use moonshine_kind::{Instance, InstanceCommands, SpawnInstance};
+use rand::{Rand, RngCore};
+use std::f32::consts;
 use wad::prelude::*;
 
+/// Resources used to spawn the things of a map.
+#[derive(SystemParam)]
+pub struct ThingSpawner<'w, 's> {
+    info: Res<'w, Info>,
+    rand: ResMut<'w, Rand>,
+    skill: Res<'w, Skill>,
+    totals: ResMut<'w, LevelTotals>,
+    /// The player of the previous level, not despawned yet.
+    player_query: Query<'w, 's, (&'static Player, &'static MapObject)>,
+}
+
+impl ThingSpawner<'_, '_> {
+    /// Things are only spawned at the skill levels they are flagged for,
+    /// and the multiplayer ones never are, as there's no multiplayer yet.
+    fn is_spawned(&self, thing: &MapThing, game_mode: GameMode) -> bool {
+        if thing.options & MapThing::NOT_SINGLE != 0 {
+            return false;
+        }
+        if thing.options & self.skill.thing_flag() == 0 {
+            return false;
+        }
+        game_mode == GameMode::Commercial || !is_doom2_only(thing.thing_type)
+    }
+
+    /// The player entering the level and its health. It keeps what it
+    /// carried at the end of the previous level, unless it starts a
+    /// `new_game` or died, in which case it is reborn.
+    ///
+    /// Mirrors `G_PlayerFinishLevel` and `G_PlayerReborn`.
+    fn player(&self, new_game: bool) -> Option<(Player, i32)> {
+        if new_game {
+            return None;
+        }
+        let (player, map_obj) = self.player_query.get_single().ok()?;
+        (map_obj.health > 0).then(|| (player.finish_level(), map_obj.health))
+    }
+}
+
+/// Things only found in Doom II, whose sprites are missing from the
+/// Doom 1 IWADs: the new monsters, the super shotgun, the megasphere
+/// and the new decorations, e.g. the burning barrel and hanging bodies.
+///
+/// Mirrors `P_IsDoomnumAllowed` of PrBoom, which only filters the
+/// monsters and leaves the others to fail on their missing sprites.
+fn is_doom2_only(thing_type: i16) -> bool {
+    matches!(thing_type, 64..=89)
+}
+
 pub trait SpawnMapObjects {
-    fn spawn_map_objects(&mut self, map: &Map) -> Vec<Instance<MapObject>>;
+    fn spawn_map_objects(
+        &mut self,
+        map: &Map,
+        game_mode: GameMode,
+        spawner: &mut ThingSpawner,
+        new_game: bool,
+    ) -> Result<Vec<Instance<MapObject>>>;
+
+    fn spawn_map_object(
+        &mut self,
+        map_obj: MapObject,
+        info: &Info,
+    ) -> InstanceCommands<'_, MapObject>;
 }
 
 impl SpawnMapObjects for Commands<'_, '_> {
-    fn spawn_map_objects(&mut self, map: &Map) -> Vec<Instance<MapObject>> {
+    /// Mirrors `P_SpawnMapThing`.
+    fn spawn_map_objects(
+        &mut self,
+        map: &Map,
+        game_mode: GameMode,
+        spawner: &mut ThingSpawner,
+        new_game: bool,
+    ) -> Result<Vec<Instance<MapObject>>> {
+        let mut carried_player = spawner.player(new_game);
+        // Counted anew for every level.
+        *spawner.totals = LevelTotals::default();
+        let info = &spawner.info;
         let mut map_objs = Vec::with_capacity(map.things.len());
         for thing in &map.things {
-            if thing.thing_type >= 2 && thing.thing_type <= 4 {
-                // Skip net players.
+            if (2..=4).contains(&thing.thing_type) || thing.thing_type == 11 {
+                // Only the first player is spawned, the other player
+                // starts are skipped. So are the deathmatch starts,
+                // kept in `DeathmatchStarts`.
+                continue;
+            }
+            if thing.thing_type != 1 && !spawner.is_spawned(thing, game_mode) {
                 continue;
             }
 
-            let map_obj = create_map_object(thing);
-            let mut instance_cmds = self.spawn_instance(map_obj);
-            if thing.thing_type == 1 {
-                instance_cmds.insert((Player, Camera::new(map_obj), InputManagerBundle::with_map(PlayerAction::default_map())));
+            let mobj_type = if thing.thing_type == 1 {
+                MobjType::MT_PLAYER
+            } else if let Some(mobj_type) = info.find_doomed_num(thing.thing_type.into()) {
+                mobj_type
+            } else {
+                bail!(
+                    "P_SpawnMapThing: Unknown type {} at ({}, {})",
+                    thing.thing_type,
+                    thing.x,
+                    thing.y
+                );
+            };
+            let mut map_obj = create_map_object(thing, mobj_type, info);
+            let player = map_obj.is_player().then(|| {
+                let (player, health) = carried_player
+                    .take()
+                    .unwrap_or_else(|| (Player::new(info), info.initial_health()));
+                map_obj.health = health;
+                player
+            });
+            if map_obj.flags.contains(MobjFlags::COUNTKILL) {
+                spawner.totals.kills += 1;
+            }
+            if map_obj.flags.contains(MobjFlags::COUNTITEM) {
+                spawner.totals.items += 1;
+            }
+
+            let spawn_state = info.mobj_info(mobj_type).spawn_state;
+            let tics = info.state(spawn_state).tics;
+            let mut instance_cmds = self.spawn_map_object(map_obj, info);
+            if tics > 0 {
+                // Keeps things of the same kind from animating in sync.
+                instance_cmds.insert(MapObjectState {
+                    state: spawn_state,
+                    tics: 1 + (spawner.rand.next_u32() as i32 % tics),
+                });
+            }
+            if let Some(player) = player {
+                instance_cmds.insert((
+                    player,
+                    Camera::new(map_obj, VIEW_HEIGHT),
+                    InputManagerBundle::with_map(PlayerAction::default_map()),
+                ));
             }
             let instance = instance_cmds.instance();
 
             map_objs.push(instance);
         }
-        map_objs
+        Ok(map_objs)
+    }
+
+    /// Spawn `map_obj` in the spawn state of its type. It is put
+    /// on the floor by [`place_on_floor`](crate::map_object::place_on_floor).
+    ///
+    /// Mirrors `P_SpawnMobj`.
+    fn spawn_map_object(
+        &mut self,
+        map_obj: MapObject,
+        info: &Info,
+    ) -> InstanceCommands<'_, MapObject> {
+        let spawn_state = info.mobj_info(map_obj.mobj_type).spawn_state;
+        let state = info.state(spawn_state);
+        let mut instance_cmds = self.spawn_instance(map_obj);
+        instance_cmds.insert((
+            MapObjectState {
+                state: spawn_state,
+                tics: state.tics,
+            },
+            ThingSprite::from_state(state),
+        ));
+        instance_cmds
     }
 }
 
-fn create_map_object(thing: &MapThing) -> MapObject {
+fn create_map_object(thing: &MapThing, mobj_type: MobjType, info: &Info) -> MapObject {
+    let pos = Vec2::new(thing.x.into(), thing.y.into());
+    let mut map_obj = MapObject::new(mobj_type, pos, info);
     let angle = consts::FRAC_PI_4 * (thing.angle / 45) as f32;
-    MapObject {
-        pos: Vec2::new(thing.x.into(), thing.y.into()),
-        velocity: Vec2::ZERO,
-        dir: Rot2::radians(angle) * Dir2::X,
-        thing_type: thing.thing_type,
-        options: thing.options,
+    map_obj.dir = Rot2::radians(angle) * Dir2::X;
+    if thing.options & MapThing::AMBUSH != 0 {
+        map_obj.flags |= MobjFlags::AMBUSH;
     }
+    map_obj.thing_type = thing.thing_type;
+    map_obj.options = thing.options;
+    map_obj
 }
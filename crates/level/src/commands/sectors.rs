@@ -42,5 +42,9 @@ fn create_sector(sector: &MapSector, wad: &WadFile) -> Result<Sector> {
         light_level: sector.light_level,
         special: sector.special,
         tag: sector.tag,
+        // Filled once the lines are spawned.
+        lines: vec![],
+        bbox: Rect::EMPTY,
+        mover: None,
     })
 }
@@ -1,4 +1,4 @@
-use crate::components::Sector;
+use crate::components::{Sector, SideDef};
 use crate::resources::LevelMap;
 use anyhow::{bail, Result};
 use moonshine_kind::Instance;
@@ -40,3 +40,23 @@ fn get_sector(sector: usize, level_map: &LevelMap) -> Result<Instance<Sector>> {
     };
     Ok(*sector)
 }
+
+pub fn get_front_side(line: &MapLine, level_map: &LevelMap) -> Result<Instance<SideDef>> {
+    let front_side = usize::try_from(line.front_side)?;
+    get_side(front_side, level_map)
+}
+
+pub fn get_back_side(line: &MapLine, level_map: &LevelMap) -> Result<Option<Instance<SideDef>>> {
+    if line.back_side < 0 {
+        return Ok(None);
+    }
+    let back_side = usize::try_from(line.back_side)?;
+    get_side(back_side, level_map).map(Some)
+}
+
+fn get_side(side: usize, level_map: &LevelMap) -> Result<Instance<SideDef>> {
+    let Some(side) = level_map.lines_sides.get(side) else {
+        bail!("Line references invalid side.");
+    };
+    Ok(*side)
+}
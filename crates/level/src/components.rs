@@ -6,20 +6,22 @@ use std::rc::Rc;
 
 #[derive(Component)]
 pub struct Sector {
-    #[allow(unused)]
     pub floor_height: f32,
-    #[allow(unused)]
     pub ceiling_height: f32,
-    #[allow(unused)]
     pub floor_tex: usize,
     #[allow(unused)]
     pub ceiling_tex: usize,
     #[allow(unused)]
     pub light_level: i16,
-    #[allow(unused)]
     pub special: i16,
-    #[allow(unused)]
     pub tag: i16,
+    /// Lines around the sector, in the order of the map.
+    pub lines: Vec<Instance<Line>>,
+    /// Bounding box of the lines around the sector.
+    pub bbox: Rect,
+    /// Thinker moving the floor or the ceiling. Only one
+    /// can move a sector at a time.
+    pub mover: Option<Entity>,
 }
 
 #[derive(Component)]
@@ -33,11 +35,8 @@ pub struct SideDef {
     pub x_offset: f32,
     #[allow(unused)]
     pub y_offset: f32,
-    #[allow(unused)]
     pub top_texture: usize,
-    #[allow(unused)]
     pub lower_texture: usize,
-    #[allow(unused)]
     pub middle_texture: usize,
     #[allow(unused)]
     pub sector: Instance<Sector>,
@@ -50,6 +49,13 @@ pub enum Line {
 }
 
 impl Line {
+    /// Flag of the lines blocking players and monsters.
+    pub const BLOCKING: i16 = 1;
+    /// Flag of the lines blocking monsters only.
+    pub const BLOCK_MONSTERS: i16 = 2;
+    /// Flag of the lines monsters can't activate.
+    pub const SECRET: i16 = 32;
+
     pub fn v1(&self) -> Vec2 {
         match self {
             Line::Wall(WallLine { v1, .. }) => *v1,
@@ -71,12 +77,68 @@ impl Line {
         }
     }
 
+    pub fn special(&self) -> i16 {
+        match self {
+            Line::Wall(WallLine { special, .. }) => *special,
+            Line::Portal(PortalLine { special, .. }) => *special,
+        }
+    }
+
+    /// Clear the special of a line that can only be activated once.
+    pub fn clear_special(&mut self) {
+        match self {
+            Line::Wall(WallLine { special, .. }) => *special = 0,
+            Line::Portal(PortalLine { special, .. }) => *special = 0,
+        }
+    }
+
+    pub fn tag(&self) -> i16 {
+        match self {
+            Line::Wall(WallLine { tag, .. }) => *tag,
+            Line::Portal(PortalLine { tag, .. }) => *tag,
+        }
+    }
+
     pub fn front_sector(&self) -> Instance<Sector> {
         match self {
             Line::Wall(WallLine { front_sector, .. }) => *front_sector,
             Line::Portal(PortalLine { front_sector, .. }) => *front_sector,
         }
     }
+
+    pub fn back_sector(&self) -> Option<Instance<Sector>> {
+        match self {
+            Line::Wall(..) => None,
+            Line::Portal(PortalLine { back_sector, .. }) => Some(*back_sector),
+        }
+    }
+
+    pub fn front_side(&self) -> Instance<SideDef> {
+        match self {
+            Line::Wall(WallLine { front_side, .. }) => *front_side,
+            Line::Portal(PortalLine { front_side, .. }) => *front_side,
+        }
+    }
+
+    pub fn back_side(&self) -> Option<Instance<SideDef>> {
+        match self {
+            Line::Wall(..) => None,
+            Line::Portal(PortalLine { back_side, .. }) => Some(*back_side),
+        }
+    }
+
+    /// Get the sector on the other side of the line from `sector`,
+    /// or `None` when the line is one-sided.
+    ///
+    /// Mirrors `getNextSector`.
+    pub fn other_sector(&self, sector: Instance<Sector>) -> Option<Instance<Sector>> {
+        let back_sector = self.back_sector()?;
+        if self.front_sector() == sector {
+            Some(back_sector)
+        } else {
+            Some(self.front_sector())
+        }
+    }
 }
 
 pub struct WallLine {
@@ -86,12 +148,11 @@ pub struct WallLine {
     pub v2: Vec2,
     #[allow(unused)]
     pub flags: i16,
-    #[allow(unused)]
     pub special: i16,
-    #[allow(unused)]
     pub tag: i16,
     #[allow(unused)]
     pub front_sector: Instance<Sector>,
+    pub front_side: Instance<SideDef>,
 }
 
 pub struct PortalLine {
@@ -101,14 +162,14 @@ pub struct PortalLine {
     pub v2: Vec2,
     #[allow(unused)]
     pub flags: i16,
-    #[allow(unused)]
     pub special: i16,
-    #[allow(unused)]
     pub tag: i16,
     #[allow(unused)]
     pub front_sector: Instance<Sector>,
     #[allow(unused)]
     pub back_sector: Instance<Sector>,
+    pub front_side: Instance<SideDef>,
+    pub back_side: Instance<SideDef>,
 }
 
 #[derive(Component, Debug)]
@@ -1,18 +1,38 @@
-use crate::prelude::PlayerMovementPlugin;
+use crate::map_object::{move_things_z, place_on_floor, raise_to_spawn_height};
+use crate::prelude::{
+    DeathmatchStarts, EnemyPlugin, MapObjectStatePlugin, MissilePlugin, PlayerMovementPlugin,
+    PlayerWeaponsPlugin, SpecialsPlugin, StartSound, Switches,
+};
 use crate::resources::LevelMap;
+use crate::specials::spawn_specials;
 use anyhow::bail;
 use bevy::prelude::*;
+use block_map::{link_things, BlockMap};
+use commands::ThingSpawner;
 use exit::macros::sys_fail;
+use game_state::conditions::in_level_state;
 use game_state::PlayingState;
-use wad::WadFile;
+use skill::Skill;
+use sky::Sky;
+use sound::log_sounds;
+use wad::prelude::*;
 
+mod block_map;
 mod commands;
 mod components;
 mod map_object;
 mod resources;
+mod skill;
+mod sky;
+mod sound;
+mod specials;
 
 pub mod prelude {
-    pub use super::{components::*, map_object::prelude::*};
+    pub use super::{
+        block_map::BlockMap, components::*, map_object::prelude::*, skill::Skill, sky::Sky,
+        sound::StartSound, specials::prelude::*, CurrentMap, ExitLevel, LevelStats, LevelTime,
+        LevelTotals,
+    };
 }
 
 #[derive(Default)]
@@ -20,36 +40,126 @@ pub struct LevelPlugin;
 
 impl Plugin for LevelPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(PlayerMovementPlugin)
-            .add_event::<LoadLevel>()
-            .init_non_send_resource::<LevelMap>()
-            .add_systems(Update, load_level.run_if(on_event::<LoadLevel>));
+        app.add_plugins((
+            PlayerMovementPlugin,
+            PlayerWeaponsPlugin,
+            MapObjectStatePlugin,
+            MissilePlugin,
+            EnemyPlugin,
+            SpecialsPlugin,
+        ))
+        .add_event::<LoadLevel>()
+        .add_event::<StartSound>()
+        .add_event::<ExitLevel>()
+        .init_non_send_resource::<LevelMap>()
+        .init_resource::<BlockMap>()
+        .init_resource::<DeathmatchStarts>()
+        .init_resource::<Skill>()
+        .init_resource::<LevelTime>()
+        .init_resource::<LevelTotals>()
+        .init_resource::<LevelStats>()
+        .add_systems(
+            Update,
+            (
+                load_level.run_if(on_event::<LoadLevel>),
+                spawn_specials.run_if(on_event::<LoadLevel>),
+            )
+                .chain(),
+        )
+        // Before the thinkers, so they only see things put in place.
+        .add_systems(
+            FixedPreUpdate,
+            (place_on_floor, raise_to_spawn_height, link_things)
+                .chain()
+                .run_if(in_level_state()),
+        )
+        .add_systems(FixedUpdate, move_things_z.run_if(in_level_state()))
+        .add_systems(FixedPostUpdate, tick_level_time.run_if(in_level_state()))
+        .add_systems(Last, log_sounds);
     }
 }
 
 #[sys_fail]
 fn load_level(
     mut load: EventReader<LoadLevel>,
-    mut playing_state: ResMut<NextState<PlayingState>>,
     mut level_map: NonSendMut<LevelMap>,
     mut commands: Commands,
     wad: Res<WadFile>,
+    mut spawner: ThingSpawner,
 ) {
-    let Some(LoadLevel { episode, map }) = load.read().last() else {
+    let Some(LoadLevel {
+        map: map_id,
+        new_game,
+    }) = load.read().last()
+    else {
         return Ok(());
     };
 
-    debug!("Loading Episode {episode} Map {map}");
-    let Some(map) = wad.map(*episode, *map) else {
-        bail!("Tried to load invalid map: E{episode}M{map}");
+    debug!("Loading Map {map_id}");
+    let Some(map) = wad.map(*map_id) else {
+        bail!("Tried to load invalid map: {map_id}");
     };
 
-    level_map.load(&mut commands, map, &wad)?;
-    playing_state.set(PlayingState::Level);
+    level_map.load(&mut commands, map, &wad, &mut spawner, *new_game)?;
+    commands.insert_resource(BlockMap::new(&map.block_map, &level_map.lines));
+    commands.insert_resource(DeathmatchStarts::new(map));
+    commands.insert_resource(Sky::new(*map_id, &wad)?);
+    commands.insert_resource(Switches::new(&wad));
+    commands.insert_resource(CurrentMap(*map_id));
+    commands.insert_resource(LevelTime::default());
+    commands.insert_resource(LevelStats::default());
+    commands.set_state(PlayingState::Level);
 }
 
 #[derive(Event, Debug)]
 pub struct LoadLevel {
-    pub episode: usize,
-    pub map: usize,
+    pub map: MapId,
+    /// Set when starting a new game, the player being reborn instead
+    /// of keeping what it carried at the end of the previous level.
+    ///
+    /// Mirrors `G_InitNew`.
+    pub new_game: bool,
+}
+
+/// The map being played.
+#[derive(Resource, Debug, Clone, Copy, Deref)]
+pub struct CurrentMap(pub MapId);
+
+/// Sent to end the level, going to the secret level when `secret`.
+///
+/// Mirrors `G_ExitLevel` and `G_SecretExitLevel`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ExitLevel {
+    pub secret: bool,
+}
+
+/// What there is to find in the level, counted when it is loaded.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct LevelTotals {
+    /// Mirrors `totalkills`.
+    pub kills: u32,
+    /// Mirrors `totalitems`.
+    pub items: u32,
+    /// Mirrors `totalsecret`.
+    pub secrets: u32,
+}
+
+/// What the player found in the level so far.
+///
+/// Mirrors `killcount`, `itemcount` and `secretcount` of `player_t`.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct LevelStats {
+    pub kills: u32,
+    pub items: u32,
+    pub secrets: u32,
+}
+
+/// Tics elapsed since the level was loaded.
+///
+/// Mirrors `leveltime`.
+#[derive(Resource, Debug, Default, Clone, Copy, Deref)]
+pub struct LevelTime(u32);
+
+fn tick_level_time(mut level_time: ResMut<LevelTime>) {
+    level_time.0 += 1;
 }
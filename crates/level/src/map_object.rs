@@ -1,11 +1,32 @@
 use bevy::prelude::*;
-use player::movement::STOP_SPEED;
+use collision::Collision;
+use info::{Info, MobjFlags, MobjType, FRACUNIT};
+use player::movement::{GRAVITY, STOP_SPEED};
+use player::Player;
 
+mod attack;
 mod camera;
+mod collision;
+mod damage;
+mod enemy;
+mod missile;
 mod player;
+mod state;
+mod thing_sprite;
 
 pub mod prelude {
-    pub use super::{camera::Camera, player::prelude::*, MapObject};
+    pub use super::{
+        attack::{random_spread, Aim, Attacks, AIM_RANGE, AIM_SPREAD, MELEE_RANGE, MISSILE_RANGE},
+        camera::Camera,
+        collision::*,
+        damage::Damage,
+        enemy::{EnemyPlugin, Target},
+        missile::{Missile, MissilePlugin},
+        player::prelude::*,
+        state::{MapObjectAction, MapObjectState, MapObjectStatePlugin, MapObjectStates},
+        thing_sprite::ThingSprite,
+        MapObject, SpawnHeight,
+    };
 }
 
 /// Represents an object that can be placed on the map during map loading.
@@ -18,18 +39,57 @@ pub struct MapObject {
     pub pos: Vec2,
     #[allow(unused)]
     pub velocity: Vec2,
+    /// Height of the bottom of the object.
+    pub z: f32,
+    pub z_velocity: f32,
+    /// Highest floor touched by the object.
+    pub floor_z: f32,
+    /// Lowest ceiling touched by the object.
+    pub ceiling_z: f32,
     #[allow(unused)]
     pub dir: Dir2,
+    pub radius: f32,
+    pub height: f32,
+    pub mobj_type: MobjType,
+    pub flags: MobjFlags,
+    pub health: i32,
     #[allow(unused)]
     pub thing_type: i16,
     #[allow(unused)]
     pub options: i16,
+    /// Tics before the object can move again, e.g. after teleporting.
+    pub reaction_time: i32,
 }
 
 impl MapObject {
+    /// Create a map object of `mobj_type` at `pos`, with the
+    /// size, flags and health of its type.
+    pub fn new(mobj_type: MobjType, pos: Vec2, info: &Info) -> Self {
+        let mobj_info = info.mobj_info(mobj_type);
+        Self {
+            pos,
+            radius: mobj_info.radius as f32 / FRACUNIT as f32,
+            height: mobj_info.height as f32 / FRACUNIT as f32,
+            mobj_type,
+            flags: mobj_info.flags,
+            health: mobj_info.spawn_health,
+            ..default()
+        }
+    }
+
     pub fn is_speed_low(&self) -> bool {
         self.velocity.abs().cmplt(STOP_SPEED).all()
     }
+
+    /// Whether this is the player, spawned on the player 1 start,
+    /// rather than a monster or an item.
+    pub fn is_player(&self) -> bool {
+        self.mobj_type == MobjType::MT_PLAYER
+    }
+
+    pub fn is_on_ground(&self) -> bool {
+        self.z <= self.floor_z
+    }
 }
 
 impl Default for MapObject {
@@ -37,9 +97,97 @@ impl Default for MapObject {
         Self {
             pos: Vec2::default(),
             velocity: Vec2::default(),
+            z: 0.0,
+            z_velocity: 0.0,
+            floor_z: 0.0,
+            ceiling_z: 0.0,
             dir: Dir2::X,
+            radius: 20.0,
+            height: 16.0,
+            mobj_type: MobjType::MT_PLAYER,
+            flags: MobjFlags::empty(),
+            health: 100,
             thing_type: i16::default(),
             options: i16::default(),
+            reaction_time: 0,
         }
     }
 }
+
+/// Height at which a map object is spawned, instead of on the floor,
+/// e.g. for bullet puffs.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SpawnHeight(pub f32);
+
+/// Put the newly spawned map objects on the floor of their sector.
+/// Missiles are put in their sector when fired.
+///
+/// Mirrors the end of `P_SpawnMobj`.
+pub(crate) fn place_on_floor(
+    collision: Collision,
+    mut query: Query<(&mut MapObject, Option<&mut Player>), Added<MapObject>>,
+) {
+    for (mut map_obj, player) in &mut query {
+        if map_obj.flags.contains(MobjFlags::MISSILE) {
+            continue;
+        }
+        let Some(sector) = collision.sector_at(map_obj.pos) else {
+            continue;
+        };
+        map_obj.floor_z = sector.floor_height;
+        map_obj.ceiling_z = sector.ceiling_height;
+        map_obj.z = if map_obj.flags.contains(MobjFlags::SPAWNCEILING) {
+            map_obj.ceiling_z - map_obj.height
+        } else {
+            map_obj.floor_z
+        };
+
+        if let Some(mut player) = player {
+            player.view_z = map_obj.z + player.view_height;
+        }
+    }
+}
+
+/// Make the things fall, or rise like puffs and blood. The player
+/// and the missiles move on their own.
+///
+/// Mirrors `P_ZMovement` for the other things.
+pub(crate) fn move_things_z(mut query: Query<&mut MapObject, Without<Player>>) {
+    for mut map_obj in &mut query {
+        if map_obj.flags.contains(MobjFlags::MISSILE) {
+            continue;
+        }
+        if map_obj.z == map_obj.floor_z && map_obj.z_velocity == 0.0 {
+            continue;
+        }
+
+        map_obj.z += map_obj.z_velocity;
+        if map_obj.z <= map_obj.floor_z {
+            // Hit the floor.
+            map_obj.z_velocity = map_obj.z_velocity.max(0.0);
+            map_obj.z = map_obj.floor_z;
+        } else if !map_obj.flags.contains(MobjFlags::NOGRAVITY) {
+            map_obj.z_velocity = if map_obj.z_velocity == 0.0 {
+                -GRAVITY * 2.0
+            } else {
+                map_obj.z_velocity - GRAVITY
+            };
+        }
+
+        if map_obj.z + map_obj.height > map_obj.ceiling_z {
+            // Hit the ceiling.
+            map_obj.z_velocity = map_obj.z_velocity.min(0.0);
+            map_obj.z = map_obj.ceiling_z - map_obj.height;
+        }
+    }
+}
+
+/// Move the newly spawned map objects with a [`SpawnHeight`] to it,
+/// once [`place_on_floor`] found their floor and ceiling.
+pub(crate) fn raise_to_spawn_height(
+    mut query: Query<(&mut MapObject, &SpawnHeight), Added<SpawnHeight>>,
+) {
+    for (mut map_obj, SpawnHeight(z)) in &mut query {
+        map_obj.z = *z;
+    }
+}
@@ -0,0 +1,537 @@
+use super::collision::{point_on_line_side, thing_intercept, Collision};
+use super::damage::Damage;
+use super::missile::Missile;
+use super::player::Player;
+use super::state::MapObjectState;
+use super::thing_sprite::ThingSprite;
+use super::{MapObject, SpawnHeight};
+use crate::block_map::BlockMap;
+use crate::commands::SpawnMapObjects;
+use crate::components::{Line, Sector};
+use crate::prelude::{ActivateLine, Activation};
+use crate::sky::Sky;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use info::{Info, MobjFlags, MobjType, StateNum, WeaponType, FRACUNIT};
+use moonshine_kind::Instance;
+use rand::{Rand, RngCore};
+use std::f32::consts::TAU;
+
+/// Distance reached by melee attacks.
+pub const MELEE_RANGE: f32 = 64.0;
+/// Distance reached by hitscan attacks.
+pub const MISSILE_RANGE: f32 = 32.0 * 64.0;
+/// Distance at which players look for something to aim at.
+pub const AIM_RANGE: f32 = 16.0 * 64.0;
+/// Turn of the aim of players on each side, when there is
+/// nothing straight ahead.
+pub const AIM_SPREAD: f32 = TAU / 64.0;
+
+/// Height above the feet of the shooter missiles are fired from.
+const MISSILE_HEIGHT: f32 = 32.0;
+
+/// Largest radius of the things. They are linked to the block of their
+/// center, so the blocks of a box grown by it hold all those touching it.
+///
+/// Mirrors `MAXRADIUS`.
+const MAX_RADIUS: f32 = 32.0;
+
+/// Highest slope aimed at, the top of the screen from the middle.
+const MAX_AIM_SLOPE: f32 = 100.0 / 160.0;
+
+/// Target found by [`Attacks::aim_line_attack`], and the
+/// slope to aim at to hit its middle.
+#[derive(Debug, Clone, Copy)]
+pub struct Aim {
+    pub slope: f32,
+    pub target: Entity,
+}
+
+/// Something crossed by a hitscan attack.
+#[derive(Debug, Clone, Copy)]
+enum Hit {
+    Line(Instance<Line>),
+    Thing(Entity),
+}
+
+/// Fires bullets and missiles and swings fists at whatever
+/// is in front of a map object.
+#[derive(SystemParam)]
+pub struct Attacks<'w, 's> {
+    info: Res<'w, Info>,
+    sky: Res<'w, Sky>,
+    block_map: Res<'w, BlockMap>,
+    collision: Collision<'w, 's>,
+    line_query: Query<'w, 's, &'static Line>,
+    sector_query: Query<'w, 's, &'static Sector>,
+    thing_query: Query<'w, 's, (Entity, &'static mut MapObject, Option<&'static mut Player>)>,
+    damage: Damage<'w, 's>,
+    activate: EventWriter<'w, ActivateLine>,
+    commands: Commands<'w, 's>,
+}
+
+impl<'w, 's> Attacks<'w, 's> {
+    /// Get a map object, and its player if it is one.
+    pub fn get_mut(
+        &mut self,
+        entity: Entity,
+    ) -> Option<(Mut<'_, MapObject>, Option<Mut<'_, Player>>)> {
+        let (_, map_obj, player) = self.thing_query.get_mut(entity).ok()?;
+        Some((map_obj, player))
+    }
+
+    pub fn get(&self, entity: Entity) -> Option<&MapObject> {
+        let (_, map_obj, _) = self.thing_query.get(entity).ok()?;
+        Some(map_obj)
+    }
+
+    pub fn damage(&mut self) -> &mut Damage<'w, 's> {
+        &mut self.damage
+    }
+
+    pub fn rand(&mut self) -> &mut Rand {
+        self.damage.rand()
+    }
+
+    /// Check if `looker` can see `target`, see [`Collision::check_sight`].
+    pub fn check_sight(&self, looker: Entity, target: Entity) -> bool {
+        let (Some(looker), Some(target)) = (self.get(looker), self.get(target)) else {
+            return false;
+        };
+        self.collision.check_sight(looker, target)
+    }
+
+    /// Find the first thing `shooter` can shoot at in direction `dir`,
+    /// up to `distance` away, looking up and down as far as the screen.
+    ///
+    /// Mirrors `P_AimLineAttack`.
+    pub fn aim_line_attack(&self, shooter: Entity, dir: Dir2, distance: f32) -> Option<Aim> {
+        let (_, shooter_obj, _) = self.thing_query.get(shooter).ok()?;
+        let start = shooter_obj.pos;
+        let shoot_z = shoot_z(shooter_obj);
+        let mut top_slope = MAX_AIM_SLOPE;
+        let mut bottom_slope = -MAX_AIM_SLOPE;
+
+        // Mirrors `PTR_AimTraverse`.
+        for (frac, hit) in self.traverse(shooter, start, start + dir * distance) {
+            let dist = distance * frac;
+            match hit {
+                Hit::Line(line) => {
+                    let line = self.line_query.get(*line).ok()?;
+                    // Only two-sided lines let the shot continue, and
+                    // restrict the possible target ranges.
+                    let (front, back) = self.sectors(line)?;
+                    let opening = self.collision.line_opening(line)?;
+                    if opening.bottom >= opening.top {
+                        return None;
+                    }
+                    if front.floor_height != back.floor_height {
+                        bottom_slope = bottom_slope.max((opening.bottom - shoot_z) / dist);
+                    }
+                    if front.ceiling_height != back.ceiling_height {
+                        top_slope = top_slope.min((opening.top - shoot_z) / dist);
+                    }
+                    if top_slope <= bottom_slope {
+                        return None;
+                    }
+                }
+                Hit::Thing(entity) => {
+                    let Ok((_, thing, _)) = self.thing_query.get(entity) else {
+                        continue;
+                    };
+                    if !thing.flags.contains(MobjFlags::SHOOTABLE) {
+                        // Corpse or something.
+                        continue;
+                    }
+                    let thing_top_slope = (thing.z + thing.height - shoot_z) / dist;
+                    if thing_top_slope < bottom_slope {
+                        // Shot over the thing.
+                        continue;
+                    }
+                    let thing_bottom_slope = (thing.z - shoot_z) / dist;
+                    if thing_bottom_slope > top_slope {
+                        // Shot under the thing.
+                        continue;
+                    }
+                    // This thing can be hit!
+                    let slope = (thing_top_slope.min(top_slope)
+                        + thing_bottom_slope.max(bottom_slope))
+                        / 2.0;
+                    return Some(Aim {
+                        slope,
+                        target: entity,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Shoot from `shooter` in direction `dir` along `slope`, hitting the
+    /// first thing or wall up to `distance` away, and triggering the
+    /// special lines on the way.
+    ///
+    /// Mirrors `P_LineAttack`.
+    pub fn line_attack(
+        &mut self,
+        shooter: Entity,
+        dir: Dir2,
+        distance: f32,
+        slope: f32,
+        damage: i32,
+    ) {
+        let Ok((_, shooter_obj, player)) = self.thing_query.get(shooter) else {
+            return;
+        };
+        let shooter_obj = *shooter_obj;
+        // The chainsaw doesn't push its targets away.
+        let chainsaw = player.is_some_and(|player| player.ready_weapon == WeaponType::wp_chainsaw);
+        let start = shooter_obj.pos;
+        let end = start + dir * distance;
+        let shoot_z = shoot_z(&shooter_obj);
+        let point_at = |frac: f32| {
+            (
+                start + (end - start) * frac,
+                shoot_z + slope * frac * distance,
+            )
+        };
+
+        // Mirrors `PTR_ShootTraverse`.
+        for (frac, hit) in self.traverse(shooter, start, end) {
+            let dist = distance * frac;
+            match hit {
+                Hit::Line(line_inst) => {
+                    let Ok(line) = self.line_query.get(*line_inst) else {
+                        continue;
+                    };
+                    if line.special() != 0 {
+                        self.activate.send(ActivateLine {
+                            line: line_inst,
+                            thing: shooter,
+                            back_side: point_on_line_side(start, line),
+                            activation: Activation::Shoot,
+                        });
+                    }
+                    if let (Some((front, back)), Some(opening)) =
+                        (self.sectors(line), self.collision.line_opening(line))
+                    {
+                        let under = front.floor_height != back.floor_height
+                            && (opening.bottom - shoot_z) / dist > slope;
+                        let over = front.ceiling_height != back.ceiling_height
+                            && (opening.top - shoot_z) / dist < slope;
+                        if !under && !over {
+                            // Shot continues.
+                            continue;
+                        }
+                    }
+
+                    // Hit line, position a bit closer.
+                    let (pos, z) = point_at(frac - 4.0 / distance);
+                    let Ok(front) = self.sector_query.get(*line.front_sector()) else {
+                        return;
+                    };
+                    if front.ceiling_tex == self.sky.flat {
+                        // Don't shoot the sky!
+                        if z > front.ceiling_height {
+                            return;
+                        }
+                        // It's a sky hack wall.
+                        let back = line
+                            .back_sector()
+                            .and_then(|back| self.sector_query.get(*back).ok());
+                        if back.is_some_and(|back| back.ceiling_tex == self.sky.flat) {
+                            return;
+                        }
+                    }
+                    self.spawn_puff(pos, z, distance == MELEE_RANGE);
+                    return;
+                }
+                Hit::Thing(entity) => {
+                    let Ok((_, thing, _)) = self.thing_query.get(entity) else {
+                        continue;
+                    };
+                    if !thing.flags.contains(MobjFlags::SHOOTABLE) {
+                        // Corpse or something.
+                        continue;
+                    }
+                    if (thing.z + thing.height - shoot_z) / dist < slope {
+                        // Shot over the thing.
+                        continue;
+                    }
+                    if (thing.z - shoot_z) / dist > slope {
+                        // Shot under the thing.
+                        continue;
+                    }
+
+                    // Hit thing, position a bit closer.
+                    let (pos, z) = point_at(frac - 10.0 / distance);
+                    if thing.flags.contains(MobjFlags::NOBLOOD) {
+                        self.spawn_puff(pos, z, distance == MELEE_RANGE);
+                    } else {
+                        self.spawn_blood(pos, z, damage);
+                    }
+                    if damage == 0 {
+                        return;
+                    }
+                    let Ok((_, mut thing, _)) = self.thing_query.get_mut(entity) else {
+                        return;
+                    };
+                    if chainsaw {
+                        self.damage.damage(entity, &mut thing, damage);
+                    } else {
+                        self.damage
+                            .damage_from(entity, &mut thing, &shooter_obj, damage);
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Take `damage` health points from `target`, pushing it
+    /// away from `inflictor`, see [`Damage::damage_from`].
+    pub fn damage_from(&mut self, target: Entity, inflictor: &MapObject, damage: i32) {
+        let Ok((_, mut thing, _)) = self.thing_query.get_mut(target) else {
+            return;
+        };
+        self.damage
+            .damage_from(target, &mut thing, inflictor, damage);
+    }
+
+    /// Find the things `thing` would touch at `pos`, which block it,
+    /// get picked up or get hit.
+    ///
+    /// Mirrors the search of `P_CheckPosition` with `PIT_CheckThing`.
+    pub fn things_touched(&self, thing: Entity, pos: Vec2) -> Vec<Entity> {
+        let Ok((_, thing_obj, _)) = self.thing_query.get(thing) else {
+            return vec![];
+        };
+        let touched_flags = MobjFlags::SOLID | MobjFlags::SPECIAL | MobjFlags::SHOOTABLE;
+        let reach = Vec2::splat(thing_obj.radius + MAX_RADIUS);
+        self.block_map
+            .things_in_box(pos - reach, pos + reach)
+            .filter(|&entity| entity != thing)
+            .filter(|&entity| {
+                self.thing_query.get(entity).is_ok_and(|(_, other, _)| {
+                    other.flags.intersects(touched_flags)
+                        && (other.pos - pos)
+                            .abs()
+                            .cmplt(Vec2::splat(other.radius + thing_obj.radius))
+                            .all()
+                })
+            })
+            .collect()
+    }
+
+    /// Hurt the shootable things in sight of `spot` less than
+    /// `damage` units away from it, the closer the harder.
+    ///
+    /// Mirrors `P_RadiusAttack`.
+    pub fn radius_attack(&mut self, spot: Entity, damage: i32) {
+        let Some(spot_obj) = self.get(spot).copied() else {
+            return;
+        };
+        let reach = Vec2::splat(damage as f32 + MAX_RADIUS);
+        let hits: Vec<_> = self
+            .block_map
+            .things_in_box(spot_obj.pos - reach, spot_obj.pos + reach)
+            .filter_map(|entity| self.thing_query.get(entity).ok())
+            .filter(|(_, thing, _)| {
+                // Boss spider and cyborg take no damage from concussion.
+                thing.flags.contains(MobjFlags::SHOOTABLE)
+                    && !matches!(thing.mobj_type, MobjType::MT_CYBORG | MobjType::MT_SPIDER)
+            })
+            .filter_map(|(entity, thing, _)| {
+                // Vanilla drops the fraction of the distance.
+                let dist =
+                    ((thing.pos - spot_obj.pos).abs().max_element() - thing.radius).max(0.0) as i32;
+                if dist >= damage || !self.collision.check_sight(thing, &spot_obj) {
+                    return None;
+                }
+                Some((entity, damage - dist))
+            })
+            .collect();
+        for (entity, damage) in hits {
+            self.damage_from(entity, &spot_obj, damage);
+        }
+    }
+
+    /// Fire a missile of `mobj_type` from `source` at `dest`, e.g. the
+    /// fireball of an imp at the player.
+    ///
+    /// Mirrors `P_SpawnMissile`.
+    pub fn spawn_missile(&mut self, source: Entity, dest: Entity, mobj_type: MobjType) {
+        let (Some(source_obj), Some(dest_obj)) = (self.get(source), self.get(dest)) else {
+            return;
+        };
+        let (source_obj, dest_obj) = (*source_obj, *dest_obj);
+        let mut dir = Dir2::new(dest_obj.pos - source_obj.pos).unwrap_or(Dir2::X);
+        // Fuzzy player.
+        if dest_obj.flags.contains(MobjFlags::SHADOW) {
+            dir = random_spread(dir, self.damage.rand(), 20);
+        }
+        // Get to the height of `dest` when reaching it.
+        let speed = self.missile_speed(mobj_type);
+        let tics = (dest_obj.pos.distance(source_obj.pos) / speed)
+            .floor()
+            .max(1.0);
+        let z_velocity = (dest_obj.z - source_obj.z) / tics;
+        self.fire_missile(source, mobj_type, dir, z_velocity);
+    }
+
+    /// Fire a missile of `mobj_type` from the player `source`, at the
+    /// thing in front of it, or a bit to the sides if there's nothing
+    /// straight ahead.
+    ///
+    /// Mirrors `P_SpawnPlayerMissile`.
+    pub fn spawn_player_missile(&mut self, source: Entity, mobj_type: MobjType) {
+        let Some(source_obj) = self.get(source) else {
+            return;
+        };
+        let source_dir = source_obj.dir;
+        let (dir, slope) = [0.0, AIM_SPREAD, -AIM_SPREAD]
+            .into_iter()
+            .find_map(|turn| {
+                let dir = Rot2::radians(turn) * source_dir;
+                let aim = self.aim_line_attack(source, dir, AIM_RANGE)?;
+                Some((dir, aim.slope))
+            })
+            .unwrap_or((source_dir, 0.0));
+        let z_velocity = self.missile_speed(mobj_type) * slope;
+        self.fire_missile(source, mobj_type, dir, z_velocity);
+    }
+
+    /// Spawn a map object of `mobj_type` at `z` above `pos`.
+    pub fn spawn_at(&mut self, mobj_type: MobjType, pos: Vec2, z: f32) {
+        let map_obj = MapObject::new(mobj_type, pos, &self.info);
+        self.commands
+            .spawn_map_object(map_obj, &self.info)
+            .insert(SpawnHeight(z));
+    }
+
+    fn missile_speed(&self, mobj_type: MobjType) -> f32 {
+        self.info.mobj_info(mobj_type).speed as f32 / FRACUNIT as f32
+    }
+
+    /// Spawn a missile of `mobj_type` fired by `shooter`, flying
+    /// in direction `dir` at the speed of its type.
+    fn fire_missile(&mut self, shooter: Entity, mobj_type: MobjType, dir: Dir2, z_velocity: f32) {
+        let Some(shooter_obj) = self.get(shooter) else {
+            return;
+        };
+        let mut missile = MapObject::new(mobj_type, shooter_obj.pos, &self.info);
+        missile.z = shooter_obj.z + MISSILE_HEIGHT;
+        missile.dir = dir;
+        missile.velocity = dir * self.missile_speed(mobj_type);
+        missile.z_velocity = z_velocity;
+        // Missiles are put in their sector here, not by `place_on_floor`.
+        if let Some(sector) = self.collision.sector_at(missile.pos) {
+            missile.floor_z = sector.floor_height;
+            missile.ceiling_z = sector.ceiling_height;
+        }
+        self.commands
+            .spawn_map_object(missile, &self.info)
+            .insert(Missile { shooter });
+    }
+
+    /// Find the lines and things crossed going from `start` to `end`,
+    /// sorted from the closest to the farthest, skipping `shooter`.
+    ///
+    /// Mirrors `P_PathTraverse` with `PT_ADDLINES | PT_ADDTHINGS`.
+    fn traverse(&self, shooter: Entity, start: Vec2, end: Vec2) -> Vec<(f32, Hit)> {
+        let lines = self
+            .collision
+            .path_traverse(start, end)
+            .into_iter()
+            .map(|intercept| (intercept.frac, Hit::Line(intercept.line)));
+        // Things not linked into the blockmap are never hit.
+        let things = self
+            .block_map
+            .things_on_path(start, end)
+            .filter(|&entity| entity != shooter)
+            .filter_map(|entity| self.thing_query.get(entity).ok())
+            .filter_map(|(entity, thing, _)| {
+                let frac = thing_intercept(start, end, thing)?;
+                Some((frac, Hit::Thing(entity)))
+            });
+        let mut intercepts: Vec<_> = lines.chain(things).collect();
+        intercepts.sort_by(|a, b| a.0.total_cmp(&b.0));
+        intercepts
+    }
+
+    /// Sectors on both sides of `line`, `None` when it is one-sided.
+    fn sectors(&self, line: &Line) -> Option<(&Sector, &Sector)> {
+        let front = self.sector_query.get(*line.front_sector()).ok()?;
+        let back = self.sector_query.get(*line.back_sector()?).ok()?;
+        Some((front, back))
+    }
+
+    /// Mirrors `P_SpawnPuff`.
+    fn spawn_puff(&mut self, pos: Vec2, z: f32, melee: bool) {
+        // Don't make punches spark on the wall.
+        let state = melee.then_some(StateNum::S_PUFF3);
+        self.spawn_splash(MobjType::MT_PUFF, pos, z, 1.0, state);
+    }
+
+    /// Mirrors `P_SpawnBlood`.
+    fn spawn_blood(&mut self, pos: Vec2, z: f32, damage: i32) {
+        let state = match damage {
+            9..=12 => Some(StateNum::S_BLOOD2),
+            ..9 => Some(StateNum::S_BLOOD3),
+            _ => None,
+        };
+        self.spawn_splash(MobjType::MT_BLOOD, pos, z, 2.0, state);
+    }
+
+    /// Spawn a puff or blood rising at `z_velocity` around `z`, starting
+    /// at `state`, or with a random cut of the tics of its spawn state.
+    fn spawn_splash(
+        &mut self,
+        mobj_type: MobjType,
+        pos: Vec2,
+        z: f32,
+        z_velocity: f32,
+        state: Option<StateNum>,
+    ) {
+        let rand = self.damage.rand();
+        // `P_SubRandom() << 10` in fixed point.
+        let z = z + rand.sub_random() as f32 / 64.0;
+        let tics_cut = (rand.next_u32() & 3) as i32;
+
+        let mut map_obj = MapObject::new(mobj_type, pos, &self.info);
+        map_obj.z_velocity = z_velocity;
+        let obj_state = match state {
+            Some(state) => MapObjectState {
+                state,
+                tics: self.info.state(state).tics,
+            },
+            None => {
+                let state = self.info.mobj_info(mobj_type).spawn_state;
+                MapObjectState {
+                    state,
+                    tics: (self.info.state(state).tics - tics_cut).max(1),
+                }
+            }
+        };
+        let sprite = ThingSprite::from_state(self.info.state(obj_state.state));
+        self.commands.spawn_map_object(map_obj, &self.info).insert((
+            obj_state,
+            sprite,
+            SpawnHeight(z),
+        ));
+    }
+}
+
+/// Turn `dir` by a random angle, mostly small, the angle units of `doom.exe`
+/// being shifted by `shift`.
+///
+/// Mirrors `angle += P_SubRandom() << shift`.
+pub fn random_spread(dir: Dir2, rand: &mut Rand, shift: u32) -> Dir2 {
+    let angle = rand.sub_random() as f32 * (1u64 << shift) as f32 * TAU / 2f32.powi(32);
+    Rot2::radians(angle) * dir
+}
+
+/// Height attacks are fired from, a bit above the middle of the shooter.
+fn shoot_z(shooter: &MapObject) -> f32 {
+    shooter.z + shooter.height / 2.0 + 8.0
+}
@@ -9,24 +9,45 @@ const HALF_FOV: f32 = FOV / 2.0;
 #[derive(Component)]
 pub struct Camera {
     position: Vec2,
+    view_z: f32,
     x_axis: Dir2,
     y_axis: Dir2,
     view_frustum: ViewFrustum,
+    extra_light: i32,
 }
 
 impl Camera {
-    pub(crate) fn new(player: MapObject) -> Self {
+    pub(crate) fn new(player: MapObject, view_z: f32) -> Self {
         let normal = player.dir;
         Self {
             position: player.pos,
+            view_z,
             x_axis: Rot2::radians(-FOV) * normal,
             y_axis: normal,
             view_frustum: ViewFrustum::new(),
+            extra_light: 0,
         }
     }
     
-    pub fn update(&mut self, player: MapObject) {
-        *self = Self::new(player);
+    pub fn update(&mut self, player: MapObject, view_z: f32, extra_light: i32) {
+        *self = Self::new(player, view_z);
+        self.extra_light = extra_light;
+    }
+
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Height of the eyes of the player.
+    pub fn view_z(&self) -> f32 {
+        self.view_z
+    }
+
+    /// Light levels added to everything in view by the muzzle flashes.
+    ///
+    /// Mirrors `extralight`.
+    pub fn extra_light(&self) -> i32 {
+        self.extra_light
     }
 
     pub fn projection_plane(&self) -> ProjectionPlane {
@@ -78,6 +99,11 @@ impl Camera {
         point.y = y;
         (x, y).into()
     }
+
+    /// Inverse of [`Camera::world_to_camera`].
+    pub fn camera_to_world(&self, point: Vec2) -> Vec2 {
+        self.position + (point.x * *self.x_axis) + (point.y * *self.y_axis)
+    }
 }
 
 struct ViewFrustum {
@@ -0,0 +1,468 @@
+use super::MapObject;
+use crate::block_map::BlockMap;
+use crate::components::{BspTree, Line, LineSegment, Sector, SubSector};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use info::MobjFlags;
+use moonshine_kind::Instance;
+
+/// Highest step a map object can climb.
+pub const MAX_STEP_HEIGHT: f32 = 24.0;
+
+/// Keeps slides from ending exactly on the blocking line.
+const SLIDE_FUDGE: f32 = 1.0 / 32.0;
+
+/// Space left between the two sectors of a two-sided line.
+///
+/// Mirrors `P_LineOpening`.
+#[derive(Debug, Clone, Copy)]
+pub struct LineOpening {
+    pub top: f32,
+    pub bottom: f32,
+    /// Lowest of the two floors.
+    pub low_floor: f32,
+}
+
+impl LineOpening {
+    pub fn range(&self) -> f32 {
+        self.top - self.bottom
+    }
+}
+
+/// Floor and ceiling heights a map object would have at a position.
+#[derive(Debug, Clone)]
+pub struct Position {
+    pub floor_z: f32,
+    pub ceiling_z: f32,
+    /// Lowest floor touched, used to keep monsters from
+    /// walking off high ledges.
+    pub dropoff_z: f32,
+    /// Line lowering the ceiling the most, to let missiles
+    /// fly into the sky.
+    ///
+    /// Mirrors `ceilingline`.
+    pub ceiling_line: Option<Instance<Line>>,
+    /// Special lines touched, which may be crossed.
+    ///
+    /// Mirrors `spechit`.
+    pub special_lines: Vec<Instance<Line>>,
+}
+
+/// A special line crossed by a move, going from its
+/// back side to its front side when `back_side`.
+#[derive(Debug, Clone, Copy)]
+pub struct Crossing {
+    pub line: Instance<Line>,
+    pub back_side: bool,
+}
+
+/// A line crossed by a trace, `frac` being the fraction
+/// of the trace at which it is crossed.
+#[derive(Debug, Clone, Copy)]
+pub struct Intercept {
+    pub frac: f32,
+    pub line: Instance<Line>,
+}
+
+/// Moves map objects around the level without going through walls.
+#[derive(SystemParam)]
+pub struct Collision<'w, 's> {
+    block_map: Res<'w, BlockMap>,
+    bsp_tree: BspTree<'w>,
+    sub_sector_query: Query<'w, 's, &'static SubSector>,
+    segment_query: Query<'w, 's, &'static LineSegment>,
+    line_query: Query<'w, 's, &'static Line>,
+    sector_query: Query<'w, 's, &'static Sector>,
+}
+
+impl Collision<'_, '_> {
+    /// Find the sector containing `point`.
+    ///
+    /// Mirrors `R_PointInSubsector`.
+    pub fn sector_at(&self, point: Vec2) -> Option<&Sector> {
+        let sector = self.sector_instance_at(point)?;
+        self.sector_query.get(*sector).ok()
+    }
+
+    /// Find the sector containing `point`, as an instance
+    /// to look up mutably.
+    pub fn sector_instance_at(&self, point: Vec2) -> Option<Instance<Sector>> {
+        let sub_sector = self.bsp_tree.find_sub_sector(point)?;
+        let sub_sector = self.sub_sector_query.get(*sub_sector).ok()?;
+        // All the segments of a sub sector belong to the same sector.
+        let segment = self.segment_query.get(*sub_sector.segments[0]).ok()?;
+        Some(segment.front_sector())
+    }
+
+    /// Get the opening of `line`, or `None` when it is one-sided.
+    pub fn line_opening(&self, line: &Line) -> Option<LineOpening> {
+        let front = self.sector_query.get(*line.front_sector()).ok()?;
+        let back = self.sector_query.get(*line.back_sector()?).ok()?;
+        Some(LineOpening {
+            top: front.ceiling_height.min(back.ceiling_height),
+            bottom: front.floor_height.max(back.floor_height),
+            low_floor: front.floor_height.min(back.floor_height),
+        })
+    }
+
+    /// Check if `thing` fits at `pos`, ignoring other map objects.
+    /// Returns `None` when a line is in the way.
+    ///
+    /// Mirrors `P_CheckPosition`.
+    pub fn check_position(&self, thing: &MapObject, pos: Vec2) -> Option<Position> {
+        let sector = self.sector_at(pos)?;
+        let mut position = Position {
+            floor_z: sector.floor_height,
+            ceiling_z: sector.ceiling_height,
+            dropoff_z: sector.floor_height,
+            ceiling_line: None,
+            special_lines: vec![],
+        };
+
+        let min = pos - thing.radius;
+        let max = pos + thing.radius;
+        for line_inst in self.block_map.lines_in_box(min, max) {
+            let Ok(line) = self.line_query.get(*line_inst) else {
+                continue;
+            };
+            // Mirrors `PIT_CheckLine`.
+            let line_min = line.v1().min(line.v2());
+            let line_max = line.v1().max(line.v2());
+            if max.cmple(line_min).any() || min.cmpge(line_max).any() {
+                continue;
+            }
+            if box_on_line_side(min, max, line).is_some() {
+                continue;
+            }
+
+            // One-sided lines block everything.
+            let opening = self.line_opening(line)?;
+            if !thing.flags.contains(MobjFlags::MISSILE) {
+                if line.flags() & Line::BLOCKING != 0 {
+                    return None;
+                }
+                if !thing.is_player() && line.flags() & Line::BLOCK_MONSTERS != 0 {
+                    return None;
+                }
+            }
+
+            // Adjust floor and ceiling heights.
+            if opening.top < position.ceiling_z {
+                position.ceiling_z = opening.top;
+                position.ceiling_line = Some(line_inst);
+            }
+            position.floor_z = position.floor_z.max(opening.bottom);
+            position.dropoff_z = position.dropoff_z.min(opening.low_floor);
+
+            if line.special() != 0 {
+                position.special_lines.push(line_inst);
+            }
+        }
+
+        Some(position)
+    }
+
+    /// Move `thing` to `pos` if nothing is in the way, and it can
+    /// climb or fit in the sectors there. The special lines crossed
+    /// are pushed to `crossed`.
+    ///
+    /// Mirrors `P_TryMove`.
+    pub fn try_move(&self, thing: &mut MapObject, pos: Vec2, crossed: &mut Vec<Crossing>) -> bool {
+        let Some(position) = self.check_position(thing, pos) else {
+            return false;
+        };
+        if position.ceiling_z - position.floor_z < thing.height {
+            // Doesn't fit.
+            return false;
+        }
+        if position.ceiling_z - thing.z < thing.height {
+            // Must lower itself to fit.
+            return false;
+        }
+        if position.floor_z - thing.z > MAX_STEP_HEIGHT {
+            // Too big a step up.
+            return false;
+        }
+        if !thing
+            .flags
+            .intersects(MobjFlags::DROPOFF | MobjFlags::FLOAT)
+            && position.floor_z - position.dropoff_z > MAX_STEP_HEIGHT
+        {
+            // Don't stand over a dropoff.
+            return false;
+        }
+
+        let old_pos = thing.pos;
+        thing.pos = pos;
+        thing.floor_z = position.floor_z;
+        thing.ceiling_z = position.ceiling_z;
+
+        if thing
+            .flags
+            .intersects(MobjFlags::TELEPORT | MobjFlags::NOCLIP)
+        {
+            return true;
+        }
+        for line_inst in position.special_lines.into_iter().rev() {
+            let Ok(line) = self.line_query.get(*line_inst) else {
+                continue;
+            };
+            let back_side = point_on_line_side(old_pos, line);
+            if point_on_line_side(pos, line) != back_side {
+                crossed.push(Crossing {
+                    line: line_inst,
+                    back_side,
+                });
+            }
+        }
+        true
+    }
+
+    /// Update the floor and ceiling heights of `thing`, after
+    /// the sectors around it moved. Things standing on the floor
+    /// follow it. Returns `false` when the thing doesn't fit
+    /// anymore.
+    ///
+    /// Mirrors `P_ThingHeightClip`.
+    pub fn height_clip(&self, thing: &mut MapObject) -> bool {
+        let on_floor = thing.z == thing.floor_z;
+        let Some(position) = self.check_position(thing, thing.pos) else {
+            return true;
+        };
+        thing.floor_z = position.floor_z;
+        thing.ceiling_z = position.ceiling_z;
+
+        if on_floor {
+            // Walking things rise and fall with the floor.
+            thing.z = thing.floor_z;
+        } else if thing.z + thing.height > thing.ceiling_z {
+            // Don't adjust a floating thing unless forced to.
+            thing.z = thing.ceiling_z - thing.height;
+        }
+
+        thing.ceiling_z - thing.floor_z >= thing.height
+    }
+
+    /// Move `thing` along the wall blocking its way, after
+    /// it failed to move by its full velocity. The special lines
+    /// crossed are pushed to `crossed`.
+    ///
+    /// Mirrors `P_SlideMove`.
+    pub fn slide_move(&self, thing: &mut MapObject, crossed: &mut Vec<Crossing>) {
+        // Give up sliding after hitting two walls in a row.
+        for _ in 0..2 {
+            let velocity = thing.velocity;
+            // Trace along the three leading corners.
+            let lead = thing.pos + leading_offset(velocity, thing.radius);
+            let trail = thing.pos - leading_offset(velocity, thing.radius);
+            let corners = [lead, Vec2::new(trail.x, lead.y), Vec2::new(lead.x, trail.y)];
+            let best_slide = corners
+                .into_iter()
+                .filter_map(|corner| self.slide_traverse(thing, corner, corner + velocity))
+                .min_by(|a, b| a.frac.total_cmp(&b.frac));
+            let Some(best_slide) = best_slide else {
+                // The move must have hit the middle, so stairstep.
+                break;
+            };
+
+            // Fudge a bit to make sure it doesn't hit.
+            let frac = best_slide.frac - SLIDE_FUDGE;
+            if frac > 0.0 && !self.try_move(thing, thing.pos + velocity * frac, crossed) {
+                break;
+            }
+
+            // Now continue along the wall, with what is left of the move.
+            let frac = (1.0 - (best_slide.frac + SLIDE_FUDGE)).min(1.0);
+            if frac <= 0.0 {
+                return;
+            }
+            let Ok(line) = self.line_query.get(*best_slide.line) else {
+                break;
+            };
+            thing.velocity = hit_slide_line(line, velocity * frac);
+            if self.try_move(thing, thing.pos + thing.velocity, crossed) {
+                return;
+            }
+        }
+
+        // Stairstep, moving along one axis only.
+        let velocity = thing.velocity;
+        if !self.try_move(thing, thing.pos + Vec2::new(0.0, velocity.y), crossed) {
+            self.try_move(thing, thing.pos + Vec2::new(velocity.x, 0.0), crossed);
+        }
+    }
+
+    /// Find the lines crossed going from `start` to `end`,
+    /// sorted from the closest to the farthest.
+    ///
+    /// Mirrors `P_PathTraverse` with `PT_ADDLINES`.
+    pub fn path_traverse(&self, start: Vec2, end: Vec2) -> Vec<Intercept> {
+        let delta = end - start;
+        let mut intercepts: Vec<_> = self
+            .block_map
+            .lines_on_path(start, end)
+            .filter_map(|line_inst| {
+                let line = self.line_query.get(*line_inst).ok()?;
+                // Mirrors `PIT_AddLineIntercepts`.
+                let s1 = delta.perp_dot(line.v1() - start) >= 0.0;
+                let s2 = delta.perp_dot(line.v2() - start) >= 0.0;
+                if s1 == s2 {
+                    // Line isn't crossed.
+                    return None;
+                }
+                let frac = intercept_frac(start, delta, line)?;
+                (0.0..=1.0).contains(&frac).then_some(Intercept {
+                    frac,
+                    line: line_inst,
+                })
+            })
+            .collect();
+        intercepts.sort_by(|a, b| a.frac.total_cmp(&b.frac));
+        intercepts
+    }
+
+    /// Check if `looker` can see `target`, i.e. if nothing is in the
+    /// way between the eyes of `looker` and some part of `target`.
+    ///
+    /// Mirrors `P_CheckSight`, without the reject table.
+    pub fn check_sight(&self, looker: &MapObject, target: &MapObject) -> bool {
+        let sight_z = looker.z + looker.height - looker.height / 4.0;
+        // Slopes over the whole trace, narrowed by the openings crossed.
+        let mut top_slope = target.z + target.height - sight_z;
+        let mut bottom_slope = target.z - sight_z;
+
+        // Mirrors `P_CrossSubsector`.
+        for intercept in self.path_traverse(looker.pos, target.pos) {
+            let Ok(line) = self.line_query.get(*intercept.line) else {
+                continue;
+            };
+            // Stop because it is not two-sided, or a closed door.
+            let (Some(opening), Some((front, back))) =
+                (self.line_opening(line), self.sectors(line))
+            else {
+                return false;
+            };
+            if opening.bottom >= opening.top {
+                return false;
+            }
+            let frac = intercept.frac.max(f32::EPSILON);
+            if front.floor_height != back.floor_height {
+                bottom_slope = bottom_slope.max((opening.bottom - sight_z) / frac);
+            }
+            if front.ceiling_height != back.ceiling_height {
+                top_slope = top_slope.min((opening.top - sight_z) / frac);
+            }
+            if top_slope <= bottom_slope {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Sectors on both sides of `line`, `None` when it is one-sided.
+    fn sectors(&self, line: &Line) -> Option<(&Sector, &Sector)> {
+        let front = self.sector_query.get(*line.front_sector()).ok()?;
+        let back = self.sector_query.get(*line.back_sector()?).ok()?;
+        Some((front, back))
+    }
+
+    /// Find the first line blocking a slide from `start` to `end`.
+    ///
+    /// Mirrors `PTR_SlideTraverse`.
+    fn slide_traverse(&self, thing: &MapObject, start: Vec2, end: Vec2) -> Option<Intercept> {
+        self.path_traverse(start, end)
+            .into_iter()
+            .find(|intercept| {
+                let Ok(line) = self.line_query.get(*intercept.line) else {
+                    return false;
+                };
+                let Some(opening) = self.line_opening(line) else {
+                    // Don't hit the back side of one-sided lines.
+                    return !point_on_line_side(thing.pos, line);
+                };
+                opening.range() < thing.height
+                    || opening.top - thing.z < thing.height
+                    || opening.bottom - thing.z > MAX_STEP_HEIGHT
+            })
+    }
+}
+
+/// Fraction of the trace going from `start` to `end` at which
+/// it crosses `thing`, if it does.
+///
+/// Mirrors `PIT_AddThingIntercepts`.
+pub fn thing_intercept(start: Vec2, end: Vec2, thing: &MapObject) -> Option<f32> {
+    let delta = end - start;
+    // Check a corner to corner cross section for a hit.
+    let (v1, v2) = if (delta.x > 0.0) == (delta.y > 0.0) {
+        (
+            thing.pos + Vec2::new(-thing.radius, thing.radius),
+            thing.pos + Vec2::new(thing.radius, -thing.radius),
+        )
+    } else {
+        (thing.pos - thing.radius, thing.pos + thing.radius)
+    };
+    let s1 = delta.perp_dot(v1 - start) >= 0.0;
+    let s2 = delta.perp_dot(v2 - start) >= 0.0;
+    if s1 == s2 {
+        // Thing isn't crossed.
+        return None;
+    }
+    let frac = segment_frac(start, delta, v1, v2)?;
+    (0.0..=1.0).contains(&frac).then_some(frac)
+}
+
+/// Returns `true` when `point` is on the back side of `line`.
+///
+/// Mirrors `P_PointOnLineSide`.
+pub fn point_on_line_side(point: Vec2, line: &Line) -> bool {
+    (line.v2() - line.v1()).perp_dot(point - line.v1()) >= 0.0
+}
+
+/// Returns the side of `line` the box is on, or `None` when
+/// the line crosses the box.
+///
+/// Mirrors `P_BoxOnLineSide`.
+fn box_on_line_side(min: Vec2, max: Vec2, line: &Line) -> Option<bool> {
+    let side = point_on_line_side(min, line);
+    let corners = [Vec2::new(min.x, max.y), Vec2::new(max.x, min.y), max];
+    corners
+        .into_iter()
+        .all(|corner| point_on_line_side(corner, line) == side)
+        .then_some(side)
+}
+
+/// Fraction of the trace going from `start` by `delta` at
+/// which `line` is crossed, `None` if they are parallel.
+///
+/// Mirrors `P_InterceptVector`.
+fn intercept_frac(start: Vec2, delta: Vec2, line: &Line) -> Option<f32> {
+    segment_frac(start, delta, line.v1(), line.v2())
+}
+
+/// Same as [`intercept_frac`], for the segment from `v1` to `v2`.
+fn segment_frac(start: Vec2, delta: Vec2, v1: Vec2, v2: Vec2) -> Option<f32> {
+    let line_delta = v2 - v1;
+    let den = delta.perp_dot(line_delta);
+    if den == 0.0 {
+        return None;
+    }
+    Some((v1 - start).perp_dot(line_delta) / den)
+}
+
+/// Offset from the center of a map object to its
+/// corner leading the move along `velocity`.
+fn leading_offset(velocity: Vec2, radius: f32) -> Vec2 {
+    let lead = |speed: f32| if speed > 0.0 { radius } else { -radius };
+    Vec2::new(lead(velocity.x), lead(velocity.y))
+}
+
+/// Keep only the part of `movement` going along `line`.
+///
+/// Mirrors `P_HitSlideLine`.
+fn hit_slide_line(line: &Line, movement: Vec2) -> Vec2 {
+    let Ok(dir) = Dir2::new(line.v2() - line.v1()) else {
+        return Vec2::ZERO;
+    };
+    *dir * movement.dot(*dir)
+}
@@ -0,0 +1,147 @@
+use super::state::MapObjectStates;
+use super::MapObject;
+use crate::skill::Skill;
+use crate::LevelStats;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use info::{Info, MobjFlags, MobjType, StateNum};
+use rand::{Rand, RngCore};
+use std::f32::consts::PI;
+
+/// Hurts map objects, and kills them when they run out of health.
+#[derive(SystemParam)]
+pub struct Damage<'w, 's> {
+    info: Res<'w, Info>,
+    skill: Res<'w, Skill>,
+    rand: ResMut<'w, Rand>,
+    states: MapObjectStates<'w, 's>,
+    stats: ResMut<'w, LevelStats>,
+}
+
+impl<'w, 's> Damage<'w, 's> {
+    /// Take `damage` health points from `target`, which may put it
+    /// in its pain state or kill it.
+    ///
+    /// Mirrors `P_DamageMobj`, for damage without an inflictor
+    /// or a source, e.g. from crushers and floors.
+    pub fn damage(&mut self, entity: Entity, target: &mut MapObject, damage: i32) {
+        self.damage_by(entity, target, None, damage);
+    }
+
+    /// Same as [`Damage::damage`], with `target` pushed away
+    /// from `inflictor`, e.g. a bullet's shooter.
+    ///
+    /// Mirrors `P_DamageMobj`.
+    pub fn damage_from(
+        &mut self,
+        entity: Entity,
+        target: &mut MapObject,
+        inflictor: &MapObject,
+        damage: i32,
+    ) {
+        self.damage_by(entity, target, Some(inflictor), damage);
+    }
+
+    fn damage_by(
+        &mut self,
+        entity: Entity,
+        target: &mut MapObject,
+        inflictor: Option<&MapObject>,
+        mut damage: i32,
+    ) {
+        if !target.flags.contains(MobjFlags::SHOOTABLE) || target.health <= 0 {
+            return;
+        }
+        if target.flags.contains(MobjFlags::SKULLFLY) {
+            target.velocity = Vec2::ZERO;
+            target.z_velocity = 0.0;
+        }
+        if let Some(inflictor) = inflictor.filter(|_| !target.flags.contains(MobjFlags::NOCLIP)) {
+            self.thrust(target, inflictor, damage);
+        }
+        if target.is_player() && *self.skill == Skill::Baby {
+            // Take half damage in trainer mode.
+            damage >>= 1;
+        }
+
+        target.health -= damage;
+        if target.health <= 0 {
+            self.kill(entity, target);
+            return;
+        }
+
+        let info = self.info.mobj_info(target.mobj_type);
+        if (self.rand.next_u32() as i32) < info.pain_chance
+            && !target.flags.contains(MobjFlags::SKULLFLY)
+        {
+            // Fight back!
+            target.flags.insert(MobjFlags::JUSTHIT);
+            self.states.set_state(entity, info.pain_state);
+        }
+    }
+
+    /// Push `target` away from `inflictor`, lighter things
+    /// flying farther.
+    fn thrust(&mut self, target: &mut MapObject, inflictor: &MapObject, damage: i32) {
+        let mass = self.info.mobj_info(target.mobj_type).mass.max(1);
+        let mut angle = (target.pos - inflictor.pos).to_angle();
+        let mut thrust = damage as f32 * 12.5 / mass as f32;
+        // Make fall forwards sometimes.
+        if damage < 40
+            && damage > target.health
+            && target.z - inflictor.z > 64.0
+            && self.rand.next_u32() & 1 != 0
+        {
+            angle += PI;
+            thrust *= 4.0;
+        }
+        target.velocity += Vec2::from_angle(angle) * thrust;
+    }
+
+    /// Put `entity` in `state`, see [`MapObjectStates::set_state`].
+    pub fn set_state(&mut self, entity: Entity, state: StateNum) -> bool {
+        self.states.set_state(entity, state)
+    }
+
+    pub fn states(&mut self) -> &mut MapObjectStates<'w, 's> {
+        &mut self.states
+    }
+
+    pub fn rand(&mut self) -> &mut Rand {
+        &mut self.rand
+    }
+
+    /// Turn `target` into a corpse.
+    ///
+    /// Mirrors `P_KillMobj`, without the drops.
+    pub fn kill(&mut self, entity: Entity, target: &mut MapObject) {
+        // Without multiplayer, every kill counts for the player.
+        if target.flags.contains(MobjFlags::COUNTKILL) {
+            self.stats.kills += 1;
+        }
+        target
+            .flags
+            .remove(MobjFlags::SHOOTABLE | MobjFlags::FLOAT | MobjFlags::SKULLFLY);
+        if target.mobj_type != MobjType::MT_SKULL {
+            target.flags.remove(MobjFlags::NOGRAVITY);
+        }
+        target.flags.insert(MobjFlags::CORPSE | MobjFlags::DROPOFF);
+        target.height /= 4.0;
+        if target.is_player() {
+            target.flags.remove(MobjFlags::SOLID);
+        }
+
+        let info = self.info.mobj_info(target.mobj_type);
+        let state = if target.health < -info.spawn_health && info.xdeath_state != StateNum::S_NULL {
+            info.xdeath_state
+        } else {
+            info.death_state
+        };
+        if !self.states.set_state(entity, state) {
+            return;
+        }
+        if let Some(mut obj_state) = self.states.get_mut(entity) {
+            obj_state.tics = (obj_state.tics - (self.rand.next_u32() & 3) as i32).max(1);
+        }
+    }
+}
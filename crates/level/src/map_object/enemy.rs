@@ -0,0 +1,167 @@
+use super::attack::{random_spread, Attacks, MELEE_RANGE};
+use super::state::{run_states, MapObjectAction};
+use crate::sound::StartSound;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use game_state::conditions::in_level_state;
+use info::{Action, MobjFlags, MobjType, Sfx};
+use rand::RngCore;
+
+/// Melee attack of imps.
+const TROOP_MELEE: Melee = Melee {
+    faces: 8,
+    damage: 3,
+    sound: Some(Sfx::sfx_claw),
+};
+/// Melee attack of cacodemons.
+const HEAD_MELEE: Melee = Melee {
+    faces: 6,
+    damage: 10,
+    sound: None,
+};
+/// Melee attack of barons and hell knights.
+const BRUISER_MELEE: Melee = Melee {
+    faces: 8,
+    damage: 10,
+    sound: Some(Sfx::sfx_claw),
+};
+
+pub struct EnemyPlugin;
+
+impl Plugin for EnemyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            enemy_attacks.after(run_states).run_if(in_level_state()),
+        );
+    }
+}
+
+/// Thing a monster is after, which its attacks are aimed at.
+///
+/// Mirrors `target` for monsters.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Target(pub Entity);
+
+/// Runs the attacks of monsters at their target.
+#[derive(SystemParam)]
+struct Enemies<'w, 's> {
+    target_query: Query<'w, 's, &'static Target>,
+    attacks: Attacks<'w, 's>,
+    sounds: EventWriter<'w, StartSound>,
+}
+
+impl Enemies<'_, '_> {
+    /// Call the action of a monster state, when the monster has a target.
+    fn action(&mut self, entity: Entity, action: Action) {
+        let Ok(&Target(target)) = self.target_query.get(entity) else {
+            return;
+        };
+        match action {
+            Action::A_FaceTarget => self.face_target(entity, target),
+            Action::A_TroopAttack => {
+                self.melee_or_missile(entity, target, TROOP_MELEE, MobjType::MT_TROOPSHOT)
+            }
+            Action::A_HeadAttack => {
+                self.melee_or_missile(entity, target, HEAD_MELEE, MobjType::MT_HEADSHOT)
+            }
+            Action::A_BruisAttack => {
+                self.melee_or_missile(entity, target, BRUISER_MELEE, MobjType::MT_BRUISERSHOT)
+            }
+            Action::A_CyberAttack => self.missile(entity, target, MobjType::MT_ROCKET),
+            Action::A_BspiAttack => self.missile(entity, target, MobjType::MT_ARACHPLAZ),
+            _ => {}
+        }
+    }
+
+    /// Turn to face the target, with a random error when it's fuzzy.
+    ///
+    /// Mirrors `A_FaceTarget`.
+    fn face_target(&mut self, entity: Entity, target: Entity) {
+        let Some(target_obj) = self.attacks.get(target).copied() else {
+            return;
+        };
+        let Some((mut map_obj, _)) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        map_obj.flags.remove(MobjFlags::AMBUSH);
+        let Ok(mut dir) = Dir2::new(target_obj.pos - map_obj.pos) else {
+            return;
+        };
+        if target_obj.flags.contains(MobjFlags::SHADOW) {
+            dir = random_spread(dir, self.attacks.rand(), 21);
+        }
+        if let Some((mut map_obj, _)) = self.attacks.get_mut(entity) {
+            map_obj.dir = dir;
+        }
+    }
+
+    /// Hit the target with `melee` when it is within reach, and fire
+    /// a missile of `mobj_type` at it otherwise.
+    ///
+    /// Mirrors `A_TroopAttack`, `A_HeadAttack` and `A_BruisAttack`.
+    fn melee_or_missile(
+        &mut self,
+        entity: Entity,
+        target: Entity,
+        melee: Melee,
+        mobj_type: MobjType,
+    ) {
+        self.face_target(entity, target);
+        if !self.check_melee_range(entity, target) {
+            self.attacks.spawn_missile(entity, target, mobj_type);
+            return;
+        }
+        if let Some(sound) = melee.sound {
+            self.sounds.send(StartSound::new(entity, sound));
+        }
+        let damage = (self.attacks.rand().next_u32() % melee.faces + 1) as i32 * melee.damage;
+        let Some(map_obj) = self.attacks.get(entity).copied() else {
+            return;
+        };
+        self.attacks.damage_from(target, &map_obj, damage);
+    }
+
+    /// Mirrors `A_CyberAttack` and `A_BspiAttack`.
+    fn missile(&mut self, entity: Entity, target: Entity, mobj_type: MobjType) {
+        self.face_target(entity, target);
+        self.attacks.spawn_missile(entity, target, mobj_type);
+    }
+
+    /// Whether the target is close enough to be hit, and in sight.
+    ///
+    /// Mirrors `P_CheckMeleeRange`.
+    fn check_melee_range(&self, entity: Entity, target: Entity) -> bool {
+        let (Some(map_obj), Some(target_obj)) =
+            (self.attacks.get(entity), self.attacks.get(target))
+        else {
+            return false;
+        };
+        let dist = approx_distance(target_obj.pos - map_obj.pos);
+        dist < MELEE_RANGE - 20.0 + target_obj.radius && self.attacks.check_sight(entity, target)
+    }
+}
+
+/// Melee attack of a monster, taking `damage` times a roll
+/// of a die of `faces` faces.
+#[derive(Debug, Clone, Copy)]
+struct Melee {
+    faces: u32,
+    damage: i32,
+    sound: Option<Sfx>,
+}
+
+/// Distance along `delta`, off by up to about 12%.
+///
+/// Mirrors `P_AproxDistance`.
+fn approx_distance(delta: Vec2) -> f32 {
+    let delta = delta.abs();
+    delta.x + delta.y - delta.min_element() / 2.0
+}
+
+fn enemy_attacks(mut params: ParamSet<(EventReader<MapObjectAction>, Enemies)>) {
+    let actions: Vec<_> = params.p0().read().copied().collect();
+    for MapObjectAction { entity, action } in actions {
+        params.p1().action(entity, action);
+    }
+}
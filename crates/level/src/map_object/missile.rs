@@ -0,0 +1,336 @@
+use super::attack::{Attacks, AIM_RANGE};
+use super::collision::Collision;
+use super::player::movement::MAX_MOMENTUM;
+use super::state::{run_states, MapObjectAction};
+use super::MapObject;
+use crate::components::{Line, Sector};
+use crate::sky::Sky;
+use crate::sound::StartSound;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use game_state::conditions::in_level_state;
+use info::{Action, Info, MobjFlags, MobjType, Sfx};
+use rand::RngCore;
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+/// Damage of explosions at their center, and distance they reach.
+const EXPLOSION_DAMAGE: i32 = 128;
+/// Rays of the spray of a BFG ball, spread a quarter turn wide.
+const BFG_RAYS: u32 = 40;
+
+pub struct MissilePlugin;
+
+impl Plugin for MissilePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (check_missile_spawn, move_missiles, a_explode, a_bfg_spray)
+                .chain()
+                .after(run_states)
+                .run_if(in_level_state()),
+        );
+    }
+}
+
+/// A projectile flying straight until it hits something, e.g.
+/// a rocket or an imp fireball.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Missile {
+    /// Thing that fired the missile, never hit by it.
+    ///
+    /// Mirrors `target` for missiles.
+    pub shooter: Entity,
+}
+
+/// Moves missiles, and makes them explode on what they hit.
+#[derive(SystemParam)]
+struct Missiles<'w, 's> {
+    info: Res<'w, Info>,
+    sky: Res<'w, Sky>,
+    collision: Collision<'w, 's>,
+    line_query: Query<'w, 's, &'static Line>,
+    sector_query: Query<'w, 's, &'static Sector>,
+    missile_query: Query<'w, 's, (Entity, &'static Missile)>,
+    attacks: Attacks<'w, 's>,
+    sounds: EventWriter<'w, StartSound>,
+    commands: Commands<'w, 's>,
+}
+
+impl Missiles<'_, '_> {
+    /// Move a missile just fired a bit forward, so that missiles
+    /// fired point blank at a wall explode on it.
+    ///
+    /// Mirrors `P_CheckMissileSpawn`, and the sound of `P_SpawnMissile`.
+    fn check_spawn(&mut self, entity: Entity) {
+        let Some(mut missile) = self.attacks.get(entity).copied() else {
+            return;
+        };
+        let see_sound = self.info.mobj_info(missile.mobj_type).see_sound;
+        if see_sound != Sfx::sfx_None {
+            self.sounds.send(StartSound::new(entity, see_sound));
+        }
+        self.cut_tics(entity);
+
+        let pos = missile.pos + missile.velocity / 2.0;
+        missile.z += missile.z_velocity / 2.0;
+        self.try_move(entity, &mut missile, pos);
+        self.store(entity, missile);
+    }
+
+    /// Mirrors `P_XYMovement` and `P_ZMovement` for missiles.
+    fn move_missile(&mut self, entity: Entity) {
+        let Some(mut missile) = self.attacks.get(entity).copied() else {
+            return;
+        };
+        // Exploding already.
+        if !missile.flags.contains(MobjFlags::MISSILE) {
+            return;
+        }
+        if missile.velocity != Vec2::ZERO && !self.move_xy(entity, &mut missile) {
+            self.store(entity, missile);
+            return;
+        }
+        self.move_z(entity, &mut missile);
+        self.store(entity, missile);
+    }
+
+    /// Returns `false` when the missile stopped.
+    fn move_xy(&mut self, entity: Entity, missile: &mut MapObject) -> bool {
+        missile.velocity = missile
+            .velocity
+            .clamp(-Vec2::splat(MAX_MOMENTUM), Vec2::splat(MAX_MOMENTUM));
+        let mut step = missile.velocity;
+        loop {
+            // Take big steps in two, not to fly through thin walls.
+            let (pos, rest) = if step.x > MAX_MOMENTUM / 2.0 || step.y > MAX_MOMENTUM / 2.0 {
+                (missile.pos + step / 2.0, step / 2.0)
+            } else {
+                (missile.pos + step, Vec2::ZERO)
+            };
+            if !self.try_move(entity, missile, pos) {
+                return false;
+            }
+            if rest == Vec2::ZERO {
+                return true;
+            }
+            step = rest;
+        }
+    }
+
+    fn move_z(&mut self, entity: Entity, missile: &mut MapObject) {
+        if missile.z == missile.floor_z && missile.z_velocity == 0.0 {
+            return;
+        }
+        missile.z += missile.z_velocity;
+        if missile.z <= missile.floor_z {
+            // Hit the floor.
+            missile.z = missile.floor_z;
+            self.explode(entity, missile);
+        } else if missile.z + missile.height > missile.ceiling_z {
+            // Hit the ceiling.
+            missile.z = missile.ceiling_z - missile.height;
+            self.explode(entity, missile);
+        }
+    }
+
+    /// Move `missile` to `pos`, unless something is in the way, on which
+    /// it explodes. Returns `false` when the missile stopped.
+    fn try_move(&mut self, entity: Entity, missile: &mut MapObject, pos: Vec2) -> bool {
+        if self.hit_thing(entity, missile, pos) {
+            self.explode(entity, missile);
+            return false;
+        }
+        // Missiles don't trigger the lines they cross.
+        if self.collision.try_move(missile, pos, &mut vec![]) {
+            return true;
+        }
+        if self.hits_sky(missile, pos) {
+            // Hack to prevent missiles exploding against the sky.
+            self.commands.entity(entity).despawn();
+        } else {
+            self.explode(entity, missile);
+        }
+        false
+    }
+
+    /// Check if `missile` hits a thing at `pos`, hurting it
+    /// when it is shootable.
+    ///
+    /// Mirrors `PIT_CheckThing` for missiles.
+    fn hit_thing(&mut self, entity: Entity, missile: &MapObject, pos: Vec2) -> bool {
+        let shooter = self
+            .missile_query
+            .get(entity)
+            .ok()
+            .map(|(_, missile)| missile.shooter);
+        let shooter_type = shooter
+            .and_then(|shooter| self.attacks.get(shooter))
+            .map(|shooter| shooter.mobj_type);
+
+        for other in self.attacks.things_touched(entity, pos) {
+            let Some(thing) = self.attacks.get(other).copied() else {
+                continue;
+            };
+            if missile.z > thing.z + thing.height {
+                // Overhead.
+                continue;
+            }
+            if missile.z + missile.height < thing.z {
+                // Underneath.
+                continue;
+            }
+            if shooter_type.is_some_and(|shooter_type| same_species(shooter_type, thing.mobj_type))
+            {
+                // Don't hit the shooter, nor explode on it.
+                if Some(other) == shooter {
+                    continue;
+                }
+                // Don't hurt the same species as the shooter, but the player.
+                if !thing.is_player() {
+                    return true;
+                }
+            }
+            if !thing.flags.contains(MobjFlags::SHOOTABLE) {
+                // Didn't do any damage.
+                if thing.flags.contains(MobjFlags::SOLID) {
+                    return true;
+                }
+                continue;
+            }
+
+            // Damage the thing.
+            let roll = (self.attacks.rand().next_u32() % 8 + 1) as i32;
+            let damage = roll * self.info.mobj_info(missile.mobj_type).damage;
+            self.attacks.damage_from(other, missile, damage);
+            return true;
+        }
+        false
+    }
+
+    /// Whether the ceiling stopping `missile` at `pos` is the sky,
+    /// above the upper texture of a two-sided line.
+    fn hits_sky(&self, missile: &MapObject, pos: Vec2) -> bool {
+        self.collision
+            .check_position(missile, pos)
+            .and_then(|position| position.ceiling_line)
+            .and_then(|line| self.line_query.get(*line).ok())
+            .and_then(|line| line.back_sector())
+            .and_then(|back| self.sector_query.get(*back).ok())
+            .is_some_and(|back| back.ceiling_tex == self.sky.flat)
+    }
+
+    /// Stop `missile` and put it in its death state.
+    ///
+    /// Mirrors `P_ExplodeMissile`.
+    fn explode(&mut self, entity: Entity, missile: &mut MapObject) {
+        missile.velocity = Vec2::ZERO;
+        missile.z_velocity = 0.0;
+        missile.flags.remove(MobjFlags::MISSILE);
+
+        let info = *self.info.mobj_info(missile.mobj_type);
+        if !self.attacks.damage().set_state(entity, info.death_state) {
+            return;
+        }
+        self.cut_tics(entity);
+        if info.death_sound != Sfx::sfx_None {
+            self.sounds.send(StartSound::new(entity, info.death_sound));
+        }
+    }
+
+    /// Take a random cut of up to 3 tics from the current state.
+    fn cut_tics(&mut self, entity: Entity) {
+        let tics_cut = (self.attacks.rand().next_u32() & 3) as i32;
+        if let Some(mut obj_state) = self.attacks.damage().states().get_mut(entity) {
+            obj_state.tics = (obj_state.tics - tics_cut).max(1);
+        }
+    }
+
+    fn store(&mut self, entity: Entity, missile: MapObject) {
+        if let Some((mut map_obj, _)) = self.attacks.get_mut(entity) {
+            *map_obj = missile;
+        }
+    }
+}
+
+/// Missiles don't hurt the monsters of the species of their shooter,
+/// barons and hell knights being of the same one.
+fn same_species(a: MobjType, b: MobjType) -> bool {
+    use MobjType::{MT_BRUISER, MT_KNIGHT};
+    a == b || matches!((a, b), (MT_KNIGHT, MT_BRUISER) | (MT_BRUISER, MT_KNIGHT))
+}
+
+/// Hit with the BFG spray the things `shooter` sees in the quarter
+/// turn around the direction `ball` flew in.
+fn bfg_spray(attacks: &mut Attacks, ball: Entity, shooter: Entity) {
+    let (Some(ball), Some(shooter_obj)) = (attacks.get(ball), attacks.get(shooter)) else {
+        return;
+    };
+    let (ball, shooter_obj) = (*ball, *shooter_obj);
+    for i in 0..BFG_RAYS {
+        let turn = -FRAC_PI_4 + FRAC_PI_2 * i as f32 / BFG_RAYS as f32;
+        let dir = Rot2::radians(turn) * ball.dir;
+        let Some(aim) = attacks.aim_line_attack(shooter, dir, AIM_RANGE) else {
+            continue;
+        };
+        let Some(target) = attacks.get(aim.target).copied() else {
+            continue;
+        };
+        attacks.spawn_at(
+            MobjType::MT_EXTRABFG,
+            target.pos,
+            target.z + target.height / 4.0,
+        );
+        let damage = (0..15)
+            .map(|_| (attacks.rand().next_u32() & 7) as i32 + 1)
+            .sum();
+        attacks.damage_from(aim.target, &shooter_obj, damage);
+    }
+}
+
+fn check_missile_spawn(query: Query<Entity, Added<Missile>>, mut missiles: Missiles) {
+    for entity in &query {
+        missiles.check_spawn(entity);
+    }
+}
+
+fn move_missiles(mut missiles: Missiles) {
+    let entities: Vec<_> = missiles
+        .missile_query
+        .iter()
+        .map(|(entity, _)| entity)
+        .collect();
+    for entity in entities {
+        missiles.move_missile(entity);
+    }
+}
+
+/// Hurt everything around an explosion, e.g. of a rocket or a barrel.
+///
+/// Mirrors `A_Explode`.
+fn a_explode(mut params: ParamSet<(EventReader<MapObjectAction>, Attacks)>) {
+    let exploding: Vec<_> = params
+        .p0()
+        .read()
+        .filter(|event| event.action == Action::A_Explode)
+        .map(|event| event.entity)
+        .collect();
+    for entity in exploding {
+        params.p1().radius_attack(entity, EXPLOSION_DAMAGE);
+    }
+}
+
+/// Mirrors `A_BFGSpray`.
+fn a_bfg_spray(mut params: ParamSet<(EventReader<MapObjectAction>, Query<&Missile>, Attacks)>) {
+    let landed: Vec<_> = params
+        .p0()
+        .read()
+        .filter(|event| event.action == Action::A_BFGSpray)
+        .map(|event| event.entity)
+        .collect();
+    for entity in landed {
+        let Ok(missile) = params.p1().get(entity).copied() else {
+            continue;
+        };
+        bfg_spray(&mut params.p2(), entity, missile.shooter);
+    }
+}
@@ -1,13 +1,154 @@
 use super::MapObject;
 use bevy::prelude::*;
+use info::{AmmoType, Info, WeaponType};
+use movement::VIEW_HEIGHT;
+use wad::prelude::*;
+use weapons::{PlayerSprite, PlayerSpriteLayer};
 
 mod controls;
 pub mod movement;
+mod use_lines;
+mod weapons;
 
 pub mod prelude {
-    pub use super::{controls::PlayerAction, movement::PlayerMovementPlugin, Player};
+    pub use super::{
+        controls::PlayerAction,
+        movement::{PlayerMovementPlugin, PLAYER_HEIGHT, PLAYER_RADIUS, VIEW_HEIGHT},
+        weapons::{
+            PlayerSprite, PlayerSpriteLayer, PlayerWeaponsPlugin, WEAPON_BOTTOM, WEAPON_TOP,
+        },
+        Card, DeathmatchStarts, Player,
+    };
 }
 
-#[derive(Component, Default)]
+/// Deathmatch starts after the first ten are ignored.
+const MAX_DEATHMATCH_STARTS: usize = 10;
+
+#[derive(Component)]
 #[require(MapObject)]
-pub struct Player;
+pub struct Player {
+    /// Height of the eyes, bobbing included.
+    pub view_z: f32,
+    /// Height of the eyes above the floor. Lowered for a moment
+    /// after stepping up or landing, then raised back smoothly.
+    pub view_height: f32,
+    pub delta_view_height: f32,
+    /// Amplitude of the view bobbing, grows with the speed.
+    pub bob: f32,
+    /// Keys picked up, indexed by [`Card`].
+    pub cards: [bool; Card::COUNT],
+    /// Use held since the last use, lines are used once per press.
+    pub use_down: bool,
+    /// Weapon in hand.
+    pub ready_weapon: WeaponType,
+    /// Weapon to switch to once the one in hand is lowered.
+    pub pending_weapon: Option<WeaponType>,
+    /// Weapons carried, indexed by [`WeaponType`].
+    pub weapons: [bool; WeaponType::COUNT],
+    /// Ammo carried, indexed by [`AmmoType`].
+    pub ammo: [i32; AmmoType::COUNT],
+    pub max_ammo: [i32; AmmoType::COUNT],
+    /// Fire held since the last shot, the rocket launcher and
+    /// the BFG only fire again once it is released.
+    pub attack_down: bool,
+    /// Shots fired in a row while holding fire, only the
+    /// first one of the pistol and chaingun is accurate.
+    pub refire: i32,
+    /// Light added to the view by the muzzle flashes.
+    pub extra_light: i32,
+    /// The weapon and its flash drawn over the view, indexed
+    /// by [`PlayerSpriteLayer`].
+    pub psprites: [PlayerSprite; PlayerSpriteLayer::COUNT],
+}
+
+impl Player {
+    /// A player starting the game, with the pistol and the fist.
+    ///
+    /// Mirrors `G_PlayerReborn`.
+    pub(crate) fn new(info: &Info) -> Self {
+        let mut weapons = [false; WeaponType::COUNT];
+        weapons[WeaponType::wp_fist.index()] = true;
+        weapons[WeaponType::wp_pistol.index()] = true;
+        let mut ammo = [0; AmmoType::COUNT];
+        ammo[AmmoType::am_clip.index()] = info.initial_bullets();
+        Self {
+            view_z: VIEW_HEIGHT,
+            view_height: VIEW_HEIGHT,
+            delta_view_height: 0.0,
+            bob: 0.0,
+            cards: [false; Card::COUNT],
+            use_down: false,
+            ready_weapon: WeaponType::wp_pistol,
+            pending_weapon: Some(WeaponType::wp_pistol),
+            weapons,
+            ammo,
+            max_ammo: std::array::from_fn(|index| {
+                AmmoType::from_index(index).map_or(0, |ammo| info.max_ammo(ammo))
+            }),
+            attack_down: false,
+            refire: 0,
+            extra_light: 0,
+            psprites: [PlayerSprite::default(); PlayerSpriteLayer::COUNT],
+        }
+    }
+
+    /// The player entering the next level with the weapons and ammo
+    /// it finished the level with, but without its keys.
+    ///
+    /// Mirrors `G_PlayerFinishLevel`, and the reset of the player
+    /// in `P_SpawnPlayer`.
+    pub(crate) fn finish_level(&self) -> Self {
+        Self {
+            view_z: VIEW_HEIGHT,
+            view_height: VIEW_HEIGHT,
+            delta_view_height: 0.0,
+            bob: 0.0,
+            cards: [false; Card::COUNT],
+            pending_weapon: Some(self.ready_weapon),
+            refire: 0,
+            extra_light: 0,
+            psprites: [PlayerSprite::default(); PlayerSpriteLayer::COUNT],
+            ..*self
+        }
+    }
+
+    /// Check if the player holds either `card` or `skull`, the
+    /// keycard and the skull key of a color opening the same doors.
+    pub fn has_key(&self, card: Card, skull: Card) -> bool {
+        self.cards[card as usize] || self.cards[skull as usize]
+    }
+}
+
+/// Keys opening the locked doors.
+///
+/// Mirrors `card_t`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Card {
+    BlueCard,
+    YellowCard,
+    RedCard,
+    BlueSkull,
+    YellowSkull,
+    RedSkull,
+}
+
+impl Card {
+    pub const COUNT: usize = 6;
+}
+
+/// Spots where the players spawn in deathmatch, i.e. the things of type 11.
+#[derive(Resource, Debug, Default, Deref)]
+pub struct DeathmatchStarts(Vec<MapThing>);
+
+impl DeathmatchStarts {
+    pub(crate) fn new(map: &Map) -> Self {
+        let starts = map
+            .things
+            .iter()
+            .filter(|thing| thing.thing_type == 11)
+            .take(MAX_DEATHMATCH_STARTS)
+            .copied()
+            .collect();
+        Self(starts)
+    }
+}
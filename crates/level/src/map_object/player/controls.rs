@@ -20,9 +20,31 @@ pub enum PlayerAction {
     StrafeRight,
     FastStrafeLeft,
     FastStrafeRight,
+    /// Open doors and flip switches.
+    Use,
+    Fire,
+    /// Switch to the weapon of a number key, from the fist to the BFG.
+    Weapon1,
+    Weapon2,
+    Weapon3,
+    Weapon4,
+    Weapon5,
+    Weapon6,
+    Weapon7,
 }
 
 impl PlayerAction {
+    /// The weapon switching actions, from the fist to the BFG.
+    pub const WEAPONS: [PlayerAction; 7] = [
+        PlayerAction::Weapon1,
+        PlayerAction::Weapon2,
+        PlayerAction::Weapon3,
+        PlayerAction::Weapon4,
+        PlayerAction::Weapon5,
+        PlayerAction::Weapon6,
+        PlayerAction::Weapon7,
+    ];
+
     pub fn default_map() -> InputMap<Self> {
         use KeyCode::*;
         use ModifierKey::*;
@@ -30,6 +52,17 @@ impl PlayerAction {
 
         InputMap::default()
             .with_multiple([(TurnLeft, KEY_LEFT), (TurnRight, KEY_RIGHT)])
+            .with(Use, Space)
+            .with_one_to_many(Fire, [ControlLeft, ControlRight])
+            .with_multiple([
+                (Weapon1, Digit1),
+                (Weapon2, Digit2),
+                (Weapon3, Digit3),
+                (Weapon4, Digit4),
+                (Weapon5, Digit5),
+                (Weapon6, Digit6),
+                (Weapon7, Digit7),
+            ])
             .with_multiple([
                 (FastLeftTurn, ButtonlikeChord::modified(Shift, KEY_LEFT)),
                 (FastRightTurn, ButtonlikeChord::modified(Shift, KEY_RIGHT)),
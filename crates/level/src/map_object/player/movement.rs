@@ -1,10 +1,12 @@
 use super::controls::PlayerAction;
-use crate::prelude::{Camera, MapObject, Player};
+use super::use_lines::use_lines;
+use crate::block_map::link_things;
+use crate::prelude::{ActivateLine, Activation, Camera, Collision, MapObject, Player};
 use bevy::prelude::*;
 pub use constants::*;
 use game_state::conditions::in_level_state;
 use leafwing_input_manager::prelude::*;
-use std::f32::consts;
+use std::f32::consts::{self, TAU};
 
 #[allow(unused)]
 pub mod constants;
@@ -17,11 +19,15 @@ impl Plugin for PlayerMovementPlugin {
             .add_systems(
                 FixedUpdate,
                 (
-                    update_side_momentum,
-                    update_forward_momentum,
-                    update_angle,
+                    (update_side_momentum, update_forward_momentum, update_angle)
+                        .run_if(player_can_move),
+                    count_down_reaction_time,
+                    update_view_height,
                     try_move_player,
+                    link_things,
                     apply_friction,
+                    move_player_z,
+                    use_lines,
                 )
                     .chain()
                     .run_if(in_level_state()),
@@ -30,6 +36,23 @@ impl Plugin for PlayerMovementPlugin {
     }
 }
 
+/// The player can't move for a bit after teleporting.
+fn player_can_move(query: Query<&MapObject, With<Player>>) -> bool {
+    query
+        .get_single()
+        .is_ok_and(|player| player.reaction_time == 0)
+}
+
+/// Mirrors the reaction time countdown of `P_PlayerThink`.
+fn count_down_reaction_time(mut query: Query<&mut MapObject, With<Player>>) {
+    let mut player = query.single_mut();
+    // Only touch the player when needed, as friction
+    // relies on change detection.
+    if player.reaction_time > 0 {
+        player.reaction_time -= 1;
+    }
+}
+
 fn update_angle(
     mut turn_held: Local<u32>,
     action_query: Query<&ActionState<PlayerAction>>,
@@ -109,7 +132,7 @@ fn update_forward_momentum(
 
     // Make sure the Bevy change system works properly by
     // avoiding calling thrust if movement is zero
-    if forward != 0.0 {
+    if forward != 0.0 && player.is_on_ground() {
         let thrust = forward * player.dir;
         player.velocity += thrust;
     }
@@ -137,7 +160,7 @@ fn update_side_momentum(
 
     // Make sure the Bevy change system works properly by
     // avoiding calling thrust if movement is zero
-    if side != 0.0 {
+    if side != 0.0 && player.is_on_ground() {
         // Rotate 90º clockwise to move side way.
         let dir = Rot2::radians(-consts::FRAC_PI_2) * player.dir;
         let thrust = side * dir;
@@ -145,14 +168,23 @@ fn update_side_momentum(
     }
 }
 
-fn try_move_player(mut query: Query<&mut MapObject, With<Player>>) {
-    let mut player = query.single_mut();
+/// Mirrors `P_XYMovement`.
+fn try_move_player(
+    collision: Collision,
+    mut query: Query<(Entity, &mut MapObject), With<Player>>,
+    mut activate: EventWriter<ActivateLine>,
+) {
+    let (entity, mut player) = query.single_mut();
 
     if player.velocity == Vec2::ZERO {
         return;
     }
+    player.velocity = player
+        .velocity
+        .clamp(-Vec2::splat(MAX_MOMENTUM), Vec2::splat(MAX_MOMENTUM));
     let mut xmove = player.velocity.x;
     let mut ymove = player.velocity.y;
+    let mut crossed = vec![];
 
     loop {
         let ptryx: f32;
@@ -170,13 +202,22 @@ fn try_move_player(mut query: Query<&mut MapObject, With<Player>>) {
             ymove = 0.0;
         }
 
-        player.pos.x = ptryx;
-        player.pos.y = ptryy;
+        if !collision.try_move(&mut player, Vec2::new(ptryx, ptryy), &mut crossed) {
+            // Blocked, try to slide along the wall.
+            collision.slide_move(&mut player, &mut crossed);
+        }
 
-        if xmove == 0.0 || ymove == 0.0 {
+        if xmove == 0.0 && ymove == 0.0 {
             break;
         }
     }
+
+    activate.send_batch(crossed.into_iter().map(|crossing| ActivateLine {
+        line: crossing.line,
+        thing: entity,
+        back_side: crossing.back_side,
+        activation: Activation::Cross,
+    }));
 }
 
 fn apply_friction(mut query: Query<&mut MapObject, With<Player>>) {
@@ -186,6 +227,10 @@ fn apply_friction(mut query: Query<&mut MapObject, With<Player>>) {
     if player.velocity == Vec2::ZERO {
         return;
     }
+    // No friction when airborne.
+    if !player.is_on_ground() {
+        return;
+    }
     // If momentum is within stop range and there is no
     // user input changing momentum (BUG???), then zero it out.
     if player.is_speed_low() && !player.is_changed() {
@@ -195,13 +240,101 @@ fn apply_friction(mut query: Query<&mut MapObject, With<Player>>) {
     player.velocity *= FRICTION;
 }
 
+/// Mirrors `P_ZMovement` for the player.
+fn move_player_z(collision: Collision, mut query: Query<(&mut MapObject, &mut Player)>) {
+    let (mut map_obj, mut player) = query.single_mut();
+
+    // Follow the floor and the ceiling when the sectors around move.
+    collision.height_clip(&mut map_obj);
+    if map_obj.z == map_obj.floor_z && map_obj.z_velocity == 0.0 {
+        return;
+    }
+
+    // Check for smooth step up.
+    if map_obj.z < map_obj.floor_z {
+        player.view_height -= map_obj.floor_z - map_obj.z;
+        player.delta_view_height = (VIEW_HEIGHT - player.view_height) / 8.0;
+    }
+
+    map_obj.z += map_obj.z_velocity;
+
+    if map_obj.z <= map_obj.floor_z {
+        // Hit the floor.
+        if map_obj.z_velocity < 0.0 {
+            if map_obj.z_velocity < -GRAVITY * 8.0 {
+                // Squat down, decreasing the view height for
+                // a moment after hitting the ground hard.
+                player.delta_view_height = map_obj.z_velocity / 8.0;
+            }
+            map_obj.z_velocity = 0.0;
+        }
+        map_obj.z = map_obj.floor_z;
+    } else if map_obj.z_velocity == 0.0 {
+        map_obj.z_velocity = -GRAVITY * 2.0;
+    } else {
+        map_obj.z_velocity -= GRAVITY;
+    }
+
+    if map_obj.z + map_obj.height > map_obj.ceiling_z {
+        // Hit the ceiling.
+        if map_obj.z_velocity > 0.0 {
+            map_obj.z_velocity = 0.0;
+        }
+        map_obj.z = map_obj.ceiling_z - map_obj.height;
+    }
+}
+
+/// Calculate the height of the eyes, with the view bobbing
+/// up and down while walking.
+///
+/// Mirrors `P_CalcHeight`.
+fn update_view_height(mut tics: Local<u32>, mut query: Query<(&MapObject, &mut Player)>) {
+    let (map_obj, mut player) = query.single_mut();
+    *tics = tics.wrapping_add(1);
+
+    // Regular movement bobbing, the speed also gives the amplitude.
+    player.bob = (map_obj.velocity.length_squared() / 4.0).min(MAX_BOB);
+
+    if !map_obj.is_on_ground() {
+        player.view_z = map_obj.z + player.view_height;
+        return;
+    }
+
+    let angle = TAU * (*tics % BOB_PERIOD) as f32 / BOB_PERIOD as f32;
+    let bob = player.bob / 2.0 * angle.sin();
+
+    // Move the view height back to normal.
+    player.view_height += player.delta_view_height;
+    if player.view_height > VIEW_HEIGHT {
+        player.view_height = VIEW_HEIGHT;
+        player.delta_view_height = 0.0;
+    }
+    if player.view_height < VIEW_HEIGHT / 2.0 {
+        player.view_height = VIEW_HEIGHT / 2.0;
+        if player.delta_view_height <= 0.0 {
+            player.delta_view_height = FIXED_STEP;
+        }
+    }
+    if player.delta_view_height != 0.0 {
+        player.delta_view_height += 0.25;
+        if player.delta_view_height == 0.0 {
+            player.delta_view_height = FIXED_STEP;
+        }
+    }
+
+    player.view_z = (map_obj.z + player.view_height + bob).min(map_obj.ceiling_z - 4.0);
+}
+
 fn update_camera(
     mut camera_query: Query<&mut Camera, With<Player>>,
-    player_query: Query<&MapObject, (Changed<MapObject>, With<Player>)>,
+    player_query: Query<(Ref<MapObject>, Ref<Player>)>,
 ) {
-    let Ok(player) = player_query.get_single() else {
+    let Ok((map_obj, player)) = player_query.get_single() else {
         return;
     };
+    if !map_obj.is_changed() && !player.is_changed() {
+        return;
+    }
     let mut camera = camera_query.single_mut();
-    camera.update(*player);
+    camera.update(*map_obj, player.view_z, player.extra_light);
 }
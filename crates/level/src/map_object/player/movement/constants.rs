@@ -5,6 +5,18 @@ pub const MAX_MOMENTUM: f32 = 30.0;
 pub(super) const FRICTION: f32 = 0.90625;
 pub const STOP_SPEED: Vec2 = Vec2::splat(0.0625);
 
+pub const PLAYER_RADIUS: f32 = 16.0;
+pub const PLAYER_HEIGHT: f32 = 56.0;
+/// Height of the eyes above the floor.
+pub const VIEW_HEIGHT: f32 = 41.0;
+/// Highest amplitude of the view bobbing.
+pub(super) const MAX_BOB: f32 = 16.0;
+/// Tics for the view to bob up and down once.
+pub(super) const BOB_PERIOD: u32 = 20;
+pub const GRAVITY: f32 = 1.0;
+/// Smallest step of vanilla fixed point numbers.
+pub(super) const FIXED_STEP: f32 = 1.0 / 65536.0;
+
 pub(super) const MOVE_FORWARD: f32 = 0.78125;
 pub(super) const RUN_FORWARD: f32 = 1.5625;
 
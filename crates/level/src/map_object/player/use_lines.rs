@@ -0,0 +1,63 @@
+use super::controls::PlayerAction;
+use crate::prelude::{
+    point_on_line_side, ActivateLine, Activation, Collision, Line, MapObject, Player, StartSound,
+};
+use bevy::prelude::*;
+use info::Sfx;
+use leafwing_input_manager::prelude::*;
+
+/// Distance at which the player reaches the lines to use.
+const USE_RANGE: f32 = 64.0;
+
+/// Use the first special line in front of the player, once
+/// per press of the use key.
+///
+/// Mirrors `P_UseLines`.
+pub(super) fn use_lines(
+    action_query: Query<&ActionState<PlayerAction>>,
+    mut query: Query<(Entity, &MapObject, &mut Player)>,
+    collision: Collision,
+    line_query: Query<&Line>,
+    mut activate: EventWriter<ActivateLine>,
+    mut sounds: EventWriter<StartSound>,
+) {
+    let action_state = action_query.single();
+    let (entity, map_obj, mut player) = query.single_mut();
+
+    if !action_state.pressed(&PlayerAction::Use) {
+        player.use_down = false;
+        return;
+    }
+    if player.use_down {
+        return;
+    }
+    player.use_down = true;
+
+    let end = map_obj.pos + map_obj.dir * USE_RANGE;
+    for intercept in collision.path_traverse(map_obj.pos, end) {
+        let Ok(line) = line_query.get(*intercept.line) else {
+            continue;
+        };
+
+        // Mirrors `PTR_UseTraverse`.
+        if line.special() == 0 {
+            let range = collision
+                .line_opening(line)
+                .map_or(0.0, |opening| opening.range());
+            if range <= 0.0 {
+                // Can't use through a wall.
+                sounds.send(StartSound::new(entity, Sfx::sfx_noway));
+                return;
+            }
+            // Not a special line, but keep checking.
+            continue;
+        }
+        activate.send(ActivateLine {
+            line: intercept.line,
+            thing: entity,
+            back_side: point_on_line_side(map_obj.pos, line),
+            activation: Activation::Use,
+        });
+        return;
+    }
+}
@@ -0,0 +1,715 @@
+use super::controls::PlayerAction;
+use super::Player;
+use crate::map_object::prelude::{
+    random_spread, Attacks, AIM_RANGE, AIM_SPREAD, MELEE_RANGE, MISSILE_RANGE,
+};
+use crate::prelude::StartSound;
+use crate::LevelTime;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use game_state::conditions::in_level_state;
+use info::{Action, Info, MobjFlags, MobjType, Sfx, StateNum, WeaponType};
+use leafwing_input_manager::prelude::*;
+use rand::RngCore;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+use wad::prelude::{GameMode, WadFile};
+
+/// Height of the weapon sprite once raised.
+pub const WEAPON_TOP: f32 = 32.0;
+/// Height of the weapon sprite once lowered off the screen.
+pub const WEAPON_BOTTOM: f32 = 128.0;
+/// Pixels the weapon goes down each tic while lowered.
+const LOWER_SPEED: f32 = 6.0;
+/// Pixels the weapon goes up each tic while raised.
+const RAISE_SPEED: f32 = 6.0;
+/// Most the chainsaw turns the player toward its target each tic.
+const SAW_TURN: f32 = FRAC_PI_2 / 20.0;
+/// Tics for the weapon bobbing to go back and forth.
+const BOB_PERIOD: u32 = 64;
+
+pub struct PlayerWeaponsPlugin;
+
+impl Plugin for PlayerWeaponsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (setup_player_sprites, change_weapon, move_player_sprites)
+                .chain()
+                .run_if(in_level_state()),
+        );
+    }
+}
+
+/// Sprites drawn over the view of the player.
+///
+/// Mirrors `psprnum_t`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PlayerSpriteLayer {
+    Weapon,
+    Flash,
+}
+
+impl PlayerSpriteLayer {
+    pub const COUNT: usize = 2;
+}
+
+/// A weapon, or its muzzle flash, drawn over the view of the player.
+///
+/// Mirrors `pspdef_t`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PlayerSprite {
+    /// `None` when not drawn.
+    pub state: Option<StateNum>,
+    /// Tics left before moving to the next state, -1 to stay forever.
+    pub tics: i32,
+    /// Position on the screen, the weapon being centered at 1.
+    pub sx: f32,
+    /// Position on the screen, from [`WEAPON_TOP`] to [`WEAPON_BOTTOM`].
+    pub sy: f32,
+}
+
+/// Raises, fires and lowers the weapons of the player.
+#[derive(SystemParam)]
+struct PlayerWeapons<'w, 's> {
+    info: Res<'w, Info>,
+    wad: Res<'w, WadFile>,
+    level_time: Res<'w, LevelTime>,
+    action_query: Query<'w, 's, &'static ActionState<PlayerAction>>,
+    attacks: Attacks<'w, 's>,
+    sounds: EventWriter<'w, StartSound>,
+}
+
+impl PlayerWeapons<'_, '_> {
+    fn fire_pressed(&self) -> bool {
+        self.action_query
+            .get_single()
+            .is_ok_and(|action_state| action_state.pressed(&PlayerAction::Fire))
+    }
+
+    fn psprite(&mut self, entity: Entity, layer: PlayerSpriteLayer) -> Option<PlayerSprite> {
+        let (_, player) = self.attacks.get_mut(entity)?;
+        Some(player?.psprites[layer as usize])
+    }
+
+    /// Put the sprite of `layer` in `state`, calling the actions
+    /// of the states entered.
+    ///
+    /// Mirrors `P_SetPsprite`.
+    fn set_psprite(&mut self, entity: Entity, layer: PlayerSpriteLayer, mut state: StateNum) {
+        loop {
+            let st = *self.info.state(state);
+            {
+                let Some((_, Some(mut player))) = self.attacks.get_mut(entity) else {
+                    return;
+                };
+                let psp = &mut player.psprites[layer as usize];
+                if state == StateNum::S_NULL {
+                    // Object removed itself.
+                    psp.state = None;
+                    return;
+                }
+                psp.state = Some(state);
+                psp.tics = st.tics;
+                if st.misc1 != 0 {
+                    // Coordinate set.
+                    psp.sx = st.misc1 as f32;
+                    psp.sy = st.misc2 as f32;
+                }
+            }
+
+            if let Some(action) = st.action {
+                self.weapon_action(entity, layer, action);
+            }
+            // The action may have changed the state.
+            let Some(psp) = self.psprite(entity, layer) else {
+                return;
+            };
+            let Some(current) = psp.state else {
+                return;
+            };
+            if psp.tics != 0 {
+                return;
+            }
+            state = self.info.state(current).next_state;
+        }
+    }
+
+    /// Count down the tics of the weapon sprites, and move
+    /// the ones whose tics ran out to their next state.
+    ///
+    /// Mirrors `P_MovePsprites`.
+    fn move_psprites(&mut self, entity: Entity) {
+        for layer in [PlayerSpriteLayer::Weapon, PlayerSpriteLayer::Flash] {
+            let Some((_, Some(mut player))) = self.attacks.get_mut(entity) else {
+                return;
+            };
+            let psp = &mut player.psprites[layer as usize];
+            // A tics of -1 is forever.
+            let Some(state) = psp.state.filter(|_| psp.tics != -1) else {
+                continue;
+            };
+            psp.tics -= 1;
+            if psp.tics == 0 {
+                let next_state = self.info.state(state).next_state;
+                self.set_psprite(entity, layer, next_state);
+            }
+        }
+
+        let Some((_, Some(mut player))) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let weapon = player.psprites[PlayerSpriteLayer::Weapon as usize];
+        let flash = &mut player.psprites[PlayerSpriteLayer::Flash as usize];
+        flash.sx = weapon.sx;
+        flash.sy = weapon.sy;
+    }
+
+    /// Start raising the pending weapon, or the one in hand.
+    ///
+    /// Mirrors `P_BringUpWeapon`.
+    fn bring_up_weapon(&mut self, entity: Entity) {
+        let Some((_, Some(mut player))) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let weapon = player.pending_weapon.take().unwrap_or(player.ready_weapon);
+        player.psprites[PlayerSpriteLayer::Weapon as usize].sy = WEAPON_BOTTOM;
+        if weapon == WeaponType::wp_chainsaw {
+            self.start_sound(entity, Sfx::sfx_sawup);
+        }
+        let up_state = self.info.weapon_info(weapon).up_state;
+        self.set_psprite(entity, PlayerSpriteLayer::Weapon, up_state);
+    }
+
+    /// Check if the weapon in hand has the ammo to fire. If not, switch
+    /// to the best weapon with ammo and start lowering the one in hand.
+    ///
+    /// Mirrors `P_CheckAmmo`.
+    fn check_ammo(&mut self, entity: Entity) -> bool {
+        let bfg_cells = self.info.bfg_cells_per_shot();
+        let Some((_, Some(mut player))) = self.attacks.get_mut(entity) else {
+            return false;
+        };
+        let weapon = self.info.weapon_info(player.ready_weapon);
+        let count = match player.ready_weapon {
+            WeaponType::wp_bfg => bfg_cells,
+            WeaponType::wp_supershotgun => 2,
+            _ => 1,
+        };
+        if weapon
+            .ammo
+            .is_none_or(|ammo| player.ammo[ammo.index()] >= count)
+        {
+            return true;
+        }
+
+        // Out of ammo, pick a weapon to change to.
+        player.pending_weapon = Some(best_weapon(&player, self.wad.game_mode()));
+        let down_state = weapon.down_state;
+        self.set_psprite(entity, PlayerSpriteLayer::Weapon, down_state);
+        false
+    }
+
+    /// Mirrors `P_FireWeapon`.
+    fn fire_weapon(&mut self, entity: Entity) {
+        if !self.check_ammo(entity) {
+            return;
+        }
+        self.attacks
+            .damage()
+            .set_state(entity, StateNum::S_PLAY_ATK1);
+        let Some((_, Some(player))) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let attack_state = self.info.weapon_info(player.ready_weapon).attack_state;
+        self.set_psprite(entity, PlayerSpriteLayer::Weapon, attack_state);
+    }
+
+    /// Take `count` ammo for the weapon in hand.
+    fn use_ammo(&mut self, entity: Entity, count: i32) {
+        let Some((_, Some(mut player))) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        if let Some(ammo) = self.info.weapon_info(player.ready_weapon).ammo {
+            player.ammo[ammo.index()] -= count;
+        }
+    }
+
+    /// Show the muzzle flash of the weapon in hand, `offset` states
+    /// after its first one, and light up the player.
+    fn flash(&mut self, entity: Entity, offset: usize) {
+        self.attacks
+            .damage()
+            .set_state(entity, StateNum::S_PLAY_ATK2);
+        self.flash_psprite(entity, offset);
+    }
+
+    /// Put the flash sprite in the flash state of the weapon in hand,
+    /// or `offset` states after it.
+    fn flash_psprite(&mut self, entity: Entity, offset: usize) {
+        let Some((_, Some(player))) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let flash_state = self.info.weapon_info(player.ready_weapon).flash_state;
+        let flash_state = StateNum::from_index(flash_state.index() + offset).unwrap_or(flash_state);
+        self.set_psprite(entity, PlayerSpriteLayer::Flash, flash_state);
+    }
+
+    fn start_sound(&mut self, entity: Entity, sfx: Sfx) {
+        self.sounds.send(StartSound::new(entity, sfx));
+    }
+
+    /// Call the action of a weapon state.
+    fn weapon_action(&mut self, entity: Entity, layer: PlayerSpriteLayer, action: Action) {
+        match action {
+            Action::A_Light0 => self.set_extra_light(entity, 0),
+            Action::A_Light1 => self.set_extra_light(entity, 1),
+            Action::A_Light2 => self.set_extra_light(entity, 2),
+            Action::A_WeaponReady => self.weapon_ready(entity, layer),
+            Action::A_Lower => self.lower(entity, layer),
+            Action::A_Raise => self.raise(entity, layer),
+            Action::A_ReFire => self.refire(entity),
+            Action::A_CheckReload => {
+                self.check_ammo(entity);
+            }
+            Action::A_GunFlash => self.flash(entity, 0),
+            Action::A_Punch => self.punch(entity),
+            Action::A_Saw => self.saw(entity),
+            Action::A_FirePistol => self.fire_pistol(entity),
+            Action::A_FireShotgun => self.fire_shotgun(entity),
+            Action::A_FireShotgun2 => self.fire_super_shotgun(entity),
+            Action::A_FireCGun => self.fire_chaingun(entity, layer),
+            Action::A_OpenShotgun2 => self.start_sound(entity, Sfx::sfx_dbopn),
+            Action::A_LoadShotgun2 => self.start_sound(entity, Sfx::sfx_dbload),
+            Action::A_CloseShotgun2 => {
+                self.start_sound(entity, Sfx::sfx_dbcls);
+                self.refire(entity);
+            }
+            Action::A_FireMissile => self.fire_missile(entity),
+            Action::A_FirePlasma => self.fire_plasma(entity),
+            Action::A_BFGsound => self.start_sound(entity, Sfx::sfx_bfg),
+            Action::A_FireBFG => self.fire_bfg(entity),
+            _ => {}
+        }
+    }
+
+    /// Mirrors `A_Light0`, `A_Light1` and `A_Light2`.
+    fn set_extra_light(&mut self, entity: Entity, extra_light: i32) {
+        if let Some((_, Some(mut player))) = self.attacks.get_mut(entity) {
+            player.extra_light = extra_light;
+        }
+    }
+
+    /// The player can fire the weapon or change to another type.
+    /// Follows after getting the weapon up, or after previous attack/fire
+    /// sequence.
+    ///
+    /// Mirrors `A_WeaponReady`.
+    fn weapon_ready(&mut self, entity: Entity, layer: PlayerSpriteLayer) {
+        // Get out of attack state.
+        let obj_state = self
+            .attacks
+            .damage()
+            .states()
+            .get_mut(entity)
+            .map(|s| s.state);
+        if matches!(
+            obj_state,
+            Some(StateNum::S_PLAY_ATK1 | StateNum::S_PLAY_ATK2)
+        ) {
+            self.attacks.damage().set_state(entity, StateNum::S_PLAY);
+        }
+
+        let fire_pressed = self.fire_pressed();
+        let level_time = **self.level_time;
+        let Some((map_obj, Some(mut player))) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let psp = player.psprites[layer as usize];
+        if player.ready_weapon == WeaponType::wp_chainsaw && psp.state == Some(StateNum::S_SAW) {
+            self.sounds.send(StartSound::new(entity, Sfx::sfx_sawidl));
+        }
+
+        // Check for change. If the player is dead, put the weapon away.
+        if player.pending_weapon.is_some() || map_obj.health <= 0 {
+            let down_state = self.info.weapon_info(player.ready_weapon).down_state;
+            self.set_psprite(entity, PlayerSpriteLayer::Weapon, down_state);
+            return;
+        }
+
+        // Check for fire. The rocket launcher and the BFG don't auto fire.
+        if fire_pressed {
+            let auto_fire = !matches!(
+                player.ready_weapon,
+                WeaponType::wp_missile | WeaponType::wp_bfg
+            );
+            if !player.attack_down || auto_fire {
+                player.attack_down = true;
+                self.fire_weapon(entity);
+                return;
+            }
+        } else {
+            player.attack_down = false;
+        }
+
+        // Bob the weapon based on movement speed.
+        let angle = TAU * (level_time % BOB_PERIOD) as f32 / BOB_PERIOD as f32;
+        let bob = player.bob;
+        let psp = &mut player.psprites[layer as usize];
+        psp.sx = 1.0 + bob * angle.cos();
+        // The weapon only bobs down from the top.
+        psp.sy = WEAPON_TOP + bob * (angle % PI).sin();
+    }
+
+    /// Lower the weapon, then raise the pending one once it is off the
+    /// screen.
+    ///
+    /// Mirrors `A_Lower`.
+    fn lower(&mut self, entity: Entity, layer: PlayerSpriteLayer) {
+        let Some((map_obj, Some(mut player))) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let psp = &mut player.psprites[layer as usize];
+        psp.sy += LOWER_SPEED;
+        if psp.sy < WEAPON_BOTTOM {
+            // Not down yet.
+            return;
+        }
+        if map_obj.health <= 0 {
+            // Player is dead, so keep the weapon off screen.
+            self.set_psprite(entity, PlayerSpriteLayer::Weapon, StateNum::S_NULL);
+            return;
+        }
+        // The old weapon has been lowered off the screen,
+        // so change the weapon and start raising it.
+        player.ready_weapon = player.pending_weapon.unwrap_or(player.ready_weapon);
+        self.bring_up_weapon(entity);
+    }
+
+    /// Mirrors `A_Raise`.
+    fn raise(&mut self, entity: Entity, layer: PlayerSpriteLayer) {
+        let Some((_, Some(mut player))) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let psp = &mut player.psprites[layer as usize];
+        psp.sy -= RAISE_SPEED;
+        if psp.sy > WEAPON_TOP {
+            return;
+        }
+        psp.sy = WEAPON_TOP;
+        // The weapon has been raised all the way, so change to the ready state.
+        let ready_state = self.info.weapon_info(player.ready_weapon).ready_state;
+        self.set_psprite(entity, PlayerSpriteLayer::Weapon, ready_state);
+    }
+
+    /// The player can re-fire the weapon without lowering it entirely.
+    ///
+    /// Mirrors `A_ReFire`.
+    fn refire(&mut self, entity: Entity) {
+        let fire_pressed = self.fire_pressed();
+        let Some((map_obj, Some(mut player))) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        // Check for fire, the weapon can't be changed while firing.
+        if fire_pressed && player.pending_weapon.is_none() && map_obj.health > 0 {
+            player.refire += 1;
+            self.fire_weapon(entity);
+        } else {
+            player.refire = 0;
+            self.check_ammo(entity);
+        }
+    }
+
+    /// Find the slope at which bullets hit something, looking a bit
+    /// to the sides when there is nothing straight ahead.
+    ///
+    /// Mirrors `P_BulletSlope`.
+    fn bullet_slope(&mut self, entity: Entity) -> f32 {
+        let Some((map_obj, _)) = self.attacks.get_mut(entity) else {
+            return 0.0;
+        };
+        let dir = map_obj.dir;
+        [0.0, AIM_SPREAD, -AIM_SPREAD]
+            .into_iter()
+            .find_map(|turn| {
+                self.attacks
+                    .aim_line_attack(entity, Rot2::radians(turn) * dir, AIM_RANGE)
+            })
+            .map_or(0.0, |aim| aim.slope)
+    }
+
+    /// Fire a bullet along `slope`, with some spread unless `accurate`.
+    ///
+    /// Mirrors `P_GunShot`.
+    fn gun_shot(&mut self, entity: Entity, accurate: bool, slope: f32) {
+        let Some((map_obj, _)) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let mut dir = map_obj.dir;
+        let rand = self.attacks.rand();
+        let damage = 5 * (rand.next_u32() % 3 + 1) as i32;
+        if !accurate {
+            dir = random_spread(dir, rand, 18);
+        }
+        self.attacks
+            .line_attack(entity, dir, MISSILE_RANGE, slope, damage);
+    }
+
+    /// Punch whatever is right in front of the player.
+    ///
+    /// Mirrors `A_Punch`, without the berserk.
+    fn punch(&mut self, entity: Entity) {
+        let Some((map_obj, _)) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let dir = map_obj.dir;
+        let rand = self.attacks.rand();
+        let damage = ((rand.next_u32() % 10 + 1) << 1) as i32;
+        let dir = random_spread(dir, rand, 18);
+        let aim = self.attacks.aim_line_attack(entity, dir, MELEE_RANGE);
+        let slope = aim.map_or(0.0, |aim| aim.slope);
+        self.attacks
+            .line_attack(entity, dir, MELEE_RANGE, slope, damage);
+
+        // Turn to face the target.
+        let Some(target) = aim.and_then(|aim| self.attacks.get_mut(aim.target)) else {
+            return;
+        };
+        let target_pos = target.0.pos;
+        self.start_sound(entity, Sfx::sfx_punch);
+        let Some((mut map_obj, _)) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        if let Ok(dir) = Dir2::new(target_pos - map_obj.pos) {
+            map_obj.dir = dir;
+        }
+    }
+
+    /// Mirrors `A_Saw`.
+    fn saw(&mut self, entity: Entity) {
+        let Some((map_obj, _)) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let dir = map_obj.dir;
+        let rand = self.attacks.rand();
+        let damage = 2 * (rand.next_u32() % 10 + 1) as i32;
+        let dir = random_spread(dir, rand, 18);
+        // Use a melee range + 1, so the puff doesn't skip the flash.
+        let aim = self.attacks.aim_line_attack(entity, dir, MELEE_RANGE + 1.0);
+        let slope = aim.map_or(0.0, |aim| aim.slope);
+        self.attacks
+            .line_attack(entity, dir, MELEE_RANGE + 1.0, slope, damage);
+
+        let Some(target) = aim.and_then(|aim| self.attacks.get_mut(aim.target)) else {
+            self.start_sound(entity, Sfx::sfx_sawful);
+            return;
+        };
+        let target_pos = target.0.pos;
+        self.start_sound(entity, Sfx::sfx_sawhit);
+
+        // Turn to face the target, a bit at a time.
+        let Some((mut map_obj, _)) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let angle = (target_pos - map_obj.pos).to_angle();
+        let current = map_obj.dir.to_angle();
+        let turn = (angle - current + PI).rem_euclid(TAU) - PI;
+        let new_angle = if turn < 0.0 {
+            if turn < -SAW_TURN {
+                angle + FRAC_PI_2 / 21.0
+            } else {
+                current - SAW_TURN
+            }
+        } else if turn > SAW_TURN {
+            angle - FRAC_PI_2 / 21.0
+        } else {
+            current + SAW_TURN
+        };
+        map_obj.dir = Dir2::from_xy(new_angle.cos(), new_angle.sin()).unwrap_or(map_obj.dir);
+        map_obj.flags.insert(MobjFlags::JUSTATTACKED);
+    }
+
+    /// Mirrors `A_FirePistol`.
+    fn fire_pistol(&mut self, entity: Entity) {
+        self.start_sound(entity, Sfx::sfx_pistol);
+        self.use_ammo(entity, 1);
+        self.flash(entity, 0);
+        let slope = self.bullet_slope(entity);
+        let accurate = self.refire_count(entity) == 0;
+        self.gun_shot(entity, accurate, slope);
+    }
+
+    /// Mirrors `A_FireShotgun`.
+    fn fire_shotgun(&mut self, entity: Entity) {
+        self.start_sound(entity, Sfx::sfx_shotgn);
+        self.use_ammo(entity, 1);
+        self.flash(entity, 0);
+        let slope = self.bullet_slope(entity);
+        for _ in 0..7 {
+            self.gun_shot(entity, false, slope);
+        }
+    }
+
+    /// Mirrors `A_FireShotgun2`.
+    fn fire_super_shotgun(&mut self, entity: Entity) {
+        self.start_sound(entity, Sfx::sfx_dshtgn);
+        self.use_ammo(entity, 2);
+        self.flash(entity, 0);
+        let slope = self.bullet_slope(entity);
+        let Some((map_obj, _)) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let dir = map_obj.dir;
+        for _ in 0..20 {
+            let rand = self.attacks.rand();
+            let damage = 5 * (rand.next_u32() % 3 + 1) as i32;
+            let dir = random_spread(dir, rand, 19);
+            // `P_SubRandom() << 5` in fixed point.
+            let slope = slope + rand.sub_random() as f32 / 2048.0;
+            self.attacks
+                .line_attack(entity, dir, MISSILE_RANGE, slope, damage);
+        }
+    }
+
+    /// Mirrors `A_FireCGun`.
+    fn fire_chaingun(&mut self, entity: Entity, layer: PlayerSpriteLayer) {
+        self.start_sound(entity, Sfx::sfx_pistol);
+        let Some((_, Some(player))) = self.attacks.get_mut(entity) else {
+            return;
+        };
+        let ammo = self.info.weapon_info(player.ready_weapon).ammo;
+        if ammo.is_some_and(|ammo| player.ammo[ammo.index()] == 0) {
+            return;
+        }
+        // Each of the two frames of the chaingun has its own flash.
+        let state = player.psprites[layer as usize].state;
+        let offset = state.map_or(0, |state| {
+            state.index().saturating_sub(StateNum::S_CHAIN1.index())
+        });
+        self.use_ammo(entity, 1);
+        self.flash(entity, offset);
+        let slope = self.bullet_slope(entity);
+        let accurate = self.refire_count(entity) == 0;
+        self.gun_shot(entity, accurate, slope);
+    }
+
+    /// Mirrors `A_FireMissile`.
+    fn fire_missile(&mut self, entity: Entity) {
+        self.use_ammo(entity, 1);
+        self.attacks
+            .spawn_player_missile(entity, MobjType::MT_ROCKET);
+    }
+
+    /// Mirrors `A_FirePlasma`.
+    fn fire_plasma(&mut self, entity: Entity) {
+        self.use_ammo(entity, 1);
+        let offset = (self.attacks.rand().next_u32() & 1) as usize;
+        self.flash_psprite(entity, offset);
+        self.attacks
+            .spawn_player_missile(entity, MobjType::MT_PLASMA);
+    }
+
+    /// Mirrors `A_FireBFG`.
+    fn fire_bfg(&mut self, entity: Entity) {
+        self.use_ammo(entity, self.info.bfg_cells_per_shot());
+        self.attacks.spawn_player_missile(entity, MobjType::MT_BFG);
+    }
+
+    fn refire_count(&mut self, entity: Entity) -> i32 {
+        self.attacks
+            .get_mut(entity)
+            .and_then(|(_, player)| player.map(|player| player.refire))
+            .unwrap_or(0)
+    }
+}
+
+/// Weapon to change to when out of ammo, the most powerful one
+/// with ammo left.
+///
+/// Mirrors the preferences of `P_CheckAmmo`.
+fn best_weapon(player: &Player, game_mode: GameMode) -> WeaponType {
+    use info::AmmoType::*;
+    use WeaponType::*;
+
+    let owned = |weapon: WeaponType| player.weapons[weapon.index()];
+    let ammo = |ammo: info::AmmoType| player.ammo[ammo.index()];
+    if owned(wp_plasma) && ammo(am_cell) > 0 && game_mode != GameMode::Shareware {
+        wp_plasma
+    } else if owned(wp_supershotgun) && ammo(am_shell) > 2 && game_mode == GameMode::Commercial {
+        wp_supershotgun
+    } else if owned(wp_chaingun) && ammo(am_clip) > 0 {
+        wp_chaingun
+    } else if owned(wp_shotgun) && ammo(am_shell) > 0 {
+        wp_shotgun
+    } else if ammo(am_clip) > 0 {
+        wp_pistol
+    } else if owned(wp_chainsaw) {
+        wp_chainsaw
+    } else if owned(wp_missile) && ammo(am_misl) > 0 {
+        wp_missile
+    } else if owned(wp_bfg) && ammo(am_cell) > 40 && game_mode != GameMode::Shareware {
+        wp_bfg
+    } else {
+        wp_fist
+    }
+}
+
+/// Bring up the weapon of the players just spawned.
+///
+/// Mirrors `P_SetupPsprites`.
+fn setup_player_sprites(mut params: ParamSet<(Query<Entity, Added<Player>>, PlayerWeapons)>) {
+    let entities: Vec<Entity> = params.p0().iter().collect();
+    for entity in entities {
+        params.p1().bring_up_weapon(entity);
+    }
+}
+
+/// Switch to the weapon of the number key pressed.
+///
+/// Mirrors the weapon change of `P_PlayerThink`.
+fn change_weapon(
+    action_query: Query<&ActionState<PlayerAction>>,
+    mut query: Query<&mut Player>,
+    wad: Res<WadFile>,
+) {
+    let (Ok(action_state), Ok(mut player)) = (action_query.get_single(), query.get_single_mut())
+    else {
+        return;
+    };
+    let Some(mut weapon) = PlayerAction::WEAPONS
+        .iter()
+        .position(|action| action_state.pressed(action))
+        .and_then(WeaponType::from_index)
+    else {
+        return;
+    };
+
+    let game_mode = wad.game_mode();
+    let owned = |weapon: WeaponType| player.weapons[weapon.index()];
+    // Without the berserk, the chainsaw always takes the place of the fist.
+    if weapon == WeaponType::wp_fist && owned(WeaponType::wp_chainsaw) {
+        weapon = WeaponType::wp_chainsaw;
+    }
+    if game_mode == GameMode::Commercial
+        && weapon == WeaponType::wp_shotgun
+        && owned(WeaponType::wp_supershotgun)
+        && player.ready_weapon != WeaponType::wp_supershotgun
+    {
+        weapon = WeaponType::wp_supershotgun;
+    }
+    if !owned(weapon) || weapon == player.ready_weapon {
+        return;
+    }
+    // Do not go to plasma or BFG in shareware, even if cheated.
+    if matches!(weapon, WeaponType::wp_plasma | WeaponType::wp_bfg)
+        && game_mode == GameMode::Shareware
+    {
+        return;
+    }
+    player.pending_weapon = Some(weapon);
+}
+
+fn move_player_sprites(query: Query<Entity, With<Player>>, mut weapons: PlayerWeapons) {
+    for entity in &query {
+        weapons.move_psprites(entity);
+    }
+}
@@ -0,0 +1,150 @@
+use super::thing_sprite::ThingSprite;
+use super::MapObject;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use game_state::conditions::in_level_state;
+use info::{Action, Info, MobjFlags, StateNum};
+
+pub struct MapObjectStatePlugin;
+
+impl Plugin for MapObjectStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MapObjectAction>().add_systems(
+            FixedUpdate,
+            (run_states, a_fall).chain().run_if(in_level_state()),
+        );
+    }
+}
+
+/// State of the animation of a map object.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MapObjectState {
+    pub state: StateNum,
+    /// Tics left before moving to the next state, -1 to stay forever.
+    pub tics: i32,
+}
+
+/// Sent when a map object enters a state calling an action function.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct MapObjectAction {
+    pub entity: Entity,
+    pub action: Action,
+}
+
+/// Moves map objects from one state to another.
+#[derive(SystemParam)]
+pub struct MapObjectStates<'w, 's> {
+    info: Res<'w, Info>,
+    query: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static mut MapObjectState,
+            &'static mut ThingSprite,
+        ),
+    >,
+    actions: EventWriter<'w, MapObjectAction>,
+    commands: Commands<'w, 's>,
+}
+
+impl MapObjectStates<'_, '_> {
+    /// Put a map object in `state`. Returns `false` when the map object
+    /// is removed, because it reached [`StateNum::S_NULL`].
+    ///
+    /// Mirrors `P_SetMobjState`.
+    pub fn set_state(&mut self, entity: Entity, state: StateNum) -> bool {
+        let Ok((_, mut obj_state, mut sprite)) = self.query.get_mut(entity) else {
+            return false;
+        };
+        enter_state(
+            &self.info,
+            &mut self.actions,
+            &mut self.commands,
+            entity,
+            (&mut obj_state, &mut sprite),
+            state,
+        )
+    }
+
+    /// Get the state of a map object, to adjust its tics.
+    pub fn get_mut(&mut self, entity: Entity) -> Option<Mut<'_, MapObjectState>> {
+        let (_, obj_state, _) = self.query.get_mut(entity).ok()?;
+        Some(obj_state)
+    }
+
+    /// Count down the tics of the state of every map object, and move
+    /// the ones whose tics ran out to their next state.
+    ///
+    /// Mirrors the end of `P_MobjThinker`.
+    fn tick(&mut self) {
+        for (entity, mut obj_state, mut sprite) in &mut self.query {
+            if obj_state.tics == -1 {
+                continue;
+            }
+            obj_state.tics -= 1;
+            if obj_state.tics == 0 {
+                let next_state = self.info.state(obj_state.state).next_state;
+                enter_state(
+                    &self.info,
+                    &mut self.actions,
+                    &mut self.commands,
+                    entity,
+                    (&mut obj_state, &mut sprite),
+                    next_state,
+                );
+            }
+        }
+    }
+}
+
+/// Go through the states lasting 0 tics until reaching one that lasts,
+/// and send the actions of every state entered.
+fn enter_state(
+    info: &Info,
+    actions: &mut EventWriter<MapObjectAction>,
+    commands: &mut Commands,
+    entity: Entity,
+    (obj_state, sprite): (&mut MapObjectState, &mut ThingSprite),
+    mut state: StateNum,
+) -> bool {
+    loop {
+        if state == StateNum::S_NULL {
+            obj_state.state = state;
+            commands.entity(entity).despawn();
+            return false;
+        }
+
+        let st = info.state(state);
+        obj_state.state = state;
+        obj_state.tics = st.tics;
+        *sprite = ThingSprite::from_state(st);
+
+        if let Some(action) = st.action {
+            actions.send(MapObjectAction { entity, action });
+        }
+
+        state = st.next_state;
+        if obj_state.tics != 0 {
+            return true;
+        }
+    }
+}
+
+pub(crate) fn run_states(mut states: MapObjectStates) {
+    states.tick();
+}
+
+/// Corpses don't block the way anymore.
+///
+/// Mirrors `A_Fall`.
+fn a_fall(mut actions: EventReader<MapObjectAction>, mut query: Query<&mut MapObject>) {
+    for event in actions.read() {
+        if event.action != Action::A_Fall {
+            continue;
+        }
+        if let Ok(mut map_obj) = query.get_mut(event.entity) {
+            map_obj.flags.remove(MobjFlags::SOLID);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use info::{SpriteNum, State};
+
+/// Sprite drawn for a map object.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ThingSprite {
+    pub sprite: SpriteNum,
+    pub frame: usize,
+    /// Drawn at full brightness, regardless of the light level of the sector.
+    pub full_bright: bool,
+}
+
+impl ThingSprite {
+    /// Get the sprite shown by a state.
+    pub fn from_state(state: &State) -> Self {
+        Self {
+            sprite: state.sprite,
+            frame: state.frame_index(),
+            full_bright: state.is_full_bright(),
+        }
+    }
+}
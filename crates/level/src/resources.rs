@@ -3,7 +3,7 @@ use crate::components::*;
 use crate::map_object::prelude::*;
 use anyhow::{bail, Result};
 pub use bevy::prelude::*;
-use moonshine_kind::{GetInstanceCommands, Instance, Kind};
+use moonshine_kind::{Instance, Kind};
 use std::rc::Rc;
 use wad::prelude::*;
 
@@ -36,14 +36,22 @@ impl Default for LevelMap {
 }
 
 impl LevelMap {
-    pub fn load(&mut self, commands: &mut Commands, map: &Map, wad: &WadFile) -> Result<()> {
+    pub fn load(
+        &mut self,
+        commands: &mut Commands,
+        map: &Map,
+        wad: &WadFile,
+        spawner: &mut ThingSpawner,
+        new_game: bool,
+    ) -> Result<()> {
         self.load_vertexes(map);
         self.load_sectors(commands, map, wad)?;
-        self.load_lines(commands, map)?;
         self.load_lines_sides(commands, map, wad)?;
+        self.load_lines(commands, map)?;
+        self.group_lines(commands, map)?;
         self.load_segments(commands, map)?;
         self.load_sub_sectors(commands, map)?;
-        self.load_things(commands, map);
+        self.load_things(commands, map, wad, spawner, new_game)?;
         self.load_bsp(map)?;
         Ok(())
     }
@@ -70,6 +78,54 @@ impl LevelMap {
         Ok(())
     }
 
+    /// Give the sectors the lines around them, and their bounding box.
+    ///
+    /// Mirrors `P_GroupLines`.
+    fn group_lines(&self, commands: &mut Commands, map: &Map) -> Result<()> {
+        let mut sectors_lines = vec![(vec![], Rect::EMPTY); self.sectors.len()];
+        for (line, &instance) in map.lines.iter().zip(&self.lines) {
+            let v1 = usize::try_from(line.v1)?;
+            let v2 = usize::try_from(line.v2)?;
+            let (Some(&v1), Some(&v2)) = (self.lines_vertexes.get(v1), self.lines_vertexes.get(v2))
+            else {
+                bail!("Line references invalid vertex");
+            };
+
+            let side_sector = |side: i16| -> Result<Option<usize>> {
+                if side < 0 {
+                    return Ok(None);
+                }
+                let Some(side) = map.side_defs.get(usize::try_from(side)?) else {
+                    bail!("Line references invalid side.");
+                };
+                Ok(Some(usize::try_from(side.sector)?))
+            };
+            let Some(front_sector) = side_sector(line.front_side)? else {
+                bail!("Line has no front side.");
+            };
+            let back_sector = side_sector(line.back_side)?.filter(|&back| back != front_sector);
+
+            for sector in std::iter::once(front_sector).chain(back_sector) {
+                let Some((lines, bbox)) = sectors_lines.get_mut(sector) else {
+                    bail!("Line side references invalid sector.");
+                };
+                lines.push(instance);
+                *bbox = bbox.union_point(v1).union_point(v2);
+            }
+        }
+
+        for (sector, (lines, bbox)) in self.sectors.iter().zip(sectors_lines) {
+            commands
+                .entity(sector.entity())
+                .entry::<Sector>()
+                .and_modify(move |mut sector| {
+                    sector.lines = lines;
+                    sector.bbox = bbox;
+                });
+        }
+        Ok(())
+    }
+
     fn load_segments(&mut self, commands: &mut Commands, map: &Map) -> Result<()> {
         unload_helper(commands, &mut self.lines_segments);
         self.lines_segments = commands.spawn_segments(map, self)?;
@@ -93,9 +149,17 @@ impl LevelMap {
         Ok(())
     }
 
-    fn load_things(&mut self, commands: &mut Commands, map: &Map) {
+    fn load_things(
+        &mut self,
+        commands: &mut Commands,
+        map: &Map,
+        wad: &WadFile,
+        spawner: &mut ThingSpawner,
+        new_game: bool,
+    ) -> Result<()> {
         unload_helper(commands, &mut self.map_objects);
-        self.map_objects = commands.spawn_map_objects(map);
+        self.map_objects = commands.spawn_map_objects(map, wad.game_mode(), spawner, new_game)?;
+        Ok(())
     }
 
     fn load_bsp(&mut self, map: &Map) -> Result<()> {
@@ -147,6 +211,9 @@ fn load_bsp_leaf_node(leaf: i16, level_map: &LevelMap) -> Result<Rc<BspNode>> {
 
 fn unload_helper<T: Kind>(commands: &mut Commands, components: &mut Vec<Instance<T>>) {
     for entity in components.drain(..) {
-        commands.instance(entity).despawn();
+        // Map objects may already be gone, e.g. after reaching `S_NULL`.
+        if let Some(mut entity_cmds) = commands.get_entity(entity.entity()) {
+            entity_cmds.despawn();
+        }
     }
 }
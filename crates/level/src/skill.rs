@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use cli::CommandLine;
+use wad::prelude::*;
+
+/// Skill level of the game, medium unless set with `--skill`.
+///
+/// Mirrors `skill_t`.
+#[derive(Resource, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Skill {
+    /// I'm too young to die.
+    Baby,
+    /// Hey, not too rough.
+    Easy,
+    /// Hurt me plenty.
+    Medium,
+    /// Ultra-Violence.
+    Hard,
+    /// Nightmare!
+    Nightmare,
+}
+
+impl Skill {
+    /// Get the skill level numbered like in the menu, from 1 to 5.
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Skill::Baby),
+            2 => Some(Skill::Easy),
+            3 => Some(Skill::Medium),
+            4 => Some(Skill::Hard),
+            5 => Some(Skill::Nightmare),
+            _ => None,
+        }
+    }
+
+    /// Flag of [`MapThing::options`] marking the things present
+    /// at this skill level.
+    pub fn thing_flag(self) -> i16 {
+        match self {
+            Skill::Baby | Skill::Easy => MapThing::EASY,
+            Skill::Medium => MapThing::NORMAL,
+            Skill::Hard | Skill::Nightmare => MapThing::HARD,
+        }
+    }
+}
+
+impl FromWorld for Skill {
+    fn from_world(world: &mut World) -> Self {
+        world
+            .get_resource::<CommandLine>()
+            .and_then(|cli| cli.skill)
+            .and_then(Skill::from_number)
+            .unwrap_or(Skill::Medium)
+    }
+}
@@ -0,0 +1,38 @@
+use anyhow::{bail, Result};
+use bevy::prelude::*;
+use wad::prelude::*;
+
+/// Wall texture drawn on the ceilings using the `F_SKY1` flat.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct Sky {
+    pub texture: usize,
+    /// Index of `F_SKY1` in the flats, or `usize::MAX` when the WAD
+    /// has none, so that no sector shows the sky.
+    ///
+    /// Mirrors `skyflatnum`.
+    pub flat: usize,
+}
+
+impl Sky {
+    /// Each episode has its own sky, while Doom II changes it
+    /// every third of the game.
+    pub(crate) fn new(map_id: MapId, wad: &WadFile) -> Result<Self> {
+        let name = match map_id {
+            MapId::Episode { episode: 2, .. } => "SKY2",
+            MapId::Episode { episode: 3, .. } => "SKY3",
+            MapId::Episode { episode: 4, .. } => "SKY4",
+            MapId::Episode { .. } => "SKY1",
+            MapId::Commercial { map: ..12 } => "SKY1",
+            MapId::Commercial { map: ..21 } => "SKY2",
+            MapId::Commercial { .. } => "SKY3",
+        };
+        let Some(texture) = wad.wall_textures().get_index_of(name) else {
+            bail!("WAD file is missing sky texture {name}");
+        };
+        let flat = wad.flats().get_index_of("F_SKY1").unwrap_or_else(|| {
+            warn!("WAD file is missing sky flat F_SKY1");
+            usize::MAX
+        });
+        Ok(Self { texture, flat })
+    }
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use info::{Info, Sfx};
+
+/// Sent to play `sfx`, coming from `origin` when it is set.
+///
+/// This is a stub: there is no sound backend yet, so nothing plays
+/// these events and [`log_sounds`] only logs them.
+///
+/// Mirrors `S_StartSound`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct StartSound {
+    pub origin: Option<Entity>,
+    pub sfx: Sfx,
+}
+
+impl StartSound {
+    pub fn new(origin: impl Into<Option<Entity>>, sfx: Sfx) -> Self {
+        Self {
+            origin: origin.into(),
+            sfx,
+        }
+    }
+}
+
+/// Stands in for the sound backend, logging the sounds it would play.
+pub(crate) fn log_sounds(mut sounds: EventReader<StartSound>, info: Res<Info>) {
+    for sound in sounds.read() {
+        let name = info.sound_name(sound.sfx).to_ascii_uppercase();
+        trace!("Sound DS{name} from {:?}", sound.origin);
+    }
+}
@@ -0,0 +1,577 @@
+use crate::block_map::link_things;
+use crate::components::{Line, Sector, SideDef};
+use crate::map_object::prelude::{MapObject, Player};
+use crate::resources::LevelMap;
+use crate::sound::StartSound;
+use crate::ExitLevel;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use ceiling::{move_ceilings, CeilingMove, CeilingType};
+use door::{move_doors, Door, DoorType};
+use floor::{move_floors, FloorType, StairType};
+use game_state::conditions::in_level_state;
+use info::MobjType;
+use lights::{flash_lights, flicker_fires, glow_lights, strobe_lights};
+use moonshine_kind::Instance;
+use plat::{move_plats, Plat, PlatType};
+use rand::Rand;
+use sector::{find_secrets, player_in_special_sector};
+use switch::{update_buttons, PressedButton, Switches};
+use teleport::{teleport_things, Teleport};
+use wad::prelude::WadFile;
+
+mod ceiling;
+mod door;
+mod floor;
+mod lights;
+mod plane;
+mod plat;
+mod sector;
+mod switch;
+mod teleport;
+
+pub mod prelude {
+    pub use super::{
+        ceiling::{CeilingMove, CeilingType},
+        door::{Door, DoorDirection, DoorType},
+        floor::{FloorMove, FloorType, StairType},
+        lights::{FireFlicker, Glow, LightFlash, StrobeFlash, FAST_DARK, SLOW_DARK},
+        plane::{Direction, MoveResult, Plane, PlaneMover},
+        plat::{Plat, PlatStatus, PlatType},
+        switch::{PressedButton, SwitchPart, Switches},
+        teleport::{Teleport, Teleporter},
+        ActivateLine, Activation, LineSpecials, SpecialsPlugin,
+    };
+}
+
+pub(crate) use sector::spawn_specials;
+
+/// Runs the line and sector specials, and the thinkers moving
+/// the floors and ceilings, flipping the buttons back or
+/// changing the lights.
+pub struct SpecialsPlugin;
+
+impl Plugin for SpecialsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ActivateLine>()
+            .add_event::<Teleport>()
+            .init_resource::<Switches>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    player_in_special_sector,
+                    find_secrets,
+                    activate_lines,
+                    teleport_things,
+                    link_things,
+                    move_doors,
+                    move_plats,
+                    move_floors,
+                    move_ceilings,
+                    update_buttons,
+                    flicker_fires,
+                    flash_lights,
+                    strobe_lights,
+                    glow_lights,
+                )
+                    .chain()
+                    .run_if(in_level_state()),
+            );
+    }
+}
+
+/// How a line special is triggered.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Activation {
+    /// Pressing use in front of the line.
+    Use,
+    /// Walking over the line.
+    Cross,
+    /// Hitting the line with a hitscan attack.
+    Shoot,
+}
+
+/// Sent when `thing` triggers the special of `line`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ActivateLine {
+    pub line: Instance<Line>,
+    pub thing: Entity,
+    /// `true` when `thing` is on the back side of the line.
+    pub back_side: bool,
+    pub activation: Activation,
+}
+
+/// What a line special does to the sectors tagged like the line.
+#[derive(Debug, Clone, Copy)]
+enum Effect {
+    Door(DoorType),
+    LockedDoor(DoorType),
+    /// A lift, and the height raised by [`PlatType::RaiseAndChange`].
+    Plat(PlatType, f32),
+    StopPlat,
+    Floor(FloorType),
+    Stairs(StairType),
+    /// Lowers the floor of the sectors, and raises the ring around them.
+    Donut,
+    Ceiling(CeilingType),
+    CeilingCrushStop,
+    /// Raises the ceiling to the highest around, and tries to lower the
+    /// floor to the lowest around, which fails as the sector is busy.
+    RaiseCeilingLowerFloor,
+    /// Sets the light of the sectors to the given level, or to the
+    /// brightest light around when `None`.
+    LightTurnOn(Option<i16>),
+    StartLightStrobing,
+    TurnTagLightsOff,
+    /// Ends the level, going to the secret level when `secret`.
+    Exit {
+        secret: bool,
+    },
+}
+
+/// Starts the effects of the line specials.
+#[derive(SystemParam)]
+pub struct LineSpecials<'w, 's> {
+    level_map: NonSend<'w, LevelMap>,
+    wad: Res<'w, WadFile>,
+    switches: Res<'w, Switches>,
+    rand: ResMut<'w, Rand>,
+    line_query: Query<'w, 's, &'static mut Line>,
+    side_query: Query<'w, 's, &'static mut SideDef>,
+    sector_query: Query<'w, 's, &'static mut Sector>,
+    thing_query: Query<'w, 's, (&'static MapObject, Option<&'static Player>)>,
+    door_query: Query<'w, 's, &'static mut Door>,
+    plat_query: Query<'w, 's, &'static mut Plat>,
+    ceiling_query: Query<'w, 's, &'static mut CeilingMove>,
+    button_query: Query<'w, 's, &'static PressedButton>,
+    sounds: EventWriter<'w, StartSound>,
+    teleports: EventWriter<'w, Teleport>,
+    exits: EventWriter<'w, ExitLevel>,
+    commands: Commands<'w, 's>,
+}
+
+impl LineSpecials<'_, '_> {
+    /// Called when `thing` uses `line` from its `back_side` or not.
+    /// Returns `false` when the line can't be used.
+    ///
+    /// Mirrors `P_UseSpecialLine`.
+    pub fn use_special_line(
+        &mut self,
+        thing: Entity,
+        line: Instance<Line>,
+        back_side: bool,
+    ) -> bool {
+        let Some((special, flags)) = self.line(line).map(|line| (line.special(), line.flags()))
+        else {
+            return false;
+        };
+        // Lines are only used from the front side.
+        if back_side {
+            return false;
+        }
+        if !self.is_player(thing) {
+            // Never open secret doors.
+            if flags & Line::SECRET != 0 {
+                return false;
+            }
+            // Monsters only open the manual doors.
+            if !matches!(special, 1 | 32 | 33 | 34) {
+                return false;
+            }
+        }
+
+        if matches!(special, 1 | 26 | 27 | 28 | 31 | 32 | 33 | 34 | 117 | 118) {
+            // Manual doors, opening the sector on the back side.
+            self.vertical_door(line, thing);
+        } else if let Some((effect, use_again)) = switch_effect(special) {
+            let activated = self.start_effect(line, thing, effect);
+            if activated {
+                self.change_switch_texture(line, use_again);
+            }
+        }
+        true
+    }
+
+    /// Called when `thing` walks over `line`, from its `back_side` or not.
+    ///
+    /// Mirrors `P_CrossSpecialLine`.
+    pub fn cross_special_line(&mut self, thing: Entity, line: Instance<Line>, back_side: bool) {
+        let Some(special) = self.line(line).map(Line::special) else {
+            return;
+        };
+        let Ok((map_obj, player)) = self.thing_query.get(thing) else {
+            return;
+        };
+        if player.is_none() {
+            // Things that should never trigger lines.
+            if matches!(
+                map_obj.mobj_type,
+                MobjType::MT_ROCKET
+                    | MobjType::MT_PLASMA
+                    | MobjType::MT_BFG
+                    | MobjType::MT_TROOPSHOT
+                    | MobjType::MT_HEADSHOT
+                    | MobjType::MT_BRUISERSHOT
+            ) {
+                return;
+            }
+            // Monsters only trigger the teleporters, some doors and lifts.
+            if !matches!(special, 39 | 97 | 125 | 126 | 4 | 10 | 88) {
+                return;
+            }
+        }
+
+        if let Some((monsters_only, repeatable)) = teleport_effect(special) {
+            if monsters_only && player.is_some() {
+                return;
+            }
+            // Teleporters are left from their back side.
+            if !back_side {
+                self.teleports.send(Teleport { line, thing });
+            }
+            if !repeatable {
+                self.clear_special(line);
+            }
+            return;
+        }
+
+        let Some((effect, repeatable)) = cross_effect(special) else {
+            return;
+        };
+        self.start_effect(line, thing, effect);
+        if !repeatable {
+            self.clear_special(line);
+        }
+    }
+
+    /// Called when `thing` hits `line` with a hitscan attack.
+    ///
+    /// Mirrors `P_ShootSpecialLine`.
+    pub fn shoot_special_line(&mut self, thing: Entity, line: Instance<Line>) {
+        let Some(special) = self.line(line).map(Line::special) else {
+            return;
+        };
+        // Monsters only open the doors.
+        if !self.is_player(thing) && special != 46 {
+            return;
+        }
+
+        let (effect, use_again) = match special {
+            24 => (Effect::Floor(FloorType::RaiseFloor), false),
+            46 => (Effect::Door(DoorType::Open), true),
+            47 => (Effect::Plat(PlatType::RaiseToNearestAndChange, 0.0), false),
+            _ => return,
+        };
+        self.start_effect(line, thing, effect);
+        self.change_switch_texture(line, use_again);
+    }
+
+    /// Returns `true` when some sector started moving.
+    fn start_effect(&mut self, line: Instance<Line>, thing: Entity, effect: Effect) -> bool {
+        match effect {
+            Effect::Door(door_type) => self.do_door(line, door_type),
+            Effect::LockedDoor(door_type) => self.do_locked_door(line, door_type, thing),
+            Effect::Plat(plat_type, amount) => self.do_plat(line, plat_type, amount),
+            Effect::StopPlat => self.stop_plat(line),
+            Effect::Floor(floor_type) => self.do_floor(line, floor_type),
+            Effect::Stairs(stair_type) => self.build_stairs(line, stair_type),
+            Effect::Donut => self.do_donut(line),
+            Effect::Ceiling(ceiling_type) => self.do_ceiling(line, ceiling_type),
+            Effect::CeilingCrushStop => self.ceiling_crush_stop(line),
+            Effect::RaiseCeilingLowerFloor => {
+                let raised = self.do_ceiling(line, CeilingType::RaiseToHighest);
+                let lowered = self.do_floor(line, FloorType::LowerFloorToLowest);
+                raised || lowered
+            }
+            Effect::LightTurnOn(bright) => self.light_turn_on(line, bright),
+            Effect::StartLightStrobing => self.start_light_strobing(line),
+            Effect::TurnTagLightsOff => self.turn_tag_lights_off(line),
+            Effect::Exit { secret } => {
+                self.exits.send(ExitLevel { secret });
+                true
+            }
+        }
+    }
+
+    fn clear_special(&mut self, line: Instance<Line>) {
+        if let Ok(mut line) = self.line_query.get_mut(*line) {
+            line.clear_special();
+        }
+    }
+
+    fn line(&self, line: Instance<Line>) -> Option<&Line> {
+        self.line_query.get(*line).ok()
+    }
+
+    fn sector(&self, sector: Instance<Sector>) -> Option<&Sector> {
+        self.sector_query.get(*sector).ok()
+    }
+
+    fn sector_mut(&mut self, sector: Instance<Sector>) -> Option<Mut<'_, Sector>> {
+        self.sector_query.get_mut(*sector).ok()
+    }
+
+    fn player(&self, thing: Entity) -> Option<&Player> {
+        self.thing_query.get(thing).ok()?.1
+    }
+
+    fn is_player(&self, thing: Entity) -> bool {
+        self.player(thing).is_some()
+    }
+
+    /// Put the thinker `mover` on `sector`, keeping other
+    /// thinkers from moving it until it is done.
+    fn start_mover(&mut self, sector: Instance<Sector>, mover: impl Component) {
+        let mover = self.commands.spawn(mover).id();
+        if let Some(mut sector) = self.sector_mut(sector) {
+            sector.mover = Some(mover);
+        }
+    }
+
+    /// Find the number of the first sector tagged with `tag`,
+    /// after the sector numbered `start`.
+    ///
+    /// Mirrors `P_FindSectorFromLineTag`.
+    fn find_sector_from_tag(&self, tag: i16, start: Option<usize>) -> Option<usize> {
+        let start = start.map_or(0, |start| start + 1);
+        self.level_map
+            .sectors
+            .iter()
+            .enumerate()
+            .skip(start)
+            .find(|(_, sector)| self.sector(**sector).is_some_and(|s| s.tag == tag))
+            .map(|(num, _)| num)
+    }
+
+    /// Sectors tagged like `line`, in the order of the map.
+    fn tagged_sectors(&self, line: Instance<Line>) -> Vec<Instance<Sector>> {
+        let Some(tag) = self.line(line).map(Line::tag) else {
+            return vec![];
+        };
+        self.level_map
+            .sectors
+            .iter()
+            .copied()
+            .filter(|&sector| self.sector(sector).is_some_and(|s| s.tag == tag))
+            .collect()
+    }
+
+    /// Sectors on the other side of the two-sided lines around `sector`.
+    fn surrounding_sectors(&self, sector: Instance<Sector>) -> impl Iterator<Item = &Sector> {
+        let lines = self.sector(sector).map_or(&[][..], |s| &s.lines);
+        lines
+            .iter()
+            .filter_map(move |&line| self.line(line)?.other_sector(sector))
+            .filter_map(|other| self.sector(other))
+    }
+
+    /// Mirrors `P_FindLowestFloorSurrounding`.
+    fn lowest_floor_surrounding(&self, sector: Instance<Sector>) -> f32 {
+        let floor = self.sector(sector).map_or(0.0, |s| s.floor_height);
+        self.surrounding_sectors(sector)
+            .map(|other| other.floor_height)
+            .fold(floor, f32::min)
+    }
+
+    /// Mirrors `P_FindHighestFloorSurrounding`.
+    fn highest_floor_surrounding(&self, sector: Instance<Sector>) -> f32 {
+        self.surrounding_sectors(sector)
+            .map(|other| other.floor_height)
+            .fold(-500.0, f32::max)
+    }
+
+    /// Find the lowest floor around `sector` higher than `height`,
+    /// or `height` when there are none.
+    ///
+    /// Mirrors `P_FindNextHighestFloor`.
+    fn next_highest_floor(&self, sector: Instance<Sector>, height: f32) -> f32 {
+        self.surrounding_sectors(sector)
+            .map(|other| other.floor_height)
+            .filter(|&floor| floor > height)
+            .reduce(f32::min)
+            .unwrap_or(height)
+    }
+
+    /// Mirrors `P_FindLowestCeilingSurrounding`.
+    fn lowest_ceiling_surrounding(&self, sector: Instance<Sector>) -> f32 {
+        self.surrounding_sectors(sector)
+            .map(|other| other.ceiling_height)
+            .fold(f32::MAX, f32::min)
+    }
+
+    /// Mirrors `P_FindHighestCeilingSurrounding`.
+    fn highest_ceiling_surrounding(&self, sector: Instance<Sector>) -> f32 {
+        self.surrounding_sectors(sector)
+            .map(|other| other.ceiling_height)
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Effect of the switches, and whether they can be used again.
+fn switch_effect(special: i16) -> Option<(Effect, bool)> {
+    use Effect::*;
+
+    let effect = match special {
+        // Switches, used once.
+        7 => (Stairs(StairType::Build8), false),
+        9 => (Donut, false),
+        11 => (Exit { secret: false }, false),
+        14 => (Plat(PlatType::RaiseAndChange, 32.0), false),
+        15 => (Plat(PlatType::RaiseAndChange, 24.0), false),
+        18 => (Floor(FloorType::RaiseFloorToNearest), false),
+        20 => (Plat(PlatType::RaiseToNearestAndChange, 0.0), false),
+        21 => (Plat(PlatType::DownWaitUpStay, 0.0), false),
+        23 => (Floor(FloorType::LowerFloorToLowest), false),
+        29 => (Door(DoorType::Normal), false),
+        41 => (Ceiling(CeilingType::LowerToFloor), false),
+        49 => (Ceiling(CeilingType::CrushAndRaise), false),
+        50 => (Door(DoorType::Close), false),
+        51 => (Exit { secret: true }, false),
+        55 => (Floor(FloorType::RaiseFloorCrush), false),
+        71 => (Floor(FloorType::TurboLower), false),
+        101 => (Floor(FloorType::RaiseFloor), false),
+        102 => (Floor(FloorType::LowerFloor), false),
+        103 => (Door(DoorType::Open), false),
+        111 => (Door(DoorType::BlazeRaise), false),
+        112 => (Door(DoorType::BlazeOpen), false),
+        113 => (Door(DoorType::BlazeClose), false),
+        122 => (Plat(PlatType::BlazeDwus, 0.0), false),
+        127 => (Stairs(StairType::Turbo16), false),
+        131 => (Floor(FloorType::RaiseFloorTurbo), false),
+        133 | 135 | 137 => (LockedDoor(DoorType::BlazeOpen), false),
+        140 => (Floor(FloorType::RaiseFloor512), false),
+        // Buttons, used again and again.
+        42 => (Door(DoorType::Close), true),
+        43 => (Ceiling(CeilingType::LowerToFloor), true),
+        45 => (Floor(FloorType::LowerFloor), true),
+        60 => (Floor(FloorType::LowerFloorToLowest), true),
+        61 => (Door(DoorType::Open), true),
+        62 => (Plat(PlatType::DownWaitUpStay, 1.0), true),
+        63 => (Door(DoorType::Normal), true),
+        64 => (Floor(FloorType::RaiseFloor), true),
+        65 => (Floor(FloorType::RaiseFloorCrush), true),
+        66 => (Plat(PlatType::RaiseAndChange, 24.0), true),
+        67 => (Plat(PlatType::RaiseAndChange, 32.0), true),
+        68 => (Plat(PlatType::RaiseToNearestAndChange, 0.0), true),
+        69 => (Floor(FloorType::RaiseFloorToNearest), true),
+        70 => (Floor(FloorType::TurboLower), true),
+        114 => (Door(DoorType::BlazeRaise), true),
+        115 => (Door(DoorType::BlazeOpen), true),
+        116 => (Door(DoorType::BlazeClose), true),
+        123 => (Plat(PlatType::BlazeDwus, 0.0), true),
+        132 => (Floor(FloorType::RaiseFloorTurbo), true),
+        99 | 134 | 136 => (LockedDoor(DoorType::BlazeOpen), true),
+        138 => (LightTurnOn(Some(255)), true),
+        139 => (LightTurnOn(Some(35)), true),
+        _ => return None,
+    };
+    Some(effect)
+}
+
+/// Effect of the lines triggered by walking over them, and
+/// whether they can be triggered again.
+fn cross_effect(special: i16) -> Option<(Effect, bool)> {
+    use Effect::*;
+
+    let effect = match special {
+        // Triggers, once.
+        2 => (Door(DoorType::Open), false),
+        3 => (Door(DoorType::Close), false),
+        4 => (Door(DoorType::Normal), false),
+        5 => (Floor(FloorType::RaiseFloor), false),
+        6 => (Ceiling(CeilingType::FastCrushAndRaise), false),
+        8 => (Stairs(StairType::Build8), false),
+        10 => (Plat(PlatType::DownWaitUpStay, 0.0), false),
+        12 => (LightTurnOn(None), false),
+        13 => (LightTurnOn(Some(255)), false),
+        16 => (Door(DoorType::Close30ThenOpen), false),
+        17 => (StartLightStrobing, false),
+        19 => (Floor(FloorType::LowerFloor), false),
+        22 => (Plat(PlatType::RaiseToNearestAndChange, 0.0), false),
+        25 => (Ceiling(CeilingType::CrushAndRaise), false),
+        30 => (Floor(FloorType::RaiseToTexture), false),
+        35 => (LightTurnOn(Some(35)), false),
+        36 => (Floor(FloorType::TurboLower), false),
+        37 => (Floor(FloorType::LowerAndChange), false),
+        38 => (Floor(FloorType::LowerFloorToLowest), false),
+        40 => (RaiseCeilingLowerFloor, false),
+        44 => (Ceiling(CeilingType::LowerAndCrush), false),
+        53 => (Plat(PlatType::PerpetualRaise, 0.0), false),
+        54 => (StopPlat, false),
+        56 => (Floor(FloorType::RaiseFloorCrush), false),
+        57 => (CeilingCrushStop, false),
+        58 => (Floor(FloorType::RaiseFloor24), false),
+        59 => (Floor(FloorType::RaiseFloor24AndChange), false),
+        100 => (Stairs(StairType::Turbo16), false),
+        104 => (TurnTagLightsOff, false),
+        108 => (Door(DoorType::BlazeRaise), false),
+        109 => (Door(DoorType::BlazeOpen), false),
+        110 => (Door(DoorType::BlazeClose), false),
+        119 => (Floor(FloorType::RaiseFloorToNearest), false),
+        121 => (Plat(PlatType::BlazeDwus, 0.0), false),
+        130 => (Floor(FloorType::RaiseFloorTurbo), false),
+        141 => (Ceiling(CeilingType::SilentCrushAndRaise), false),
+        // Exits, ending the level before they could trigger again.
+        52 => (Exit { secret: false }, true),
+        124 => (Exit { secret: true }, true),
+        // Retriggers.
+        72 => (Ceiling(CeilingType::LowerAndCrush), true),
+        73 => (Ceiling(CeilingType::CrushAndRaise), true),
+        74 => (CeilingCrushStop, true),
+        75 => (Door(DoorType::Close), true),
+        76 => (Door(DoorType::Close30ThenOpen), true),
+        77 => (Ceiling(CeilingType::FastCrushAndRaise), true),
+        79 => (LightTurnOn(Some(35)), true),
+        80 => (LightTurnOn(None), true),
+        81 => (LightTurnOn(Some(255)), true),
+        82 => (Floor(FloorType::LowerFloorToLowest), true),
+        83 => (Floor(FloorType::LowerFloor), true),
+        84 => (Floor(FloorType::LowerAndChange), true),
+        86 => (Door(DoorType::Open), true),
+        87 => (Plat(PlatType::PerpetualRaise, 0.0), true),
+        88 => (Plat(PlatType::DownWaitUpStay, 0.0), true),
+        89 => (StopPlat, true),
+        90 => (Door(DoorType::Normal), true),
+        91 => (Floor(FloorType::RaiseFloor), true),
+        92 => (Floor(FloorType::RaiseFloor24), true),
+        93 => (Floor(FloorType::RaiseFloor24AndChange), true),
+        94 => (Floor(FloorType::RaiseFloorCrush), true),
+        95 => (Plat(PlatType::RaiseToNearestAndChange, 0.0), true),
+        96 => (Floor(FloorType::RaiseToTexture), true),
+        98 => (Floor(FloorType::TurboLower), true),
+        105 => (Door(DoorType::BlazeRaise), true),
+        106 => (Door(DoorType::BlazeOpen), true),
+        107 => (Door(DoorType::BlazeClose), true),
+        120 => (Plat(PlatType::BlazeDwus, 0.0), true),
+        128 => (Floor(FloorType::RaiseFloorToNearest), true),
+        129 => (Floor(FloorType::RaiseFloorTurbo), true),
+        _ => return None,
+    };
+    Some(effect)
+}
+
+/// Whether the teleporters only take monsters, and
+/// whether they can be triggered again.
+fn teleport_effect(special: i16) -> Option<(bool, bool)> {
+    match special {
+        39 => Some((false, false)),
+        97 => Some((false, true)),
+        125 => Some((true, false)),
+        126 => Some((true, true)),
+        _ => None,
+    }
+}
+
+fn activate_lines(mut events: EventReader<ActivateLine>, mut specials: LineSpecials) {
+    for event in events.read() {
+        match event.activation {
+            Activation::Use => {
+                specials.use_special_line(event.thing, event.line, event.back_side);
+            }
+            Activation::Cross => {
+                specials.cross_special_line(event.thing, event.line, event.back_side);
+            }
+            Activation::Shoot => specials.shoot_special_line(event.thing, event.line),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use super::plane::{Direction, MoveResult, Plane, PlaneMover};
+use super::LineSpecials;
+use crate::components::{Line, Sector};
+use bevy::prelude::*;
+use info::Sfx;
+use moonshine_kind::Instance;
+
+const CEIL_SPEED: f32 = 1.0;
+
+/// Mirrors `ceiling_e`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CeilingType {
+    LowerToFloor,
+    RaiseToHighest,
+    /// Lower to 8 units above the floor.
+    LowerAndCrush,
+    /// Crusher going down and up until stopped.
+    CrushAndRaise,
+    FastCrushAndRaise,
+    SilentCrushAndRaise,
+}
+
+/// Thinker moving the ceiling of a sector, e.g. a crusher.
+///
+/// Mirrors `ceiling_t`.
+#[derive(Component, Debug)]
+pub struct CeilingMove {
+    pub ceiling_type: CeilingType,
+    pub sector: Instance<Sector>,
+    pub bottom_height: f32,
+    pub top_height: f32,
+    pub speed: f32,
+    pub crush: bool,
+    pub direction: Direction,
+    /// Stopped by a line special, until activated again.
+    pub in_stasis: bool,
+    pub tag: i16,
+}
+
+impl LineSpecials<'_, '_> {
+    /// Start moving the ceilings of the sectors tagged like `line`.
+    ///
+    /// Mirrors `EV_DoCeiling`.
+    pub fn do_ceiling(&mut self, line: Instance<Line>, ceiling_type: CeilingType) -> bool {
+        let Some(tag) = self.line(line).map(Line::tag) else {
+            return false;
+        };
+
+        // Reactivate the crushers in stasis.
+        if matches!(
+            ceiling_type,
+            CeilingType::FastCrushAndRaise
+                | CeilingType::SilentCrushAndRaise
+                | CeilingType::CrushAndRaise
+        ) {
+            self.activate_in_stasis_ceiling(tag);
+        }
+
+        let mut activated = false;
+        for sector_inst in self.tagged_sectors(line) {
+            let Some(sector) = self.sector(sector_inst) else {
+                continue;
+            };
+            if sector.mover.is_some() {
+                continue;
+            }
+            activated = true;
+
+            let mut ceiling = CeilingMove {
+                ceiling_type,
+                sector: sector_inst,
+                bottom_height: sector.floor_height,
+                top_height: sector.ceiling_height,
+                speed: CEIL_SPEED,
+                crush: false,
+                direction: Direction::Down,
+                in_stasis: false,
+                tag: sector.tag,
+            };
+            match ceiling_type {
+                CeilingType::FastCrushAndRaise => {
+                    ceiling.crush = true;
+                    ceiling.bottom_height += 8.0;
+                    ceiling.speed = CEIL_SPEED * 2.0;
+                }
+                CeilingType::SilentCrushAndRaise | CeilingType::CrushAndRaise => {
+                    ceiling.crush = true;
+                    ceiling.bottom_height += 8.0;
+                }
+                CeilingType::LowerAndCrush => ceiling.bottom_height += 8.0,
+                CeilingType::LowerToFloor => {}
+                CeilingType::RaiseToHighest => {
+                    ceiling.top_height = self.highest_ceiling_surrounding(sector_inst);
+                    ceiling.direction = Direction::Up;
+                }
+            }
+            self.start_mover(sector_inst, ceiling);
+        }
+        activated
+    }
+
+    /// Put in stasis the crushers tagged like `line`.
+    ///
+    /// Mirrors `EV_CeilingCrushStop`.
+    pub fn ceiling_crush_stop(&mut self, line: Instance<Line>) -> bool {
+        let Some(tag) = self.line(line).map(Line::tag) else {
+            return false;
+        };
+        let mut stopped = false;
+        for mut ceiling in &mut self.ceiling_query {
+            if !ceiling.in_stasis && ceiling.tag == tag {
+                ceiling.in_stasis = true;
+                stopped = true;
+            }
+        }
+        stopped
+    }
+
+    /// Mirrors `P_ActivateInStasisCeiling`.
+    fn activate_in_stasis_ceiling(&mut self, tag: i16) {
+        for mut ceiling in &mut self.ceiling_query {
+            if ceiling.in_stasis && ceiling.tag == tag {
+                ceiling.in_stasis = false;
+            }
+        }
+    }
+}
+
+/// Mirrors `T_MoveCeiling`.
+pub(super) fn move_ceilings(mut query: Query<(Entity, &mut CeilingMove)>, mut mover: PlaneMover) {
+    for (entity, mut ceiling) in &mut query {
+        if mover.remove_orphan(entity, ceiling.sector) || ceiling.in_stasis {
+            continue;
+        }
+
+        match ceiling.direction {
+            Direction::Up => {
+                let result = mover.move_plane(
+                    ceiling.sector,
+                    ceiling.speed,
+                    ceiling.top_height,
+                    false,
+                    Plane::Ceiling,
+                    Direction::Up,
+                );
+                if ceiling.ceiling_type != CeilingType::SilentCrushAndRaise {
+                    mover.stone_sound(ceiling.sector);
+                }
+                if result != MoveResult::PastDest {
+                    continue;
+                }
+                match ceiling.ceiling_type {
+                    CeilingType::RaiseToHighest => mover.remove(entity, ceiling.sector),
+                    CeilingType::SilentCrushAndRaise => {
+                        ceiling.direction = Direction::Down;
+                        mover.start_sound(ceiling.sector, Sfx::sfx_pstop);
+                    }
+                    CeilingType::FastCrushAndRaise | CeilingType::CrushAndRaise => {
+                        ceiling.direction = Direction::Down;
+                    }
+                    _ => {}
+                }
+            }
+            Direction::Down => {
+                let result = mover.move_plane(
+                    ceiling.sector,
+                    ceiling.speed,
+                    ceiling.bottom_height,
+                    ceiling.crush,
+                    Plane::Ceiling,
+                    Direction::Down,
+                );
+                if ceiling.ceiling_type != CeilingType::SilentCrushAndRaise {
+                    mover.stone_sound(ceiling.sector);
+                }
+                match (result, ceiling.ceiling_type) {
+                    (MoveResult::PastDest, CeilingType::SilentCrushAndRaise) => {
+                        ceiling.speed = CEIL_SPEED;
+                        ceiling.direction = Direction::Up;
+                        mover.start_sound(ceiling.sector, Sfx::sfx_pstop);
+                    }
+                    (MoveResult::PastDest, CeilingType::CrushAndRaise) => {
+                        ceiling.speed = CEIL_SPEED;
+                        ceiling.direction = Direction::Up;
+                    }
+                    (MoveResult::PastDest, CeilingType::FastCrushAndRaise) => {
+                        ceiling.direction = Direction::Up;
+                    }
+                    (
+                        MoveResult::PastDest,
+                        CeilingType::LowerAndCrush | CeilingType::LowerToFloor,
+                    ) => mover.remove(entity, ceiling.sector),
+                    // Slow down while crushing.
+                    (
+                        MoveResult::Crushed,
+                        CeilingType::SilentCrushAndRaise
+                        | CeilingType::CrushAndRaise
+                        | CeilingType::LowerAndCrush,
+                    ) => ceiling.speed = CEIL_SPEED / 8.0,
+                    _ => {}
+                }
+            }
+        }
+    }
+}
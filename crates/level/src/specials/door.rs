@@ -0,0 +1,375 @@
+use super::plane::{Direction, MoveResult, Plane, PlaneMover};
+use super::LineSpecials;
+use crate::components::{Line, Sector};
+use crate::map_object::prelude::Card;
+use crate::sound::StartSound;
+use bevy::prelude::*;
+use info::Sfx;
+use moonshine_kind::Instance;
+
+const VDOOR_SPEED: f32 = 2.0;
+/// Tics spent open, 150 tics being a bit more than 4 seconds.
+const VDOOR_WAIT: i32 = 150;
+
+const PD_BLUEO: &str = "You need a blue key to activate this object";
+const PD_REDO: &str = "You need a red key to activate this object";
+const PD_YELLOWO: &str = "You need a yellow key to activate this object";
+const PD_BLUEK: &str = "You need a blue key to open this door";
+const PD_REDK: &str = "You need a red key to open this door";
+const PD_YELLOWK: &str = "You need a yellow key to open this door";
+
+/// Mirrors `vldoor_e`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DoorType {
+    /// Opens, waits, then closes.
+    Normal,
+    Close30ThenOpen,
+    Close,
+    Open,
+    RaiseIn5Mins,
+    BlazeRaise,
+    BlazeOpen,
+    BlazeClose,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DoorDirection {
+    Up,
+    Down,
+    /// Waiting at the top, or closed for [`DoorType::Close30ThenOpen`].
+    Waiting,
+    /// Waiting before opening, for [`DoorType::RaiseIn5Mins`].
+    InitialWait,
+}
+
+/// Thinker moving the ceiling of a door sector.
+///
+/// Mirrors `vldoor_t`.
+#[derive(Component, Debug)]
+pub struct Door {
+    pub door_type: DoorType,
+    pub sector: Instance<Sector>,
+    pub top_height: f32,
+    pub speed: f32,
+    pub direction: DoorDirection,
+    /// Tics to wait at the top.
+    pub top_wait: i32,
+    /// Tics left before moving again.
+    pub top_countdown: i32,
+}
+
+impl LineSpecials<'_, '_> {
+    /// Start the doors of the sectors tagged like `line`.
+    ///
+    /// Mirrors `EV_DoDoor`.
+    pub fn do_door(&mut self, line: Instance<Line>, door_type: DoorType) -> bool {
+        let mut activated = false;
+        for sector_inst in self.tagged_sectors(line) {
+            let Some(sector) = self.sector(sector_inst) else {
+                continue;
+            };
+            if sector.mover.is_some() {
+                continue;
+            }
+            activated = true;
+
+            let ceiling_height = sector.ceiling_height;
+            let top_height = self.lowest_ceiling_surrounding(sector_inst) - 4.0;
+            let (top_height, direction, speed) = match door_type {
+                DoorType::BlazeClose => (top_height, DoorDirection::Down, VDOOR_SPEED * 4.0),
+                DoorType::Close => (top_height, DoorDirection::Down, VDOOR_SPEED),
+                DoorType::Close30ThenOpen => (ceiling_height, DoorDirection::Down, VDOOR_SPEED),
+                DoorType::BlazeRaise | DoorType::BlazeOpen => {
+                    (top_height, DoorDirection::Up, VDOOR_SPEED * 4.0)
+                }
+                DoorType::Normal | DoorType::Open | DoorType::RaiseIn5Mins => {
+                    (top_height, DoorDirection::Up, VDOOR_SPEED)
+                }
+            };
+            let sfx = match door_type {
+                DoorType::BlazeClose => Some(Sfx::sfx_bdcls),
+                DoorType::Close | DoorType::Close30ThenOpen => Some(Sfx::sfx_dorcls),
+                DoorType::BlazeRaise | DoorType::BlazeOpen => {
+                    (top_height != ceiling_height).then_some(Sfx::sfx_bdopn)
+                }
+                DoorType::Normal | DoorType::Open => {
+                    (top_height != ceiling_height).then_some(Sfx::sfx_doropn)
+                }
+                DoorType::RaiseIn5Mins => None,
+            };
+            if let Some(sfx) = sfx {
+                self.sounds.send(StartSound::new(*sector_inst, sfx));
+            }
+            self.start_mover(
+                sector_inst,
+                Door {
+                    door_type,
+                    sector: sector_inst,
+                    top_height,
+                    speed,
+                    direction,
+                    top_wait: VDOOR_WAIT,
+                    top_countdown: 0,
+                },
+            );
+        }
+        activated
+    }
+
+    /// Start the doors of the sectors tagged like `line`, if `thing`
+    /// is a player holding the key opening them.
+    ///
+    /// Mirrors `EV_DoLockedDoor`.
+    pub fn do_locked_door(
+        &mut self,
+        line: Instance<Line>,
+        door_type: DoorType,
+        thing: Entity,
+    ) -> bool {
+        let Some(special) = self.line(line).map(Line::special) else {
+            return false;
+        };
+        let lock = match special {
+            99 | 133 => Some((Card::BlueCard, Card::BlueSkull, PD_BLUEO)),
+            134 | 135 => Some((Card::RedCard, Card::RedSkull, PD_REDO)),
+            136 | 137 => Some((Card::YellowCard, Card::YellowSkull, PD_YELLOWO)),
+            _ => None,
+        };
+        if !self.check_lock(thing, lock) {
+            return false;
+        }
+        self.do_door(line, door_type)
+    }
+
+    /// Open the door on the back side of `line`, or reverse the
+    /// one moving there. Used by the manual doors, which aren't tagged.
+    ///
+    /// Mirrors `EV_VerticalDoor`.
+    pub fn vertical_door(&mut self, line_inst: Instance<Line>, thing: Entity) {
+        let Some(line) = self.line(line_inst) else {
+            return;
+        };
+        let special = line.special();
+        let Some(sector_inst) = line.back_sector() else {
+            // Manual doors are always two-sided.
+            return;
+        };
+
+        let lock = match special {
+            26 | 32 => Some((Card::BlueCard, Card::BlueSkull, PD_BLUEK)),
+            27 | 34 => Some((Card::YellowCard, Card::YellowSkull, PD_YELLOWK)),
+            28 | 33 => Some((Card::RedCard, Card::RedSkull, PD_REDK)),
+            _ => None,
+        };
+        if !self.check_lock(thing, lock) {
+            return;
+        }
+
+        // If the sector has an active thinker, use it.
+        let Some(sector) = self.sector(sector_inst) else {
+            return;
+        };
+        if let Some(mover) = sector.mover {
+            // Only for "raise" doors, not "open"s.
+            if matches!(special, 1 | 26 | 27 | 28 | 117) {
+                let is_player = self.is_player(thing);
+                if let Ok(mut door) = self.door_query.get_mut(mover) {
+                    if door.direction == DoorDirection::Down {
+                        // Go back up.
+                        door.direction = DoorDirection::Up;
+                    } else if is_player {
+                        // Start going down immediately. Bad guys never close doors.
+                        door.direction = DoorDirection::Down;
+                    }
+                }
+            }
+            return;
+        }
+
+        let (door_type, speed, sfx) = match special {
+            1 | 26 | 27 | 28 => (DoorType::Normal, VDOOR_SPEED, Sfx::sfx_doropn),
+            31..=34 => (DoorType::Open, VDOOR_SPEED, Sfx::sfx_doropn),
+            117 => (DoorType::BlazeRaise, VDOOR_SPEED * 4.0, Sfx::sfx_bdopn),
+            118 => (DoorType::BlazeOpen, VDOOR_SPEED * 4.0, Sfx::sfx_bdopn),
+            _ => return,
+        };
+        self.sounds.send(StartSound::new(*sector_inst, sfx));
+        if matches!(door_type, DoorType::Open | DoorType::BlazeOpen) {
+            self.clear_special(line_inst);
+        }
+
+        // Find the top of the movement range.
+        let top_height = self.lowest_ceiling_surrounding(sector_inst) - 4.0;
+        self.start_mover(
+            sector_inst,
+            Door {
+                door_type,
+                sector: sector_inst,
+                top_height,
+                speed,
+                direction: DoorDirection::Up,
+                top_wait: VDOOR_WAIT,
+                top_countdown: 0,
+            },
+        );
+    }
+
+    /// Close the door of `sector` after 30 seconds.
+    ///
+    /// Mirrors `P_SpawnDoorCloseIn30`.
+    pub fn spawn_door_close_in_30(&mut self, sector_inst: Instance<Sector>) {
+        let Some(mut sector) = self.sector_mut(sector_inst) else {
+            return;
+        };
+        sector.special = 0;
+        let top_height = sector.ceiling_height;
+        self.start_mover(
+            sector_inst,
+            Door {
+                door_type: DoorType::Normal,
+                sector: sector_inst,
+                top_height,
+                speed: VDOOR_SPEED,
+                direction: DoorDirection::Waiting,
+                top_wait: VDOOR_WAIT,
+                top_countdown: 30 * 35,
+            },
+        );
+    }
+
+    /// Open the door of `sector` after 5 minutes.
+    ///
+    /// Mirrors `P_SpawnDoorRaiseIn5Mins`.
+    pub fn spawn_door_raise_in_5_mins(&mut self, sector_inst: Instance<Sector>) {
+        let Some(mut sector) = self.sector_mut(sector_inst) else {
+            return;
+        };
+        sector.special = 0;
+        let top_height = self.lowest_ceiling_surrounding(sector_inst) - 4.0;
+        self.start_mover(
+            sector_inst,
+            Door {
+                door_type: DoorType::RaiseIn5Mins,
+                sector: sector_inst,
+                top_height,
+                speed: VDOOR_SPEED,
+                direction: DoorDirection::InitialWait,
+                top_wait: VDOOR_WAIT,
+                top_countdown: 5 * 60 * 35,
+            },
+        );
+    }
+
+    /// Check that `thing` can open a door needing `lock`, i.e. the
+    /// two keys of a color and the message shown to players
+    /// without them.
+    fn check_lock(&self, thing: Entity, lock: Option<(Card, Card, &str)>) -> bool {
+        let Some((card, skull, message)) = lock else {
+            return true;
+        };
+        let Some(player) = self.player(thing) else {
+            return false;
+        };
+        if !player.has_key(card, skull) {
+            info!("{message}");
+            return false;
+        }
+        true
+    }
+}
+
+/// Mirrors `T_VerticalDoor`.
+pub(super) fn move_doors(mut query: Query<(Entity, &mut Door)>, mut mover: PlaneMover) {
+    for (entity, mut door) in &mut query {
+        if mover.remove_orphan(entity, door.sector) {
+            continue;
+        }
+
+        match door.direction {
+            DoorDirection::Waiting => {
+                door.top_countdown -= 1;
+                if door.top_countdown == 0 {
+                    match door.door_type {
+                        DoorType::BlazeRaise => {
+                            door.direction = DoorDirection::Down;
+                            mover.start_sound(door.sector, Sfx::sfx_bdcls);
+                        }
+                        DoorType::Normal => {
+                            door.direction = DoorDirection::Down;
+                            mover.start_sound(door.sector, Sfx::sfx_dorcls);
+                        }
+                        DoorType::Close30ThenOpen => {
+                            door.direction = DoorDirection::Up;
+                            mover.start_sound(door.sector, Sfx::sfx_doropn);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            DoorDirection::InitialWait => {
+                door.top_countdown -= 1;
+                if door.top_countdown == 0 && door.door_type == DoorType::RaiseIn5Mins {
+                    door.direction = DoorDirection::Up;
+                    door.door_type = DoorType::Normal;
+                    mover.start_sound(door.sector, Sfx::sfx_doropn);
+                }
+            }
+            DoorDirection::Down => {
+                let Some(floor_height) = mover.with_sector(door.sector, |s| s.floor_height) else {
+                    continue;
+                };
+                let result = mover.move_plane(
+                    door.sector,
+                    door.speed,
+                    floor_height,
+                    false,
+                    Plane::Ceiling,
+                    Direction::Down,
+                );
+                match (result, door.door_type) {
+                    (MoveResult::PastDest, DoorType::BlazeRaise | DoorType::BlazeClose) => {
+                        mover.remove(entity, door.sector);
+                        mover.start_sound(door.sector, Sfx::sfx_bdcls);
+                    }
+                    (MoveResult::PastDest, DoorType::Normal | DoorType::Close) => {
+                        mover.remove(entity, door.sector);
+                    }
+                    (MoveResult::PastDest, DoorType::Close30ThenOpen) => {
+                        door.direction = DoorDirection::Waiting;
+                        door.top_countdown = 35 * 30;
+                    }
+                    // Don't go back up.
+                    (MoveResult::Crushed, DoorType::BlazeClose | DoorType::Close) => {}
+                    (MoveResult::Crushed, _) => {
+                        door.direction = DoorDirection::Up;
+                        mover.start_sound(door.sector, Sfx::sfx_doropn);
+                    }
+                    _ => {}
+                }
+            }
+            DoorDirection::Up => {
+                let result = mover.move_plane(
+                    door.sector,
+                    door.speed,
+                    door.top_height,
+                    false,
+                    Plane::Ceiling,
+                    Direction::Up,
+                );
+                if result != MoveResult::PastDest {
+                    continue;
+                }
+                match door.door_type {
+                    DoorType::BlazeRaise | DoorType::Normal => {
+                        door.direction = DoorDirection::Waiting;
+                        door.top_countdown = door.top_wait;
+                    }
+                    DoorType::Close30ThenOpen | DoorType::BlazeOpen | DoorType::Open => {
+                        mover.remove(entity, door.sector);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
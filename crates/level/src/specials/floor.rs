@@ -0,0 +1,390 @@
+use super::plane::{Direction, MoveResult, Plane, PlaneMover};
+use super::LineSpecials;
+use crate::components::{Line, Sector};
+use bevy::prelude::*;
+use info::Sfx;
+use moonshine_kind::Instance;
+
+const FLOOR_SPEED: f32 = 1.0;
+
+/// Mirrors `floor_e`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FloorType {
+    /// Lower to the highest surrounding floor.
+    LowerFloor,
+    /// Lower to the lowest surrounding floor.
+    LowerFloorToLowest,
+    /// Lower fast to 8 units above the highest surrounding floor.
+    TurboLower,
+    /// Raise to the lowest surrounding ceiling.
+    RaiseFloor,
+    /// Raise to the next highest surrounding floor.
+    RaiseFloorToNearest,
+    /// Raise by the height of the shortest lower texture around.
+    RaiseToTexture,
+    /// Lower to the lowest surrounding floor, then take its texture.
+    LowerAndChange,
+    RaiseFloor24,
+    RaiseFloor24AndChange,
+    /// Raise to 8 units below the lowest surrounding ceiling,
+    /// crushing the things in the way.
+    RaiseFloorCrush,
+    RaiseFloorTurbo,
+    /// Raise the ring of a donut to the floor outside of it, then
+    /// take its texture. Only started by [`LineSpecials::do_donut`].
+    DonutRaise,
+    RaiseFloor512,
+}
+
+/// Mirrors `stair_e`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum StairType {
+    /// Slowly build steps of 8 units.
+    Build8,
+    /// Quickly build steps of 16 units.
+    Turbo16,
+}
+
+/// Thinker moving the floor of a sector.
+///
+/// Mirrors `floormove_t`.
+#[derive(Component, Debug)]
+pub struct FloorMove {
+    /// `None` for the steps of stairs.
+    pub floor_type: Option<FloorType>,
+    pub crush: bool,
+    pub sector: Instance<Sector>,
+    pub direction: Direction,
+    /// Special given to the sector once it stops.
+    pub new_special: i16,
+    /// Floor texture given to the sector once it stops.
+    pub texture: usize,
+    pub floor_dest_height: f32,
+    pub speed: f32,
+}
+
+impl LineSpecials<'_, '_> {
+    /// Start moving the floors of the sectors tagged like `line`.
+    ///
+    /// Mirrors `EV_DoFloor`.
+    pub fn do_floor(&mut self, line_inst: Instance<Line>, floor_type: FloorType) -> bool {
+        let Some(line) = self.line(line_inst) else {
+            return false;
+        };
+        let front_sector = self
+            .sector(line.front_sector())
+            .map(|sector| (sector.floor_tex, sector.special));
+
+        let mut activated = false;
+        for sector_inst in self.tagged_sectors(line_inst) {
+            let Some(sector) = self.sector(sector_inst) else {
+                continue;
+            };
+            if sector.mover.is_some() {
+                continue;
+            }
+            activated = true;
+
+            let floor_height = sector.floor_height;
+            let mut floor = FloorMove {
+                floor_type: Some(floor_type),
+                crush: false,
+                sector: sector_inst,
+                direction: Direction::Up,
+                new_special: sector.special,
+                texture: sector.floor_tex,
+                floor_dest_height: floor_height,
+                speed: FLOOR_SPEED,
+            };
+            match floor_type {
+                FloorType::LowerFloor => {
+                    floor.direction = Direction::Down;
+                    floor.floor_dest_height = self.highest_floor_surrounding(sector_inst);
+                }
+                FloorType::LowerFloorToLowest => {
+                    floor.direction = Direction::Down;
+                    floor.floor_dest_height = self.lowest_floor_surrounding(sector_inst);
+                }
+                FloorType::TurboLower => {
+                    floor.direction = Direction::Down;
+                    floor.speed = FLOOR_SPEED * 4.0;
+                    floor.floor_dest_height = self.highest_floor_surrounding(sector_inst);
+                    if floor.floor_dest_height != floor_height {
+                        floor.floor_dest_height += 8.0;
+                    }
+                }
+                FloorType::RaiseFloor | FloorType::RaiseFloorCrush => {
+                    let ceiling_height = sector.ceiling_height;
+                    floor.floor_dest_height = self
+                        .lowest_ceiling_surrounding(sector_inst)
+                        .min(ceiling_height);
+                    if floor_type == FloorType::RaiseFloorCrush {
+                        floor.crush = true;
+                        floor.floor_dest_height -= 8.0;
+                    }
+                }
+                FloorType::RaiseFloorTurbo => {
+                    floor.speed = FLOOR_SPEED * 4.0;
+                    floor.floor_dest_height = self.next_highest_floor(sector_inst, floor_height);
+                }
+                FloorType::RaiseFloorToNearest => {
+                    floor.floor_dest_height = self.next_highest_floor(sector_inst, floor_height);
+                }
+                FloorType::RaiseFloor24 => floor.floor_dest_height = floor_height + 24.0,
+                FloorType::RaiseFloor512 => floor.floor_dest_height = floor_height + 512.0,
+                FloorType::RaiseFloor24AndChange => {
+                    floor.floor_dest_height = floor_height + 24.0;
+                    if let (Some((floor_tex, special)), Some(mut sector)) =
+                        (front_sector, self.sector_mut(sector_inst))
+                    {
+                        sector.floor_tex = floor_tex;
+                        sector.special = special;
+                    }
+                }
+                FloorType::RaiseToTexture => {
+                    floor.floor_dest_height =
+                        floor_height + self.shortest_lower_texture(sector_inst);
+                }
+                // Like vanilla, which has no case for it.
+                FloorType::DonutRaise => {}
+                FloorType::LowerAndChange => {
+                    floor.direction = Direction::Down;
+                    floor.floor_dest_height = self.lowest_floor_surrounding(sector_inst);
+                    // Take the texture and special of the first sector
+                    // around at the destination height.
+                    let model = self
+                        .surrounding_sectors(sector_inst)
+                        .find(|other| other.floor_height == floor.floor_dest_height);
+                    if let Some(model) = model {
+                        floor.texture = model.floor_tex;
+                        floor.new_special = model.special;
+                    }
+                }
+            }
+            self.start_mover(sector_inst, floor);
+        }
+        activated
+    }
+
+    /// Lower the floors of the sectors tagged like `line`, the holes of
+    /// the donuts, while raising the rings around them. Both stop at the
+    /// floor outside of the ring, whose texture the ring takes.
+    ///
+    /// Mirrors `EV_DoDonut`.
+    pub fn do_donut(&mut self, line: Instance<Line>) -> bool {
+        let mut activated = false;
+        for hole in self.tagged_sectors(line) {
+            let Some(hole_sector) = self.sector(hole) else {
+                continue;
+            };
+            if hole_sector.mover.is_some() {
+                continue;
+            }
+            activated = true;
+            let (hole_special, hole_texture) = (hole_sector.special, hole_sector.floor_tex);
+
+            // The ring is behind the first line of the hole, and the
+            // outside behind the first line of the ring not facing the
+            // hole. Vanilla crashes on one-sided lines instead.
+            let ring = hole_sector
+                .lines
+                .first()
+                .and_then(|&line| self.line(line)?.other_sector(hole));
+            let Some(ring) = ring else {
+                continue;
+            };
+            // Unlike vanilla, a ring already moving is left alone.
+            if self.sector(ring).and_then(|s| s.mover).is_some() {
+                continue;
+            }
+            let outside = self
+                .sector(ring)
+                .map_or(&[][..], |s| &s.lines)
+                .iter()
+                .filter_map(|&line| self.line(line)?.back_sector())
+                .find(|&back| back != hole)
+                .and_then(|outside| self.sector(outside))
+                .map(|outside| (outside.floor_height, outside.floor_tex));
+            let Some((height, texture)) = outside else {
+                continue;
+            };
+
+            self.start_mover(
+                ring,
+                FloorMove {
+                    floor_type: Some(FloorType::DonutRaise),
+                    crush: false,
+                    sector: ring,
+                    direction: Direction::Up,
+                    new_special: 0,
+                    texture,
+                    floor_dest_height: height,
+                    speed: FLOOR_SPEED / 2.0,
+                },
+            );
+            self.start_mover(
+                hole,
+                FloorMove {
+                    floor_type: Some(FloorType::LowerFloor),
+                    crush: false,
+                    sector: hole,
+                    direction: Direction::Down,
+                    new_special: hole_special,
+                    texture: hole_texture,
+                    floor_dest_height: height,
+                    speed: FLOOR_SPEED / 2.0,
+                },
+            );
+        }
+        activated
+    }
+
+    /// Raise the floors of the sectors tagged like `line`, and of the
+    /// sectors following them with the same floor texture, into stairs.
+    ///
+    /// Mirrors `EV_BuildStairs`.
+    pub fn build_stairs(&mut self, line: Instance<Line>, stair_type: StairType) -> bool {
+        let Some(tag) = self.line(line).map(Line::tag) else {
+            return false;
+        };
+        let (speed, stair_size) = match stair_type {
+            StairType::Build8 => (FLOOR_SPEED / 4.0, 8.0),
+            StairType::Turbo16 => (FLOOR_SPEED * 4.0, 16.0),
+        };
+
+        let mut activated = false;
+        let mut sector_num = None;
+        while let Some(num) = self.find_sector_from_tag(tag, sector_num) {
+            sector_num = Some(num);
+            let mut sector_inst = self.level_map.sectors[num];
+            let Some(sector) = self.sector(sector_inst) else {
+                continue;
+            };
+            if sector.mover.is_some() {
+                continue;
+            }
+            activated = true;
+
+            let texture = sector.floor_tex;
+            let mut height = sector.floor_height + stair_size;
+            self.start_stair(sector_inst, speed, height);
+
+            // Find the next sector to raise: the first one behind a
+            // line facing away from the current step, with the same
+            // floor texture.
+            'steps: loop {
+                let lines = self
+                    .sector(sector_inst)
+                    .map(|s| s.lines.clone())
+                    .unwrap_or_default();
+                for line in lines {
+                    let Some(line) = self.line(line) else {
+                        continue;
+                    };
+                    let Some(back_sector) = line.back_sector() else {
+                        continue;
+                    };
+                    if line.front_sector() != sector_inst {
+                        continue;
+                    }
+                    let Some(back) = self.sector(back_sector) else {
+                        continue;
+                    };
+                    if back.floor_tex != texture {
+                        continue;
+                    }
+                    height += stair_size;
+                    if back.mover.is_some() {
+                        continue;
+                    }
+                    sector_inst = back_sector;
+                    self.start_stair(sector_inst, speed, height);
+                    continue 'steps;
+                }
+                break;
+            }
+
+            // The search for the tagged sectors continues after the last step.
+            sector_num = self
+                .level_map
+                .sectors
+                .iter()
+                .position(|&s| s == sector_inst);
+        }
+        activated
+    }
+
+    fn start_stair(&mut self, sector: Instance<Sector>, speed: f32, height: f32) {
+        let Some((special, texture)) = self.sector(sector).map(|s| (s.special, s.floor_tex)) else {
+            return;
+        };
+        self.start_mover(
+            sector,
+            FloorMove {
+                floor_type: None,
+                crush: false,
+                sector,
+                direction: Direction::Up,
+                new_special: special,
+                texture,
+                floor_dest_height: height,
+                speed,
+            },
+        );
+    }
+
+    /// Height of the shortest lower texture on the two-sided lines
+    /// around `sector`.
+    fn shortest_lower_texture(&self, sector: Instance<Sector>) -> f32 {
+        let textures = self.wad.wall_textures();
+        let Some(sector) = self.sector(sector) else {
+            return 0.0;
+        };
+        sector
+            .lines
+            .iter()
+            .filter_map(|&line| self.line(line))
+            .filter_map(|line| Some([line.front_side(), line.back_side()?]))
+            .flatten()
+            .filter_map(|side| self.side_query.get(*side).ok())
+            .filter_map(|side| textures.get_by_index(side.lower_texture))
+            .map(|texture| texture.height() as f32)
+            .reduce(f32::min)
+            .unwrap_or(0.0)
+    }
+}
+
+/// Mirrors `T_MoveFloor`.
+pub(super) fn move_floors(mut query: Query<(Entity, &mut FloorMove)>, mut mover: PlaneMover) {
+    for (entity, floor) in &mut query {
+        if mover.remove_orphan(entity, floor.sector) {
+            continue;
+        }
+
+        let result = mover.move_plane(
+            floor.sector,
+            floor.speed,
+            floor.floor_dest_height,
+            floor.crush,
+            Plane::Floor,
+            floor.direction,
+        );
+        mover.stone_sound(floor.sector);
+        if result != MoveResult::PastDest {
+            continue;
+        }
+        mover.start_sound(floor.sector, Sfx::sfx_pstop);
+
+        let changes = match floor.direction {
+            Direction::Up => floor.floor_type == Some(FloorType::DonutRaise),
+            Direction::Down => floor.floor_type == Some(FloorType::LowerAndChange),
+        };
+        if changes {
+            mover.with_sector(floor.sector, |sector| {
+                sector.special = floor.new_special;
+                sector.floor_tex = floor.texture;
+            });
+        }
+        mover.remove(entity, floor.sector);
+    }
+}
@@ -0,0 +1,319 @@
+use super::plane::Direction;
+use super::LineSpecials;
+use crate::components::{Line, Sector};
+use bevy::prelude::*;
+use moonshine_kind::Instance;
+use rand::{Rand, RngCore};
+
+/// Tics spent dark by the fast strobes.
+pub const FAST_DARK: i32 = 15;
+/// Tics spent dark by the slow strobes.
+pub const SLOW_DARK: i32 = 35;
+/// Tics spent bright by the strobes.
+const STROBE_BRIGHT: i32 = 5;
+const GLOW_SPEED: i16 = 8;
+
+/// Light flickering like a fire.
+///
+/// Mirrors `fireflicker_t`.
+#[derive(Component, Debug)]
+pub struct FireFlicker {
+    pub sector: Instance<Sector>,
+    pub count: i32,
+    pub max_light: i16,
+    pub min_light: i16,
+}
+
+/// Light going off for short random times.
+///
+/// Mirrors `lightflash_t`.
+#[derive(Component, Debug)]
+pub struct LightFlash {
+    pub sector: Instance<Sector>,
+    pub count: i32,
+    pub max_light: i16,
+    pub min_light: i16,
+    /// Mask of the random tics spent bright.
+    pub max_time: i32,
+    /// Mask of the random tics spent dark.
+    pub min_time: i32,
+}
+
+/// Light blinking at a regular pace.
+///
+/// Mirrors `strobe_t`.
+#[derive(Component, Debug)]
+pub struct StrobeFlash {
+    pub sector: Instance<Sector>,
+    pub count: i32,
+    pub min_light: i16,
+    pub max_light: i16,
+    pub dark_time: i32,
+    pub bright_time: i32,
+}
+
+/// Light fading down and up again.
+///
+/// Mirrors `glow_t`.
+#[derive(Component, Debug)]
+pub struct Glow {
+    pub sector: Instance<Sector>,
+    pub min_light: i16,
+    pub max_light: i16,
+    pub direction: Direction,
+}
+
+impl LineSpecials<'_, '_> {
+    /// Mirrors `P_SpawnFireFlicker`.
+    pub fn spawn_fire_flicker(&mut self, sector: Instance<Sector>) {
+        let Some(light) = self.take_light_special(sector) else {
+            return;
+        };
+        let min_light = self.min_surrounding_light(sector, light) + 16;
+        self.commands.spawn(FireFlicker {
+            sector,
+            count: 4,
+            max_light: light,
+            min_light,
+        });
+    }
+
+    /// Mirrors `P_SpawnLightFlash`.
+    pub fn spawn_light_flash(&mut self, sector: Instance<Sector>) {
+        let Some(light) = self.take_light_special(sector) else {
+            return;
+        };
+        let min_light = self.min_surrounding_light(sector, light);
+        let max_time = 64;
+        let count = (self.rand.next_u32() as i32 & max_time) + 1;
+        self.commands.spawn(LightFlash {
+            sector,
+            count,
+            max_light: light,
+            min_light,
+            max_time,
+            min_time: 7,
+        });
+    }
+
+    /// Start a strobe, staying dark for `dark_time` tics. Strobes
+    /// `in_sync` all blink together.
+    ///
+    /// Mirrors `P_SpawnStrobeFlash`.
+    pub fn spawn_strobe_flash(&mut self, sector: Instance<Sector>, dark_time: i32, in_sync: bool) {
+        let Some(light) = self.take_light_special(sector) else {
+            return;
+        };
+        let mut min_light = self.min_surrounding_light(sector, light);
+        if min_light == light {
+            min_light = 0;
+        }
+        let count = if in_sync {
+            1
+        } else {
+            (self.rand.next_u32() as i32 & 7) + 1
+        };
+        self.commands.spawn(StrobeFlash {
+            sector,
+            count,
+            min_light,
+            max_light: light,
+            dark_time,
+            bright_time: STROBE_BRIGHT,
+        });
+    }
+
+    /// Mirrors `P_SpawnGlowingLight`.
+    pub fn spawn_glowing_light(&mut self, sector: Instance<Sector>) {
+        let Some(light) = self.take_light_special(sector) else {
+            return;
+        };
+        let min_light = self.min_surrounding_light(sector, light);
+        self.commands.spawn(Glow {
+            sector,
+            min_light,
+            max_light: light,
+            direction: Direction::Down,
+        });
+    }
+
+    /// Set the light of the sectors tagged like `line` to `bright`, or
+    /// when `None` to the brightest light around the first of them.
+    ///
+    /// Mirrors `EV_LightTurnOn`.
+    pub fn light_turn_on(&mut self, line: Instance<Line>, mut bright: Option<i16>) -> bool {
+        for sector in self.tagged_sectors(line) {
+            let light = *bright.get_or_insert_with(|| self.max_surrounding_light(sector));
+            if let Some(mut sector) = self.sector_mut(sector) {
+                sector.light_level = light;
+            }
+        }
+        true
+    }
+
+    /// Start slow strobes in the sectors tagged like `line`.
+    ///
+    /// Mirrors `EV_StartLightStrobing`.
+    pub fn start_light_strobing(&mut self, line: Instance<Line>) -> bool {
+        for sector in self.tagged_sectors(line) {
+            if self.sector(sector).is_some_and(|s| s.mover.is_some()) {
+                continue;
+            }
+            self.spawn_strobe_flash(sector, SLOW_DARK, false);
+        }
+        true
+    }
+
+    /// Set the light of the sectors tagged like `line` to the
+    /// darkest light around them.
+    ///
+    /// Mirrors `EV_TurnTagLightsOff`.
+    pub fn turn_tag_lights_off(&mut self, line: Instance<Line>) -> bool {
+        for sector_inst in self.tagged_sectors(line) {
+            let Some(light) = self.sector(sector_inst).map(|s| s.light_level) else {
+                continue;
+            };
+            let min_light = self.min_surrounding_light(sector_inst, light);
+            if let Some(mut sector) = self.sector_mut(sector_inst) {
+                sector.light_level = min_light;
+            }
+        }
+        true
+    }
+
+    /// Clear the special of `sector`, now run by a light thinker,
+    /// and get its light level.
+    fn take_light_special(&mut self, sector: Instance<Sector>) -> Option<i16> {
+        let mut sector = self.sector_mut(sector)?;
+        sector.special = 0;
+        Some(sector.light_level)
+    }
+
+    /// Find the darkest sector around `sector`, no brighter than `max`.
+    ///
+    /// Mirrors `P_FindMinSurroundingLight`.
+    fn min_surrounding_light(&self, sector: Instance<Sector>, max: i16) -> i16 {
+        self.surrounding_sectors(sector)
+            .map(|other| other.light_level)
+            .fold(max, i16::min)
+    }
+
+    /// Find the brightest sector around `sector`.
+    fn max_surrounding_light(&self, sector: Instance<Sector>) -> i16 {
+        self.surrounding_sectors(sector)
+            .map(|other| other.light_level)
+            .fold(0, i16::max)
+    }
+}
+
+/// Mirrors `T_FireFlicker`.
+pub(super) fn flicker_fires(
+    mut query: Query<(Entity, &mut FireFlicker)>,
+    mut sector_query: Query<&mut Sector>,
+    mut rand: ResMut<Rand>,
+    mut commands: Commands,
+) {
+    for (entity, mut flicker) in &mut query {
+        let Ok(mut sector) = sector_query.get_mut(*flicker.sector) else {
+            // The sector is gone with the previous level.
+            commands.entity(entity).despawn();
+            continue;
+        };
+        flicker.count -= 1;
+        if flicker.count != 0 {
+            continue;
+        }
+
+        let amount = (rand.next_u32() & 3) as i16 * 16;
+        sector.light_level = if sector.light_level - amount < flicker.min_light {
+            flicker.min_light
+        } else {
+            flicker.max_light - amount
+        };
+        flicker.count = 4;
+    }
+}
+
+/// Mirrors `T_LightFlash`.
+pub(super) fn flash_lights(
+    mut query: Query<(Entity, &mut LightFlash)>,
+    mut sector_query: Query<&mut Sector>,
+    mut rand: ResMut<Rand>,
+    mut commands: Commands,
+) {
+    for (entity, mut flash) in &mut query {
+        let Ok(mut sector) = sector_query.get_mut(*flash.sector) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        flash.count -= 1;
+        if flash.count != 0 {
+            continue;
+        }
+
+        if sector.light_level == flash.max_light {
+            sector.light_level = flash.min_light;
+            flash.count = (rand.next_u32() as i32 & flash.min_time) + 1;
+        } else {
+            sector.light_level = flash.max_light;
+            flash.count = (rand.next_u32() as i32 & flash.max_time) + 1;
+        }
+    }
+}
+
+/// Mirrors `T_StrobeFlash`.
+pub(super) fn strobe_lights(
+    mut query: Query<(Entity, &mut StrobeFlash)>,
+    mut sector_query: Query<&mut Sector>,
+    mut commands: Commands,
+) {
+    for (entity, mut strobe) in &mut query {
+        let Ok(mut sector) = sector_query.get_mut(*strobe.sector) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        strobe.count -= 1;
+        if strobe.count != 0 {
+            continue;
+        }
+
+        if sector.light_level == strobe.min_light {
+            sector.light_level = strobe.max_light;
+            strobe.count = strobe.bright_time;
+        } else {
+            sector.light_level = strobe.min_light;
+            strobe.count = strobe.dark_time;
+        }
+    }
+}
+
+/// Mirrors `T_Glow`.
+pub(super) fn glow_lights(
+    mut query: Query<(Entity, &mut Glow)>,
+    mut sector_query: Query<&mut Sector>,
+    mut commands: Commands,
+) {
+    for (entity, mut glow) in &mut query {
+        let Ok(mut sector) = sector_query.get_mut(*glow.sector) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        match glow.direction {
+            Direction::Down => {
+                sector.light_level -= GLOW_SPEED;
+                if sector.light_level <= glow.min_light {
+                    sector.light_level += GLOW_SPEED;
+                    glow.direction = Direction::Up;
+                }
+            }
+            Direction::Up => {
+                sector.light_level += GLOW_SPEED;
+                if sector.light_level >= glow.max_light {
+                    sector.light_level -= GLOW_SPEED;
+                    glow.direction = Direction::Down;
+                }
+            }
+        }
+    }
+}
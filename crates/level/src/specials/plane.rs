@@ -0,0 +1,208 @@
+use crate::components::Sector;
+use crate::map_object::prelude::{Collision, Damage, MapObject};
+use crate::sound::StartSound;
+use crate::LevelTime;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use info::{MobjFlags, Sfx, StateNum};
+use moonshine_kind::Instance;
+
+/// Damage dealt by crushers every 4 tics.
+const CRUSH_DAMAGE: i32 = 10;
+
+/// Plane of a sector moved by a thinker.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Plane {
+    Floor,
+    Ceiling,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Direction {
+    Down,
+    Up,
+}
+
+/// Outcome of a step of a moving plane.
+///
+/// Mirrors `result_e`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MoveResult {
+    Ok,
+    /// Things are in the way.
+    Crushed,
+    /// The plane reached its destination.
+    PastDest,
+}
+
+/// Moves the floors and ceilings of sectors, and the things in them.
+#[derive(SystemParam)]
+pub struct PlaneMover<'w, 's> {
+    sectors: ParamSet<'w, 's, (Query<'w, 's, &'static mut Sector>, Collision<'w, 's>)>,
+    thing_query: Query<'w, 's, (Entity, &'static mut MapObject)>,
+    damage: Damage<'w, 's>,
+    level_time: Res<'w, LevelTime>,
+    sounds: EventWriter<'w, StartSound>,
+    commands: Commands<'w, 's>,
+}
+
+impl PlaneMover<'_, '_> {
+    /// Run `f` on `sector`, if it still exists.
+    pub fn with_sector<R>(
+        &mut self,
+        sector: Instance<Sector>,
+        f: impl FnOnce(&mut Sector) -> R,
+    ) -> Option<R> {
+        let mut sector_query = self.sectors.p0();
+        let mut sector = sector_query.get_mut(*sector).ok()?;
+        Some(f(&mut sector))
+    }
+
+    /// Remove the thinker `mover` of `sector`, once it is done.
+    pub fn remove(&mut self, mover: Entity, sector: Instance<Sector>) {
+        self.with_sector(sector, |sector| sector.mover = None);
+        self.commands.entity(mover).despawn();
+    }
+
+    /// Play `sfx` from `sector`.
+    pub fn start_sound(&mut self, sector: Instance<Sector>, sfx: Sfx) {
+        self.sounds.send(StartSound::new(*sector, sfx));
+    }
+
+    /// Play the sound of grinding stone from `sector` every 8 tics,
+    /// while one of its planes moves.
+    pub fn stone_sound(&mut self, sector: Instance<Sector>) {
+        if **self.level_time & 7 == 0 {
+            self.start_sound(sector, Sfx::sfx_stnmov);
+        }
+    }
+
+    /// Remove `mover` when its sector is gone with the previous
+    /// level. Returns `true` when it is removed.
+    pub fn remove_orphan(&mut self, mover: Entity, sector: Instance<Sector>) -> bool {
+        if self.sectors.p0().contains(*sector) {
+            return false;
+        }
+        self.commands.entity(mover).despawn();
+        true
+    }
+
+    /// Move `plane` of `sector` by `speed` toward `dest`. Things in
+    /// the way stop it, unless it `crush`es them.
+    ///
+    /// Mirrors `T_MovePlane`.
+    pub fn move_plane(
+        &mut self,
+        sector: Instance<Sector>,
+        speed: f32,
+        dest: f32,
+        crush: bool,
+        plane: Plane,
+        direction: Direction,
+    ) -> MoveResult {
+        let Some(last_pos) = self.height(sector, plane) else {
+            return MoveResult::Ok;
+        };
+        let past_dest = match direction {
+            Direction::Down => last_pos - speed < dest,
+            Direction::Up => last_pos + speed > dest,
+        };
+        let height = if past_dest {
+            dest
+        } else {
+            match direction {
+                Direction::Down => last_pos - speed,
+                Direction::Up => last_pos + speed,
+            }
+        };
+
+        self.set_height(sector, plane, height);
+        let no_fit = self.change_sector(sector, crush);
+        if past_dest {
+            if no_fit {
+                self.set_height(sector, plane, last_pos);
+                self.change_sector(sector, crush);
+            }
+            return MoveResult::PastDest;
+        }
+        if !no_fit {
+            return MoveResult::Ok;
+        }
+
+        match (plane, direction) {
+            // A rising ceiling never gets stuck.
+            (Plane::Ceiling, Direction::Up) => MoveResult::Ok,
+            // Crushers keep going through the things in the way.
+            (Plane::Floor, Direction::Up) | (Plane::Ceiling, Direction::Down) if crush => {
+                MoveResult::Crushed
+            }
+            _ => {
+                self.set_height(sector, plane, last_pos);
+                self.change_sector(sector, crush);
+                MoveResult::Crushed
+            }
+        }
+    }
+
+    fn height(&mut self, sector: Instance<Sector>, plane: Plane) -> Option<f32> {
+        self.with_sector(sector, |sector| match plane {
+            Plane::Floor => sector.floor_height,
+            Plane::Ceiling => sector.ceiling_height,
+        })
+    }
+
+    fn set_height(&mut self, sector: Instance<Sector>, plane: Plane, height: f32) {
+        self.with_sector(sector, |sector| match plane {
+            Plane::Floor => sector.floor_height = height,
+            Plane::Ceiling => sector.ceiling_height = height,
+        });
+    }
+
+    /// Fit the things touching `sector` between its new floor and
+    /// ceiling heights. Returns `true` when some don't fit, in
+    /// which case they are hurt if `crush`.
+    ///
+    /// Mirrors `P_ChangeSector`.
+    fn change_sector(&mut self, sector: Instance<Sector>, crush: bool) -> bool {
+        let Some(bbox) = self.with_sector(sector, |sector| sector.bbox) else {
+            return false;
+        };
+        let collision = self.sectors.p1();
+        let mut no_fit = false;
+        for (entity, mut thing) in &mut self.thing_query {
+            if thing.flags.contains(MobjFlags::NOBLOCKMAP)
+                || !bbox.inflate(thing.radius).contains(thing.pos)
+            {
+                continue;
+            }
+
+            // Mirrors `PIT_ChangeSector`.
+            if collision.height_clip(&mut thing) {
+                continue;
+            }
+            if thing.health <= 0 {
+                // Crunch bodies to giblets.
+                self.damage.set_state(entity, StateNum::S_GIBS);
+                thing.flags.remove(MobjFlags::SOLID);
+                thing.height = 0.0;
+                thing.radius = 0.0;
+                continue;
+            }
+            if thing.flags.contains(MobjFlags::DROPPED) {
+                // Crunch dropped items.
+                self.commands.entity(entity).despawn();
+                continue;
+            }
+            if !thing.flags.contains(MobjFlags::SHOOTABLE) {
+                // Assume it is bloody gibs or something.
+                continue;
+            }
+
+            no_fit = true;
+            if crush && **self.level_time & 3 == 0 {
+                self.damage.damage(entity, &mut thing, CRUSH_DAMAGE);
+            }
+        }
+        no_fit
+    }
+}
@@ -0,0 +1,241 @@
+use super::plane::{Direction, MoveResult, Plane, PlaneMover};
+use super::LineSpecials;
+use crate::components::{Line, Sector};
+use crate::sound::StartSound;
+use bevy::prelude::*;
+use info::Sfx;
+use moonshine_kind::Instance;
+use rand::RngCore;
+
+const PLAT_SPEED: f32 = 1.0;
+/// Tics spent at the bottom, i.e. 3 seconds.
+const PLAT_WAIT: i32 = 3 * 35;
+
+/// Mirrors `plattype_e`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PlatType {
+    /// Goes up and down until stopped.
+    PerpetualRaise,
+    /// Lift going down, waiting, then back up.
+    DownWaitUpStay,
+    RaiseAndChange,
+    RaiseToNearestAndChange,
+    BlazeDwus,
+}
+
+/// Mirrors `plat_e`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PlatStatus {
+    Up,
+    Down,
+    Waiting,
+    /// Stopped by a line special, until activated again.
+    InStasis,
+}
+
+/// Thinker moving the floor of a lift.
+///
+/// Mirrors `plat_t`.
+#[derive(Component, Debug)]
+pub struct Plat {
+    pub plat_type: PlatType,
+    pub sector: Instance<Sector>,
+    pub speed: f32,
+    pub low: f32,
+    pub high: f32,
+    /// Tics to wait at the bottom or at the top.
+    pub wait: i32,
+    /// Tics left waiting.
+    pub count: i32,
+    pub status: PlatStatus,
+    /// Status before being put in stasis.
+    pub old_status: PlatStatus,
+    pub crush: bool,
+    pub tag: i16,
+}
+
+impl LineSpecials<'_, '_> {
+    /// Start the lifts of the sectors tagged like `line`. `amount`
+    /// is the height raised by [`PlatType::RaiseAndChange`].
+    ///
+    /// Mirrors `EV_DoPlat`.
+    pub fn do_plat(&mut self, line_inst: Instance<Line>, plat_type: PlatType, amount: f32) -> bool {
+        let Some(line) = self.line(line_inst) else {
+            return false;
+        };
+        let tag = line.tag();
+        let front_floor_tex = self.sector(line.front_sector()).map(|s| s.floor_tex);
+
+        // Activate all the plats that are in stasis.
+        if plat_type == PlatType::PerpetualRaise {
+            self.activate_in_stasis(tag);
+        }
+
+        let mut activated = false;
+        for sector_inst in self.tagged_sectors(line_inst) {
+            let Some(sector) = self.sector(sector_inst) else {
+                continue;
+            };
+            if sector.mover.is_some() {
+                continue;
+            }
+            activated = true;
+
+            let floor_height = sector.floor_height;
+            let mut plat = Plat {
+                plat_type,
+                sector: sector_inst,
+                speed: PLAT_SPEED,
+                low: floor_height,
+                high: floor_height,
+                wait: 0,
+                count: 0,
+                status: PlatStatus::Up,
+                old_status: PlatStatus::Up,
+                crush: false,
+                tag,
+            };
+            match plat_type {
+                PlatType::RaiseToNearestAndChange => {
+                    plat.speed = PLAT_SPEED / 2.0;
+                    plat.high = self.next_highest_floor(sector_inst, floor_height);
+                    if let Some(mut sector) = self.sector_mut(sector_inst) {
+                        sector.floor_tex = front_floor_tex.unwrap_or(sector.floor_tex);
+                        // No more damage, if applicable.
+                        sector.special = 0;
+                    }
+                }
+                PlatType::RaiseAndChange => {
+                    plat.speed = PLAT_SPEED / 2.0;
+                    plat.high = floor_height + amount;
+                    if let Some(mut sector) = self.sector_mut(sector_inst) {
+                        sector.floor_tex = front_floor_tex.unwrap_or(sector.floor_tex);
+                    }
+                }
+                PlatType::DownWaitUpStay | PlatType::BlazeDwus => {
+                    plat.speed = if plat_type == PlatType::BlazeDwus {
+                        PLAT_SPEED * 8.0
+                    } else {
+                        PLAT_SPEED * 4.0
+                    };
+                    plat.low = self.lowest_floor_surrounding(sector_inst).min(floor_height);
+                    plat.wait = PLAT_WAIT;
+                    plat.status = PlatStatus::Down;
+                }
+                PlatType::PerpetualRaise => {
+                    plat.speed = PLAT_SPEED * 4.0;
+                    plat.low = self.lowest_floor_surrounding(sector_inst).min(floor_height);
+                    plat.high = self
+                        .highest_floor_surrounding(sector_inst)
+                        .max(floor_height);
+                    plat.wait = PLAT_WAIT;
+                    plat.status = if self.rand.next_u32() & 1 == 0 {
+                        PlatStatus::Up
+                    } else {
+                        PlatStatus::Down
+                    };
+                }
+            }
+            let sfx = match plat_type {
+                PlatType::RaiseAndChange | PlatType::RaiseToNearestAndChange => Sfx::sfx_stnmov,
+                _ => Sfx::sfx_pstart,
+            };
+            self.sounds.send(StartSound::new(*sector_inst, sfx));
+            self.start_mover(sector_inst, plat);
+        }
+        activated
+    }
+
+    /// Put in stasis the lifts tagged like `line`.
+    ///
+    /// Mirrors `EV_StopPlat`.
+    pub fn stop_plat(&mut self, line: Instance<Line>) -> bool {
+        let Some(tag) = self.line(line).map(Line::tag) else {
+            return false;
+        };
+        for mut plat in &mut self.plat_query {
+            if plat.status != PlatStatus::InStasis && plat.tag == tag {
+                plat.old_status = plat.status;
+                plat.status = PlatStatus::InStasis;
+            }
+        }
+        true
+    }
+
+    /// Mirrors `P_ActivateInStasis`.
+    fn activate_in_stasis(&mut self, tag: i16) {
+        for mut plat in &mut self.plat_query {
+            if plat.status == PlatStatus::InStasis && plat.tag == tag {
+                plat.status = plat.old_status;
+            }
+        }
+    }
+}
+
+/// Mirrors `T_PlatRaise`.
+pub(super) fn move_plats(mut query: Query<(Entity, &mut Plat)>, mut mover: PlaneMover) {
+    for (entity, mut plat) in &mut query {
+        if mover.remove_orphan(entity, plat.sector) {
+            continue;
+        }
+
+        match plat.status {
+            PlatStatus::Up => {
+                let result = mover.move_plane(
+                    plat.sector,
+                    plat.speed,
+                    plat.high,
+                    plat.crush,
+                    Plane::Floor,
+                    Direction::Up,
+                );
+                if matches!(
+                    plat.plat_type,
+                    PlatType::RaiseAndChange | PlatType::RaiseToNearestAndChange
+                ) {
+                    mover.stone_sound(plat.sector);
+                }
+                if result == MoveResult::Crushed && !plat.crush {
+                    plat.count = plat.wait;
+                    plat.status = PlatStatus::Down;
+                    mover.start_sound(plat.sector, Sfx::sfx_pstart);
+                } else if result == MoveResult::PastDest {
+                    plat.count = plat.wait;
+                    plat.status = PlatStatus::Waiting;
+                    mover.start_sound(plat.sector, Sfx::sfx_pstop);
+                    if plat.plat_type != PlatType::PerpetualRaise {
+                        mover.remove(entity, plat.sector);
+                    }
+                }
+            }
+            PlatStatus::Down => {
+                let result = mover.move_plane(
+                    plat.sector,
+                    plat.speed,
+                    plat.low,
+                    false,
+                    Plane::Floor,
+                    Direction::Down,
+                );
+                if result == MoveResult::PastDest {
+                    plat.count = plat.wait;
+                    plat.status = PlatStatus::Waiting;
+                    mover.start_sound(plat.sector, Sfx::sfx_pstop);
+                }
+            }
+            PlatStatus::Waiting => {
+                plat.count -= 1;
+                if plat.count == 0 {
+                    let floor_height = mover.with_sector(plat.sector, |s| s.floor_height);
+                    plat.status = if floor_height == Some(plat.low) {
+                        PlatStatus::Up
+                    } else {
+                        PlatStatus::Down
+                    };
+                    mover.start_sound(plat.sector, Sfx::sfx_pstart);
+                }
+            }
+            PlatStatus::InStasis => {}
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use super::lights::{FAST_DARK, SLOW_DARK};
+use super::LineSpecials;
+use crate::components::Sector;
+use crate::map_object::prelude::{Collision, Damage, MapObject, Player};
+use crate::{ExitLevel, LevelStats, LevelTime, LevelTotals};
+use bevy::prelude::*;
+
+/// Start the thinkers of the sector specials, and count the secrets.
+///
+/// Mirrors the sectors part of `P_SpawnSpecials`.
+pub(crate) fn spawn_specials(mut specials: LineSpecials, mut totals: ResMut<LevelTotals>) {
+    for sector in specials.level_map.sectors.clone() {
+        let Some(special) = specials.sector(sector).map(|s| s.special) else {
+            continue;
+        };
+        match special {
+            1 => specials.spawn_light_flash(sector),
+            2 => specials.spawn_strobe_flash(sector, FAST_DARK, false),
+            3 => specials.spawn_strobe_flash(sector, SLOW_DARK, false),
+            4 => {
+                specials.spawn_strobe_flash(sector, FAST_DARK, false);
+                // The floor still hurts.
+                if let Some(mut sector) = specials.sector_mut(sector) {
+                    sector.special = 4;
+                }
+            }
+            8 => specials.spawn_glowing_light(sector),
+            9 => totals.secrets += 1,
+            10 => specials.spawn_door_close_in_30(sector),
+            12 => specials.spawn_strobe_flash(sector, SLOW_DARK, true),
+            13 => specials.spawn_strobe_flash(sector, FAST_DARK, true),
+            14 => specials.spawn_door_raise_in_5_mins(sector),
+            17 => specials.spawn_fire_flicker(sector),
+            _ => {}
+        }
+    }
+}
+
+/// Hurt the player standing on a damaging floor.
+///
+/// Mirrors `P_PlayerInSpecialSector`, but for the secrets.
+pub(super) fn player_in_special_sector(
+    mut query: Query<(Entity, &mut MapObject), With<Player>>,
+    mut sectors: ParamSet<(Collision, Query<&mut Sector>)>,
+    mut damage: Damage,
+    level_time: Res<LevelTime>,
+    mut exit: EventWriter<ExitLevel>,
+) {
+    let Ok((entity, mut map_obj)) = query.get_single_mut() else {
+        return;
+    };
+    let Some(sector) = sectors.p0().sector_instance_at(map_obj.pos) else {
+        return;
+    };
+    let sector_query = sectors.p1();
+    let Ok(sector) = sector_query.get(*sector) else {
+        return;
+    };
+    // Falling, not all the way down yet?
+    if map_obj.z != sector.floor_height {
+        return;
+    }
+
+    // Floors hurt once a second or so.
+    let hurt = **level_time & 0x1f == 0;
+    match sector.special {
+        // Hellslime damage.
+        5 if hurt => damage.damage(entity, &mut map_obj, 10),
+        // Nukage damage.
+        7 if hurt => damage.damage(entity, &mut map_obj, 5),
+        // Super hellslime damage, and strobe hurt.
+        4 | 16 if hurt => damage.damage(entity, &mut map_obj, 20),
+        // Exit super damage, for the end of episode 1.
+        11 => {
+            if hurt {
+                damage.damage(entity, &mut map_obj, 20);
+            }
+            if map_obj.health <= 10 {
+                exit.send(ExitLevel { secret: false });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Count the secret sectors the player stands in, once each.
+///
+/// Mirrors the secrets part of `P_PlayerInSpecialSector`.
+pub(super) fn find_secrets(
+    query: Query<&MapObject, With<Player>>,
+    mut sectors: ParamSet<(Collision, Query<&mut Sector>)>,
+    mut stats: ResMut<LevelStats>,
+) {
+    let Ok(map_obj) = query.get_single() else {
+        return;
+    };
+    let Some(sector) = sectors.p0().sector_instance_at(map_obj.pos) else {
+        return;
+    };
+    let mut sector_query = sectors.p1();
+    let Ok(mut sector) = sector_query.get_mut(*sector) else {
+        return;
+    };
+    if map_obj.z == sector.floor_height && sector.special == 9 {
+        stats.secrets += 1;
+        sector.special = 0;
+    }
+}
@@ -0,0 +1,212 @@
+use super::LineSpecials;
+use crate::components::{Line, Sector, SideDef};
+use crate::prelude::StartSound;
+use bevy::prelude::*;
+use info::Sfx;
+use moonshine_kind::Instance;
+use wad::prelude::{GameMode, WadFile};
+
+/// Tics before a button pops back out, i.e. 1 second.
+const BUTTON_TIME: u32 = 35;
+
+/// Pairs of switch textures, turned off then on, and the first
+/// episode they are found in. Episode 3 stands for Doom II.
+///
+/// Mirrors `alphSwitchList`.
+const SWITCH_NAMES: [(&str, &str, u8); 40] = [
+    // Doom shareware episode 1 switches.
+    ("SW1BRCOM", "SW2BRCOM", 1),
+    ("SW1BRN1", "SW2BRN1", 1),
+    ("SW1BRN2", "SW2BRN2", 1),
+    ("SW1BRNGN", "SW2BRNGN", 1),
+    ("SW1BROWN", "SW2BROWN", 1),
+    ("SW1COMM", "SW2COMM", 1),
+    ("SW1COMP", "SW2COMP", 1),
+    ("SW1DIRT", "SW2DIRT", 1),
+    ("SW1EXIT", "SW2EXIT", 1),
+    ("SW1GRAY", "SW2GRAY", 1),
+    ("SW1GRAY1", "SW2GRAY1", 1),
+    ("SW1METAL", "SW2METAL", 1),
+    ("SW1PIPE", "SW2PIPE", 1),
+    ("SW1SLAD", "SW2SLAD", 1),
+    ("SW1STARG", "SW2STARG", 1),
+    ("SW1STON1", "SW2STON1", 1),
+    ("SW1STON2", "SW2STON2", 1),
+    ("SW1STONE", "SW2STONE", 1),
+    ("SW1STRTN", "SW2STRTN", 1),
+    // Doom registered episodes 2 and 3 switches.
+    ("SW1BLUE", "SW2BLUE", 2),
+    ("SW1CMT", "SW2CMT", 2),
+    ("SW1GARG", "SW2GARG", 2),
+    ("SW1GSTON", "SW2GSTON", 2),
+    ("SW1HOT", "SW2HOT", 2),
+    ("SW1LION", "SW2LION", 2),
+    ("SW1SATYR", "SW2SATYR", 2),
+    ("SW1SKIN", "SW2SKIN", 2),
+    ("SW1VINE", "SW2VINE", 2),
+    ("SW1WOOD", "SW2WOOD", 2),
+    // Doom II switches.
+    ("SW1PANEL", "SW2PANEL", 3),
+    ("SW1ROCK", "SW2ROCK", 3),
+    ("SW1MET2", "SW2MET2", 3),
+    ("SW1WDMET", "SW2WDMET", 3),
+    ("SW1BRIK", "SW2BRIK", 3),
+    ("SW1MOD1", "SW2MOD1", 3),
+    ("SW1ZIM", "SW2ZIM", 3),
+    ("SW1STON6", "SW2STON6", 3),
+    ("SW1TEK", "SW2TEK", 3),
+    ("SW1MARB", "SW2MARB", 3),
+    ("SW1SKULL", "SW2SKULL", 3),
+];
+
+/// Textures of the switches of the loaded game, as pairs of
+/// wall texture numbers.
+///
+/// Mirrors `switchlist`.
+#[derive(Resource, Debug, Default)]
+pub struct Switches(Vec<[usize; 2]>);
+
+impl Switches {
+    /// Mirrors `P_InitSwitchList`.
+    pub(crate) fn new(wad: &WadFile) -> Self {
+        let episode = match wad.game_mode() {
+            GameMode::Shareware => 1,
+            GameMode::Registered | GameMode::Retail => 2,
+            GameMode::Commercial => 3,
+        };
+        let textures = wad.wall_textures();
+        let switches = SWITCH_NAMES
+            .iter()
+            .filter(|(_, _, first_episode)| *first_episode <= episode)
+            .filter_map(|(off, on, _)| {
+                Some([textures.get_index_of(off)?, textures.get_index_of(on)?])
+            })
+            .collect();
+        Self(switches)
+    }
+
+    /// Get the texture of the switch in the other position,
+    /// or `None` when `texture` isn't a switch.
+    pub fn toggle(&self, texture: usize) -> Option<usize> {
+        self.0.iter().find_map(|&[off, on]| match texture {
+            _ if texture == off => Some(on),
+            _ if texture == on => Some(off),
+            _ => None,
+        })
+    }
+}
+
+/// Texture of a side showing a switch.
+///
+/// Mirrors `bwhere_e`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SwitchPart {
+    Top,
+    Middle,
+    Bottom,
+}
+
+impl SwitchPart {
+    fn texture_mut(self, side: &mut SideDef) -> &mut usize {
+        match self {
+            SwitchPart::Top => &mut side.top_texture,
+            SwitchPart::Middle => &mut side.middle_texture,
+            SwitchPart::Bottom => &mut side.lower_texture,
+        }
+    }
+}
+
+/// Thinker popping a pressed button back out.
+///
+/// Mirrors `button_t`.
+#[derive(Component, Debug)]
+pub struct PressedButton {
+    pub line: Instance<Line>,
+    pub side: Instance<SideDef>,
+    pub part: SwitchPart,
+    /// Texture put back once the button pops out.
+    pub texture: usize,
+    /// Tics left before the button pops out.
+    pub timer: u32,
+    /// Sector the sound of the button comes from.
+    pub sound_origin: Instance<Sector>,
+}
+
+impl LineSpecials<'_, '_> {
+    /// Flip the switch on the front side of `line`. Switches that
+    /// can be `use_again` are buttons, flipping back after a second,
+    /// the other ones lose their special.
+    ///
+    /// Mirrors `P_ChangeSwitchTexture`.
+    pub(super) fn change_switch_texture(&mut self, line_inst: Instance<Line>, use_again: bool) {
+        let Some(line) = self.line(line_inst) else {
+            return;
+        };
+        let side_inst = line.front_side();
+        let sound_origin = line.front_sector();
+        let sfx = if line.special() == 11 {
+            Sfx::sfx_swtchx
+        } else {
+            Sfx::sfx_swtchn
+        };
+        if !use_again {
+            self.clear_special(line_inst);
+        }
+
+        let Ok(mut side) = self.side_query.get_mut(*side_inst) else {
+            return;
+        };
+        let flipped = [SwitchPart::Top, SwitchPart::Middle, SwitchPart::Bottom]
+            .into_iter()
+            .find_map(|part| {
+                let texture = part.texture_mut(&mut side);
+                let other = self.switches.toggle(*texture)?;
+                Some((part, std::mem::replace(texture, other)))
+            });
+        let Some((part, texture)) = flipped else {
+            return;
+        };
+
+        self.sounds.send(StartSound::new(*sound_origin, sfx));
+        if use_again {
+            self.start_button(PressedButton {
+                line: line_inst,
+                side: side_inst,
+                part,
+                texture,
+                timer: BUTTON_TIME,
+                sound_origin,
+            });
+        }
+    }
+
+    /// Mirrors `P_StartButton`.
+    fn start_button(&mut self, button: PressedButton) {
+        // See if the button is already pressed.
+        if self.button_query.iter().any(|b| b.line == button.line) {
+            return;
+        }
+        self.commands.spawn(button);
+    }
+}
+
+/// Mirrors the buttons part of `P_UpdateSpecials`.
+pub(super) fn update_buttons(
+    mut query: Query<(Entity, &mut PressedButton)>,
+    mut side_query: Query<&mut SideDef>,
+    mut sounds: EventWriter<StartSound>,
+    mut commands: Commands,
+) {
+    for (entity, mut button) in &mut query {
+        button.timer = button.timer.saturating_sub(1);
+        if button.timer > 0 {
+            continue;
+        }
+        // The side is gone with the previous level otherwise.
+        if let Ok(mut side) = side_query.get_mut(*button.side) {
+            *button.part.texture_mut(&mut side) = button.texture;
+            sounds.send(StartSound::new(*button.sound_origin, Sfx::sfx_swtchn));
+        }
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,153 @@
+use crate::commands::SpawnMapObjects;
+use crate::components::{Line, Sector};
+use crate::map_object::prelude::{Collision, Damage, MapObject, Player};
+use crate::prelude::StartSound;
+use crate::resources::LevelMap;
+use crate::CurrentMap;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use info::{Info, MobjFlags, MobjType, Sfx};
+use moonshine_kind::Instance;
+use wad::prelude::MapId;
+
+/// Tics a player can't move for after teleporting.
+const TELEPORT_REACTION_TIME: i32 = 18;
+/// Damage killing anything standing on a teleport destination.
+const TELEFRAG_DAMAGE: i32 = 10000;
+
+/// Sent when `thing` enters the teleporter `line`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Teleport {
+    pub line: Instance<Line>,
+    pub thing: Entity,
+}
+
+/// Moves things to the teleport destinations.
+#[derive(SystemParam)]
+pub struct Teleporter<'w, 's> {
+    level_map: NonSend<'w, LevelMap>,
+    info: Res<'w, Info>,
+    current_map: Res<'w, CurrentMap>,
+    collision: Collision<'w, 's>,
+    line_query: Query<'w, 's, &'static Line>,
+    sector_query: Query<'w, 's, &'static Sector>,
+    thing_query: Query<'w, 's, (Entity, &'static mut MapObject, Option<&'static mut Player>)>,
+    damage: Damage<'w, 's>,
+    sounds: EventWriter<'w, StartSound>,
+    commands: Commands<'w, 's>,
+}
+
+impl Teleporter<'_, '_> {
+    /// Move `thing` to the teleport destination in the sectors tagged
+    /// like `line`. Returns `false` when there is none, or it's blocked.
+    ///
+    /// Mirrors `EV_Teleport`.
+    pub fn teleport(&mut self, line: Instance<Line>, thing: Entity) -> bool {
+        let Ok((_, map_obj, _)) = self.thing_query.get(thing) else {
+            return false;
+        };
+        // Don't teleport missiles.
+        if map_obj.flags.contains(MobjFlags::MISSILE) {
+            return false;
+        }
+        let old_pos = map_obj.pos;
+        let Some(dest) = self.find_destination(line) else {
+            return false;
+        };
+        if !self.teleport_move(thing, dest.pos) {
+            return false;
+        }
+
+        let Ok((_, mut map_obj, player)) = self.thing_query.get_mut(thing) else {
+            return false;
+        };
+        map_obj.z = map_obj.floor_z;
+        if let Some(mut player) = player {
+            player.view_z = map_obj.z + player.view_height;
+            // Don't move for a bit.
+            map_obj.reaction_time = TELEPORT_REACTION_TIME;
+        }
+        map_obj.dir = dest.dir;
+        map_obj.velocity = Vec2::ZERO;
+        map_obj.z_velocity = 0.0;
+
+        // Spawn teleport fog at the source and the destination.
+        self.spawn_fog(old_pos);
+        self.spawn_fog(dest.pos + dest.dir * 20.0);
+        true
+    }
+
+    /// Find the first teleport destination, in the first sector
+    /// tagged like `line` that has one.
+    fn find_destination(&self, line: Instance<Line>) -> Option<MapObject> {
+        let tag = self.line_query.get(*line).ok()?.tag();
+        self.level_map
+            .sectors
+            .iter()
+            .filter(|&&sector| self.sector_query.get(*sector).is_ok_and(|s| s.tag == tag))
+            .find_map(|&sector| {
+                self.level_map
+                    .map_objects
+                    .iter()
+                    .filter_map(|&thing| self.thing_query.get(*thing).ok())
+                    .map(|(_, map_obj, _)| *map_obj)
+                    .find(|map_obj| {
+                        map_obj.mobj_type == MobjType::MT_TELEPORTMAN
+                            && self.collision.sector_instance_at(map_obj.pos) == Some(sector)
+                    })
+            })
+    }
+
+    /// Move `thing` to `pos`, killing the things standing there.
+    /// Returns `false` when a monster is in the way of a monster.
+    ///
+    /// Mirrors `P_TeleportMove`.
+    fn teleport_move(&mut self, thing: Entity, pos: Vec2) -> bool {
+        let Some(sector) = self.collision.sector_at(pos) else {
+            return false;
+        };
+        let (floor_z, ceiling_z) = (sector.floor_height, sector.ceiling_height);
+        let Ok((_, map_obj, _)) = self.thing_query.get(thing) else {
+            return false;
+        };
+        let radius = map_obj.radius;
+        // Monsters only stomp things on the boss level of Doom II.
+        let can_stomp = map_obj.is_player() || **self.current_map == MapId::Commercial { map: 30 };
+
+        for (entity, mut other, _) in &mut self.thing_query {
+            // Mirrors `PIT_StompThing`.
+            if entity == thing || !other.flags.contains(MobjFlags::SHOOTABLE) {
+                continue;
+            }
+            let block_dist = other.radius + radius;
+            if (other.pos - pos).abs().cmpge(Vec2::splat(block_dist)).any() {
+                // Didn't hit it.
+                continue;
+            }
+            if !can_stomp {
+                return false;
+            }
+            self.damage.damage(entity, &mut other, TELEFRAG_DAMAGE);
+        }
+
+        let Ok((_, mut map_obj, _)) = self.thing_query.get_mut(thing) else {
+            return false;
+        };
+        map_obj.pos = pos;
+        map_obj.floor_z = floor_z;
+        map_obj.ceiling_z = ceiling_z;
+        true
+    }
+
+    fn spawn_fog(&mut self, pos: Vec2) {
+        let fog = MapObject::new(MobjType::MT_TFOG, pos, &self.info);
+        let fog = self.commands.spawn_map_object(fog, &self.info).instance();
+        self.sounds.send(StartSound::new(*fog, Sfx::sfx_telept));
+    }
+}
+
+pub(super) fn teleport_things(mut events: EventReader<Teleport>, mut teleporter: Teleporter) {
+    for event in events.read() {
+        teleporter.teleport(event.line, event.thing);
+    }
+}
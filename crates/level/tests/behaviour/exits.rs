@@ -0,0 +1,36 @@
+use super::test_map::{Room, TestMap};
+use super::LevelHarness;
+use bevy::prelude::*;
+use level::prelude::*;
+use window::InputScript;
+
+#[test]
+fn exit_line_ends_the_level() {
+    // W1 Exit Level.
+    let map = TestMap::new()
+        .room(Room::new(128))
+        .line(52, 0)
+        .room(Room::new(256))
+        .thing(1, 64, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+
+    assert!(harness.run_collecting::<ExitLevel>(10).is_empty());
+    harness.input(InputScript::new().hold(KeyCode::ArrowUp, 0, 15));
+    let exits = harness.run_collecting::<ExitLevel>(30);
+    assert!(matches!(exits[..], [ExitLevel { secret: false }]));
+}
+
+#[test]
+fn secret_exit_switch_ends_the_level() {
+    // S1 Exit Level (Secret), on the east wall.
+    let map = TestMap::new()
+        .room(Room::new(128))
+        .room(Room::new(128))
+        .line(51, 0)
+        .thing(1, 200, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+
+    harness.input(InputScript::new().hold(KeyCode::Space, 0, 1));
+    let exits = harness.run_collecting::<ExitLevel>(5);
+    assert!(matches!(exits[..], [ExitLevel { secret: true }]));
+}
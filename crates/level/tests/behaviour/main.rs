@@ -0,0 +1,135 @@
+//! Behaviour tests of the level. Each test builds a small map of rooms
+//! in a row, plays it for a number of tics with scripted input, and
+//! checks what happened to the things and sectors.
+
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::*;
+use game_state::{GameState, GameStatePlugin};
+use level::prelude::*;
+use level::{LevelPlugin, LoadLevel};
+use test_map::TestMap;
+use wad::prelude::*;
+use window::{InputScript, WindowPlugin};
+
+mod exits;
+mod missiles;
+mod movers;
+mod sector_specials;
+mod skill;
+mod test_map;
+mod use_lines;
+mod walk_over;
+mod weapons;
+
+/// Plays a [`TestMap`] with the plugins of the game, without a window.
+/// Every update is one tic.
+struct LevelHarness {
+    app: App,
+}
+
+impl LevelHarness {
+    fn new(map: &TestMap) -> Self {
+        let wad_file = WadFileParser::parse(&map.build(), &[]).unwrap();
+
+        let mut app = App::new();
+        app.insert_resource(Time::<Fixed>::from_hz(35.0))
+            .insert_resource(wad_file)
+            .add_plugins((
+                MinimalPlugins,
+                bevy::input::InputPlugin,
+                bevy::state::app::StatesPlugin,
+                exit::ExitPlugin,
+                GameStatePlugin,
+                info::InfoPlugin,
+                rand::RandPlugin,
+                WindowPlugin { headless: true },
+                LevelPlugin,
+            ));
+        app.finish();
+        app.cleanup();
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.world_mut().send_event(LoadLevel {
+            map: MapId::Episode { episode: 1, map: 1 },
+            new_game: true,
+        });
+        // Enter the game, load the level, then enter it.
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.should_exit().is_none(), "Failed to load the level");
+        Self { app }
+    }
+
+    /// Press and release keys following `script`, the tics
+    /// counting from now.
+    fn input(&mut self, script: InputScript) {
+        self.app.insert_resource(script);
+    }
+
+    /// Play `tics` tics.
+    fn run(&mut self, tics: u32) {
+        for _ in 0..tics {
+            self.app.update();
+        }
+    }
+
+    /// Play `tics` tics, collecting the events of type `E` sent.
+    fn run_collecting<E: Event + Clone>(&mut self, tics: u32) -> Vec<E> {
+        let mut cursor = EventCursor::<E>::default();
+        let mut events = vec![];
+        for _ in 0..tics {
+            self.app.update();
+            let sent = self.app.world().resource::<Events<E>>();
+            events.extend(cursor.read(sent).cloned());
+        }
+        events
+    }
+
+    fn world(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    fn player_entity(&mut self) -> Entity {
+        let world = self.world();
+        let mut query = world.query_filtered::<Entity, With<Player>>();
+        query.single(world)
+    }
+
+    fn player_obj(&mut self) -> MapObject {
+        let world = self.world();
+        let mut query = world.query_filtered::<&MapObject, With<Player>>();
+        *query.single(world)
+    }
+
+    /// Look at the player, or change it.
+    fn player<R>(&mut self, f: impl FnOnce(&mut Player) -> R) -> R {
+        let world = self.world();
+        let mut query = world.query::<&mut Player>();
+        f(&mut query.single_mut(world))
+    }
+
+    /// The map objects of `mobj_type`.
+    fn things(&mut self, mobj_type: info::MobjType) -> Vec<MapObject> {
+        let world = self.world();
+        let mut query = world.query::<&MapObject>();
+        query
+            .iter(world)
+            .filter(|map_obj| map_obj.mobj_type == mobj_type)
+            .copied()
+            .collect()
+    }
+
+    /// Look at the sector of the room starting at `x`.
+    fn sector<R>(&mut self, x: f32, f: impl FnOnce(&Sector) -> R) -> R {
+        let world = self.world();
+        let mut query = world.query::<&Sector>();
+        let sector = query
+            .iter(world)
+            .find(|sector| sector.bbox.min.x == x)
+            .expect("No room starts there");
+        f(sector)
+    }
+}
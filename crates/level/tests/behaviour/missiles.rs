@@ -0,0 +1,94 @@
+use super::test_map::{Room, TestMap};
+use super::LevelHarness;
+use bevy::prelude::*;
+use info::{AmmoType, MobjFlags, MobjType, StateNum, WeaponType};
+use level::prelude::*;
+use window::InputScript;
+
+#[test]
+fn rocket_explodes_on_wall() {
+    let map = TestMap::new()
+        .room(Room::new(256))
+        .room(Room::new(256))
+        .thing(1, 64, 128, 0)
+        // Zombieman by the east wall, out of the way of the rocket.
+        .thing(3004, 480, 200, 180);
+    let mut harness = LevelHarness::new(&map);
+    harness.player(|player| {
+        player.weapons[WeaponType::wp_missile.index()] = true;
+        player.ammo[AmmoType::am_misl.index()] = 10;
+    });
+
+    harness.input(InputScript::new().hold(KeyCode::Digit5, 0, 1));
+    // The pistol is raised, lowered, then the launcher raised.
+    harness.run(60);
+    harness.input(InputScript::new().hold(KeyCode::ControlLeft, 0, 1));
+    harness.run(15);
+    let [rocket] = harness.things(MobjType::MT_ROCKET)[..] else {
+        panic!("No rocket was fired");
+    };
+    assert!(rocket.velocity.x > 0.0);
+
+    // Flies until it hits the east wall.
+    let rocket = (0..30)
+        .find_map(|_| {
+            harness.run(1);
+            let [rocket] = harness.things(MobjType::MT_ROCKET)[..] else {
+                panic!("The rocket is gone");
+            };
+            (!rocket.flags.contains(MobjFlags::MISSILE)).then_some(rocket)
+        })
+        .expect("The rocket didn't explode");
+    assert_eq!(rocket.velocity, Vec2::ZERO);
+    assert!(rocket.pos.x > 512.0 - 32.0);
+
+    // The blast reaches the zombieman.
+    harness.run(1);
+    let [zombie] = harness.things(MobjType::MT_POSSESSED)[..] else {
+        panic!("The zombieman is gone");
+    };
+    assert!(zombie.health < 20);
+    assert_eq!(harness.player_obj().health, 100);
+}
+
+#[test]
+fn imp_fireball_flies_and_explodes() {
+    let map = TestMap::new()
+        .room(Room::new(256))
+        .room(Room::new(256))
+        .thing(1, 64, 128, 0)
+        .thing(3001, 400, 128, 180);
+    let mut harness = LevelHarness::new(&map);
+    let player = harness.player_entity();
+
+    // Make the imp throw a fireball at the player on the next tic.
+    let world = harness.world();
+    let mut query = world.query::<(Entity, &MapObject)>();
+    let (imp, _) = query
+        .iter(world)
+        .find(|(_, map_obj)| map_obj.mobj_type == MobjType::MT_TROOP)
+        .unwrap();
+    world.entity_mut(imp).insert((
+        Target(player),
+        MapObjectState {
+            state: StateNum::S_TROO_ATK2,
+            tics: 1,
+        },
+    ));
+
+    harness.run(5);
+    let [fireball] = harness.things(MobjType::MT_TROOPSHOT)[..] else {
+        panic!("No fireball was thrown");
+    };
+    assert!(fireball.velocity.x < 0.0);
+    assert!(fireball.pos.x < 400.0);
+
+    // Flies until it hits the player.
+    harness.run(40);
+    let exploded = harness
+        .things(MobjType::MT_TROOPSHOT)
+        .iter()
+        .all(|fireball| !fireball.flags.contains(MobjFlags::MISSILE));
+    assert!(exploded);
+    assert!(harness.player_obj().health < 100);
+}
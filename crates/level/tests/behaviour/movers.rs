@@ -0,0 +1,40 @@
+use super::test_map::{Room, TestMap};
+use super::LevelHarness;
+use bevy::prelude::*;
+use window::InputScript;
+
+/// The player faces a switch of `special`, in front of a raised
+/// room tagged 1, going to the floor of the rooms around it.
+fn raised_room_behind_switch(special: i16) -> LevelHarness {
+    let map = TestMap::new()
+        .room(Room::new(128))
+        .line(special, 1)
+        .room(Room::new(128).floor(64).tag(1))
+        .room(Room::new(128))
+        .thing(1, 100, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+    harness.input(InputScript::new().hold(KeyCode::Space, 0, 1));
+    harness
+}
+
+#[test]
+fn switch_lowers_floor() {
+    // S1 Floor Lower to Lowest Floor.
+    let mut harness = raised_room_behind_switch(23);
+    harness.run(80);
+    assert_eq!(harness.sector(128.0, |sector| sector.floor_height), 0.0);
+}
+
+#[test]
+fn lift_lowers_waits_and_comes_back() {
+    // SR Lift Also Monsters.
+    let mut harness = raised_room_behind_switch(62);
+    harness.run(30);
+    assert_eq!(harness.sector(128.0, |sector| sector.floor_height), 0.0);
+    // The lift waits three seconds at the bottom.
+    harness.run(60);
+    assert_eq!(harness.sector(128.0, |sector| sector.floor_height), 0.0);
+    harness.run(70);
+    assert_eq!(harness.sector(128.0, |sector| sector.floor_height), 64.0);
+    assert!(harness.sector(128.0, |sector| sector.mover.is_none()));
+}
@@ -0,0 +1,44 @@
+use super::test_map::{Room, TestMap};
+use super::LevelHarness;
+use bevy::prelude::*;
+use level::prelude::*;
+use window::InputScript;
+
+#[test]
+fn secret_counts_once() {
+    let map = TestMap::new()
+        .room(Room::new(128))
+        .room(Room::new(256).special(9))
+        .thing(1, 64, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+    assert_eq!(harness.world().resource::<LevelTotals>().secrets, 1);
+
+    // In, out and in again.
+    harness.input(InputScript::new().hold(KeyCode::ArrowUp, 0, 20));
+    harness.run(40);
+    assert!(harness.player_obj().pos.x > 128.0);
+    assert_eq!(harness.world().resource::<LevelStats>().secrets, 1);
+    assert_eq!(harness.sector(128.0, |sector| sector.special), 0);
+
+    harness.input(InputScript::new().hold(KeyCode::ArrowDown, 0, 25));
+    harness.run(40);
+    assert!(harness.player_obj().pos.x < 128.0);
+    harness.input(InputScript::new().hold(KeyCode::ArrowUp, 0, 20));
+    harness.run(40);
+    assert!(harness.player_obj().pos.x > 128.0);
+    assert_eq!(harness.world().resource::<LevelStats>().secrets, 1);
+}
+
+#[test]
+fn damaging_floor_hurts_the_player() {
+    // 5% damage every 32 tics.
+    let map = TestMap::new()
+        .room(Room::new(128).special(7))
+        .room(Room::new(128))
+        .thing(1, 64, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+
+    harness.run(70);
+    let health = harness.player_obj().health;
+    assert!((85..100).contains(&health), "Health is {health}");
+}
@@ -0,0 +1,31 @@
+use super::test_map::{Room, TestMap};
+use super::LevelHarness;
+use info::MobjType;
+use level::prelude::*;
+use wad::prelude::*;
+
+#[test]
+fn things_are_filtered_by_skill_and_game_mode() {
+    let map = TestMap::new()
+        .room(Room::new(256))
+        .room(Room::new(256))
+        .thing(1, 64, 128, 0)
+        .thing_with_options(3004, 200, 64, 0, MapThing::EASY)
+        .thing_with_options(9, 200, 128, 0, MapThing::NORMAL)
+        .thing_with_options(3001, 200, 192, 0, MapThing::NORMAL | MapThing::NOT_SINGLE)
+        // Doom II only, the map being in a Doom 1 IWAD.
+        .thing(64, 400, 64, 0)
+        .thing(83, 400, 192, 0)
+        // Deathmatch start.
+        .thing(11, 400, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+
+    // The game is played at the medium skill by default.
+    assert!(harness.things(MobjType::MT_POSSESSED).is_empty());
+    assert_eq!(harness.things(MobjType::MT_SHOTGUY).len(), 1);
+    assert!(harness.things(MobjType::MT_TROOP).is_empty());
+    assert!(harness.things(MobjType::MT_VILE).is_empty());
+    assert!(harness.things(MobjType::MT_MEGA).is_empty());
+    let starts = harness.world().resource::<DeathmatchStarts>();
+    assert_eq!(starts.len(), 1);
+}
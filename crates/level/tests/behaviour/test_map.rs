@@ -0,0 +1,338 @@
+//! Maps made from scratch to try the level on, so the tests don't
+//! need the copyrighted IWADs.
+//!
+//! A map is a row of rectangular rooms going east, each one its own
+//! sector. The line east of a room is shared with the next room, or
+//! is the east wall of the last one. Every room spans `0..ROOM_DEPTH`
+//! from south to north.
+
+use common::Array2d;
+use std::f32::consts::TAU;
+use wad::prelude::*;
+
+/// Size of the rooms from south to north.
+pub const ROOM_DEPTH: i16 = 256;
+
+/// A room of a [`TestMap`], 128 high with a bright light
+/// unless changed.
+#[derive(Debug, Clone, Copy)]
+pub struct Room {
+    width: i16,
+    floor: i16,
+    ceiling: i16,
+    ceiling_tex: &'static str,
+    special: i16,
+    tag: i16,
+}
+
+impl Room {
+    pub fn new(width: i16) -> Self {
+        Self {
+            width,
+            floor: 0,
+            ceiling: 128,
+            ceiling_tex: "CEIL",
+            special: 0,
+            tag: 0,
+        }
+    }
+
+    pub fn floor(self, floor: i16) -> Self {
+        Self { floor, ..self }
+    }
+
+    pub fn ceiling(self, ceiling: i16) -> Self {
+        Self { ceiling, ..self }
+    }
+
+    pub fn special(self, special: i16) -> Self {
+        Self { special, ..self }
+    }
+
+    pub fn tag(self, tag: i16) -> Self {
+        Self { tag, ..self }
+    }
+}
+
+/// Special and tag of the line east of a room.
+#[derive(Debug, Clone, Copy, Default)]
+struct LineSpecial {
+    special: i16,
+    tag: i16,
+}
+
+/// A map of rooms in a row, see the module documentation.
+#[derive(Debug, Clone, Default)]
+pub struct TestMap {
+    rooms: Vec<(Room, LineSpecial)>,
+    things: Vec<MapThing>,
+}
+
+impl TestMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a room east of the last one.
+    pub fn room(mut self, room: Room) -> Self {
+        self.rooms.push((room, LineSpecial::default()));
+        self
+    }
+
+    /// Give a special to the line east of the last room added.
+    pub fn line(mut self, special: i16, tag: i16) -> Self {
+        let (_, line) = self.rooms.last_mut().expect("No room to put the line in");
+        *line = LineSpecial { special, tag };
+        self
+    }
+
+    /// Place a thing present at every skill level, facing `angle`
+    /// degrees counterclockwise from east.
+    pub fn thing(self, thing_type: i16, x: i16, y: i16, angle: i16) -> Self {
+        self.thing_with_options(thing_type, x, y, angle, 7)
+    }
+
+    /// Place a thing with the given [`MapThing::options`].
+    pub fn thing_with_options(
+        mut self,
+        thing_type: i16,
+        x: i16,
+        y: i16,
+        angle: i16,
+        options: i16,
+    ) -> Self {
+        self.things.push(MapThing {
+            x,
+            y,
+            angle,
+            thing_type,
+            options,
+        });
+        self
+    }
+
+    /// Build an IWAD holding the map as E1M1.
+    pub fn build(&self) -> Vec<u8> {
+        let mut wad = WadBuilder::iwad();
+        wad.palettes(&[[[0; 3]; 256]; 14])
+            .colormaps(&[[0; 256]; 34])
+            .patch("TITLEPIC", &image(1, 1), 0, 0)
+            .map(MapId::Episode { episode: 1, map: 1 }, &self.map_lumps())
+            .textures(&textures())
+            .patch("WALL", &image(64, 128), 0, 0)
+            .patch("SKY1", &image(256, 128), 0, 0)
+            .marker("S_START")
+            .patch("PLAYA0", &image(16, 16), 8, 15)
+            .marker("S_END")
+            .marker("F_START")
+            .flat("FLOOR", &[0; Flat::WIDTH * Flat::HEIGHT])
+            .flat("CEIL", &[0; Flat::WIDTH * Flat::HEIGHT])
+            .flat("F_SKY1", &[0; Flat::WIDTH * Flat::HEIGHT])
+            .marker("F_END");
+        wad.build()
+    }
+
+    fn map_lumps(&self) -> MapLumps {
+        assert!(self.rooms.len() >= 2, "The BSP needs at least two rooms");
+        let room_count = self.rooms.len();
+        // West edge of every room, and east edge of the last one.
+        let edges: Vec<i16> = std::iter::once(0)
+            .chain(self.rooms.iter().scan(0, |x, (room, _)| {
+                *x += room.width;
+                Some(*x)
+            }))
+            .collect();
+        // South and north vertex of each edge.
+        let vertexes: Vec<_> = edges
+            .iter()
+            .flat_map(|&x| [(x, 0), (x, ROOM_DEPTH)])
+            .collect();
+        let south = |edge: usize| (2 * edge) as i16;
+        let north = |edge: usize| (2 * edge + 1) as i16;
+
+        let mut lines = vec![];
+        let mut side_defs = vec![];
+        // Segments of each room, as line number and side.
+        let mut room_segs = vec![vec![]; room_count];
+        let mut add_line = |v1: i16, v2: i16, front: usize, back: Option<usize>, special| {
+            let LineSpecial { special, tag } = special;
+            let line_num = lines.len();
+            let (flags, middle, lower_upper) = match back {
+                Some(_) => (4, "-", "WALL"),
+                None => (1, "WALL", "-"),
+            };
+            let front_side = side_defs.len() as i16;
+            let back_side = back.map_or(-1, |_| front_side + 1);
+            for sector in std::iter::once(front).chain(back) {
+                side_defs.push(MapSideDef {
+                    x_offset: 0,
+                    y_offset: 0,
+                    top_texture: lower_upper.to_owned(),
+                    lower_texture: lower_upper.to_owned(),
+                    middle_texture: middle.to_owned(),
+                    sector: sector as i16,
+                });
+            }
+            lines.push(MapLine {
+                v1,
+                v2,
+                flags,
+                special,
+                tag,
+                front_side,
+                back_side,
+            });
+            room_segs[front].push((line_num, 0));
+            if let Some(back) = back {
+                room_segs[back].push((line_num, 1));
+            }
+        };
+
+        // The front side of a line is on its right.
+        add_line(south(0), north(0), 0, None, LineSpecial::default());
+        for (index, &(_, special)) in self.rooms.iter().enumerate() {
+            add_line(
+                north(index),
+                north(index + 1),
+                index,
+                None,
+                LineSpecial::default(),
+            );
+            add_line(
+                south(index + 1),
+                south(index),
+                index,
+                None,
+                LineSpecial::default(),
+            );
+            let next = (index + 1 < room_count).then_some(index + 1);
+            add_line(north(index + 1), south(index + 1), index, next, special);
+        }
+
+        let mut segments = vec![];
+        let mut sub_sectors = vec![];
+        for segs in &room_segs {
+            sub_sectors.push(MapSubSector {
+                num_segs: segs.len() as i16,
+                first_seg: segments.len() as i16,
+            });
+            for &(line_num, side) in segs {
+                let line: &MapLine = &lines[line_num];
+                let (v1, v2) = if side == 0 {
+                    (line.v1, line.v2)
+                } else {
+                    (line.v2, line.v1)
+                };
+                let (x1, y1) = vertexes[v1 as usize];
+                let (x2, y2) = vertexes[v2 as usize];
+                let angle = f32::from(y2 - y1).atan2(f32::from(x2 - x1)).rem_euclid(TAU);
+                segments.push(MapSegment {
+                    v1,
+                    v2,
+                    angle: (angle / TAU * 65536.0) as u16 as i16,
+                    line: line_num as i16,
+                    side,
+                    offset: 0,
+                });
+            }
+        }
+
+        // One node at the west edge of each room but the first, the
+        // rooms east of it on its right. The root, splitting off the
+        // first room, is the last node.
+        let leaf = |room: usize| (0x8000 | room as u16) as i16;
+        let nodes = (1..room_count)
+            .rev()
+            .map(|edge| {
+                let x = edges[edge];
+                let right_child = if edge + 1 == room_count {
+                    leaf(edge)
+                } else {
+                    (room_count - edge - 2) as i16
+                };
+                MapNode {
+                    x,
+                    y: 0,
+                    dx: 0,
+                    dy: ROOM_DEPTH,
+                    right_box_top: ROOM_DEPTH,
+                    right_box_bottom: 0,
+                    right_box_left: x,
+                    right_box_right: edges[room_count],
+                    left_box_top: ROOM_DEPTH,
+                    left_box_bottom: 0,
+                    left_box_left: edges[edge - 1],
+                    left_box_right: x,
+                    right_child,
+                    left_child: leaf(edge - 1),
+                }
+            })
+            .collect();
+
+        let sectors = self
+            .rooms
+            .iter()
+            .map(|(room, _)| MapSector {
+                floor_height: room.floor,
+                ceiling_height: room.ceiling,
+                floor_tex: "FLOOR".to_owned(),
+                ceiling_tex: room.ceiling_tex.to_owned(),
+                light_level: 192,
+                special: room.special,
+                tag: room.tag,
+            })
+            .collect();
+
+        MapLumps {
+            things: self.things.clone(),
+            lines,
+            side_defs,
+            vertexes: vertexes
+                .into_iter()
+                .map(|(x, y)| MapVertex { x, y })
+                .collect(),
+            segments,
+            sub_sectors,
+            nodes,
+            sectors,
+            ..Default::default()
+        }
+    }
+}
+
+fn image(width: usize, height: usize) -> Array2d<Option<u8>> {
+    let mut pixels = Array2d::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            pixels[(x, y)] = Some(0);
+        }
+    }
+    pixels
+}
+
+fn textures() -> Vec<TextureDef<'static>> {
+    const WALL: &[TexturePatch] = &[TexturePatch {
+        origin_x: 0,
+        origin_y: 0,
+        patch: "WALL",
+    }];
+    const SKY: &[TexturePatch] = &[TexturePatch {
+        origin_x: 0,
+        origin_y: 0,
+        patch: "SKY1",
+    }];
+    // The first texture is never drawn, like in vanilla.
+    [
+        ("AASTINKY", 64, WALL),
+        ("WALL", 64, WALL),
+        ("SKY1", 256, SKY),
+    ]
+    .into_iter()
+    .map(|(name, width, patches)| TextureDef {
+        name,
+        width,
+        height: 128,
+        patches,
+    })
+    .collect()
+}
@@ -0,0 +1,56 @@
+use super::test_map::{Room, TestMap};
+use super::LevelHarness;
+use bevy::prelude::*;
+use info::Sfx;
+use level::prelude::*;
+use window::InputScript;
+
+#[test]
+fn door_opens_on_use_and_closes() {
+    // DR Door Open Wait Close, in front of the player.
+    let map = TestMap::new()
+        .room(Room::new(128))
+        .line(1, 0)
+        .room(Room::new(16).ceiling(0))
+        .room(Room::new(128))
+        .thing(1, 100, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+    harness.input(InputScript::new().hold(KeyCode::Space, 0, 1));
+
+    // Up to 4 below the lowest ceiling around.
+    harness.run(70);
+    assert_eq!(harness.sector(128.0, |sector| sector.ceiling_height), 124.0);
+    // The door stays open for a bit more than four seconds.
+    harness.run(100);
+    assert_eq!(harness.sector(128.0, |sector| sector.ceiling_height), 124.0);
+    harness.run(130);
+    assert_eq!(harness.sector(128.0, |sector| sector.ceiling_height), 0.0);
+}
+
+#[test]
+fn using_a_wall_oofs() {
+    let map = TestMap::new()
+        .room(Room::new(128))
+        .room(Room::new(128))
+        .thing(1, 200, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+    harness.input(InputScript::new().hold(KeyCode::Space, 0, 1));
+
+    let sounds = harness.run_collecting::<StartSound>(5);
+    assert!(sounds.iter().any(|sound| sound.sfx == Sfx::sfx_noway));
+}
+
+#[test]
+fn nothing_is_used_out_of_reach() {
+    let map = TestMap::new()
+        .room(Room::new(128))
+        .line(1, 0)
+        .room(Room::new(16).ceiling(0))
+        .room(Room::new(128))
+        .thing(1, 32, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+    harness.input(InputScript::new().hold(KeyCode::Space, 0, 1));
+
+    harness.run(70);
+    assert_eq!(harness.sector(128.0, |sector| sector.ceiling_height), 0.0);
+}
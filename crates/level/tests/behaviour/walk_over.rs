@@ -0,0 +1,58 @@
+use super::test_map::{Room, TestMap};
+use super::LevelHarness;
+use bevy::prelude::*;
+use level::prelude::*;
+use window::InputScript;
+
+#[test]
+fn w1_line_fires_once() {
+    // W1 Floor Lower to Lowest Floor, tagging the room past the next one.
+    let map = TestMap::new()
+        .room(Room::new(128))
+        .line(38, 1)
+        .room(Room::new(256))
+        .room(Room::new(128).floor(64).tag(1))
+        .thing(1, 64, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+
+    harness.input(InputScript::new().hold(KeyCode::ArrowUp, 0, 15));
+    harness.run(100);
+    assert!(harness.player_obj().pos.x > 128.0);
+    assert_eq!(harness.sector(384.0, |sector| sector.floor_height), 0.0);
+    assert!(harness.sector(384.0, |sector| sector.mover.is_none()));
+
+    // Put the floor back up, and cross the line both ways.
+    let world = harness.world();
+    let mut query = world.query::<&mut Sector>();
+    for mut sector in query.iter_mut(world) {
+        if sector.tag == 1 {
+            sector.floor_height = 64.0;
+        }
+    }
+    harness.input(InputScript::new().hold(KeyCode::ArrowDown, 0, 20));
+    harness.run(40);
+    assert!(harness.player_obj().pos.x < 128.0);
+    harness.input(InputScript::new().hold(KeyCode::ArrowUp, 0, 15));
+    harness.run(80);
+    assert!(harness.player_obj().pos.x > 128.0);
+    assert_eq!(harness.sector(384.0, |sector| sector.floor_height), 64.0);
+}
+
+#[test]
+fn teleport_moves_the_player() {
+    // W1 Teleport, to the destination in the last room, facing north.
+    let map = TestMap::new()
+        .room(Room::new(128))
+        .line(39, 1)
+        .room(Room::new(256))
+        .room(Room::new(256).tag(1))
+        .thing(1, 64, 128, 0)
+        .thing(14, 512, 64, 90);
+    let mut harness = LevelHarness::new(&map);
+
+    harness.input(InputScript::new().hold(KeyCode::ArrowUp, 0, 15));
+    harness.run(20);
+    let player = harness.player_obj();
+    assert_eq!(player.pos, Vec2::new(512.0, 64.0));
+    assert!(player.dir.angle_to(Vec2::Y).abs() < 0.01);
+}
@@ -0,0 +1,48 @@
+use super::test_map::{Room, TestMap};
+use super::LevelHarness;
+use bevy::prelude::*;
+use info::{AmmoType, MobjType, WeaponType};
+use window::InputScript;
+
+#[test]
+fn pistol_shot_damages_target() {
+    let map = TestMap::new()
+        .room(Room::new(256))
+        .room(Room::new(256))
+        .thing(1, 64, 128, 0)
+        // Zombieman, 20 health points.
+        .thing(3004, 320, 128, 180);
+    let mut harness = LevelHarness::new(&map);
+    let ammo = harness.player(|player| player.ammo[AmmoType::am_clip.index()]);
+
+    // Wait for the pistol to be raised, then fire once.
+    harness.run(20);
+    harness.input(InputScript::new().hold(KeyCode::ControlLeft, 0, 1));
+    harness.run(20);
+
+    let [zombie] = harness.things(MobjType::MT_POSSESSED)[..] else {
+        panic!("The zombieman is gone");
+    };
+    assert!(zombie.health < 20);
+    assert_eq!(
+        harness.player(|player| player.ammo[AmmoType::am_clip.index()]),
+        ammo - 1
+    );
+}
+
+#[test]
+fn weapon_key_switches_weapon() {
+    let map = TestMap::new()
+        .room(Room::new(256))
+        .room(Room::new(256))
+        .thing(1, 64, 128, 0);
+    let mut harness = LevelHarness::new(&map);
+
+    // The pistol is lowered, then the fist raised.
+    harness.input(InputScript::new().hold(KeyCode::Digit1, 0, 1));
+    harness.run(40);
+    assert_eq!(
+        harness.player(|player| player.ready_weapon),
+        WeaponType::wp_fist
+    );
+}
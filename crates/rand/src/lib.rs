@@ -18,6 +18,17 @@ impl Plugin for RandPlugin {
 #[derive(Resource, Default)]
 pub struct Rand(usize);
 
+impl Rand {
+    /// Difference of two random numbers, from -255 to 255,
+    /// mostly around 0.
+    ///
+    /// Mirrors `P_SubRandom`.
+    pub fn sub_random(&mut self) -> i32 {
+        let r = self.next_u32() as i32;
+        r - self.next_u32() as i32
+    }
+}
+
 impl RngCore for Rand {
     #[inline]
     fn next_u32(&mut self) -> u32 {
@@ -35,11 +35,24 @@ fn transition(
     time: Res<Time>,
     mut query: Query<&mut ScreenTimer>,
     mut load_level: EventWriter<LoadLevel>,
+    game_mode: Res<GameMode>,
 ) {
     let mut timer = query.single_mut();
     if timer.tick(time.delta()).just_finished() {
         debug!("Exiting Title Screen");
-        load_level.send(LoadLevel { episode: 1, map: 1 });
+        load_level.send(LoadLevel {
+            map: first_map(*game_mode),
+            new_game: true,
+        });
+    }
+}
+
+/// Doom II and Final Doom have no episodes, so they start on `MAP01`.
+fn first_map(game_mode: GameMode) -> MapId {
+    if game_mode == GameMode::Commercial {
+        MapId::Commercial { map: 1 }
+    } else {
+        MapId::Episode { episode: 1, map: 1 }
     }
 }
 
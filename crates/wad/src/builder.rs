@@ -0,0 +1,609 @@
+use crate::flat::Flat;
+use crate::header::{WadHeaderParser, WadId};
+use crate::lump::LumpsDirectoryParser;
+use crate::map::{
+    MapBlockMap, MapId, MapLine, MapNode, MapSector, MapSegment, MapSideDef, MapSubSector,
+    MapThing, MapVertex,
+};
+use anyhow::Result;
+use common::Array2d;
+
+/// Size of the side of a block of the block map.
+const BLOCK_SIZE: i32 = 128;
+
+/// Assembles lumps into the bytes of a WAD file, that can be given
+/// to [`WadFileParser::parse`](crate::WadFileParser::parse).
+///
+/// Lumps are written in the order they are added. Besides raw lumps,
+/// there are helpers encoding maps, palettes, colormaps, patches, flats
+/// and wall textures.
+#[derive(Debug, Clone)]
+pub struct WadBuilder {
+    id: WadId,
+    lumps: Vec<(String, Vec<u8>)>,
+}
+
+impl WadBuilder {
+    /// Start an IWAD, with no lumps.
+    pub fn iwad() -> Self {
+        Self::new(WadId::Iwad)
+    }
+
+    /// Start a PWAD, with no lumps.
+    pub fn pwad() -> Self {
+        Self::new(WadId::Pwad)
+    }
+
+    fn new(id: WadId) -> Self {
+        Self { id, lumps: vec![] }
+    }
+
+    /// Start from the lumps of an existing WAD, to patch it.
+    pub fn from_bytes(wad_data: &[u8]) -> Result<Self> {
+        let header = WadHeaderParser::parse(wad_data)?;
+        let lumps_dir = LumpsDirectoryParser::parse(wad_data, header)?;
+        let lumps = lumps_dir
+            .get_index(..)
+            .unwrap_or_default()
+            .iter()
+            .map(|lump| (lump.name().to_owned(), lump.data().to_vec()))
+            .collect();
+        Ok(Self {
+            id: header.id,
+            lumps,
+        })
+    }
+
+    /// Add a lump. Names longer than 8 characters are truncated.
+    pub fn lump(&mut self, name: &str, data: impl Into<Vec<u8>>) -> &mut Self {
+        self.lumps.push((name.to_owned(), data.into()));
+        self
+    }
+
+    /// Add an empty lump, like `F_START`.
+    pub fn marker(&mut self, name: &str) -> &mut Self {
+        self.lump(name, vec![])
+    }
+
+    /// Replace the data of the last lump named `name`, or add it
+    /// when there is none.
+    pub fn replace(&mut self, name: &str, data: impl Into<Vec<u8>>) -> &mut Self {
+        let lump = self
+            .lumps
+            .iter_mut()
+            .rev()
+            .find(|(lump_name, _)| lump_name.eq_ignore_ascii_case(name));
+        match lump {
+            Some((_, lump_data)) => *lump_data = data.into(),
+            None => {
+                self.lump(name, data);
+            }
+        }
+        self
+    }
+
+    /// Add the lumps of a map, starting with its marker.
+    pub fn map(&mut self, id: MapId, map: &MapLumps) -> &mut Self {
+        let block_map = match &map.block_map {
+            Some(block_map) => encode_block_map(block_map),
+            None => encode_block_map(&create_block_map(&map.vertexes, &map.lines)),
+        };
+        let reject = if map.reject.is_empty() {
+            // Nothing is rejected.
+            vec![0; (map.sectors.len() * map.sectors.len()).div_ceil(8)]
+        } else {
+            map.reject.clone()
+        };
+
+        self.marker(&id.to_string())
+            .lump("THINGS", encode(&map.things, encode_thing))
+            .lump("LINEDEFS", encode(&map.lines, encode_line))
+            .lump("SIDEDEFS", encode(&map.side_defs, encode_side_def))
+            .lump("VERTEXES", encode(&map.vertexes, encode_vertex))
+            .lump("SEGS", encode(&map.segments, encode_segment))
+            .lump("SSECTORS", encode(&map.sub_sectors, encode_sub_sector))
+            .lump("NODES", encode(&map.nodes, encode_node))
+            .lump("SECTORS", encode(&map.sectors, encode_sector))
+            .lump("REJECT", reject)
+            .lump("BLOCKMAP", block_map)
+    }
+
+    /// Add the `PLAYPAL` lump, made of 256 colors per palette.
+    pub fn palettes(&mut self, palettes: &[[[u8; 3]; 256]]) -> &mut Self {
+        let data: Vec<u8> = palettes.iter().flatten().flatten().copied().collect();
+        self.lump("PLAYPAL", data)
+    }
+
+    /// Add the `COLORMAP` lump.
+    pub fn colormaps(&mut self, colormaps: &[[u8; 256]]) -> &mut Self {
+        let data: Vec<u8> = colormaps.iter().flatten().copied().collect();
+        self.lump("COLORMAP", data)
+    }
+
+    /// Add a flat, to be placed between `F_START` and `F_END`.
+    pub fn flat(&mut self, name: &str, pixels: &[u8; Flat::WIDTH * Flat::HEIGHT]) -> &mut Self {
+        self.lump(name, pixels.to_vec())
+    }
+
+    /// Add a patch, `None` pixels being see-through.
+    pub fn patch(
+        &mut self,
+        name: &str,
+        pixels: &Array2d<Option<u8>>,
+        left_offset: i16,
+        top_offset: i16,
+    ) -> &mut Self {
+        self.lump(name, encode_patch(pixels, left_offset, top_offset))
+    }
+
+    /// Add the wall textures of `TEXTURE1`, and the `PNAMES` lump
+    /// listing the patches they use.
+    pub fn textures(&mut self, textures: &[TextureDef]) -> &mut Self {
+        let mut patch_names: Vec<&str> = vec![];
+        for patch in textures.iter().flat_map(|texture| texture.patches) {
+            if !patch_names.contains(&patch.patch) {
+                patch_names.push(patch.patch);
+            }
+        }
+
+        let mut pnames = Vec::with_capacity(4 + 8 * patch_names.len());
+        put_i32(&mut pnames, patch_names.len() as i32);
+        for name in &patch_names {
+            put_name(&mut pnames, name);
+        }
+
+        let mut definitions = Vec::new();
+        let mut offsets = Vec::with_capacity(textures.len());
+        let offsets_size = 4 + 4 * textures.len();
+        for texture in textures {
+            offsets.push((offsets_size + definitions.len()) as i32);
+            put_name(&mut definitions, texture.name);
+            put_i32(&mut definitions, 0); // Masked, unused.
+            put_i16(&mut definitions, texture.width);
+            put_i16(&mut definitions, texture.height);
+            put_i32(&mut definitions, 0); // Column directory, unused.
+            put_i16(&mut definitions, texture.patches.len() as i16);
+            for patch in texture.patches {
+                let patch_num = patch_names.iter().position(|name| *name == patch.patch);
+                put_i16(&mut definitions, patch.origin_x);
+                put_i16(&mut definitions, patch.origin_y);
+                put_i16(&mut definitions, patch_num.unwrap_or_default() as i16);
+                put_i16(&mut definitions, 1); // Step dir, unused.
+                put_i16(&mut definitions, 0); // Colormap, unused.
+            }
+        }
+
+        let mut texture1 = Vec::with_capacity(offsets_size + definitions.len());
+        put_i32(&mut texture1, textures.len() as i32);
+        for offset in offsets {
+            put_i32(&mut texture1, offset);
+        }
+        texture1.extend(definitions);
+
+        self.lump("TEXTURE1", texture1).lump("PNAMES", pnames)
+    }
+
+    /// Serialize the WAD: the header, the lumps, then the directory.
+    pub fn build(&self) -> Vec<u8> {
+        let data_size: usize = self.lumps.iter().map(|(_, data)| data.len()).sum();
+        let mut wad = Vec::with_capacity(12 + data_size + 16 * self.lumps.len());
+        wad.extend(match self.id {
+            WadId::Iwad => b"IWAD",
+            WadId::Pwad => b"PWAD",
+        });
+        put_i32(&mut wad, self.lumps.len() as i32);
+        put_i32(&mut wad, (12 + data_size) as i32);
+
+        let mut directory = Vec::with_capacity(16 * self.lumps.len());
+        for (name, data) in &self.lumps {
+            put_i32(&mut directory, wad.len() as i32);
+            put_i32(&mut directory, data.len() as i32);
+            put_name(&mut directory, name);
+            wad.extend(data);
+        }
+        wad.extend(directory);
+        wad
+    }
+}
+
+/// Data of the lumps of a map.
+#[derive(Debug, Default)]
+pub struct MapLumps {
+    pub things: Vec<MapThing>,
+    pub lines: Vec<MapLine>,
+    pub side_defs: Vec<MapSideDef>,
+    pub vertexes: Vec<MapVertex>,
+    pub segments: Vec<MapSegment>,
+    pub sub_sectors: Vec<MapSubSector>,
+    pub nodes: Vec<MapNode>,
+    pub sectors: Vec<MapSector>,
+    /// Left empty, nothing is rejected.
+    pub reject: Vec<u8>,
+    /// Left empty, it is created from the lines.
+    pub block_map: Option<MapBlockMap>,
+}
+
+/// A wall texture made of patches.
+#[derive(Debug, Clone, Copy)]
+pub struct TextureDef<'a> {
+    pub name: &'a str,
+    pub width: i16,
+    pub height: i16,
+    pub patches: &'a [TexturePatch<'a>],
+}
+
+/// A patch drawn in a wall texture.
+#[derive(Debug, Clone, Copy)]
+pub struct TexturePatch<'a> {
+    pub origin_x: i16,
+    pub origin_y: i16,
+    /// Name of the patch lump.
+    pub patch: &'a str,
+}
+
+fn encode<T>(items: &[T], encode_item: fn(&mut Vec<u8>, &T)) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for item in items {
+        encode_item(&mut bytes, item);
+    }
+    bytes
+}
+
+fn encode_thing(bytes: &mut Vec<u8>, thing: &MapThing) {
+    for value in [
+        thing.x,
+        thing.y,
+        thing.angle,
+        thing.thing_type,
+        thing.options,
+    ] {
+        put_i16(bytes, value);
+    }
+}
+
+fn encode_line(bytes: &mut Vec<u8>, line: &MapLine) {
+    for value in [
+        line.v1,
+        line.v2,
+        line.flags,
+        line.special,
+        line.tag,
+        line.front_side,
+        line.back_side,
+    ] {
+        put_i16(bytes, value);
+    }
+}
+
+fn encode_side_def(bytes: &mut Vec<u8>, side_def: &MapSideDef) {
+    put_i16(bytes, side_def.x_offset);
+    put_i16(bytes, side_def.y_offset);
+    put_name(bytes, &side_def.top_texture);
+    put_name(bytes, &side_def.lower_texture);
+    put_name(bytes, &side_def.middle_texture);
+    put_i16(bytes, side_def.sector);
+}
+
+fn encode_vertex(bytes: &mut Vec<u8>, vertex: &MapVertex) {
+    put_i16(bytes, vertex.x);
+    put_i16(bytes, vertex.y);
+}
+
+fn encode_segment(bytes: &mut Vec<u8>, segment: &MapSegment) {
+    for value in [
+        segment.v1,
+        segment.v2,
+        segment.angle,
+        segment.line,
+        segment.side,
+        segment.offset,
+    ] {
+        put_i16(bytes, value);
+    }
+}
+
+fn encode_sub_sector(bytes: &mut Vec<u8>, sub_sector: &MapSubSector) {
+    put_i16(bytes, sub_sector.num_segs);
+    put_i16(bytes, sub_sector.first_seg);
+}
+
+fn encode_node(bytes: &mut Vec<u8>, node: &MapNode) {
+    for value in [
+        node.x,
+        node.y,
+        node.dx,
+        node.dy,
+        node.right_box_top,
+        node.right_box_bottom,
+        node.right_box_left,
+        node.right_box_right,
+        node.left_box_top,
+        node.left_box_bottom,
+        node.left_box_left,
+        node.left_box_right,
+        node.right_child,
+        node.left_child,
+    ] {
+        put_i16(bytes, value);
+    }
+}
+
+fn encode_sector(bytes: &mut Vec<u8>, sector: &MapSector) {
+    put_i16(bytes, sector.floor_height);
+    put_i16(bytes, sector.ceiling_height);
+    put_name(bytes, &sector.floor_tex);
+    put_name(bytes, &sector.ceiling_tex);
+    put_i16(bytes, sector.light_level);
+    put_i16(bytes, sector.special);
+    put_i16(bytes, sector.tag);
+}
+
+fn encode_block_map(block_map: &MapBlockMap) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for value in [
+        block_map.origin_x,
+        block_map.origin_y,
+        block_map.width,
+        block_map.height,
+    ] {
+        put_i16(&mut bytes, value);
+    }
+    for offset in &block_map.offsets {
+        put_i16(&mut bytes, *offset as i16);
+    }
+    for line in &block_map.block_lists {
+        put_i16(&mut bytes, *line);
+    }
+    bytes
+}
+
+/// Create a block map listing, in every block, the lines whose
+/// bounding box touches it. Lines may be listed in blocks they
+/// don't actually cross, which is harmless.
+fn create_block_map(vertexes: &[MapVertex], lines: &[MapLine]) -> MapBlockMap {
+    let bounds = |line: &MapLine| {
+        let v1 = vertexes.get(line.v1 as usize)?;
+        let v2 = vertexes.get(line.v2 as usize)?;
+        Some((
+            i32::from(v1.x.min(v2.x)),
+            i32::from(v1.y.min(v2.y)),
+            i32::from(v1.x.max(v2.x)),
+            i32::from(v1.y.max(v2.y)),
+        ))
+    };
+    let (min_x, min_y, max_x, max_y) = lines.iter().filter_map(bounds).fold(
+        (i32::MAX, i32::MAX, i32::MIN, i32::MIN),
+        |(min_x, min_y, max_x, max_y), (x1, y1, x2, y2)| {
+            (min_x.min(x1), min_y.min(y1), max_x.max(x2), max_y.max(y2))
+        },
+    );
+    if min_x > max_x {
+        // No lines.
+        return MapBlockMap {
+            origin_x: 0,
+            origin_y: 0,
+            width: 0,
+            height: 0,
+            offsets: vec![],
+            block_lists: vec![],
+        };
+    }
+
+    // Leave a margin around the map, like the node builders do.
+    let origin_x = min_x - 8;
+    let origin_y = min_y - 8;
+    let width = (max_x + 8 - origin_x) / BLOCK_SIZE + 1;
+    let height = (max_y + 8 - origin_y) / BLOCK_SIZE + 1;
+
+    let num_blocks = (width * height) as usize;
+    let mut offsets = Vec::with_capacity(num_blocks);
+    let mut block_lists = Vec::new();
+    for block in 0..(width * height) {
+        let left = origin_x + (block % width) * BLOCK_SIZE;
+        let bottom = origin_y + (block / width) * BLOCK_SIZE;
+        offsets.push((4 + num_blocks + block_lists.len()) as u16);
+        // Every list starts with 0, and ends with -1.
+        block_lists.push(0);
+        for (line_num, line) in lines.iter().enumerate() {
+            let Some((x1, y1, x2, y2)) = bounds(line) else {
+                continue;
+            };
+            if x2 >= left && x1 <= left + BLOCK_SIZE && y2 >= bottom && y1 <= bottom + BLOCK_SIZE {
+                block_lists.push(line_num as i16);
+            }
+        }
+        block_lists.push(-1);
+    }
+
+    MapBlockMap {
+        origin_x: origin_x as i16,
+        origin_y: origin_y as i16,
+        width: width as i16,
+        height: height as i16,
+        offsets,
+        block_lists,
+    }
+}
+
+/// Encode the columns of a patch as posts of opaque pixels.
+fn encode_patch(pixels: &Array2d<Option<u8>>, left_offset: i16, top_offset: i16) -> Vec<u8> {
+    let width = pixels.width();
+    let height = pixels.height();
+    let header_size = 8 + 4 * width;
+    let mut columns = Vec::new();
+    let mut offsets = Vec::with_capacity(width);
+    for x in 0..width {
+        offsets.push((header_size + columns.len()) as i32);
+        let mut y = 0;
+        while y < height {
+            if pixels[(x, y)].is_none() {
+                y += 1;
+                continue;
+            }
+            // Posts hold up to 255 pixels. Like vanilla, patches
+            // taller than 254 pixels aren't supported.
+            let top_delta = y;
+            let mut post = Vec::new();
+            while y < height && post.len() < 255 {
+                let Some(color) = pixels[(x, y)] else {
+                    break;
+                };
+                post.push(color);
+                y += 1;
+            }
+            columns.extend([top_delta as u8, post.len() as u8, 0]);
+            columns.extend(post);
+            columns.push(0);
+        }
+        columns.push(0xff);
+    }
+
+    let mut bytes = Vec::with_capacity(header_size + columns.len());
+    put_i16(&mut bytes, width as i16);
+    put_i16(&mut bytes, height as i16);
+    put_i16(&mut bytes, left_offset);
+    put_i16(&mut bytes, top_offset);
+    for offset in offsets {
+        put_i32(&mut bytes, offset);
+    }
+    bytes.extend(columns);
+    bytes
+}
+
+fn put_i16(bytes: &mut Vec<u8>, value: i16) {
+    bytes.extend(value.to_le_bytes());
+}
+
+fn put_i32(bytes: &mut Vec<u8>, value: i32) {
+    bytes.extend(value.to_le_bytes());
+}
+
+/// Write a lump or texture name, padded with zeros to 8 bytes.
+fn put_name(bytes: &mut Vec<u8>, name: &str) {
+    let mut padded = [0; 8];
+    let len = name.len().min(8);
+    padded[..len].copy_from_slice(&name.as_bytes()[..len]);
+    bytes.extend(padded);
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    /// A WAD with a square room, and everything else the parser requires.
+    pub(crate) fn square_room() -> WadBuilder {
+        let vertexes = [(0, 0), (0, 128), (128, 128), (128, 0)];
+        let mut wad = WadBuilder::iwad();
+        wad.palettes(&[[[0; 3]; 256]; 14])
+            .colormaps(&[[0; 256]; 34])
+            .patch("TITLEPIC", &Array2d::new(1, 1), 0, 0)
+            .map(
+                MapId::Episode { episode: 1, map: 1 },
+                &MapLumps {
+                    things: vec![MapThing {
+                        x: 64,
+                        y: 64,
+                        angle: 90,
+                        thing_type: 1,
+                        options: 7,
+                    }],
+                    lines: (0..4)
+                        .map(|i| MapLine {
+                            v1: i,
+                            v2: (i + 1) % 4,
+                            flags: 1,
+                            special: 0,
+                            tag: 0,
+                            front_side: i,
+                            back_side: -1,
+                        })
+                        .collect(),
+                    side_defs: (0..4)
+                        .map(|_| MapSideDef {
+                            x_offset: 0,
+                            y_offset: 0,
+                            top_texture: "-".to_owned(),
+                            lower_texture: "-".to_owned(),
+                            middle_texture: "WALL".to_owned(),
+                            sector: 0,
+                        })
+                        .collect(),
+                    vertexes: vertexes.map(|(x, y)| MapVertex { x, y }).into(),
+                    sectors: vec![MapSector {
+                        floor_height: 0,
+                        ceiling_height: 128,
+                        floor_tex: "FLAT".to_owned(),
+                        ceiling_tex: "FLAT".to_owned(),
+                        light_level: 160,
+                        special: 0,
+                        tag: 0,
+                    }],
+                    ..Default::default()
+                },
+            )
+            .textures(&[TextureDef {
+                name: "WALL",
+                width: 8,
+                height: 8,
+                patches: &[TexturePatch {
+                    origin_x: 0,
+                    origin_y: 0,
+                    patch: "WALLPAT",
+                }],
+            }])
+            .patch("WALLPAT", &Array2d::new(8, 8), 0, 0)
+            .marker("S_START")
+            .patch("TROOA0", &Array2d::new(4, 4), 2, 4)
+            .marker("S_END")
+            .marker("F_START")
+            .flat("FLAT", &[0; 4096])
+            .marker("F_END");
+        wad
+    }
+
+    #[test]
+    fn built_wad_is_parsed() {
+        let wad_file = WadFileParser::parse(&square_room().build(), &[]).unwrap();
+
+        assert_eq!(wad_file.game_mode(), GameMode::Shareware);
+        let map = wad_file.map(MapId::Episode { episode: 1, map: 1 }).unwrap();
+        assert_eq!(map.things.len(), 1);
+        assert_eq!(map.lines.len(), 4);
+        assert_eq!(map.sectors[0].ceiling_tex, "FLAT");
+        // The room fits in a 2x2 block map, every block being
+        // crossed by two lines, after the leading 0 of its list.
+        assert_eq!((map.block_map.width, map.block_map.height), (2, 2));
+        assert_eq!(map.block_map.block_lines(0).count(), 3);
+        assert!(wad_file.wall_textures().get("WALL").is_some());
+        assert!(wad_file.sprites().get("TROO").is_some());
+    }
+
+    #[test]
+    fn invalid_sprite_names_are_skipped() {
+        let iwad = square_room().build();
+        let mut pwad = WadBuilder::pwad();
+        pwad.marker("SS_START")
+            .patch("TROOB0", &Array2d::new(4, 4), 2, 4)
+            .lump("README", *b"not a sprite")
+            .marker("SS_END");
+        let wad_file = WadFileParser::parse(&iwad, &[pwad.build()]).unwrap();
+
+        let sprite = wad_file.sprites().get("TROO").unwrap();
+        assert_eq!(sprite.num_frames(), 2);
+        assert!(wad_file.sprites().get("READ").is_none());
+    }
+
+    #[test]
+    fn patched_wad_keeps_other_lumps() {
+        let iwad = square_room().build();
+        let mut patched = WadBuilder::from_bytes(&iwad).unwrap();
+        patched.replace("COLORMAP", [1; 34 * 256]);
+        let wad_file = WadFileParser::parse(&patched.build(), &[]).unwrap();
+
+        assert_eq!(wad_file.colormaps()[0][0], 1);
+        assert!(wad_file
+            .map(MapId::Episode { episode: 1, map: 1 })
+            .is_some());
+    }
+}
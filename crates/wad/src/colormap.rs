@@ -0,0 +1,51 @@
+use crate::lump::LumpsDirectory;
+use anyhow::{bail, Result};
+use derive_more::{Deref, Index};
+
+/// Maps each color of the palette to a darker (or otherwise altered) one.
+#[derive(Deref, Index, Debug)]
+pub struct Colormap(Vec<u8>);
+
+impl Colormap {
+    const SIZE: usize = 256;
+}
+
+/// The `COLORMAP` lump holds 34 colormaps:
+/// - 0 to 31 go from full brightness to almost black, and are used for lighting.
+/// - 32 is the inverted grayscale used by the invulnerability powerup.
+/// - 33 is all black, and unused.
+#[derive(Deref, Index, Debug)]
+pub struct Colormaps(Vec<Colormap>);
+
+impl Colormaps {
+    /// Number of colormaps used for light diminishing.
+    pub const LIGHT_COLORMAPS: usize = 32;
+    /// Colormap of the invulnerability powerup.
+    pub const INVERSE_COLORMAP: usize = 32;
+    const COUNT: usize = 34;
+}
+
+pub struct ColormapsParser;
+
+impl ColormapsParser {
+    pub fn parse(lumps_dir: &LumpsDirectory) -> Result<Colormaps> {
+        let Some(colormap_lump) = lumps_dir.get("COLORMAP") else {
+            bail!("Missing colormap lump");
+        };
+        let colormaps_data = colormap_lump.data();
+        if colormaps_data.len() < Colormaps::COUNT * Colormap::SIZE {
+            bail!(
+                "Colormap lump has invalid size: expected {} bytes, got {} bytes",
+                Colormaps::COUNT * Colormap::SIZE,
+                colormaps_data.len()
+            );
+        }
+
+        let colormaps = colormaps_data
+            .chunks_exact(Colormap::SIZE)
+            .take(Colormaps::COUNT)
+            .map(|colormap_data| Colormap(colormap_data.to_vec()))
+            .collect();
+        Ok(Colormaps(colormaps))
+    }
+}
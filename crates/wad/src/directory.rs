@@ -3,7 +3,15 @@ use anyhow::Result;
 use std::path::Path;
 use std::{env, path::PathBuf};
 
-pub static IWADS: &[&str; 2] = &["doom.wad", "doom1.wad"];
+pub static IWADS: &[&str; 7] = &[
+    "doom2.wad",
+    "plutonia.wad",
+    "tnt.wad",
+    "doom.wad",
+    "doom1.wad",
+    "freedoom2.wad",
+    "freedoom1.wad",
+];
 
 #[derive(Debug)]
 pub struct IwadDirs {
@@ -51,8 +59,9 @@ impl IwadDirs {
         }
     }
     
-    /// Name can either be the name of the IWAD or the absolute path to the IWAD.
-    pub fn find_iwad(&self, name: &Path) -> Option<PathBuf> {
+    /// Name can either be the name of the WAD or the absolute path to the WAD.
+    /// Used to find both the IWAD and the PWADs passed in the command line.
+    pub fn find_wad(&self, name: &Path) -> Option<PathBuf> {
         if name.exists() {
             // Absolute path
             return Some(name.to_path_buf());
@@ -4,14 +4,21 @@ use anyhow::{bail, Result};
 
 #[derive(Debug)]
 pub struct Flat {
-    #[allow(unused)]
     data: Vec<u8>,
 }
 
 impl Flat {
-    const WIDTH: usize = 64;
-    const HEIGHT: usize = 64;
+    pub const WIDTH: usize = 64;
+    pub const HEIGHT: usize = 64;
     const SIZE: usize = Self::WIDTH * Self::HEIGHT;
+
+    /// Get the color at `(x, y)`. Flats tile the whole plane, so the
+    /// coordinates wrap around.
+    pub fn pixel(&self, x: usize, y: usize) -> u8 {
+        let x = x % Self::WIDTH;
+        let y = y % Self::HEIGHT;
+        self.data[x + (y * Self::WIDTH)]
+    }
 }
 
 #[derive(Default, Debug)]
@@ -58,14 +65,10 @@ impl FlatsParser {
         if end_marker < start_marker {
             bail!("WAD file has flat end marker placed before flat start marker")
         }
-        if start_marker + 1 >= end_marker {
-            bail!("WAD file contains no flats");
-        }
 
-        let first_flat = start_marker + 1;
-        let last_flat = end_marker - 1;
-        // We can safely unwrap here, because markers are guaranteed to exist in lump directory.
-        let flat_lumps = lumps_dir.get_index(first_flat..=last_flat).unwrap();
+        // PWADs usually add their flats between FF_START and FF_END, so that
+        // the flats of the IWAD are not lost when the markers are looked up.
+        let flat_lumps = lumps_dir.get_namespace(&["F_START", "FF_START"], &["F_END", "FF_END"]);
 
         let mut flats = IndexMap::default();
 
@@ -75,8 +78,13 @@ impl FlatsParser {
                 continue;
             }
             let flat = FlatParser::parse(lump)?;
+            // A flat with the same name replaces the old one,
+            // but keeps its index.
             flats.insert(lump.name().to_owned(), flat);
         }
+        if flats.is_empty() {
+            bail!("WAD file contains no flats");
+        }
 
         Ok(Flats(flats))
     }
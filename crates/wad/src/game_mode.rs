@@ -0,0 +1,87 @@
+use crate::lump::LumpsDirectory;
+use bevy::prelude::*;
+use std::path::Path;
+
+/// The version of the game the IWAD belongs to. It determines, among other
+/// things, how many episodes are available and the naming of the maps.
+#[derive(Resource, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GameMode {
+    /// Doom shareware, only the first episode is available.
+    Shareware,
+    /// Registered Doom, with three episodes.
+    Registered,
+    /// Doom II and Final Doom, with a single episode of `MAPxx` maps.
+    Commercial,
+    /// The Ultimate Doom, which adds the fourth episode.
+    Retail,
+}
+
+impl GameMode {
+    /// Number of episodes available in this game mode.
+    pub fn episodes(self) -> usize {
+        match self {
+            GameMode::Shareware | GameMode::Commercial => 1,
+            GameMode::Registered => 3,
+            GameMode::Retail => 4,
+        }
+    }
+}
+
+/// The mission of the game, i.e. which set of levels is being played.
+#[derive(Resource, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GameMission {
+    /// Doom 1.
+    Doom,
+    /// Doom II: Hell on Earth.
+    Doom2,
+    /// Final Doom: TNT: Evilution.
+    PackTnt,
+    /// Final Doom: The Plutonia Experiment.
+    PackPlut,
+}
+
+impl GameMission {
+    /// Vanilla identifies the mission by the name of the IWAD file, because
+    /// Doom II and Final Doom IWADs share the same set of map lumps.
+    pub fn from_iwad_name(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        match name.as_str() {
+            "doom.wad" | "doom1.wad" | "freedoom1.wad" => Some(GameMission::Doom),
+            "doom2.wad" | "freedoom2.wad" => Some(GameMission::Doom2),
+            "tnt.wad" => Some(GameMission::PackTnt),
+            "plutonia.wad" => Some(GameMission::PackPlut),
+            _ => None,
+        }
+    }
+}
+
+impl From<GameMode> for GameMission {
+    /// Fallback used when the IWAD has an unknown name.
+    fn from(game_mode: GameMode) -> Self {
+        if game_mode == GameMode::Commercial {
+            GameMission::Doom2
+        } else {
+            GameMission::Doom
+        }
+    }
+}
+
+pub struct GameModeParser;
+
+impl GameModeParser {
+    /// Identifies the game mode from the lumps present in the IWAD. PWADs must
+    /// not be taken into account, otherwise a PWAD that adds E4M1 would turn
+    /// registered Doom into The Ultimate Doom.
+    pub fn parse(lumps_dir: &LumpsDirectory) -> GameMode {
+        if lumps_dir.get_index_of("MAP01").is_some() {
+            return GameMode::Commercial;
+        }
+        if lumps_dir.get_index_of("E4M1").is_some() {
+            return GameMode::Retail;
+        }
+        if lumps_dir.get_index_of("E3M1").is_some() || lumps_dir.get("TEXTURE2").is_some() {
+            return GameMode::Registered;
+        }
+        GameMode::Shareware
+    }
+}
@@ -0,0 +1,39 @@
+use crate::lump::LumpsDirectory;
+use crate::patch::{Patch, PatchParser};
+use anyhow::{bail, Result};
+use bevy::utils::HashMap;
+
+/// Prefixes of the lumps drawn on the intermission screen, e.g.
+/// `WIMAP0`, `WILV00` and `CWILV00`.
+const GRAPHIC_PREFIXES: [&str; 3] = ["WI", "CWILV", "INTERPIC"];
+
+/// Full screen and interface graphics, looked up by lump name.
+#[derive(Debug, Default)]
+pub struct Graphics(HashMap<String, Patch>);
+
+impl Graphics {
+    pub fn get(&self, name: &str) -> Option<&Patch> {
+        self.0.get(&name.to_ascii_uppercase())
+    }
+}
+
+pub struct GraphicsParser;
+
+impl GraphicsParser {
+    pub fn parse(lumps_dir: &LumpsDirectory) -> Result<Graphics> {
+        let mut graphics = HashMap::new();
+        let lumps = lumps_dir.get_index(..).unwrap_or_default();
+        for lump in lumps {
+            let name = lump.name().to_ascii_uppercase();
+            if lump.is_marker() || !GRAPHIC_PREFIXES.iter().any(|p| name.starts_with(p)) {
+                continue;
+            }
+            let Ok(patch) = PatchParser::parse(lump.data()) else {
+                bail!("Graphic '{name}' is not a valid patch");
+            };
+            // Lumps from the PWADs come last, replacing the ones before.
+            graphics.insert(name, patch);
+        }
+        Ok(Graphics(graphics))
+    }
+}
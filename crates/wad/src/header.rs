@@ -3,6 +3,7 @@ use anyhow::{bail, Result};
 
 #[derive(Copy, Clone, Debug)]
 pub struct WadHeader {
+    pub id: WadId,
     pub dir_offset: usize,
     pub num_lumps: usize,
 }
@@ -17,7 +18,9 @@ pub struct WadHeaderParser;
 
 impl WadHeaderParser {
     pub fn parse(bytes: &[u8]) -> Result<WadHeader> {
-        let header_bytes = &bytes[0..12];
+        let Some(header_bytes) = bytes.get(0..12) else {
+            bail!("WAD file is too small to hold a header");
+        };
 
         let id_bytes = &header_bytes[0..4];
         let id = WadHeaderParser::parse_id(id_bytes)?;
@@ -29,6 +32,7 @@ impl WadHeaderParser {
         let dir_offset = WadHeaderParser::parse_dir_offset(dir_offset_bytes)?;
 
         Ok(WadHeader {
+            id,
             dir_offset,
             num_lumps,
         })
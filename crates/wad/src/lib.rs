@@ -1,40 +1,55 @@
-use anyhow::{Error, Result};
+use anyhow::{bail, Error, Result};
 use bevy::prelude::*;
 use cli::CommandLine;
-use directory::IwadDirs;
+use colormap::ColormapsParser;
 use exit::macros::sys_fail;
-use flat::{Flats, FlatsParser};
+use flat::FlatsParser;
+use game_mode::GameModeParser;
+use graphic::{Graphics, GraphicsParser};
 use header::WadHeaderParser;
-use lump::LumpsDirectoryParser;
+use lump::{LumpsDirectory, LumpsDirectoryParser};
 use map::Maps;
 use map::MapsParser;
 use palette::{Palette, Palettes, PalettesParser};
 use patch::PatchParser;
 use prelude::*;
-use std::{fs::File, io::Read};
+use sprite::SpritesParser;
+use std::{fs::File, io::Read, path::Path};
 use wall_texture::{WallTextures, WallTexturesParser};
 
 pub mod prelude {
     pub use super::{
+        builder::{MapLumps, TextureDef, TexturePatch, WadBuilder},
+        colormap::{Colormap, Colormaps},
+        flat::{Flat, Flats},
+        game_mode::{GameMission, GameMode},
         map::{
-            Map, MapLine, MapNode, MapNodes, MapSector, MapSegment, MapSideDef, MapSubSector,
+            Map, MapBlockMap, MapId, MapLine, MapNode, MapNodes, MapSector, MapSegment, MapSideDef, MapSubSector,
             MapThing, MapVertex,
         },
         palette::PaletteVariant,
         patch::{DrawPath, Patch},
+        sprite::{Sprite, SpriteFrame, SpriteRotation, Sprites},
         wall_texture::{WallTextures, WallTexture},
-        WadFile,
+        WadFile, WadFileParser,
     };
 }
 
+pub use directory::IwadDirs;
+
+mod builder;
+mod colormap;
 mod directory;
 mod flat;
+mod game_mode;
+mod graphic;
 mod header;
 mod index_map;
 mod lump;
 mod map;
 mod palette;
 mod patch;
+mod sprite;
 mod sys;
 mod util;
 mod wall_texture;
@@ -55,7 +70,7 @@ impl Plugin for WadPlugin {
 fn load_iwad(cli: Res<CommandLine>, mut commands: Commands) {
     let dirs = IwadDirs::try_new()?;
     let iwad_path = if let Some(name) = &cli.iwad {
-        dirs.find_iwad(name)
+        dirs.find_wad(name)
     } else {
         dirs.search_iwads()
     };
@@ -68,15 +83,35 @@ fn load_iwad(cli: Res<CommandLine>, mut commands: Commands) {
     };
 
     debug!("Loading IWAD: {iwad_path:?}");
-    let mut wad_handle = File::open(iwad_path)?;
-    let mut wad_data = Vec::new();
-    wad_handle.read_to_end(&mut wad_data)?;
-    let wad_file = WadFileParser::parse(&wad_data)?;
+    let iwad_data = read_wad(&iwad_path)?;
+
+    let mut pwads_data = Vec::with_capacity(cli.file.len());
+    for name in &cli.file {
+        let Some(pwad_path) = dirs.find_wad(name) else {
+            bail!("Could not find PWAD file {name:?}");
+        };
+        debug!("Loading PWAD: {pwad_path:?}");
+        pwads_data.push(read_wad(&pwad_path)?);
+    }
 
+    let wad_file = WadFileParser::parse(&iwad_data, &pwads_data)?;
+    let game_mode = wad_file.game_mode();
+    let game_mission = GameMission::from_iwad_name(&iwad_path).unwrap_or(game_mode.into());
+    debug!("Game mode: {game_mode:?}, game mission: {game_mission:?}");
+
+    commands.insert_resource(game_mode);
+    commands.insert_resource(game_mission);
     commands.insert_resource(wad_file);
     commands.set_state(WadLoadState::Loaded);
 }
 
+fn read_wad(path: &Path) -> Result<Vec<u8>> {
+    let mut wad_handle = File::open(path)?;
+    let mut wad_data = Vec::new();
+    wad_handle.read_to_end(&mut wad_data)?;
+    Ok(wad_data)
+}
+
 fn finished_loading(mut loaded_events: EventWriter<WadLoaded>) {
     debug!("Finished Loading IWAD");
     loaded_events.send_default();
@@ -94,24 +129,44 @@ pub struct WadLoaded;
 
 #[derive(Resource, Debug)]
 pub struct WadFile {
+    game_mode: GameMode,
     maps: Maps,
     flats: Flats,
     wall_textures: WallTextures,
+    sprites: Sprites,
     palettes: Palettes,
+    colormaps: Colormaps,
     title_screen: Patch,
+    graphics: Graphics,
+    dehacked_lumps: Vec<Vec<u8>>,
 }
 
 impl WadFile {
+    /// Game mode identified from the lumps of the IWAD.
+    #[must_use]
+    pub fn game_mode(&self) -> GameMode {
+        self.game_mode
+    }
+
     #[must_use]
     pub fn get_palette(&self, palette: PaletteVariant) -> &Palette {
         let pal_num: usize = palette.into();
         &self.palettes[pal_num]
     }
 
+    pub fn colormaps(&self) -> &Colormaps {
+        &self.colormaps
+    }
+
     pub fn title_screen(&self) -> &Patch {
         &self.title_screen
     }
 
+    /// Get the interface graphic named `name`, e.g. `WIMAP0`.
+    pub fn graphic(&self, name: &str) -> Option<&Patch> {
+        self.graphics.get(name)
+    }
+
     pub fn flats(&self) -> &Flats {
         &self.flats
     }
@@ -120,32 +175,113 @@ impl WadFile {
         &self.wall_textures
     }
 
-    pub fn map(&self, episode: usize, map: usize) -> Option<&Map> {
-        self.maps.map(episode, map)
+    pub fn sprites(&self) -> &Sprites {
+        &self.sprites
+    }
+
+    pub fn map(&self, id: MapId) -> Option<&Map> {
+        self.maps.map(id)
+    }
+
+    /// Data of the `DEHACKED` lumps of the PWADs, in the order
+    /// they were loaded.
+    pub fn dehacked_lumps(&self) -> &[Vec<u8>] {
+        &self.dehacked_lumps
     }
 }
 
-struct WadFileParser;
+pub struct WadFileParser;
 
 impl WadFileParser {
-    fn parse(wad_data: &[u8]) -> Result<WadFile> {
-        let wad_header = WadHeaderParser::parse(wad_data)?;
-        let lumps_dir = LumpsDirectoryParser::parse(wad_data, wad_header)?;
+    /// Parses the IWAD and loads every PWAD on top of it. Lumps from the
+    /// PWADs override the ones with the same name loaded before them.
+    pub fn parse(iwad_data: &[u8], pwads_data: &[Vec<u8>]) -> Result<WadFile> {
+        let mut lumps_dir = Self::parse_lumps_dir(iwad_data)?;
+        let game_mode = GameModeParser::parse(&lumps_dir);
+        let mut dehacked_lumps = Vec::new();
+        for pwad_data in pwads_data {
+            let pwad_lumps_dir = Self::parse_lumps_dir(pwad_data)?;
+            // Like Chocolate Doom, patches embedded in the IWAD are ignored.
+            let dehacked = pwad_lumps_dir.get_all("DEHACKED");
+            dehacked_lumps.extend(dehacked.map(|lump| lump.data().to_vec()));
+            lumps_dir.append(pwad_lumps_dir);
+        }
 
         let maps = MapsParser::parse(&lumps_dir)?;
         let flats = FlatsParser::parse(&lumps_dir)?;
         let wall_textures = WallTexturesParser::parse(&lumps_dir)?;
+        let sprites = SpritesParser::parse(&lumps_dir)?;
         let palettes = PalettesParser::parse(&lumps_dir)?;
+        let colormaps = ColormapsParser::parse(&lumps_dir)?;
 
-        let lump = lumps_dir.get("TITLEPIC").unwrap();
+        let Some(lump) = lumps_dir.get("TITLEPIC") else {
+            bail!("Missing title screen lump");
+        };
         let title_screen = PatchParser::parse(lump.data())?;
+        let graphics = GraphicsParser::parse(&lumps_dir)?;
 
         Ok(WadFile {
+            game_mode,
             maps,
             flats,
             wall_textures,
+            sprites,
             palettes,
+            colormaps,
             title_screen,
+            graphics,
+            dehacked_lumps,
         })
     }
+
+    fn parse_lumps_dir(wad_data: &[u8]) -> Result<LumpsDirectory<'_>> {
+        let wad_header = WadHeaderParser::parse(wad_data)?;
+        LumpsDirectoryParser::parse(wad_data, wad_header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::tests::square_room;
+
+    #[test]
+    fn truncated_wad_is_an_error() {
+        let wad = square_room().build();
+        for len in 0..wad.len() {
+            assert!(WadFileParser::parse(&wad[..len], &[]).is_err());
+        }
+    }
+
+    #[test]
+    fn truncated_lumps_dont_panic() {
+        let wad = square_room().build();
+        let header = WadHeaderParser::parse(&wad).unwrap();
+        let lumps_dir = LumpsDirectoryParser::parse(&wad, header).unwrap();
+        for lump in lumps_dir.get_index(..).unwrap_or_default() {
+            // Big lumps are cut at fewer places, to keep the test fast.
+            let step = lump.size().div_ceil(256).max(1);
+            for len in (0..lump.size()).step_by(step) {
+                let mut patched = WadBuilder::from_bytes(&wad).unwrap();
+                patched.replace(lump.name(), &lump.data()[..len]);
+                // Some lumps are still valid when cut, only
+                // panics are a failure.
+                let _ = WadFileParser::parse(&patched.build(), &[]);
+            }
+        }
+    }
+
+    #[test]
+    fn dehacked_lumps_come_from_pwads() {
+        let mut iwad = square_room();
+        iwad.lump("DEHACKED", "iwad");
+        let mut pwad1 = WadBuilder::pwad();
+        pwad1.lump("DEHACKED", "pwad1");
+        let mut pwad2 = WadBuilder::pwad();
+        pwad2.lump("DEHACKED", "pwad2");
+
+        let pwads = [pwad1.build(), pwad2.build()];
+        let wad_file = WadFileParser::parse(&iwad.build(), &pwads).unwrap();
+        assert_eq!(wad_file.dehacked_lumps(), [b"pwad1", b"pwad2"]);
+    }
 }
@@ -83,11 +83,18 @@ pub struct LumpsDirectory<'a> {
 
 impl<'a> LumpsDirectory<'a> {
     /// Get a lump by name.
-    pub fn get(&self, lump_name: &str) -> Option<&Lump> {
+    pub fn get(&self, lump_name: &str) -> Option<&Lump<'a>> {
         let index = self.get_index_of(lump_name)?;
         self.lumps.get(index)
     }
 
+    /// Get every lump named `lump_name`, in the order they were loaded.
+    pub fn get_all<'b>(&'b self, lump_name: &'b str) -> impl Iterator<Item = &'b Lump<'a>> {
+        self.lumps
+            .iter()
+            .filter(move |lump| lump.name.eq_ignore_ascii_case(lump_name))
+    }
+
     /// Get a lump by index.
     pub fn get_index<I>(&self, index: I) -> Option<&I::Output>
     where
@@ -97,23 +104,55 @@ impl<'a> LumpsDirectory<'a> {
     }
 
     /// Get the index of the lump named `lump_name`.
+    ///
+    /// The directory is scanned backwards, so when several lumps share
+    /// the same name the one loaded last (i.e. from the last PWAD) wins.
     pub fn get_index_of(&self, lump_name: &str) -> Option<usize> {
-        for (i, lump) in self.lumps.iter().enumerate() {
-            if lump.name.eq_ignore_ascii_case(lump_name) {
-                return Some(i);
+        self.lumps
+            .iter()
+            .rposition(|lump| lump.name.eq_ignore_ascii_case(lump_name))
+    }
+
+    /// Get all the lumps enclosed by a pair of start and end markers, e.g.
+    /// `F_START` and `F_END`. Every namespace in the directory is visited in
+    /// order, so lumps from PWADs come after the ones they replace.
+    pub fn get_namespace(&self, start_markers: &[&str], end_markers: &[&str]) -> Vec<&Lump<'a>> {
+        let is_any = |lump: &Lump, markers: &[&str]| {
+            markers
+                .iter()
+                .any(|marker| lump.name.eq_ignore_ascii_case(marker))
+        };
+
+        let mut namespace = Vec::new();
+        let mut inside = false;
+        for lump in &self.lumps {
+            if is_any(lump, start_markers) {
+                inside = true;
+            } else if is_any(lump, end_markers) {
+                inside = false;
+            } else if inside {
+                namespace.push(lump);
             }
         }
-        None
+        namespace
+    }
+
+    /// Add the lumps of another directory at the end of this one, the way
+    /// vanilla loads PWADs on top of the IWAD.
+    pub fn append(&mut self, other: LumpsDirectory<'a>) {
+        self.lumps.extend(other.lumps);
     }
 }
 
 pub struct LumpsDirectoryParser;
 
 impl LumpsDirectoryParser {
-    pub fn parse(wad_data: &[u8], header: WadHeader) -> Result<LumpsDirectory> {
+    pub fn parse(wad_data: &[u8], header: WadHeader) -> Result<LumpsDirectory<'_>> {
         let start_offset = header.dir_offset;
         let end_offset = start_offset + (16 * header.num_lumps);
-        let dir_data = &wad_data[start_offset..end_offset];
+        let Some(dir_data) = wad_data.get(start_offset..end_offset) else {
+            bail!("WAD directory exceeds WAD file size");
+        };
 
         let mut lumps = Vec::with_capacity(header.num_lumps);
         for lump_data in dir_data.chunks_exact(16) {
@@ -1,7 +1,7 @@
 use crate::lump::LumpsDirectory;
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
 use bevy::utils::HashMap;
-use block_map::{MapBlockMap, MapBlockMapParser};
+use block_map::MapBlockMapParser;
 use derive_more::{Deref, DerefMut};
 use line::{MapLines, MapLinesParser};
 use node::MapNodesParser;
@@ -9,10 +9,12 @@ use reject::{MapRejectMatrix, MapRejectMatrixParser};
 use sector::{MapSectors, MapSectorsParser};
 use segment::{MapSegments, MapSegmentsParser};
 use side::{MapSideDefs, MapSideDefsParser};
+use std::fmt;
 use sub_sector::{MapSubSectors, MapSubSectorsParser};
 use thing::{MapThings, MapThingsParser};
 use vertex::{MapVertexes, MapVertexesParser};
 
+pub use block_map::MapBlockMap;
 pub use line::MapLine;
 pub use node::{MapNode, MapNodes};
 pub use sector::MapSector;
@@ -33,13 +35,31 @@ mod sub_sector;
 mod thing;
 mod vertex;
 
+/// Identifies a map by its marker lump name. Doom and Ultimate Doom name
+/// their maps `ExMy`, while Doom II and Final Doom use `MAPxx`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MapId {
+    /// `ExMy` naming scheme, used by Doom and Ultimate Doom.
+    Episode { episode: usize, map: usize },
+    /// `MAPxx` naming scheme, used by Doom II and Final Doom.
+    Commercial { map: usize },
+}
+
+impl fmt::Display for MapId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapId::Episode { episode, map } => write!(f, "E{episode}M{map}"),
+            MapId::Commercial { map } => write!(f, "MAP{map:02}"),
+        }
+    }
+}
+
 #[derive(Deref, DerefMut, Debug)]
-pub struct Maps(HashMap<String, Map>);
+pub struct Maps(HashMap<MapId, Map>);
 
 impl Maps {
-    pub fn map(&self, episode: usize, map: usize) -> Option<&Map> {
-        let name = format!("E{episode}M{map}");
-        self.get(&name)
+    pub fn map(&self, id: MapId) -> Option<&Map> {
+        self.get(&id)
     }
 }
 
@@ -51,16 +71,35 @@ impl MapsParser {
 
         for episode in 1..=4 {
             for map in 1..=9 {
-                let map_name = format!("E{episode}M{map}");
-                let Ok(map) = MapParser::parse(lumps_dir, &map_name) else {
+                let map_id = MapId::Episode { episode, map };
+                // Episodes end at their first missing map.
+                let Some(map) = Self::parse_map(lumps_dir, map_id)? else {
                     break;
                 };
-                maps.insert(map_name, map);
+                maps.insert(map_id, map);
             }
         }
+        for map in 1..=32 {
+            let map_id = MapId::Commercial { map };
+            let Some(map) = Self::parse_map(lumps_dir, map_id)? else {
+                break;
+            };
+            maps.insert(map_id, map);
+        }
 
         Ok(Maps(maps))
     }
+
+    /// Parse the map `map_id`, or `None` when its marker lump is missing.
+    fn parse_map(lumps_dir: &LumpsDirectory, map_id: MapId) -> Result<Option<Map>> {
+        let map_name = map_id.to_string();
+        if lumps_dir.get_index_of(&map_name).is_none() {
+            return Ok(None);
+        }
+        let map = MapParser::parse(lumps_dir, &map_name)
+            .with_context(|| format!("Couldn't parse {map_id}"))?;
+        Ok(Some(map))
+    }
 }
 
 #[derive(Debug)]
@@ -83,8 +122,7 @@ pub struct Map {
     pub sectors: MapSectors,
     #[allow(unused)]
     reject_matrix: MapRejectMatrix,
-    #[allow(unused)]
-    block_map: MapBlockMap,
+    pub block_map: MapBlockMap,
 }
 
 pub struct MapParser;
@@ -2,22 +2,48 @@ use crate::lump::LumpsDirectory;
 use crate::util::bytes_to_i16;
 use anyhow::{bail, Result};
 
+/// Grid of 128x128 blocks covering the map, each listing the lines
+/// crossing it. Used to find the lines close to a point without
+/// going through all of them.
 #[derive(Debug)]
 pub struct MapBlockMap {
-    #[allow(unused)]
     pub origin_x: i16,
-    #[allow(unused)]
     pub origin_y: i16,
-    #[allow(unused)]
     pub width: i16,
-    #[allow(unused)]
     pub height: i16,
-    #[allow(unused)]
-    pub offsets: Vec<i16>,
-    #[allow(unused)]
+    /// Offset of the line list of each block, in words from
+    /// the start of the lump. Stored unsigned, as big maps
+    /// have offsets above `i16::MAX`.
+    pub offsets: Vec<u16>,
     pub block_lists: Vec<i16>,
 }
 
+impl MapBlockMap {
+    /// Number of words before the first block list, i.e. the
+    /// header and the offsets.
+    fn lists_start(&self) -> usize {
+        4 + self.offsets.len()
+    }
+
+    /// Get the lines of the block at index `block`, in the
+    /// order they are listed.
+    pub fn block_lines(&self, block: usize) -> impl Iterator<Item = i16> + '_ {
+        let start = self
+            .offsets
+            .get(block)
+            .and_then(|offset| (*offset as usize).checked_sub(self.lists_start()))
+            .unwrap_or(self.block_lists.len());
+        // Lists are terminated by -1. Like vanilla, the leading 0
+        // of every list is kept, so line 0 is in every block.
+        self.block_lists
+            .get(start..)
+            .unwrap_or_default()
+            .iter()
+            .copied()
+            .take_while(|line| *line != -1)
+    }
+}
+
 pub struct MapBlockMapParser;
 
 impl MapBlockMapParser {
@@ -28,19 +54,27 @@ impl MapBlockMapParser {
         };
 
         let block_map_data = lump.data();
+        if block_map_data.len() < 8 {
+            bail!("Block Map Lump for Map Lump #{map_lump} is too small");
+        }
 
         let origin_x = bytes_to_i16(&block_map_data[0..=1])?;
         let origin_y = bytes_to_i16(&block_map_data[2..=3])?;
         let width = bytes_to_i16(&block_map_data[4..=5])?;
         let height = bytes_to_i16(&block_map_data[6..=7])?;
+        if width < 0 || height < 0 {
+            bail!("Block Map Lump for Map Lump #{map_lump} has invalid size");
+        }
 
-        let size = (width * height) as usize;
+        let size = width as usize * height as usize;
+        let Some(offsets_data) = block_map_data.get(8..(8 + 2 * size)) else {
+            bail!("Block Map Lump for Map Lump #{map_lump} is missing offsets");
+        };
 
         let mut offsets = Vec::with_capacity(size);
-        let offsets_data = &block_map_data[8..(8 + 2 * size)];
         for offset_data in offsets_data.chunks_exact(2) {
             let offset = bytes_to_i16(offset_data)?;
-            offsets.push(offset);
+            offsets.push(offset as u16);
         }
 
         let mut block_lists = Vec::new();
@@ -40,7 +40,7 @@ impl MapThingsParser {
 /// Things represent players, monsters, pick-ups, and projectiles.
 /// They also represent obstacles, certain decorations, player start
 /// positions and teleport landing sites. 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct MapThing {
     /// x position
     pub x: i16,
@@ -69,3 +69,16 @@ pub struct MapThing {
     /// Flags
     pub options: i16,
 }
+
+impl MapThing {
+    /// Flag of the things present in the easy skill levels.
+    pub const EASY: i16 = 1;
+    /// Flag of the things present in the medium skill level.
+    pub const NORMAL: i16 = 2;
+    /// Flag of the things present in the hard skill levels.
+    pub const HARD: i16 = 4;
+    /// Flag of the deaf monsters, only woken up by seeing the player.
+    pub const AMBUSH: i16 = 8;
+    /// Flag of the things only present in multiplayer games.
+    pub const NOT_SINGLE: i16 = 16;
+}
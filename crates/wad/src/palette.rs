@@ -101,10 +101,20 @@ impl TryFrom<usize> for PaletteVariant {
 #[derive(Deref, Index, Debug)]
 pub struct Palettes(Vec<Palette>);
 
+impl Palettes {
+    /// One palette per [`PaletteVariant`].
+    const COUNT: usize = 14;
+}
+
 #[derive(Deref, Index, IntoIterator, Debug)]
 #[into_iterator(ref)]
 pub struct Palette(Vec<Color>);
 
+impl Palette {
+    /// 256 colors of 3 bytes each.
+    const SIZE: usize = 768;
+}
+
 #[derive(Debug)]
 pub struct Color {
     pub r: u8,
@@ -120,8 +130,19 @@ impl PalettesParser {
             bail!("Missing palette lump");
         };
         let palettes_data = palettes_lump.data();
-        let mut palettes = Vec::with_capacity(14);
-        for palette_data in palettes_data.chunks_exact(768) {
+        if palettes_data.len() < Palettes::COUNT * Palette::SIZE {
+            bail!(
+                "Palette lump has invalid size: expected {} bytes, got {} bytes",
+                Palettes::COUNT * Palette::SIZE,
+                palettes_data.len()
+            );
+        }
+
+        let mut palettes = Vec::with_capacity(Palettes::COUNT);
+        for palette_data in palettes_data
+            .chunks_exact(Palette::SIZE)
+            .take(Palettes::COUNT)
+        {
             let palette = PaletteParser::parse(palette_data)?;
             palettes.push(palette);
         }
@@ -21,8 +21,10 @@ pub struct Patch {
 }
 
 impl Patch {
+    /// Get the column at `col`, which must be less than the width
+    /// of the patch. The parser makes sure there is one per column.
     pub fn column(&self, col: usize) -> &Column {
-        self.columns.get(col).unwrap()
+        &self.columns[col]
     }
 }
 
@@ -45,7 +47,9 @@ impl PatchParser {
         let mut columns = Vec::with_capacity(header.width);
 
         for offset in &header.column_offsets {
-            let column_bytes = &lump_data[*offset..];
+            let Some(column_bytes) = lump_data.get(*offset..) else {
+                bail!("Patch has invalid column offset");
+            };
             let column = ColumnParser::parse(column_bytes)?;
             columns.push(column);
         }
@@ -69,12 +73,13 @@ impl DrawPath for Buffer {
         let Some(y) = y.checked_add_signed(-top_offset) else {
             bail!("Bad V_DrawPatch");
         };
-        assert!(
-            x < self.width()
-                && (x + patch.width) <= self.width()
-                && (y + patch.height) <= self.height()
-                && y < self.height()
-        );
+        if x >= self.width()
+            || (x + patch.width) > self.width()
+            || (y + patch.height) > self.height()
+            || y >= self.height()
+        {
+            bail!("Bad V_DrawPatch");
+        }
 
         let width = cmp::min(self.width(), patch.width);
         for col in 0..width {
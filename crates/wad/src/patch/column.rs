@@ -1,5 +1,5 @@
 use crate::wall_texture::WallTexture;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use common::Buffer;
 
 #[derive(Debug)]
@@ -40,7 +40,7 @@ impl Column {
 
             for i in 0..count {
                 let y = position + i;
-                tex[(tex_col, y)] = post.data[i];
+                tex.put_pixel(tex_col, y, post.data[i]);
             }
         }
     }
@@ -49,7 +49,11 @@ impl Column {
         for post in &self.posts {
             for row in 0..post.length {
                 let buffer_y = y + row + post.top_delta;
-                buffer[(x, buffer_y)] = post.data[row];
+                // Posts may reach past the height of the patch.
+                let Some(pixel) = buffer.get_mut(x, buffer_y) else {
+                    break;
+                };
+                *pixel = post.data[row];
             }
         }
     }
@@ -72,12 +76,21 @@ impl ColumnParser {
         let mut col_length = 0;
         let mut post_bytes = bytes;
         loop {
-            let top_delta = post_bytes[0];
+            let Some(&top_delta) = post_bytes.first() else {
+                bail!("Patch column is missing its end marker");
+            };
             if top_delta == 255 {
                 break;
             }
-            let length = post_bytes[1] as usize;
-            let post_data = &post_bytes[3..(3 + length)];
+            // Posts are made of the top delta, the length, an unused
+            // byte, the pixels, then another unused byte.
+            let Some(&length) = post_bytes.get(1) else {
+                bail!("Patch column has truncated post");
+            };
+            let length = length as usize;
+            let Some(post_data) = post_bytes.get(3..(3 + length)) else {
+                bail!("Patch column has truncated post");
+            };
 
             let post = Post {
                 top_delta: top_delta as usize,
@@ -87,7 +100,7 @@ impl ColumnParser {
             col_length += post.data.len();
             posts.push(post);
 
-            post_bytes = &post_bytes[(length + 4)..];
+            post_bytes = post_bytes.get((length + 4)..).unwrap_or_default();
         }
 
         Ok(Column {
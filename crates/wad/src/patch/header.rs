@@ -12,7 +12,13 @@ pub struct PatchHeader {
 pub struct PatchHeaderParser;
 
 impl PatchHeaderParser {
+    const SIZE: usize = 8;
+
     pub fn parse(lump_data: &[u8]) -> Result<PatchHeader> {
+        if lump_data.len() < Self::SIZE {
+            bail!("Patch is too small to hold a header");
+        }
+
         let width_bytes = &lump_data[0..2];
         let width = Self::parse_width(width_bytes)?;
 
@@ -25,7 +31,7 @@ impl PatchHeaderParser {
         let top_ofs_bytes = &lump_data[6..8];
         let top_offset = bytes_to_i16(top_ofs_bytes)?;
 
-        let offsets_bytes = &lump_data[8..];
+        let offsets_bytes = &lump_data[Self::SIZE..];
         let column_offsets = Self::parse_offsets(offsets_bytes, width)?;
 
         Ok(PatchHeader {
@@ -54,6 +60,9 @@ impl PatchHeaderParser {
     }
 
     fn parse_offsets(bytes: &[u8], width: usize) -> Result<Vec<usize>> {
+        if bytes.len() < 4 * width {
+            bail!("Patch header is missing column offsets");
+        }
         let mut offsets = Vec::with_capacity(width);
         for ofs_bytes in bytes.chunks_exact(4).take(width) {
             let offset = bytes_to_i32(ofs_bytes)?;
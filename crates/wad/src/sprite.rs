@@ -0,0 +1,247 @@
+use crate::index_map::IndexMap;
+use crate::lump::{Lump, LumpsDirectory};
+use crate::patch::{Patch, PatchParser};
+use anyhow::{bail, Result};
+use bevy::log::warn;
+
+/// Lump of a sprite used by a frame, seen from one of the 8 angles.
+#[derive(Debug, Clone, Copy)]
+pub struct SpriteRotation {
+    /// Index of the patch in [`Sprites`].
+    pub patch: usize,
+    /// The patch must be drawn mirrored horizontally.
+    pub flipped: bool,
+}
+
+#[derive(Debug)]
+pub struct SpriteFrame {
+    /// When false, the same patch is used for every angle.
+    pub rotate: bool,
+    /// Rotations ordered counterclockwise starting from the front,
+    /// i.e. rotation `1` of the lump name is at index `0`.
+    pub rotations: [SpriteRotation; 8],
+}
+
+impl SpriteFrame {
+    /// Get the rotation seen from `angle`, where `0` is the front
+    /// of the thing and `7` is 45 degrees to its right.
+    pub fn rotation(&self, angle: usize) -> SpriteRotation {
+        if self.rotate {
+            self.rotations[angle % 8]
+        } else {
+            self.rotations[0]
+        }
+    }
+}
+
+/// A sprite is identified by the first 4 characters of its lumps,
+/// e.g. `TROO` for the Imp.
+#[derive(Debug)]
+pub struct Sprite {
+    frames: Vec<SpriteFrame>,
+}
+
+impl Sprite {
+    pub fn frame(&self, frame: usize) -> Option<&SpriteFrame> {
+        self.frames.get(frame)
+    }
+
+    pub fn num_frames(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+#[derive(Debug)]
+pub struct Sprites {
+    sprites: IndexMap<String, Sprite>,
+    patches: Vec<Patch>,
+}
+
+impl Sprites {
+    pub fn get(&self, name: &str) -> Option<&Sprite> {
+        self.sprites.get(name)
+    }
+
+    pub fn get_index_of(&self, name: &str) -> Option<usize> {
+        self.sprites.get_index_of(name)
+    }
+
+    pub fn get_by_index(&self, sprite: usize) -> Option<&Sprite> {
+        self.sprites.get_index(sprite).map(|(_, sprite)| sprite)
+    }
+
+    pub fn patch(&self, rotation: SpriteRotation) -> &Patch {
+        &self.patches[rotation.patch]
+    }
+}
+
+pub struct SpritesParser;
+
+impl SpritesParser {
+    pub fn parse(lumps_dir: &LumpsDirectory) -> Result<Sprites> {
+        let Some(start_marker) = lumps_dir.get_index_of("S_START") else {
+            bail!("WAD file is missing sprite start marker");
+        };
+        let Some(end_marker) = lumps_dir.get_index_of("S_END") else {
+            bail!("WAD file is missing sprite end marker");
+        };
+        if end_marker < start_marker {
+            bail!("WAD file has sprite end marker placed before sprite start marker")
+        }
+
+        // Same as flats, PWADs add their sprites between SS_START and SS_END.
+        let sprite_lumps = lumps_dir.get_namespace(&["S_START", "SS_START"], &["S_END", "SS_END"]);
+
+        // A lump with the same name replaces the old one, but keeps its index.
+        let mut lumps = IndexMap::default();
+        for lump in sprite_lumps {
+            if lump.is_marker() {
+                // Skip marker lumps
+                continue;
+            }
+            lumps.insert(lump.name().to_ascii_uppercase(), lump);
+        }
+
+        let mut builders: IndexMap<String, SpriteBuilder> = IndexMap::default();
+        let mut patches = Vec::with_capacity(lumps.len());
+        for (name, lump) in &lumps {
+            if !is_valid_name(name) {
+                warn!("Skipping lump '{name}' with an invalid sprite name");
+                continue;
+            }
+            let patch = patches.len();
+            patches.push(Self::parse_patch(lump)?);
+
+            let builder = builders.entry(name[0..4].to_owned()).or_default();
+            // The second pair of frame and rotation uses the same
+            // patch, mirrored.
+            for (pair, flipped) in name.as_bytes()[4..].chunks_exact(2).zip([false, true]) {
+                builder.install(name, pair[0], pair[1], patch, flipped)?;
+            }
+        }
+        if patches.is_empty() {
+            bail!("WAD file contains no sprites");
+        }
+
+        let mut sprites = IndexMap::default();
+        for (name, builder) in builders {
+            let sprite = builder.build(&name)?;
+            sprites.insert(name, sprite);
+        }
+
+        Ok(Sprites { sprites, patches })
+    }
+
+    fn parse_patch(lump: &Lump) -> Result<Patch> {
+        match PatchParser::parse(lump.data()) {
+            Ok(patch) => Ok(patch),
+            Err(err) => bail!("Sprite '{}' is not a valid patch: {err}", lump.name()),
+        }
+    }
+}
+
+/// Whether `name` is made of the sprite name followed by one or two
+/// pairs of frame and rotation, e.g. TROOA1 or TROOA2A8.
+fn is_valid_name(name: &str) -> bool {
+    let Some(pairs) = name.as_bytes().get(4..) else {
+        return false;
+    };
+    name.is_ascii()
+        && matches!(pairs.len(), 2 | 4)
+        // Vanilla allows frames past 'Z', e.g. '[' and '\'.
+        && pairs
+            .chunks_exact(2)
+            .all(|pair| pair[0] >= b'A' && (b'0'..=b'8').contains(&pair[1]))
+}
+
+/// Frames of a sprite while its lumps are being collected.
+#[derive(Default)]
+struct SpriteBuilder {
+    frames: Vec<Option<FrameBuilder>>,
+}
+
+struct FrameBuilder {
+    rotate: bool,
+    rotations: [Option<SpriteRotation>; 8],
+}
+
+impl SpriteBuilder {
+    /// Mirrors `R_InstallSpriteLump`.
+    fn install(
+        &mut self,
+        name: &str,
+        frame: u8,
+        rotation: u8,
+        patch: usize,
+        flipped: bool,
+    ) -> Result<()> {
+        // Both were checked by `is_valid_name`.
+        let frame = (frame - b'A') as usize;
+        let rotation = rotation - b'0';
+
+        if self.frames.len() <= frame {
+            self.frames.resize_with(frame + 1, || None);
+        }
+        let sprite_rotation = Some(SpriteRotation { patch, flipped });
+
+        match (&mut self.frames[frame], rotation) {
+            (entry @ None, 0) => {
+                // The same patch is used for all the angles.
+                *entry = Some(FrameBuilder {
+                    rotate: false,
+                    rotations: [sprite_rotation; 8],
+                });
+            }
+            (entry @ None, rotation) => {
+                let mut rotations = [None; 8];
+                rotations[rotation as usize - 1] = sprite_rotation;
+                *entry = Some(FrameBuilder {
+                    rotate: true,
+                    rotations,
+                });
+            }
+            (Some(frame_builder), rotation) => {
+                if !frame_builder.rotate {
+                    bail!("Sprite '{name}' has multiple rot=0 lumps");
+                }
+                if rotation == 0 {
+                    bail!("Sprite '{name}' has rotations and a rot=0 lump");
+                }
+                let entry = &mut frame_builder.rotations[rotation as usize - 1];
+                if entry.is_some() {
+                    bail!("Sprite '{name}' has two lumps mapped to it");
+                }
+                *entry = sprite_rotation;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check that no frame is missing and that every frame
+    /// with rotations has all the 8 angles.
+    fn build(self, name: &str) -> Result<Sprite> {
+        let mut frames = Vec::with_capacity(self.frames.len());
+        for (frame, frame_builder) in self.frames.into_iter().enumerate() {
+            let frame_char = (b'A' + frame as u8) as char;
+            let Some(frame_builder) = frame_builder else {
+                bail!("Sprite '{name}' is missing frame {frame_char}");
+            };
+            let mut rotations = [SpriteRotation {
+                patch: 0,
+                flipped: false,
+            }; 8];
+            for (rotation, entry) in frame_builder.rotations.into_iter().enumerate() {
+                let Some(entry) = entry else {
+                    bail!("Sprite '{name}' frame {frame_char} is missing rotations");
+                };
+                rotations[rotation] = entry;
+            }
+            frames.push(SpriteFrame {
+                rotate: frame_builder.rotate,
+                rotations,
+            });
+        }
+        Ok(Sprite { frames })
+    }
+}
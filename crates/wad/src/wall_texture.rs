@@ -2,7 +2,7 @@ use crate::index_map::IndexMap;
 use crate::lump::{Lump, LumpsDirectory};
 use anyhow::{bail, Result};
 use column::WallTextureColumnsParser;
-use common::Buffer;
+use common::{Array2d, Buffer};
 use definition::{WallTextureDefinition, WallTextureDefinitionsParser};
 use derive_more::{Deref, DerefMut};
 use patch_names::{PatchNames, PatchNamesParser};
@@ -86,14 +86,31 @@ impl WallTexturesParser {
 }
 
 #[derive(Deref, DerefMut, Debug)]
-pub struct WallTexture(Buffer);
+pub struct WallTexture {
+    #[deref]
+    #[deref_mut]
+    data: Buffer,
+    /// Pixels covered by a patch. The others are see-through
+    /// when the texture is used as a masked middle texture.
+    opaque: Array2d<bool>,
+}
 
 impl WallTexture {
     fn new(width: usize, height: usize) -> Self {
-        let mut tex_data = Buffer::new(width, height);
-        tex_data.fill(251);
+        let mut data = Buffer::new(width, height);
+        data.fill(251);
+        let opaque = Array2d::new(width, height);
+
+        WallTexture { data, opaque }
+    }
+
+    pub fn is_opaque(&self, x: usize, y: usize) -> bool {
+        self.opaque[(x, y)]
+    }
 
-        WallTexture(tex_data)
+    pub(crate) fn put_pixel(&mut self, x: usize, y: usize, color: u8) {
+        self.data[(x, y)] = color;
+        self.opaque[(x, y)] = true;
     }
 }
 
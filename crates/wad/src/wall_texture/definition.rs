@@ -9,7 +9,9 @@ pub struct WallTextureDefinitionsParser;
 
 impl WallTextureDefinitionsParser {
     pub fn parse(lump_data: &[u8]) -> anyhow::Result<WallTextureDefinitions> {
-        let num_texture_bytes = &lump_data[0..4];
+        let Some(num_texture_bytes) = lump_data.get(0..4) else {
+            bail!("Texture definitions are missing the number of textures");
+        };
         let num_textures = Self::parse_num_textures(num_texture_bytes)?;
 
         let texture_defs = Self::parse_texture_definitions(lump_data, num_textures)?;
@@ -29,9 +31,12 @@ impl WallTextureDefinitionsParser {
         lump_data: &[u8],
         num_textures: usize,
     ) -> anyhow::Result<Vec<WallTextureDefinition>> {
-        let mut texture_defs = Vec::with_capacity(num_textures);
         let max_offset = lump_data.len();
         let offsets_bytes = &lump_data[4..];
+        if offsets_bytes.len() < 4 * num_textures {
+            bail!("Texture definitions are missing texture offsets");
+        }
+        let mut texture_defs = Vec::with_capacity(num_textures);
         for offset_bytes in offsets_bytes.chunks_exact(4).take(num_textures) {
             let offset = bytes_to_i32(offset_bytes)?;
             if offset < 0 || (offset as usize) > max_offset {
@@ -57,7 +62,14 @@ pub struct WallTextureDefinition {
 struct WallTextureDefinitionParser;
 
 impl WallTextureDefinitionParser {
+    /// Size of a definition, without its patch descriptors.
+    const SIZE: usize = 22;
+
     fn parse(lump_data: &[u8]) -> anyhow::Result<WallTextureDefinition> {
+        if lump_data.len() < Self::SIZE {
+            bail!("Texture definition is truncated");
+        }
+
         let texture_name_bytes = &lump_data[0..8];
         let name = Self::parse_name(texture_name_bytes)?;
 
@@ -70,7 +82,7 @@ impl WallTextureDefinitionParser {
         let num_patches_bytes = &lump_data[20..22];
         let num_descriptors = Self::parse_num_descriptors(num_patches_bytes)?;
 
-        let patch_descriptors_bytes = &lump_data[22..];
+        let patch_descriptors_bytes = &lump_data[Self::SIZE..];
         let patch_descriptors =
             Self::parse_patch_descriptors(patch_descriptors_bytes, num_descriptors)?;
 
@@ -115,6 +127,9 @@ impl WallTextureDefinitionParser {
         bytes: &[u8],
         num_descriptors: usize,
     ) -> anyhow::Result<Vec<PatchDescriptor>> {
+        if bytes.len() < 10 * num_descriptors {
+            bail!("Texture definition is missing patch descriptors");
+        }
         let mut patch_descriptors = Vec::with_capacity(num_descriptors);
         for patch_descriptor_bytes in bytes.chunks_exact(10).take(num_descriptors) {
             let patch_descriptor = PatchDescriptorParser::parse(patch_descriptor_bytes)?;
@@ -39,7 +39,9 @@ impl PatchNamesParser {
 
         let lump_data = patch_names_lump.data();
 
-        let num_patches_bytes = &lump_data[0..4];
+        let Some(num_patches_bytes) = lump_data.get(0..4) else {
+            bail!("PNAMES lump is missing the number of patches");
+        };
         let num_patches = Self::parse_num_patches(num_patches_bytes)?;
 
         let patch_names_bytes = &lump_data[4..];
@@ -63,8 +65,11 @@ impl PatchNamesParser {
     }
 
     fn parse_names(names_bytes: &[u8], num_patches: usize) -> Result<HashMap<usize, String>> {
+        if names_bytes.len() < 8 * num_patches {
+            bail!("PNAMES lump is missing patch names");
+        }
         let mut patch_map = HashMap::with_capacity(num_patches);
-        for (index, patch_name_bytes) in names_bytes.chunks_exact(8).enumerate() {
+        for (index, patch_name_bytes) in names_bytes.chunks_exact(8).take(num_patches).enumerate() {
             let patch_name = bytes_to_str(patch_name_bytes)?;
             patch_map.insert(index, patch_name.to_owned());
         }
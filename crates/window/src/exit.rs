@@ -1,4 +1,5 @@
 use crate::sdl_window::SdlWindow;
+use crate::Headless;
 use bevy::prelude::*;
 use exit::Exit;
 use game_state::conditions::in_exiting_state;
@@ -22,7 +23,11 @@ fn close_window(world: &mut World) {
     world.remove_non_send_resource::<SdlWindow>();
 }
 
-fn handle_exit(mut exit_events: EventReader<Exit>, mut app_exit: EventWriter<AppExit>) {
+fn handle_exit(
+    mut exit_events: EventReader<Exit>,
+    mut app_exit: EventWriter<AppExit>,
+    headless: Option<Res<Headless>>,
+) {
     let Some(exit_event) = exit_events.read().next() else {
         return;
     };
@@ -31,15 +36,15 @@ fn handle_exit(mut exit_events: EventReader<Exit>, mut app_exit: EventWriter<App
             app_exit.send(AppExit::Success);
         }
         Exit::Error(error) => {
-            display_error(error);
+            display_error(error, headless.is_some());
             app_exit.send(AppExit::error());
         }
     }
 }
 
 /// Display the error to the user, either in the terminal or via a dialog box.
-fn display_error(error: &anyhow::Error) {
-    if std::io::stdout().is_terminal() {
+fn display_error(error: &anyhow::Error, headless: bool) {
+    if headless || std::io::stdout().is_terminal() {
         error!("{error:?}");
         return;
     }
@@ -0,0 +1,83 @@
+use super::ScreenBuffer;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use game_state::conditions::in_playing_state;
+use std::collections::VecDeque;
+
+/// Replaces the SDL window when running headless. The screen is sent
+/// to an in-memory [`ScreenSink`], and every update advances the game
+/// by exactly one tic, regardless of how long it took.
+pub struct HeadlessPlugin;
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenSink>()
+            .add_systems(Last, update_sink.run_if(in_playing_state()));
+    }
+
+    fn finish(&self, app: &mut App) {
+        let timestep = app.world().resource::<Time<Fixed>>().timestep();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(timestep));
+    }
+}
+
+fn update_sink(mut sink: ResMut<ScreenSink>, screen: Res<ScreenBuffer>) {
+    sink.push(screen.clone());
+}
+
+/// Collects the frames drawn when running headless. Only the
+/// most recent frames are kept, up to the sink capacity.
+#[derive(Resource, Debug)]
+pub struct ScreenSink {
+    frames: VecDeque<ScreenBuffer>,
+    capacity: usize,
+    frame_count: usize,
+}
+
+impl ScreenSink {
+    /// Create a sink keeping up to `capacity` frames.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            frames: VecDeque::with_capacity(capacity),
+            capacity,
+            frame_count: 0,
+        }
+    }
+
+    /// Get the last frame drawn.
+    pub fn last_frame(&self) -> Option<&ScreenBuffer> {
+        self.frames.back()
+    }
+
+    /// Iterate over the frames kept, from the oldest to the newest.
+    pub fn frames(&self) -> impl Iterator<Item = &ScreenBuffer> {
+        self.frames.iter()
+    }
+
+    /// Remove the frames kept, from the oldest to the newest.
+    pub fn take_frames(&mut self) -> Vec<ScreenBuffer> {
+        self.frames.drain(..).collect()
+    }
+
+    /// Total number of frames drawn, including the dropped ones.
+    pub fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    fn push(&mut self, frame: ScreenBuffer) {
+        self.frame_count += 1;
+        if self.capacity == 0 {
+            return;
+        }
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+    }
+}
+
+impl Default for ScreenSink {
+    fn default() -> Self {
+        Self::with_capacity(1)
+    }
+}
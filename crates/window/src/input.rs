@@ -2,6 +2,9 @@ use bevy::{input::keyboard::KeyboardInput, prelude::*};
 use sdl2::{event::Event, EventPump};
 
 mod keyboard;
+mod script;
+
+pub use script::{InputScript, ScriptedInput, ScriptedInputPlugin};
 
 pub struct InputPlugin;
 
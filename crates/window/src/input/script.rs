@@ -0,0 +1,248 @@
+use anyhow::{bail, Context, Result};
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput, NativeKey},
+        ButtonState,
+    },
+    prelude::*,
+};
+use cli::CommandLine;
+use exit::macros::sys_fail;
+use std::collections::VecDeque;
+use std::fs;
+
+/// Feeds the game with the input of an [`InputScript`] instead of
+/// the keyboard, used when running headless.
+pub struct ScriptedInputPlugin;
+
+impl Plugin for ScriptedInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputScript>()
+            .add_systems(Startup, load_input_script)
+            .add_systems(PreUpdate, dispatch_scripted_events);
+    }
+}
+
+#[sys_fail]
+fn load_input_script(cli: Option<Res<CommandLine>>, mut script: ResMut<InputScript>) {
+    let Some(path) = cli.as_ref().and_then(|cli| cli.input_script.as_ref()) else {
+        return Ok(());
+    };
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Could not read input script {path:?}"))?;
+    *script = InputScript::parse(&text)?;
+}
+
+fn dispatch_scripted_events(
+    mut script: ResMut<InputScript>,
+    mut exit_events: EventWriter<AppExit>,
+    mut keyboard_events: EventWriter<KeyboardInput>,
+) {
+    for input in script.advance() {
+        match input {
+            ScriptedInput::Quit => {
+                exit_events.send(AppExit::Success);
+            }
+            ScriptedInput::Press(key_code) => {
+                keyboard_events.send(key_event(key_code, ButtonState::Pressed));
+            }
+            ScriptedInput::Release(key_code) => {
+                keyboard_events.send(key_event(key_code, ButtonState::Released));
+            }
+        }
+    }
+}
+
+fn key_event(key_code: KeyCode, state: ButtonState) -> KeyboardInput {
+    KeyboardInput {
+        key_code,
+        state,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptedInput {
+    Press(KeyCode),
+    Release(KeyCode),
+    Quit,
+}
+
+/// Input sent to the game at given tics. Headless runs advance one
+/// tic per update, so the same script always gives the same run.
+///
+/// Scripts can also be read from text, one input per line:
+///
+/// ```text
+/// # Walk forward for a second, then quit.
+/// 0 press ArrowUp
+/// 35 release ArrowUp
+/// 40 quit
+/// ```
+///
+/// Keys are named after [`KeyCode`] variants.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct InputScript {
+    /// Inputs sorted by tic.
+    inputs: VecDeque<(u32, ScriptedInput)>,
+    tic: u32,
+}
+
+impl InputScript {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Press `key_code` at `tic`.
+    pub fn press(self, tic: u32, key_code: KeyCode) -> Self {
+        self.with(tic, ScriptedInput::Press(key_code))
+    }
+
+    /// Release `key_code` at `tic`.
+    pub fn release(self, tic: u32, key_code: KeyCode) -> Self {
+        self.with(tic, ScriptedInput::Release(key_code))
+    }
+
+    /// Hold `key_code` from tic `start` until tic `end`.
+    pub fn hold(self, key_code: KeyCode, start: u32, end: u32) -> Self {
+        self.press(start, key_code).release(end, key_code)
+    }
+
+    /// Exit the game at `tic`.
+    pub fn quit(self, tic: u32) -> Self {
+        self.with(tic, ScriptedInput::Quit)
+    }
+
+    /// Current tic of the script.
+    pub fn tic(&self) -> u32 {
+        self.tic
+    }
+
+    /// All the inputs have been sent.
+    pub fn is_done(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut script = Self::new();
+        for (line_num, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let words: Vec<_> = line.split_whitespace().collect();
+            let Ok(tic) = words[0].parse() else {
+                bail!("Input script line {}: invalid tic", line_num + 1);
+            };
+            let input = match words[1..] {
+                ["press", key] => ScriptedInput::Press(parse_key_code(key, line_num)?),
+                ["release", key] => ScriptedInput::Release(parse_key_code(key, line_num)?),
+                ["quit"] => ScriptedInput::Quit,
+                _ => bail!("Input script line {}: invalid input", line_num + 1),
+            };
+            script = script.with(tic, input);
+        }
+        Ok(script)
+    }
+
+    fn with(mut self, tic: u32, input: ScriptedInput) -> Self {
+        // Inputs of the same tic are sent in the order they were added.
+        let index = self.inputs.partition_point(|(other, _)| *other <= tic);
+        self.inputs.insert(index, (tic, input));
+        self
+    }
+
+    /// Get the inputs of the current tic, and move to the next one.
+    fn advance(&mut self) -> Vec<ScriptedInput> {
+        let count = self.inputs.partition_point(|(tic, _)| *tic <= self.tic);
+        self.tic += 1;
+        self.inputs.drain(..count).map(|(_, input)| input).collect()
+    }
+}
+
+fn parse_key_code(name: &str, line_num: usize) -> Result<KeyCode> {
+    let key_code = SCRIPT_KEYS
+        .iter()
+        .find(|key_code| format!("{key_code:?}") == name);
+    match key_code {
+        Some(key_code) => Ok(*key_code),
+        None => bail!("Input script line {}: unknown key {name}", line_num + 1),
+    }
+}
+
+/// Keys that can be used in text scripts.
+const SCRIPT_KEYS: [KeyCode; 71] = [
+    KeyCode::KeyA,
+    KeyCode::KeyB,
+    KeyCode::KeyC,
+    KeyCode::KeyD,
+    KeyCode::KeyE,
+    KeyCode::KeyF,
+    KeyCode::KeyG,
+    KeyCode::KeyH,
+    KeyCode::KeyI,
+    KeyCode::KeyJ,
+    KeyCode::KeyK,
+    KeyCode::KeyL,
+    KeyCode::KeyM,
+    KeyCode::KeyN,
+    KeyCode::KeyO,
+    KeyCode::KeyP,
+    KeyCode::KeyQ,
+    KeyCode::KeyR,
+    KeyCode::KeyS,
+    KeyCode::KeyT,
+    KeyCode::KeyU,
+    KeyCode::KeyV,
+    KeyCode::KeyW,
+    KeyCode::KeyX,
+    KeyCode::KeyY,
+    KeyCode::KeyZ,
+    KeyCode::Digit0,
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+    KeyCode::ArrowUp,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowRight,
+    KeyCode::Space,
+    KeyCode::Enter,
+    KeyCode::Escape,
+    KeyCode::Tab,
+    KeyCode::Backspace,
+    KeyCode::ShiftLeft,
+    KeyCode::ShiftRight,
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::AltLeft,
+    KeyCode::AltRight,
+    KeyCode::Comma,
+    KeyCode::Period,
+    KeyCode::Minus,
+    KeyCode::Equal,
+    KeyCode::Pause,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::PageUp,
+    KeyCode::PageDown,
+    KeyCode::PrintScreen,
+];
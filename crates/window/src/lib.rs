@@ -1,14 +1,23 @@
 use bevy::{app::PluginsState, prelude::*};
+use cli::CommandLine;
 use common::Buffer;
 use derive_more::{Deref, DerefMut};
 use exit::ExitAppPlugin;
-use input::InputPlugin;
+use headless::HeadlessPlugin;
+use input::{InputPlugin, ScriptedInputPlugin};
 use palette::PalettePlugin;
+use screenshot::ScreenshotPlugin;
 use sdl_window::SdlWindowPlugin;
 
+pub use headless::ScreenSink;
+pub use input::{InputScript, ScriptedInput};
+pub use screenshot::{ScreenshotSettings, TakeScreenshot};
+
 mod exit;
+mod headless;
 mod input;
 pub mod palette;
+mod screenshot;
 mod sdl_window;
 
 const SCREEN_WIDTH: u32 = 320;
@@ -26,16 +35,37 @@ const SCREEN_HEIGHT: u32 = 200;
 const SCREEN_HEIGHT_4_3: u32 = 240;
 
 #[derive(Default)]
-pub struct WindowPlugin;
+pub struct WindowPlugin {
+    /// Run without SDL, as if the `--headless` command line
+    /// parameter was given.
+    pub headless: bool,
+}
 
 impl Plugin for WindowPlugin {
     fn build(&self, app: &mut App) {
+        let headless = self.headless
+            || app
+                .world()
+                .get_resource::<CommandLine>()
+                .is_some_and(|cli| cli.headless);
+
         app.init_resource::<ScreenBuffer>()
-            .add_plugins((InputPlugin, PalettePlugin, SdlWindowPlugin, ExitAppPlugin))
+            .add_plugins((PalettePlugin, ScreenshotPlugin, ExitAppPlugin))
             .set_runner(window_runner);
+        if headless {
+            app.insert_resource(Headless)
+                .add_plugins((ScriptedInputPlugin, HeadlessPlugin));
+        } else {
+            app.add_plugins((InputPlugin, SdlWindowPlugin));
+        }
     }
 }
 
+/// Present when running without a window, the screen going
+/// to the [`ScreenSink`] and the input coming from an [`InputScript`].
+#[derive(Resource, Debug)]
+pub struct Headless;
+
 fn window_runner(mut app: App) -> AppExit {
     if app.plugins_state() != PluginsState::Cleaned {
         while app.plugins_state() == PluginsState::Adding {
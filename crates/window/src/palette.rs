@@ -1,6 +1,8 @@
 use crate::sdl_window::SdlWindow;
 use anyhow::Error;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use derive_more::Deref;
 use exit::macros::sys_fail;
 use game_state::conditions::{in_playing_state, in_setup_state};
 use gamma_table::GAMMA_TABLE;
@@ -58,40 +60,69 @@ fn set_palette(mut palette_events: EventReader<SetPalette>, mut current_palette:
 }
 
 #[sys_fail]
-fn set_window_palette(
-    current_palette: Res<Palette>,
-    gamma_level: Res<GammaLevel>,
-    mut sdl_window: NonSendMut<SdlWindow>,
-    wad_file: Res<WadFile>,
-) {
-    let palette = wad_file.get_palette(current_palette.0);
-    let gamma = &GAMMA_TABLE[gamma_level.0];
-    let mut sdl_palette = Vec::with_capacity(256);
-
-    for color in palette {
-        let mut r = color.r;
-        let mut g = color.g;
-        let mut b = color.b;
-
-        // Apply gamma correction.
-        r = gamma[r as usize];
-        g = gamma[g as usize];
-        b = gamma[b as usize];
-
-        // Zero out the bottom two bits of each channel:
-        // the PC VGA controller only supports 6 bits of accuracy.
-        r &= !3;
-        g &= !3;
-        b &= !3;
-
-        let sdl_color: SdlColor = (r, g, b).into();
-        sdl_palette.push(sdl_color);
-    }
+fn set_window_palette(screen_palette: ScreenPalette, sdl_window: Option<NonSendMut<SdlWindow>>) {
+    let Some(mut sdl_window) = sdl_window else {
+        // Running headless.
+        return Ok(());
+    };
+    let sdl_palette: Vec<SdlColor> = screen_palette
+        .colors()
+        .iter()
+        .map(|&[r, g, b]| (r, g, b).into())
+        .collect();
 
     let sdl_palette = SdlPalette::with_colors(&sdl_palette).map_err(Error::msg)?;
     sdl_window.update_palette(&sdl_palette)?;
 }
 
+/// Gives access to the colors the screen is currently shown with.
+#[derive(SystemParam)]
+pub struct ScreenPalette<'w> {
+    current_palette: Res<'w, Palette>,
+    gamma_level: Res<'w, GammaLevel>,
+    wad_file: Res<'w, WadFile>,
+}
+
+impl ScreenPalette<'_> {
+    /// Get the colors of the current palette, at the current gamma level.
+    pub fn colors(&self) -> ScreenColors {
+        ScreenColors::new(&self.wad_file, self.current_palette.0, self.gamma_level.0)
+    }
+}
+
+/// RGB colors of the 256 indices of the screen buffer.
+#[derive(Deref, Clone, Debug)]
+pub struct ScreenColors(Vec<[u8; 3]>);
+
+impl ScreenColors {
+    pub fn new(wad_file: &WadFile, variant: PaletteVariant, gamma_level: usize) -> Self {
+        let palette = wad_file.get_palette(variant);
+        let gamma = &GAMMA_TABLE[gamma_level % GAMMA_TABLE.len()];
+        let mut colors = Vec::with_capacity(256);
+
+        for color in palette {
+            let mut r = color.r;
+            let mut g = color.g;
+            let mut b = color.b;
+
+            // Apply gamma correction.
+            r = gamma[r as usize];
+            g = gamma[g as usize];
+            b = gamma[b as usize];
+
+            // Zero out the bottom two bits of each channel:
+            // the PC VGA controller only supports 6 bits of accuracy.
+            r &= !3;
+            g &= !3;
+            b &= !3;
+
+            colors.push([r, g, b]);
+        }
+
+        ScreenColors(colors)
+    }
+}
+
 #[derive(Resource, Default, Debug)]
 struct GammaLevel(pub usize);
 
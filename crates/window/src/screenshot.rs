@@ -0,0 +1,229 @@
+use super::{ScreenBuffer, SCREEN_HEIGHT, SCREEN_HEIGHT_4_3};
+use crate::palette::{ScreenColors, ScreenPalette};
+use anyhow::{bail, Context, Result};
+use bevy::prelude::*;
+use game_state::conditions::in_playing_state;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Key saving a screenshot of the game.
+const KEY_SCREENSHOT: KeyCode = KeyCode::PrintScreen;
+
+/// Most screenshots that can be saved, named `DOOM00` to `DOOM99`.
+const MAX_SCREENSHOTS: usize = 100;
+
+/// Saves the screen to PNG and PCX files, when the screenshot
+/// key is pressed or a [`TakeScreenshot`] event is sent.
+pub struct ScreenshotPlugin;
+
+impl Plugin for ScreenshotPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TakeScreenshot>()
+            .init_resource::<ScreenshotSettings>()
+            .add_systems(
+                PreUpdate,
+                screenshot_key
+                    .after(bevy::input::InputSystem)
+                    .run_if(resource_exists::<ButtonInput<KeyCode>>),
+            )
+            .add_systems(
+                Last,
+                take_screenshot.run_if(on_event::<TakeScreenshot>.and(in_playing_state())),
+            );
+    }
+}
+
+/// Save the screen, once the frame is drawn.
+#[derive(Event, Default)]
+pub struct TakeScreenshot;
+
+#[derive(Resource, Debug)]
+pub struct ScreenshotSettings {
+    /// Folder where screenshots are saved.
+    pub directory: PathBuf,
+    /// Stretch the PNG screenshots to 4:3, like the screen
+    /// looked on a CRT monitor.
+    pub aspect_correction: bool,
+}
+
+impl Default for ScreenshotSettings {
+    fn default() -> Self {
+        Self {
+            directory: PathBuf::from("."),
+            aspect_correction: false,
+        }
+    }
+}
+
+fn screenshot_key(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut screenshot_events: EventWriter<TakeScreenshot>,
+) {
+    if keyboard.just_pressed(KEY_SCREENSHOT) {
+        screenshot_events.send_default();
+    }
+}
+
+/// A failed screenshot is only reported, not to end the game.
+fn take_screenshot(
+    mut screenshot_events: EventReader<TakeScreenshot>,
+    screen: Res<ScreenBuffer>,
+    screen_palette: ScreenPalette,
+    settings: Res<ScreenshotSettings>,
+) {
+    screenshot_events.clear();
+    let colors = screen_palette.colors();
+    match save_screenshot(&screen, &colors, &settings) {
+        Ok(name) => info!("Screen shot {name}"),
+        Err(err) => warn!("{err:#}"),
+    }
+}
+
+/// Save the screen under the next free `DOOMnn` name, returned.
+///
+/// Mirrors `M_ScreenShot`.
+fn save_screenshot(
+    screen: &ScreenBuffer,
+    colors: &ScreenColors,
+    settings: &ScreenshotSettings,
+) -> Result<String> {
+    let name = next_screenshot_name(&settings.directory)?;
+    let path = settings.directory.join(&name);
+    screen.save_png(
+        path.with_extension("png"),
+        colors,
+        settings.aspect_correction,
+    )?;
+    screen.save_pcx(path.with_extension("pcx"), colors)?;
+    Ok(name)
+}
+
+/// Find the first `DOOMnn` name not used by a screenshot yet.
+fn next_screenshot_name(directory: &Path) -> Result<String> {
+    for number in 0..MAX_SCREENSHOTS {
+        let name = format!("DOOM{number:02}");
+        let path = directory.join(&name);
+        if !path.with_extension("png").exists() && !path.with_extension("pcx").exists() {
+            return Ok(name);
+        }
+    }
+    bail!("Couldn't create a screenshot, all {MAX_SCREENSHOTS} names are taken");
+}
+
+impl ScreenBuffer {
+    /// Convert the screen to RGB pixels, row by row. With `aspect_correction`,
+    /// rows are repeated to stretch the screen to a 4:3 aspect ratio.
+    pub fn to_rgb(&self, colors: &ScreenColors, aspect_correction: bool) -> Vec<u8> {
+        let width = self.width();
+        let height = self.output_height(aspect_correction);
+        let mut rgb = Vec::with_capacity(width * height * 3);
+        for y in 0..height {
+            let src_y = y * self.height() / height;
+            for x in 0..width {
+                let index = self[(x, src_y)] as usize;
+                rgb.extend_from_slice(&colors.get(index).copied().unwrap_or_default());
+            }
+        }
+        rgb
+    }
+
+    /// Write the screen as a PNG image.
+    pub fn write_png(
+        &self,
+        writer: impl Write,
+        colors: &ScreenColors,
+        aspect_correction: bool,
+    ) -> Result<()> {
+        let width = self.width() as u32;
+        let height = self.output_height(aspect_correction) as u32;
+        let mut encoder = png::Encoder::new(writer, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.to_rgb(colors, aspect_correction))?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Write the screen as a 256 colors PCX image, the format of
+    /// vanilla screenshots.
+    ///
+    /// Mirrors `WritePCXfile`.
+    pub fn write_pcx(&self, mut writer: impl Write, colors: &ScreenColors) -> Result<()> {
+        let width = self.width() as u16;
+        let height = self.height() as u16;
+
+        let mut header = Vec::with_capacity(128);
+        header.extend_from_slice(&[
+            0x0a, // Manufacturer.
+            5,    // Version.
+            1,    // Run length encoding.
+            8,    // Bits per pixel.
+        ]);
+        for value in [0, 0, width - 1, height - 1, width, height] {
+            // Window, then horizontal and vertical resolutions.
+            header.extend_from_slice(&value.to_le_bytes());
+        }
+        header.extend_from_slice(&[0; 48]); // Palette, unused for 256 colors.
+        header.push(0); // Reserved.
+        header.push(1); // Color planes.
+        header.extend_from_slice(&width.to_le_bytes()); // Bytes per line.
+        header.extend_from_slice(&2u16.to_le_bytes()); // Palette type.
+        header.resize(128, 0);
+        writer.write_all(&header)?;
+
+        // Pack the image, without actually compressing it.
+        let mut data = Vec::with_capacity(self.size() * 2);
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let index = self[(x, y)];
+                if index & 0xc0 == 0xc0 {
+                    data.push(0xc1);
+                }
+                data.push(index);
+            }
+        }
+        writer.write_all(&data)?;
+
+        // Write the palette.
+        let mut palette = Vec::with_capacity(1 + 256 * 3);
+        palette.push(0x0c);
+        for index in 0..256 {
+            palette.extend_from_slice(&colors.get(index).copied().unwrap_or_default());
+        }
+        writer.write_all(&palette)?;
+        Ok(())
+    }
+
+    /// Save the screen to a PNG file.
+    pub fn save_png(
+        &self,
+        path: impl AsRef<Path>,
+        colors: &ScreenColors,
+        aspect_correction: bool,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let file = File::create(path).with_context(|| format!("Couldn't create {path:?}"))?;
+        self.write_png(BufWriter::new(file), colors, aspect_correction)
+    }
+
+    /// Save the screen to a PCX file.
+    pub fn save_pcx(&self, path: impl AsRef<Path>, colors: &ScreenColors) -> Result<()> {
+        let path = path.as_ref();
+        let file = File::create(path).with_context(|| format!("Couldn't create {path:?}"))?;
+        let mut writer = BufWriter::new(file);
+        self.write_pcx(&mut writer, colors)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn output_height(&self, aspect_correction: bool) -> usize {
+        if aspect_correction {
+            self.height() * SCREEN_HEIGHT_4_3 as usize / SCREEN_HEIGHT as usize
+        } else {
+            self.height()
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::light::scale_light;
+use bevy::prelude::*;
+use std::cmp;
+use wad::prelude::*;
+use window::ScreenBuffer;
+
+/// A fragment of a segment that has been drawn on screen. Draw segments
+/// are kept until the end of the frame, so sprites and masked middle
+/// textures, which are drawn last, can be clipped by the walls in front
+/// of them.
+#[derive(Debug)]
+pub struct DrawSeg {
+    pub x1: usize,
+    pub x2: usize,
+    pub scale1: f32,
+    pub scale2: f32,
+    pub scale_step: f32,
+    /// First vertex of the segment in world space.
+    pub v1: Vec2,
+    pub normal: Dir2,
+    /// Sprites with their bottom below this height are clipped by
+    /// `bottom_clip`, e.g. when standing behind a step.
+    pub bottom_sil_height: Option<f32>,
+    /// Sprites with their top above this height are clipped by
+    /// `top_clip`, e.g. when standing behind a lower ceiling.
+    pub top_sil_height: Option<f32>,
+    /// Copy of the floor clip after the fragment was drawn, from `x1` to `x2`.
+    pub bottom_clip: Option<Vec<i32>>,
+    /// Copy of the ceiling clip after the fragment was drawn, from `x1` to `x2`.
+    pub top_clip: Option<Vec<i32>>,
+    pub masked: Option<MaskedTexture>,
+}
+
+impl DrawSeg {
+    /// Mirrors `R_PointOnSegSide`.
+    pub fn is_in_front(&self, point: Vec2) -> bool {
+        (point - self.v1).dot(*self.normal) > 0.0
+    }
+
+    pub fn scale(&self, x: usize) -> f32 {
+        self.scale1 + ((x - self.x1) as f32 * self.scale_step)
+    }
+
+    pub fn bottom_clip(&self, x: usize) -> Option<i32> {
+        let clip = self.bottom_clip.as_ref()?;
+        Some(clip[x - self.x1])
+    }
+
+    pub fn top_clip(&self, x: usize) -> Option<i32> {
+        let clip = self.top_clip.as_ref()?;
+        Some(clip[x - self.x1])
+    }
+
+    /// Draw the columns `x1..=x2` of the masked middle texture,
+    /// skipping the ones already drawn.
+    ///
+    /// Mirrors `R_RenderMaskedSegRange`.
+    pub fn render_masked_range(
+        &mut self,
+        screen: &mut ScreenBuffer,
+        wad: &WadFile,
+        x1: usize,
+        x2: usize,
+    ) {
+        let Some(mut masked) = self.masked.take() else {
+            return;
+        };
+        let texture = wad.wall_textures().get_by_index(masked.texture).unwrap();
+
+        for x in x1..=x2 {
+            let Some(offset) = masked.columns[x - self.x1].take() else {
+                continue;
+            };
+            let scale = self.scale(x);
+            let iscale = scale.recip();
+            let colormap = &wad.colormaps()[scale_light(masked.light_num, scale)];
+            let tex_col = (offset as usize) % texture.width();
+
+            // Masked textures do not tile vertically.
+            let top = 100.0 - (masked.tex_mid * scale);
+            let bottom = top + (texture.height() as f32 * scale);
+            let clip_top = self.top_clip(x).unwrap_or(-1);
+            let clip_bottom = self.bottom_clip(x).unwrap_or(200);
+            let yl = cmp::max(top.ceil() as i32, clip_top + 1);
+            let yh = cmp::min(bottom.ceil() as i32 - 1, clip_bottom - 1);
+
+            for y in yl..=yh {
+                let dy = (y - 100) as f32;
+                let row = (masked.tex_mid + (dy * iscale)).max(0.0) as usize;
+                let row = cmp::min(row, texture.height() - 1);
+                if texture.is_opaque(tex_col, row) {
+                    screen[(x, y as usize)] = colormap[texture[(tex_col, row)] as usize];
+                }
+            }
+        }
+
+        self.masked = Some(masked);
+    }
+}
+
+/// Middle texture of a two-sided line. Only the pixels covered by
+/// patches are drawn, so what is behind can be seen through.
+#[derive(Debug)]
+pub struct MaskedTexture {
+    pub texture: usize,
+    pub tex_mid: f32,
+    pub light_num: i32,
+    /// Texture offset of each column, from `x1` to `x2`. Set to `None`
+    /// once the column has been drawn.
+    pub columns: Vec<Option<f32>>,
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct DrawSegs(Vec<DrawSeg>);
+
+impl DrawSegs {
+    pub fn reset(&mut self) {
+        self.clear();
+    }
+}
@@ -1,4 +1,7 @@
 use crate::depth_clipping::{DepthClipping, ScreenOcclusion, SegmentFragment};
+use crate::draw_seg::{DrawSeg, DrawSegs, MaskedTexture};
+use crate::light::{light_num, scale_light, wall_light_num};
+use crate::vis_sprite::{draw_player_sprite, draw_sprite, VisSprite, VisSprites};
 use bevy::{
     ecs::{
         schedule::ScheduleLabel,
@@ -7,13 +10,22 @@ use bevy::{
     prelude::*,
 };
 use game_state::conditions::in_level_state;
-use level::prelude::*;
+use info::{Info, MobjFlags};
 use level::prelude::Camera;
+use level::prelude::*;
 use moonshine_kind::Instance;
+use sky::draw_sky;
+use std::cmp;
+use visplane::{draw_span, Visplanes};
 use wad::prelude::*;
 use window::ScreenBuffer;
 
 mod depth_clipping;
+mod draw_seg;
+mod light;
+mod sky;
+mod vis_sprite;
+mod visplane;
 
 /// Plugin responsible for rendering the 3D view of the world.
 #[derive(Default)]
@@ -26,10 +38,22 @@ impl Plugin for WorldViewPlugin {
             .add_event::<RenderFragment>()
             .init_resource::<ScreenOcclusion>()
             .init_resource::<VerticalClip>()
+            .init_resource::<Visplanes>()
+            .init_resource::<DrawSegs>()
+            .init_resource::<VisSprites>()
             .init_schedule(RenderSubSectorSchedule)
             .add_systems(
                 PostUpdate,
-                (prepare, render_sectors).chain().run_if(in_level_state()),
+                (
+                    prepare,
+                    render_sectors,
+                    render_planes,
+                    project_sprites,
+                    render_masked,
+                    draw_player_sprites,
+                )
+                    .chain()
+                    .run_if(in_level_state()),
             )
             .add_systems(
                 RenderSubSectorSchedule,
@@ -38,9 +62,18 @@ impl Plugin for WorldViewPlugin {
     }
 }
 
-fn prepare(mut depth_clipping: DepthClipping, mut vertical_clip: ResMut<VerticalClip>) {
+fn prepare(
+    mut depth_clipping: DepthClipping,
+    mut vertical_clip: ResMut<VerticalClip>,
+    mut visplanes: ResMut<Visplanes>,
+    mut draw_segs: ResMut<DrawSegs>,
+    mut vis_sprites: ResMut<VisSprites>,
+) {
     depth_clipping.reset();
     vertical_clip.reset();
+    visplanes.reset();
+    draw_segs.reset();
+    vis_sprites.reset();
 }
 
 /// Render solid walls and portals (two-sided lines that connect sectors).
@@ -65,16 +98,141 @@ fn render_sectors(world: &mut World) {
     }
 }
 
+/// Render floors and ceilings. These fill the spans left open by walls and
+/// portals, so they are drawn once the whole BSP tree has been walked.
+fn render_planes(
+    visplanes: Res<Visplanes>,
+    camera_query: Query<&Camera, With<Player>>,
+    sky: Res<Sky>,
+    wad: Res<WadFile>,
+    mut screen: ResMut<ScreenBuffer>,
+) {
+    let camera = camera_query.single();
+    for plane in visplanes.iter() {
+        if plane.is_sky {
+            let sky_tex = wad.wall_textures().get_by_index(sky.texture).unwrap();
+            draw_sky(&mut screen, camera, plane, sky_tex, &wad.colormaps()[0]);
+            continue;
+        }
+        let flat = wad.flats().get_by_index(plane.flat).unwrap();
+        let colormaps = wad.colormaps();
+        plane
+            .spans(|y, x1, x2| draw_span(&mut screen, camera, plane, flat, colormaps, (y, x1, x2)));
+    }
+}
+
+/// Project the things of the visible sectors.
+fn project_sprites(
+    mut vis_sprites: ResMut<VisSprites>,
+    bsp_tree: BspTree,
+    camera_query: Query<&Camera, With<Player>>,
+    thing_query: Query<(&MapObject, &ThingSprite), Without<Player>>,
+    sector_finder: SectorFinder,
+    wad: Res<WadFile>,
+    info: Res<Info>,
+) {
+    let camera = camera_query.single();
+    for (thing, thing_sprite) in &thing_query {
+        if thing.flags.contains(MobjFlags::NOSECTOR) {
+            // Not linked into the sectors, like teleport destinations.
+            continue;
+        }
+        let Some(sub_sector) = bsp_tree.find_sub_sector(thing.pos) else {
+            continue;
+        };
+        let (sector, sector_inst) = sector_finder.find(sub_sector);
+        if !vis_sprites.is_sector_visible(sector_inst.entity()) {
+            continue;
+        }
+        let sprite = VisSprite::project(camera, thing, thing_sprite, sector, wad.sprites(), &info);
+        if let Some(sprite) = sprite {
+            vis_sprites.push(sprite);
+        }
+    }
+    vis_sprites.sort();
+}
+
+/// Render sprites and masked middle textures, from back to front.
+fn render_masked(
+    vis_sprites: Res<VisSprites>,
+    mut draw_segs: ResMut<DrawSegs>,
+    wad: Res<WadFile>,
+    mut screen: ResMut<ScreenBuffer>,
+) {
+    for sprite in vis_sprites.iter() {
+        draw_sprite(&mut screen, sprite, &mut draw_segs, &wad);
+    }
+    // Render the masked middle textures that were not behind any sprite.
+    for ds in draw_segs.iter_mut().rev() {
+        let (x1, x2) = (ds.x1, ds.x2);
+        ds.render_masked_range(&mut screen, &wad, x1, x2);
+    }
+}
+
+/// Draw the weapon of the player over the view, lit by the sector
+/// the player stands in and the muzzle flashes.
+///
+/// Mirrors `R_DrawPlayerSprites`.
+fn draw_player_sprites(
+    player_query: Query<(&MapObject, &Player, &Camera)>,
+    bsp_tree: BspTree,
+    sector_finder: SectorFinder,
+    wad: Res<WadFile>,
+    info: Res<Info>,
+    mut screen: ResMut<ScreenBuffer>,
+) {
+    let (map_obj, player, camera) = player_query.single();
+    let Some(sub_sector) = bsp_tree.find_sub_sector(map_obj.pos) else {
+        return;
+    };
+    let (sector, _) = sector_finder.find(sub_sector);
+    let light_num = light_num(sector.light_level) + camera.extra_light();
+    for psp in &player.psprites {
+        if let Some(sprite) = VisSprite::project_player_sprite(psp, light_num, wad.sprites(), &info)
+        {
+            draw_player_sprite(&mut screen, &sprite, &wad);
+        }
+    }
+}
+
 fn render_sub_sector(
     mut render_sub_sector: EventReader<RenderSubSector>,
     mut render_segment: EventWriter<RenderSegment>,
-    sub_sector_query: Query<&SubSector>,
+    mut visplanes: ResMut<Visplanes>,
+    mut vis_sprites: ResMut<VisSprites>,
+    camera_query: Query<&Camera, With<Player>>,
+    sector_finder: SectorFinder,
+    sky: Res<Sky>,
 ) {
-    let Some(RenderSubSector(sub_sector)) = render_sub_sector.read().next() else {
+    let Some(RenderSubSector(sub_sector_inst)) = render_sub_sector.read().next() else {
         return;
     };
-    let sub_sector = sub_sector_query.get(sub_sector.entity()).unwrap();
-    for segment in &sub_sector.segments {
+    let view_z = camera_query.single().view_z();
+
+    let (sector, sector_inst) = sector_finder.find(*sub_sector_inst);
+    vis_sprites.add_sector(sector_inst.entity());
+
+    let floor = (sector.floor_height < view_z).then(|| {
+        visplanes.find(
+            sector.floor_height,
+            sector.floor_tex,
+            sector.light_level,
+            false,
+        )
+    });
+    visplanes.set_floor(floor);
+    let is_sky = sector.ceiling_tex == sky.flat;
+    let ceiling = (sector.ceiling_height > view_z || is_sky).then(|| {
+        visplanes.find(
+            sector.ceiling_height,
+            sector.ceiling_tex,
+            sector.light_level,
+            is_sky,
+        )
+    });
+    visplanes.set_ceiling(ceiling);
+
+    for segment in sector_finder.segments(*sub_sector_inst) {
         render_segment.send(RenderSegment(*segment));
     }
 }
@@ -121,6 +279,8 @@ fn render_fragments(
     mut render_fragments: EventReader<RenderFragment>,
     info_extractor: DrawInfoExtractor,
     mut vertical_clip: ResMut<VerticalClip>,
+    mut visplanes: ResMut<Visplanes>,
+    mut draw_segs: ResMut<DrawSegs>,
     mut screen: ResMut<ScreenBuffer>,
 ) {
     for ev in render_fragments.read() {
@@ -128,7 +288,31 @@ fn render_fragments(
         let segment = ev.segment;
 
         let mut info = info_extractor.extract(segment, fragment);
+        if info.mark_ceiling {
+            visplanes.check_ceiling(info.x1, info.x2);
+        }
+        if info.mark_floor {
+            visplanes.check_floor(info.x1, info.x2);
+        }
+
+        let segment = info_extractor.segment_query.get(*segment).unwrap();
+        let mut draw_seg = DrawSeg {
+            x1: info.x1,
+            x2: info.x2,
+            scale1: info.scale1,
+            scale2: info.scale2,
+            scale_step: info.scale_step,
+            v1: segment.v1(),
+            normal: segment.normal(),
+            bottom_sil_height: info.bottom_sil_height,
+            top_sil_height: info.top_sil_height,
+            bottom_clip: None,
+            top_clip: None,
+            masked: None,
+        };
+        let mut masked_columns = Vec::new();
 
+        let colormaps = info_extractor.wad.colormaps();
         let dx = (info.x2 - info.x1) as f32;
         let length = (info.v2 - info.v1).length();
 
@@ -143,29 +327,50 @@ fn render_fragments(
                 yh = vertical_clip.floor[i] - 1;
             }
 
+            // Reserve the spans between the clipped ends and the wall
+            // for the floor and ceiling.
+            if info.mark_ceiling {
+                let top = vertical_clip.ceiling[i] + 1;
+                let bottom = cmp::min(yl - 1, vertical_clip.floor[i] - 1);
+                visplanes.mark_ceiling(i, top, bottom);
+            }
+            if info.mark_floor {
+                let top = cmp::max(yh + 1, vertical_clip.ceiling[i] + 1);
+                let bottom = vertical_clip.floor[i] - 1;
+                visplanes.mark_floor(i, top, bottom);
+            }
+
             let s = (i - info.x1) as f32 / dx;
             let t = s * info.v1.y / (s * info.v1.y + (1.0 - s) * info.v2.y);
             let offset = info.base_offset + (length * t);
 
             let inv_scale = info.scale1.recip();
+            let colormap = &colormaps[scale_light(info.light_num, info.scale1)];
 
             info.scale1 += info.scale_step;
             info.top += info.top_step;
             info.bottom += info.bottom_step;
 
+            if info.masked_tex.is_some() {
+                // Save the texture column, to draw it after the sprites behind.
+                masked_columns.push(Some(offset));
+            }
+
             if let Some(mid_tex) = &info.mid_tex {
                 let tex = mid_tex.tex;
                 let tex_col = (offset as usize) % tex.width();
                 draw_col(
                     &mut screen,
                     i,
-                    tex_col,
-                    yl,
-                    yh,
-                    100,
-                    tex,
-                    mid_tex.tex_mid,
-                    inv_scale,
+                    &WallColumn {
+                        texture: tex,
+                        tex_col,
+                        yl,
+                        yh,
+                        texture_mid: mid_tex.tex_mid,
+                        inv_scale,
+                        colormap,
+                    },
                 );
                 continue;
             }
@@ -183,13 +388,15 @@ fn render_fragments(
                     draw_col(
                         &mut screen,
                         i,
-                        tex_col,
-                        yl,
-                        yh,
-                        100,
-                        tex,
-                        top_tex.tex_mid,
-                        inv_scale,
+                        &WallColumn {
+                            texture: tex,
+                            tex_col,
+                            yl,
+                            yh,
+                            texture_mid: top_tex.tex_mid,
+                            inv_scale,
+                            colormap,
+                        },
                     );
                     vertical_clip.ceiling[i] = yh;
                 } else {
@@ -212,13 +419,15 @@ fn render_fragments(
                     draw_col(
                         &mut screen,
                         i,
-                        tex_col,
-                        yl,
-                        yh,
-                        100,
-                        tex,
-                        bottom_tex.tex_mid,
-                        inv_scale,
+                        &WallColumn {
+                            texture: tex,
+                            tex_col,
+                            yl,
+                            yh,
+                            texture_mid: bottom_tex.tex_mid,
+                            inv_scale,
+                            colormap,
+                        },
                     );
                     vertical_clip.floor[i] = yl;
                 } else {
@@ -228,6 +437,56 @@ fn render_fragments(
                 vertical_clip.floor[i] = yh + 1;
             }
         }
+
+        // Save the clipping of the fragment, so sprites
+        // behind it can be clipped later.
+        let columns = info.x1..=info.x2;
+        if info.bottom_closed {
+            draw_seg.bottom_clip = Some(vec![-1; columns.clone().count()]);
+        }
+        if info.top_closed {
+            draw_seg.top_clip = Some(vec![200; columns.clone().count()]);
+        }
+        if let Some(masked_tex) = info.masked_tex {
+            draw_seg.masked = Some(MaskedTexture {
+                texture: masked_tex.texture,
+                tex_mid: masked_tex.tex_mid,
+                light_num: info.light_num,
+                columns: masked_columns,
+            });
+            // Masked textures need both clips.
+            draw_seg.bottom_sil_height.get_or_insert(f32::INFINITY);
+            draw_seg.top_sil_height.get_or_insert(f32::NEG_INFINITY);
+        }
+        if draw_seg.bottom_sil_height.is_some() && draw_seg.bottom_clip.is_none() {
+            draw_seg.bottom_clip = Some(vertical_clip.floor[columns.clone()].to_vec());
+        }
+        if draw_seg.top_sil_height.is_some() && draw_seg.top_clip.is_none() {
+            draw_seg.top_clip = Some(vertical_clip.ceiling[columns].to_vec());
+        }
+        draw_segs.push(draw_seg);
+    }
+}
+
+#[derive(SystemParam)]
+struct SectorFinder<'w, 's> {
+    sub_sector_query: Query<'w, 's, &'static SubSector>,
+    segment_query: Query<'w, 's, &'static LineSegment>,
+    sector_query: Query<'w, 's, &'static Sector>,
+}
+
+impl SectorFinder<'_, '_> {
+    fn segments(&self, sub_sector: Instance<SubSector>) -> &[Instance<LineSegment>] {
+        &self.sub_sector_query.get(*sub_sector).unwrap().segments
+    }
+
+    /// Find the sector a sub sector belongs to.
+    fn find(&self, sub_sector: Instance<SubSector>) -> (&Sector, Instance<Sector>) {
+        let sub_sector = self.sub_sector_query.get(*sub_sector).unwrap();
+        // All the segments of a sub sector belong to the same sector.
+        let segment = self.segment_query.get(*sub_sector.segments[0]).unwrap();
+        let sector = segment.front_sector();
+        (self.sector_query.get(*sector).unwrap(), sector)
     }
 }
 
@@ -239,9 +498,14 @@ struct DrawInfoExtractor<'w, 's> {
     segment_query: Query<'w, 's, &'static LineSegment>,
     side_query: Query<'w, 's, &'static SideDef>,
     wad: Res<'w, WadFile>,
+    sky: Res<'w, Sky>,
 }
 
 impl DrawInfoExtractor<'_, '_> {
+    fn view_z(&self) -> f32 {
+        self.camera_query.single().view_z()
+    }
+
     fn extract(&self, segment: Instance<LineSegment>, fragment: SegmentFragment) -> DrawInfo {
         let segment = self.segment_query.get(*segment).unwrap();
         let side = self.side_query.get(*segment.side()).unwrap();
@@ -262,6 +526,9 @@ impl DrawInfoExtractor<'_, '_> {
         self.set_ends(&mut info, segment, fragment, camera);
         self.set_scales(&mut info, camera);
         self.set_world_bounds(&mut info, front_sec, back_sec);
+        self.set_silhouette(&mut info, front_sec, back_sec);
+        info.light_num = wall_light_num(front_sec.light_level, segment.v1(), segment.v2())
+            + camera.extra_light();
         self.set_projection(&mut info);
         self.set_base_offset(&mut info, segment, side, camera);
         self.set_tex(&mut info, line, side, front_sec, back_sec);
@@ -295,8 +562,9 @@ impl DrawInfoExtractor<'_, '_> {
     }
 
     fn set_world_bounds(&self, info: &mut DrawInfo, front_sec: &Sector, back_sec: Option<&Sector>) {
-        info.world_top = front_sec.ceiling_height - 41.0;
-        info.world_bottom = front_sec.floor_height - 41.0;
+        let view_z = self.view_z();
+        info.world_top = front_sec.ceiling_height - view_z;
+        info.world_bottom = front_sec.floor_height - view_z;
 
         let Some(back_sec) = back_sec else {
             info.mark_ceiling = true;
@@ -304,9 +572,9 @@ impl DrawInfoExtractor<'_, '_> {
             return;
         };
 
-        info.world_high = back_sec.ceiling_height - 41.0;
-        info.world_low = back_sec.floor_height - 41.0;
-        let sky = self.wad.flats().get_index_of("F_SKY1").unwrap();
+        info.world_high = back_sec.ceiling_height - view_z;
+        info.world_low = back_sec.floor_height - view_z;
+        let sky = self.sky.flat;
         if front_sec.ceiling_tex == sky && back_sec.ceiling_tex == sky {
             // Hack to allow height changes in outdoor areas.
             info.world_top = info.world_high;
@@ -340,16 +608,49 @@ impl DrawInfoExtractor<'_, '_> {
         // if a floor / ceiling plane is on the wrong side
         //  of the view plane, it is definitely invisible
         //  and doesn't need to be marked.
-        if front_sec.floor_height >= 41.0 {
+        if front_sec.floor_height >= view_z {
             // above view plane
             info.mark_floor = false;
         }
-        if front_sec.ceiling_height <= 41.0 && front_sec.ceiling_tex != sky {
+        if front_sec.ceiling_height <= view_z && front_sec.ceiling_tex != sky {
             // below view plane
             info.mark_ceiling = false;
         }
     }
 
+    /// Find how the fragment clips the sprites behind it.
+    fn set_silhouette(&self, info: &mut DrawInfo, front_sec: &Sector, back_sec: Option<&Sector>) {
+        let view_z = self.view_z();
+        let Some(back_sec) = back_sec else {
+            // Solid walls hide everything behind them.
+            info.bottom_sil_height = Some(f32::INFINITY);
+            info.top_sil_height = Some(f32::NEG_INFINITY);
+            info.bottom_closed = true;
+            info.top_closed = true;
+            return;
+        };
+
+        if front_sec.floor_height > back_sec.floor_height {
+            info.bottom_sil_height = Some(front_sec.floor_height);
+        } else if back_sec.floor_height > view_z {
+            info.bottom_sil_height = Some(f32::INFINITY);
+        }
+        if front_sec.ceiling_height < back_sec.ceiling_height {
+            info.top_sil_height = Some(front_sec.ceiling_height);
+        } else if back_sec.ceiling_height < view_z {
+            info.top_sil_height = Some(f32::NEG_INFINITY);
+        }
+
+        if back_sec.ceiling_height <= front_sec.floor_height {
+            info.bottom_sil_height = Some(f32::INFINITY);
+            info.bottom_closed = true;
+        }
+        if back_sec.floor_height >= front_sec.ceiling_height {
+            info.top_sil_height = Some(f32::NEG_INFINITY);
+            info.top_closed = true;
+        }
+    }
+
     fn set_projection(&self, info: &mut DrawInfo) {
         info.top = 100.0 - (info.world_top * info.scale1);
         info.top_step = -(info.scale_step * info.world_top);
@@ -386,7 +687,7 @@ impl DrawInfoExtractor<'_, '_> {
         back_sec: Option<&Sector>,
     ) {
         if let Some(back_sec) = back_sec {
-            self.set_portal_tex(info, line, side, back_sec);
+            self.set_portal_tex(info, line, side, front_sec, back_sec);
         } else {
             self.set_wall_tex(info, line, side, front_sec);
         }
@@ -399,13 +700,14 @@ impl DrawInfoExtractor<'_, '_> {
         side: &SideDef,
         front_sec: &Sector,
     ) {
+        let view_z = self.view_z();
         let textures = self.wad.wall_textures();
         let mid_tex = textures.get_by_index(side.middle_texture).unwrap();
         let mut data = TextureInfoData::new(mid_tex);
         if line.flags() & 16 != 0 {
             let tex_height = data.tex.height() as f32;
             let vtop = front_sec.floor_height + tex_height;
-            data.tex_mid = vtop - 41.0;
+            data.tex_mid = vtop - view_z;
         } else {
             data.tex_mid = info.world_top;
         };
@@ -419,8 +721,10 @@ impl DrawInfoExtractor<'_, '_> {
         info: &mut DrawInfo<'a>,
         line: &Line,
         side: &SideDef,
+        front_sec: &Sector,
         back_sec: &Sector,
     ) {
+        let view_z = self.view_z();
         let textures = self.wad.wall_textures();
         let mut toptexture = None;
         let mut bottomtexture = None;
@@ -434,7 +738,7 @@ impl DrawInfoExtractor<'_, '_> {
             } else {
                 // bottom of texture
                 let vtop = back_sec.ceiling_height + data.tex.height() as f32;
-                data.tex_mid = vtop - 41.0;
+                data.tex_mid = vtop - view_z;
             }
             data.tex_mid += side.y_offset;
             toptexture = Some(data);
@@ -455,6 +759,23 @@ impl DrawInfoExtractor<'_, '_> {
             bottomtexture = Some(data);
         }
 
+        if side.middle_texture != 0 {
+            // Masked middle texture, e.g. grates and fences.
+            let tex_mid = if line.flags() & 16 != 0 {
+                // bottom of texture at bottom
+                let tex = textures.get_by_index(side.middle_texture).unwrap();
+                let floor = front_sec.floor_height.max(back_sec.floor_height);
+                floor + tex.height() as f32 - view_z
+            } else {
+                // top of texture at top
+                front_sec.ceiling_height.min(back_sec.ceiling_height) - view_z
+            };
+            info.masked_tex = Some(MaskedTextureInfo {
+                texture: side.middle_texture,
+                tex_mid: tex_mid + side.y_offset,
+            });
+        }
+
         info.top_tex = toptexture;
         info.bottom_tex = bottomtexture;
     }
@@ -494,15 +815,27 @@ struct DrawInfo<'a> {
     base_offset: f32,
     //====================================== TEXTURE MAPPING =======================================
 
+    //====================================== LIGHTING ==============================================
+    light_num: i32,
+    //====================================== LIGHTING ==============================================
+
     //====================================== FLATS CALCULATION =====================================
     mark_ceiling: bool,
     mark_floor: bool,
     //====================================== FLATS CALCULATION =====================================
 
+    //====================================== SPRITES CLIPPING ======================================
+    bottom_sil_height: Option<f32>,
+    top_sil_height: Option<f32>,
+    bottom_closed: bool,
+    top_closed: bool,
+    //====================================== SPRITES CLIPPING ======================================
+
     //====================================== TEXTURE DATA ==========================================
     mid_tex: Option<TextureInfoData<'a>>,
     top_tex: Option<TextureInfoData<'a>>,
     bottom_tex: Option<TextureInfoData<'a>>,
+    masked_tex: Option<MaskedTextureInfo>,
     //====================================== TEXTURE DATA ==========================================
 }
 
@@ -518,28 +851,41 @@ impl<'a> TextureInfoData<'a> {
     }
 }
 
-fn draw_col(
-    screen: &mut ScreenBuffer,
-    i: usize,
+#[derive(Debug)]
+struct MaskedTextureInfo {
+    texture: usize,
+    tex_mid: f32,
+}
+
+/// Row of the center of the view, where the texture mid is drawn.
+const CENTER_Y: i32 = 100;
+
+/// A column of a wall texture, to draw from row `yl` to row `yh`.
+struct WallColumn<'a> {
+    texture: &'a WallTexture,
     tex_col: usize,
     yl: i32,
     yh: i32,
-    center_y: usize,
-    texture: &WallTexture,
+    /// Texture row drawn at [`CENTER_Y`].
     texture_mid: f32,
+    /// Texture rows per screen row.
     inv_scale: f32,
-) {
-    for y in yl..=yh {
+    colormap: &'a Colormap,
+}
+
+fn draw_col(screen: &mut ScreenBuffer, x: usize, column: &WallColumn) {
+    let texture = column.texture;
+    for y in column.yl..=column.yh {
         // Linear interpolate texture coordinate.
-        let dy = y - (center_y as i32);
-        let texture_frac_y = texture_mid + ((dy as f32) * inv_scale);
+        let dy = y - CENTER_Y;
+        let texture_frac_y = column.texture_mid + ((dy as f32) * column.inv_scale);
 
         // Index texture and retrieve color.
         let texture_y = (texture_frac_y.trunc() as usize) % texture.height();
-        let color = texture[(tex_col, texture_y)];
+        let color = column.colormap[texture[(column.tex_col, texture_y)] as usize];
 
         // Draw!
-        screen[(i, y as usize)] = color;
+        screen[(x, y as usize)] = color;
     }
 }
 
@@ -0,0 +1,66 @@
+//! Light diminishing: the farther something is, the darker
+//! the colormap it is drawn with.
+
+use bevy::prelude::*;
+use std::cmp;
+use wad::prelude::*;
+
+/// Number of light levels, sector light levels go from 0 to 255.
+const LIGHT_LEVELS: i32 = 16;
+/// Shift to convert a sector light level to one of the light levels.
+const LIGHT_SEG_SHIFT: i32 = 4;
+/// Number of steps of the scale, for walls and sprites.
+const MAX_LIGHT_SCALE: i32 = 48;
+/// Number of steps of the distance, for floors and ceilings.
+const MAX_LIGHT_Z: i32 = 128;
+/// Number of colormaps used for lighting.
+const NUM_COLORMAPS: i32 = Colormaps::LIGHT_COLORMAPS as i32;
+
+/// Convert the light level of a sector to one of the light levels.
+pub fn light_num(light_level: i16) -> i32 {
+    i32::from(light_level) >> LIGHT_SEG_SHIFT
+}
+
+/// Same as [`light_num`], with the fake contrast of walls aligned with the
+/// axes: horizontal walls are darker and vertical walls are brighter.
+pub fn wall_light_num(light_level: i16, v1: Vec2, v2: Vec2) -> i32 {
+    let light_num = light_num(light_level);
+    if v1.y == v2.y {
+        light_num - 1
+    } else if v1.x == v2.x {
+        light_num + 1
+    } else {
+        light_num
+    }
+}
+
+/// Colormap of a wall, masked texture or sprite drawn at `scale`.
+///
+/// Mirrors `scalelight`.
+pub fn scale_light(light_num: i32, scale: f32) -> usize {
+    let index = cmp::min((scale * 16.0) as i32, MAX_LIGHT_SCALE - 1);
+    colormap_index(light_num, index / 2)
+}
+
+/// Colormap of the weapon sprites, as bright as the closest sprites.
+///
+/// Mirrors `spritelights[MAXLIGHTSCALE - 1]`.
+pub fn player_sprite_light(light_num: i32) -> usize {
+    colormap_index(light_num, (MAX_LIGHT_SCALE - 1) / 2)
+}
+
+/// Colormap of a floor or ceiling span at `distance` from the camera.
+///
+/// Mirrors `zlight`.
+pub fn z_light(light_num: i32, distance: f32) -> usize {
+    let index = cmp::min((distance / 16.0) as i32, MAX_LIGHT_Z - 1);
+    colormap_index(light_num, 80 / (index + 1))
+}
+
+/// Brighter sectors start with a brighter colormap, then the
+/// colormap gets darker by `fade` steps.
+fn colormap_index(light_num: i32, fade: i32) -> usize {
+    let light_num = light_num.clamp(0, LIGHT_LEVELS - 1);
+    let start_map = ((LIGHT_LEVELS - 1 - light_num) * 2) * NUM_COLORMAPS / LIGHT_LEVELS;
+    (start_map - fade).clamp(0, NUM_COLORMAPS - 1) as usize
+}
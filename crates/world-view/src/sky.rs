@@ -0,0 +1,46 @@
+use crate::visplane::Visplane;
+use crate::{draw_col, WallColumn};
+use bevy::prelude::*;
+use level::prelude::Camera;
+use std::f32::consts::TAU;
+use wad::prelude::*;
+use window::ScreenBuffer;
+
+/// The sky texture wraps around 4 times in a full turn,
+/// so 256 columns cover the 90° field of view.
+const SKY_COLUMNS_PER_TURN: f32 = 1024.0;
+
+/// Draw the sky on the columns of a sky plane. The sky is not
+/// affected by the height of the player, only by the view angle.
+///
+/// Mirrors the sky case of `R_DrawPlanes`.
+pub fn draw_sky(
+    screen: &mut ScreenBuffer,
+    camera: &Camera,
+    plane: &Visplane,
+    sky: &WallTexture,
+    colormap: &Colormap,
+) {
+    let origin = camera.camera_to_world(Vec2::ZERO);
+    for (x, top, bottom) in plane.columns() {
+        // Angle of the ray going through the column.
+        let ray = camera.camera_to_world(Vec2::new(x as f32 - 160.0, 160.0)) - origin;
+        let angle = ray.y.atan2(ray.x).rem_euclid(TAU);
+        let tex_col = (angle / TAU * SKY_COLUMNS_PER_TURN) as usize % sky.width();
+        // The middle of the sky texture is on the center of the screen,
+        // at full scale and full brightness.
+        draw_col(
+            screen,
+            x,
+            &WallColumn {
+                texture: sky,
+                tex_col,
+                yl: top,
+                yh: bottom,
+                texture_mid: 100.0,
+                inv_scale: 1.0,
+                colormap,
+            },
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use crate::draw_seg::DrawSegs;
+use crate::light::{light_num, player_sprite_light, scale_light};
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use info::Info;
+use level::prelude::Camera;
+use level::prelude::*;
+use std::cmp;
+use std::f32::consts::{FRAC_PI_4, TAU};
+use wad::prelude::*;
+use window::ScreenBuffer;
+
+/// Things closer than this to the camera are not drawn.
+const MIN_Z: f32 = 4.0;
+
+/// A map object projected on screen, waiting to be drawn
+/// once all the walls are done.
+#[derive(Debug)]
+pub struct VisSprite {
+    pub x1: usize,
+    pub x2: usize,
+    /// Position of the thing in world space.
+    pub pos: Vec2,
+    /// Bottom of the sprite in world space.
+    pub gz: f32,
+    /// Top of the sprite in world space.
+    pub gzt: f32,
+    pub scale: f32,
+    pub texture_mid: f32,
+    /// Texture column of `x1`.
+    pub start_frac: f32,
+    /// Texture columns to step for each screen column. Negative when
+    /// the patch is flipped.
+    pub x_iscale: f32,
+    pub rotation: SpriteRotation,
+    pub colormap: usize,
+}
+
+impl VisSprite {
+    /// Mirrors `R_ProjectSprite`.
+    pub fn project(
+        camera: &Camera,
+        thing: &MapObject,
+        thing_sprite: &ThingSprite,
+        sector: &Sector,
+        sprites: &Sprites,
+        info: &Info,
+    ) -> Option<Self> {
+        let Vec2 { x: tx, y: tz } = camera.world_to_camera(thing.pos);
+        if tz < MIN_Z {
+            // Behind the camera, or too close to it.
+            return None;
+        }
+        if tx.abs() > tz * 4.0 {
+            // Too far off the side.
+            return None;
+        }
+        let scale = 160.0 / tz;
+
+        let frame = sprites.get(info.sprite_name(thing_sprite.sprite))?.frame(thing_sprite.frame)?;
+        let rotation = if frame.rotate {
+            // Pick the rotation facing the camera, each covers 45°.
+            let view_angle = (thing.pos - camera.position()).to_angle();
+            let angle = view_angle - thing.dir.to_angle() + (9.0 * FRAC_PI_4 / 2.0);
+            let rot = (angle.rem_euclid(TAU) / FRAC_PI_4) as usize;
+            frame.rotation(rot)
+        } else {
+            frame.rotation(0)
+        };
+        let patch = sprites.patch(rotation);
+
+        // Calculate edges of the shape.
+        let tx = tx - patch.left_offset as f32;
+        let x1 = (160.0 + tx * scale).floor() as isize;
+        if x1 >= 320 {
+            // Off the right side.
+            return None;
+        }
+        let tx = tx + patch.width as f32;
+        let x2 = (160.0 + tx * scale).floor() as isize - 1;
+        if x2 < 0 {
+            // Off the left side.
+            return None;
+        }
+
+        let gz = thing.z;
+        let gzt = gz + patch.top_offset as f32;
+        let vis_x1 = cmp::max(x1, 0);
+        let vis_x2 = cmp::min(x2, 319);
+
+        let iscale = scale.recip();
+        let (mut start_frac, x_iscale) = if rotation.flipped {
+            (patch.width as f32, -iscale)
+        } else {
+            (0.0, iscale)
+        };
+        start_frac += x_iscale * (vis_x1 - x1) as f32;
+
+        let colormap = if thing_sprite.full_bright {
+            0
+        } else {
+            scale_light(light_num(sector.light_level) + camera.extra_light(), scale)
+        };
+
+        Some(Self {
+            x1: vis_x1 as usize,
+            x2: vis_x2 as usize,
+            pos: thing.pos,
+            gz,
+            gzt,
+            scale,
+            texture_mid: gzt - camera.view_z(),
+            start_frac,
+            x_iscale,
+            rotation,
+            colormap,
+        })
+    }
+
+    /// Project the weapon of the player, or its flash, lit by `light_num`.
+    /// It's drawn at scale 1, over the whole view.
+    ///
+    /// Mirrors `R_DrawPSprite`.
+    pub fn project_player_sprite(
+        psp: &PlayerSprite,
+        light_num: i32,
+        sprites: &Sprites,
+        info: &Info,
+    ) -> Option<Self> {
+        let state = info.state(psp.state?);
+        let frame = sprites
+            .get(info.sprite_name(state.sprite))?
+            .frame(state.frame_index())?;
+        let rotation = frame.rotation(0);
+        let patch = sprites.patch(rotation);
+
+        // Calculate edges of the shape.
+        let tx = psp.sx - 160.0 - patch.left_offset as f32;
+        let x1 = (160.0 + tx).floor() as isize;
+        if x1 >= 320 {
+            // Off the right side.
+            return None;
+        }
+        let x2 = (160.0 + tx + patch.width as f32).floor() as isize - 1;
+        if x2 < 0 {
+            // Off the left side.
+            return None;
+        }
+
+        let vis_x1 = cmp::max(x1, 0);
+        let vis_x2 = cmp::min(x2, 319);
+        let (mut start_frac, x_iscale) = if rotation.flipped {
+            (patch.width as f32 - 1.0, -1.0)
+        } else {
+            (0.0, 1.0)
+        };
+        start_frac += x_iscale * (vis_x1 - x1) as f32;
+
+        let colormap = if state.is_full_bright() {
+            0
+        } else {
+            player_sprite_light(light_num)
+        };
+
+        Some(Self {
+            x1: vis_x1 as usize,
+            x2: vis_x2 as usize,
+            pos: Vec2::ZERO,
+            gz: 0.0,
+            gzt: 0.0,
+            scale: 1.0,
+            texture_mid: 100.0 + 0.5 - (psp.sy - patch.top_offset as f32),
+            start_frac,
+            x_iscale,
+            rotation,
+            colormap,
+        })
+    }
+
+    /// Draw the sprite between the ceiling clip `clip_top` and
+    /// the floor clip `clip_bottom`.
+    ///
+    /// Mirrors `R_DrawVisSprite`.
+    pub fn draw(
+        &self,
+        screen: &mut ScreenBuffer,
+        patch: &Patch,
+        colormap: &Colormap,
+        clip_top: &[i32],
+        clip_bottom: &[i32],
+    ) {
+        let sprite_top = 100.0 - (self.texture_mid * self.scale);
+        let iscale = self.scale.recip();
+
+        let mut frac = self.start_frac;
+        for x in self.x1..=self.x2 {
+            let tex_col = cmp::min(frac.max(0.0) as usize, patch.width.saturating_sub(1));
+            frac += self.x_iscale;
+
+            for post in &patch.column(tex_col).posts {
+                let top = sprite_top + (self.scale * post.top_delta as f32);
+                let bottom = top + (self.scale * post.data.len() as f32);
+
+                let yl = cmp::max(top.ceil() as i32, clip_top[x] + 1);
+                let yh = cmp::min(bottom.ceil() as i32 - 1, clip_bottom[x] - 1);
+                for y in yl..=yh {
+                    let dy = (y - 100) as f32;
+                    let row = self.texture_mid + (dy * iscale) - post.top_delta as f32;
+                    let row = cmp::min(row.max(0.0) as usize, post.data.len() - 1);
+                    screen[(x, y as usize)] = colormap[post.data[row] as usize];
+                }
+            }
+        }
+    }
+}
+
+/// Map objects to draw in the current frame.
+#[derive(Resource, Default)]
+pub struct VisSprites {
+    sprites: Vec<VisSprite>,
+    /// Sectors of the sub sectors rendered so far. The things
+    /// of a sector are projected only if the sector is visible.
+    sectors: HashSet<Entity>,
+}
+
+impl VisSprites {
+    pub fn reset(&mut self) {
+        self.sprites.clear();
+        self.sectors.clear();
+    }
+
+    pub fn add_sector(&mut self, sector: Entity) {
+        self.sectors.insert(sector);
+    }
+
+    pub fn is_sector_visible(&self, sector: Entity) -> bool {
+        self.sectors.contains(&sector)
+    }
+
+    pub fn push(&mut self, sprite: VisSprite) {
+        self.sprites.push(sprite);
+    }
+
+    /// Sort sprites back to front, so the closest ones are drawn last.
+    pub fn sort(&mut self) {
+        self.sprites.sort_by(|a, b| a.scale.total_cmp(&b.scale));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &VisSprite> {
+        self.sprites.iter()
+    }
+}
+
+/// Draw the weapon of the player, only clipped by the screen.
+pub fn draw_player_sprite(screen: &mut ScreenBuffer, sprite: &VisSprite, wad: &WadFile) {
+    let patch = wad.sprites().patch(sprite.rotation);
+    let colormap = &wad.colormaps()[sprite.colormap];
+    sprite.draw(screen, patch, colormap, &[-1; 320], &[200; 320]);
+}
+
+/// Clip the sprite by the walls in front of it, and draw the masked
+/// middle textures behind it first.
+///
+/// Mirrors `R_DrawSprite`.
+pub fn draw_sprite(
+    screen: &mut ScreenBuffer,
+    sprite: &VisSprite,
+    draw_segs: &mut DrawSegs,
+    wad: &WadFile,
+) {
+    // -2 means not clipped yet.
+    let mut clip_top = [-2; 320];
+    let mut clip_bottom = [-2; 320];
+
+    // Scan draw segments from the closest to the farthest.
+    for ds in draw_segs.iter_mut().rev() {
+        if ds.x1 > sprite.x2 || ds.x2 < sprite.x1 {
+            // Does not cover the sprite.
+            continue;
+        }
+        if ds.bottom_sil_height.is_none() && ds.top_sil_height.is_none() && ds.masked.is_none() {
+            // Does not clip anything.
+            continue;
+        }
+        let r1 = cmp::max(ds.x1, sprite.x1);
+        let r2 = cmp::min(ds.x2, sprite.x2);
+
+        let low_scale = ds.scale1.min(ds.scale2);
+        let scale = ds.scale1.max(ds.scale2);
+        if scale < sprite.scale || (low_scale < sprite.scale && ds.is_in_front(sprite.pos)) {
+            // The segment is behind the sprite.
+            ds.render_masked_range(screen, wad, r1, r2);
+            continue;
+        }
+
+        let clips_bottom = ds
+            .bottom_sil_height
+            .is_some_and(|height| sprite.gz < height);
+        let clips_top = ds.top_sil_height.is_some_and(|height| sprite.gzt > height);
+        for x in r1..=r2 {
+            if clips_bottom && clip_bottom[x] == -2 {
+                clip_bottom[x] = ds.bottom_clip(x).unwrap_or(-2);
+            }
+            if clips_top && clip_top[x] == -2 {
+                clip_top[x] = ds.top_clip(x).unwrap_or(-2);
+            }
+        }
+    }
+
+    // Whatever is not clipped by a segment is clipped by the screen.
+    for x in sprite.x1..=sprite.x2 {
+        if clip_bottom[x] == -2 {
+            clip_bottom[x] = 200;
+        }
+        if clip_top[x] == -2 {
+            clip_top[x] = -1;
+        }
+    }
+
+    let patch = wad.sprites().patch(sprite.rotation);
+    let colormap = &wad.colormaps()[sprite.colormap];
+    sprite.draw(screen, patch, colormap, &clip_top, &clip_bottom);
+}
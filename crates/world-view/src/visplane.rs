@@ -0,0 +1,241 @@
+use crate::light::{light_num, z_light};
+use bevy::prelude::*;
+use level::prelude::Camera;
+use wad::prelude::*;
+use window::ScreenBuffer;
+
+/// Marks a column of a plane that has nothing to draw.
+const UNSET: i32 = i32::MAX;
+
+/// A horizontal surface (floor or ceiling) visible on screen. All the
+/// columns of a visplane share the same height, flat and light level,
+/// so it can be drawn in one go after all the walls have been rendered.
+#[derive(Debug)]
+pub struct Visplane {
+    pub height: f32,
+    pub flat: usize,
+    pub light_level: i16,
+    pub is_sky: bool,
+    min_x: usize,
+    max_x: usize,
+    top: Vec<i32>,
+    bottom: Vec<i32>,
+}
+
+impl Visplane {
+    fn new(height: f32, flat: usize, light_level: i16, is_sky: bool) -> Self {
+        Self {
+            height,
+            flat,
+            light_level,
+            is_sky,
+            // Empty range, it grows as segments are checked.
+            min_x: 320,
+            max_x: 0,
+            top: vec![UNSET; 320],
+            bottom: vec![-1; 320],
+        }
+    }
+
+    fn column(&self, x: Option<usize>) -> (i32, i32) {
+        match x {
+            Some(x) if x < 320 => (self.top[x], self.bottom[x]),
+            _ => (UNSET, -1),
+        }
+    }
+
+    /// Iterate over the columns with something to draw, as `(x, top, bottom)`.
+    pub fn columns(&self) -> impl Iterator<Item = (usize, i32, i32)> + '_ {
+        (self.min_x..=self.max_x)
+            .map(|x| (x, self.top[x], self.bottom[x]))
+            .filter(|(_, top, bottom)| top <= bottom)
+    }
+
+    /// Convert the columns of the plane into horizontal spans, which
+    /// are a lot easier to texture. Each span is sent to `map_span`
+    /// as `(y, x1, x2)`.
+    ///
+    /// Mirrors `R_MakeSpans`.
+    pub fn spans(&self, mut map_span: impl FnMut(usize, usize, usize)) {
+        if self.min_x > self.max_x {
+            return;
+        }
+        let mut span_start = [0; 200];
+
+        for x in self.min_x..=self.max_x + 1 {
+            let (mut t1, mut b1) = self.column(x.checked_sub(1));
+            let (mut t2, mut b2) = self.column(Some(x));
+
+            // Close the spans that end on the previous column.
+            while t1 < t2 && t1 <= b1 {
+                map_span(t1 as usize, span_start[t1 as usize], x - 1);
+                t1 += 1;
+            }
+            while b1 > b2 && b1 >= t1 {
+                map_span(b1 as usize, span_start[b1 as usize], x - 1);
+                b1 -= 1;
+            }
+            // Open the spans that start on this column.
+            while t2 < t1 && t2 <= b2 {
+                span_start[t2 as usize] = x;
+                t2 += 1;
+            }
+            while b2 > b1 && b2 >= t2 {
+                span_start[b2 as usize] = x;
+                b2 -= 1;
+            }
+        }
+    }
+}
+
+/// Floors and ceilings collected while walking the BSP tree.
+#[derive(Resource, Default)]
+pub struct Visplanes {
+    planes: Vec<Visplane>,
+    /// Floor of the sub sector being rendered, if visible.
+    floor: Option<usize>,
+    /// Ceiling of the sub sector being rendered, if visible.
+    ceiling: Option<usize>,
+}
+
+impl Visplanes {
+    pub fn reset(&mut self) {
+        self.planes.clear();
+        self.floor = None;
+        self.ceiling = None;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Visplane> {
+        self.planes.iter()
+    }
+
+    pub fn set_floor(&mut self, floor: Option<usize>) {
+        self.floor = floor;
+    }
+
+    pub fn set_ceiling(&mut self, ceiling: Option<usize>) {
+        self.ceiling = ceiling;
+    }
+
+    /// Find a plane matching the given properties, or create a new one.
+    ///
+    /// Mirrors `R_FindPlane`.
+    pub fn find(&mut self, height: f32, flat: usize, light_level: i16, is_sky: bool) -> usize {
+        // All skies are the same plane, regardless of height and light.
+        let (height, light_level) = if is_sky {
+            (0.0, 0)
+        } else {
+            (height, light_level)
+        };
+
+        let found = self.planes.iter().position(|plane| {
+            plane.height == height && plane.flat == flat && plane.light_level == light_level
+        });
+        if let Some(index) = found {
+            return index;
+        }
+        self.planes
+            .push(Visplane::new(height, flat, light_level, is_sky));
+        self.planes.len() - 1
+    }
+
+    /// Make sure the floor can hold the columns `start..=stop`.
+    pub fn check_floor(&mut self, start: usize, stop: usize) {
+        if let Some(floor) = self.floor {
+            self.floor = Some(self.check(floor, start, stop));
+        }
+    }
+
+    /// Make sure the ceiling can hold the columns `start..=stop`.
+    pub fn check_ceiling(&mut self, start: usize, stop: usize) {
+        if let Some(ceiling) = self.ceiling {
+            self.ceiling = Some(self.check(ceiling, start, stop));
+        }
+    }
+
+    pub fn mark_floor(&mut self, x: usize, top: i32, bottom: i32) {
+        if let Some(floor) = self.floor {
+            self.mark(floor, x, top, bottom);
+        }
+    }
+
+    pub fn mark_ceiling(&mut self, x: usize, top: i32, bottom: i32) {
+        if let Some(ceiling) = self.ceiling {
+            self.mark(ceiling, x, top, bottom);
+        }
+    }
+
+    /// A plane can only hold a single span per column, so when the
+    /// columns `start..=stop` overlap the ones already marked, a new
+    /// plane with the same properties is created.
+    ///
+    /// Mirrors `R_CheckPlane`.
+    fn check(&mut self, index: usize, start: usize, stop: usize) -> usize {
+        let plane = &mut self.planes[index];
+
+        let intersect_low = plane.min_x.max(start);
+        let intersect_high = plane.max_x.min(stop);
+        let is_free = (intersect_low..=intersect_high).all(|x| plane.top[x] == UNSET);
+        if is_free {
+            plane.min_x = plane.min_x.min(start);
+            plane.max_x = plane.max_x.max(stop);
+            return index;
+        }
+
+        let mut new_plane =
+            Visplane::new(plane.height, plane.flat, plane.light_level, plane.is_sky);
+        new_plane.min_x = start;
+        new_plane.max_x = stop;
+        self.planes.push(new_plane);
+        self.planes.len() - 1
+    }
+
+    fn mark(&mut self, index: usize, x: usize, top: i32, bottom: i32) {
+        if top > bottom {
+            return;
+        }
+        let plane = &mut self.planes[index];
+        plane.top[x] = top;
+        plane.bottom[x] = bottom;
+    }
+}
+
+/// Draw a span of a plane on row `y`, from column `x1` to `x2`.
+///
+/// Mirrors `R_MapPlane`.
+pub fn draw_span(
+    screen: &mut ScreenBuffer,
+    camera: &Camera,
+    plane: &Visplane,
+    flat: &Flat,
+    colormaps: &Colormaps,
+    (y, x1, x2): (usize, usize, usize),
+) {
+    let height = (plane.height - camera.view_z()).abs();
+    // Offset by half a pixel, so the row at the horizon never
+    // ends up at an infinite distance.
+    let dy = (y as f32 - 100.0 + 0.5).abs();
+    let depth = height * 160.0 / dy;
+    let light_num = light_num(plane.light_level) + camera.extra_light();
+    let colormap = &colormaps[z_light(light_num, depth)];
+
+    let to_world = |x: usize| {
+        let x = (x as f32 - 160.0) * depth / 160.0;
+        camera.camera_to_world(Vec2::new(x, depth))
+    };
+    let start = to_world(x1);
+    let step = if x1 < x2 {
+        (to_world(x2) - start) / (x2 - x1) as f32
+    } else {
+        Vec2::ZERO
+    };
+
+    for (i, x) in (x1..=x2).enumerate() {
+        let pos = start + (i as f32 * step);
+        // Going through isize keeps negative coordinates tiling
+        // properly, as the flat size is a power of two.
+        let tex_x = pos.x.floor() as isize as usize;
+        let tex_y = (-pos.y).floor() as isize as usize;
+        screen[(x, y)] = colormap[flat.pixel(tex_x, tex_y) as usize];
+    }
+}
@@ -0,0 +1,130 @@
+//! Comparison of rendered frames with the golden frames stored
+//! next to the tests, as paletted PNG images.
+
+use std::env;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use window::palette::ScreenColors;
+use window::ScreenBuffer;
+
+/// Set to write the rendered frames as the new golden frames.
+const UPDATE_VAR: &str = "UPDATE_GOLDEN";
+
+/// A frame as palette indices, row by row.
+struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl From<&ScreenBuffer> for Frame {
+    fn from(screen: &ScreenBuffer) -> Self {
+        let (width, height) = (screen.width(), screen.height());
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            pixels.extend((0..width).map(|x| screen[(x, y)]));
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+/// Compare `screen` with the golden frame `name`. Returns a description
+/// of the differences when they don't match, pointing to images of the
+/// rendered frame and of the differing pixels.
+pub fn check_golden(
+    name: &str,
+    screen: &ScreenBuffer,
+    colors: &ScreenColors,
+) -> Result<(), String> {
+    let frame = Frame::from(screen);
+    let golden_path = golden_dir().join(format!("{name}.png"));
+    if env::var_os(UPDATE_VAR).is_some() {
+        fs::create_dir_all(golden_dir()).map_err(|err| err.to_string())?;
+        write_png(&golden_path, &frame, colors);
+        return Ok(());
+    }
+
+    let Some(golden) = read_png(&golden_path) else {
+        return Err(format!(
+            "{name}: missing golden frame {golden_path:?}, run the tests with {UPDATE_VAR}=1"
+        ));
+    };
+    if (golden.width, golden.height) != (frame.width, frame.height) {
+        return Err(format!(
+            "{name}: frame is {}x{}, golden frame is {}x{}",
+            frame.width, frame.height, golden.width, golden.height
+        ));
+    }
+
+    let mut count = 0;
+    let mut min = (usize::MAX, usize::MAX);
+    let mut max = (0, 0);
+    let mut diff = Frame {
+        width: frame.width,
+        height: frame.height,
+        pixels: vec![0; frame.pixels.len()],
+    };
+    for (i, (pixel, golden_pixel)) in frame.pixels.iter().zip(&golden.pixels).enumerate() {
+        if pixel == golden_pixel {
+            continue;
+        }
+        let (x, y) = (i % frame.width, i / frame.width);
+        count += 1;
+        min = (min.0.min(x), min.1.min(y));
+        max = (max.0.max(x), max.1.max(y));
+        diff.pixels[i] = 0xff;
+    }
+    if count == 0 {
+        return Ok(());
+    }
+
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden");
+    fs::create_dir_all(&out_dir).map_err(|err| err.to_string())?;
+    let actual_path = out_dir.join(format!("{name}.actual.png"));
+    let diff_path = out_dir.join(format!("{name}.diff.png"));
+    write_png(&actual_path, &frame, colors);
+    write_png(&diff_path, &diff, colors);
+    Err(format!(
+        "{name}: {count} pixels differ, from ({}, {}) to ({}, {}), see {actual_path:?} and {diff_path:?}",
+        min.0, min.1, max.0, max.1
+    ))
+}
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden/frames")
+}
+
+fn write_png(path: &Path, frame: &Frame, colors: &ScreenColors) {
+    let file = File::create(path).unwrap();
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(file),
+        frame.width as u32,
+        frame.height as u32,
+    );
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(colors.iter().flatten().copied().collect::<Vec<_>>());
+    let mut writer = encoder.write_header().unwrap();
+    writer.write_image_data(&frame.pixels).unwrap();
+}
+
+fn read_png(path: &Path) -> Option<Frame> {
+    let file = File::open(path).ok()?;
+    let mut decoder = png::Decoder::new(file);
+    // Keep the palette indices.
+    decoder.set_transformations(png::Transformations::IDENTITY);
+    let mut reader = decoder.read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    pixels.truncate(info.buffer_size());
+    Some(Frame {
+        width: info.width as usize,
+        height: info.height as usize,
+        pixels,
+    })
+}
@@ -0,0 +1,124 @@
+//! Golden-image tests of the world view. The synthetic level is rendered
+//! from a few points of view, and each frame is compared with the one
+//! stored in `tests/golden/frames`. After an intended rendering change,
+//! run the tests with `UPDATE_GOLDEN=1` and review the new frames.
+
+use bevy::prelude::*;
+use game_state::{GameState, GameStatePlugin};
+use golden::check_golden;
+use level::prelude::*;
+use level::{LevelPlugin, LoadLevel};
+use wad::prelude::*;
+use window::palette::ScreenColors;
+use window::{ScreenBuffer, WindowPlugin};
+use world_view::WorldViewPlugin;
+
+mod golden;
+mod synthetic_wad;
+
+/// Name, position and angle in degrees of each view.
+const VIEWS: &[(&str, Vec2, f32)] = &[
+    ("start", Vec2::new(64.0, 128.0), 0.0),
+    ("corner", Vec2::new(32.0, 32.0), 45.0),
+    ("facing_wall", Vec2::new(128.0, 200.0), 90.0),
+    ("back_to_start", Vec2::new(448.0, 128.0), 180.0),
+    ("barrel_side", Vec2::new(300.0, 40.0), 60.0),
+];
+
+/// Tics for the pistol to come up from the bottom of the screen.
+const RAISE_TICS: u32 = 20;
+
+/// Renders the synthetic level with the plugins of the game,
+/// without a window.
+struct WorldViewHarness {
+    app: App,
+}
+
+impl WorldViewHarness {
+    fn new() -> Self {
+        let wad_file = WadFileParser::parse(&synthetic_wad::build(), &[]).unwrap();
+
+        let mut app = App::new();
+        app.insert_resource(Time::<Fixed>::from_hz(35.0))
+            .insert_resource(wad_file)
+            .add_plugins((
+                MinimalPlugins,
+                bevy::input::InputPlugin,
+                bevy::state::app::StatesPlugin,
+                exit::ExitPlugin,
+                GameStatePlugin,
+                info::InfoPlugin,
+                rand::RandPlugin,
+                WindowPlugin { headless: true },
+                LevelPlugin,
+                WorldViewPlugin,
+            ));
+        app.finish();
+        app.cleanup();
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.world_mut().send_event(LoadLevel {
+            map: MapId::Episode { episode: 1, map: 1 },
+            new_game: true,
+        });
+        // Enter the game, load the level, then enter it.
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.should_exit().is_none(), "Failed to load the level");
+
+        // Let the pistol come up, checking that each update is a tic.
+        let level_time = **app.world().resource::<LevelTime>();
+        for _ in 0..RAISE_TICS {
+            app.update();
+        }
+        assert_eq!(
+            **app.world().resource::<LevelTime>(),
+            level_time + RAISE_TICS,
+            "The game didn't run one tic per update"
+        );
+        Self { app }
+    }
+
+    /// Render the view of the player standing at `pos`, facing `angle`.
+    fn render(&mut self, pos: Vec2, angle: f32) -> ScreenBuffer {
+        let world = self.app.world_mut();
+        let mut query = world.query_filtered::<&mut MapObject, With<Player>>();
+        let mut player = query.single_mut(world);
+        player.pos = pos;
+        player.dir = Rot2::degrees(angle) * Dir2::X;
+        player.velocity = Vec2::ZERO;
+
+        // The player is moved to the floor during the first tic,
+        // and the view follows during the second one.
+        self.app.update();
+        self.app.update();
+        self.app.world().resource::<ScreenBuffer>().clone()
+    }
+
+    fn colors(&self) -> ScreenColors {
+        let wad_file = self.app.world().resource::<WadFile>();
+        ScreenColors::new(wad_file, PaletteVariant::Palette0, 0)
+    }
+}
+
+#[test]
+fn golden_frames() {
+    let mut harness = WorldViewHarness::new();
+    let colors = harness.colors();
+
+    let failures: Vec<_> = VIEWS
+        .iter()
+        .filter_map(|(name, pos, angle)| {
+            let screen = harness.render(*pos, *angle);
+            check_golden(name, &screen, &colors).err()
+        })
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "Frames differ from the golden ones:\n{}",
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,397 @@
+//! A tiny IWAD made from scratch, so the tests don't need the copyrighted
+//! ones. Colors use a 3-3-2 RGB palette, which makes the colormaps easy
+//! to compute.
+//!
+//! E1M1 is made of two square rooms side by side. The player starts in
+//! the west room, pistol in hand. The east room has a higher floor, a
+//! sky ceiling and a barrel in its middle.
+
+use common::Array2d;
+use std::f32::consts::TAU;
+use wad::prelude::*;
+
+/// Size of the side of a room.
+const ROOM_SIZE: i16 = 256;
+
+/// Colors of the textures, as palette indices.
+const BRICK: u8 = rgb(5, 1, 0);
+const MORTAR: u8 = rgb(4, 4, 2);
+const STRIPE_DARK: u8 = rgb(0, 2, 3);
+const STRIPE_LIGHT: u8 = rgb(1, 3, 3);
+const TILE_DARK: u8 = rgb(2, 2, 1);
+const TILE_LIGHT: u8 = rgb(3, 3, 1);
+const CEILING: u8 = rgb(6, 6, 2);
+const CEILING_GRID: u8 = rgb(4, 4, 1);
+const BARREL: u8 = rgb(0, 6, 0);
+const BARREL_RING: u8 = rgb(0, 3, 0);
+const WHITE: u8 = rgb(7, 7, 3);
+const GUN: u8 = rgb(2, 2, 1);
+const GUN_LIGHT: u8 = rgb(5, 5, 2);
+const FLASH: u8 = rgb(7, 6, 0);
+
+/// Build the WAD.
+pub fn build() -> Vec<u8> {
+    let mut wad = WadBuilder::iwad();
+    wad.palettes(&[palette(); 14])
+        .colormaps(&colormaps())
+        .patch("TITLEPIC", &image(1, 1, |_, _| Some(0)), 0, 0)
+        .map(MapId::Episode { episode: 1, map: 1 }, &map())
+        .textures(&textures())
+        .patch("WALL1", &image(64, 128, |x, y| Some(brick(x, y))), 0, 0)
+        .patch("WALL2", &image(64, 64, |x, _| Some(stripe(x))), 0, 0)
+        .patch("SKY1", &image(256, 128, |x, y| Some(sky(x, y))), 0, 0)
+        .marker("S_START")
+        .patch("BAR1A0", &image(16, 24, barrel), 8, 23)
+        .patch("BAR1B0", &image(16, 24, barrel), 8, 23)
+        // The weapons are drawn from their offsets, centered at the
+        // bottom of the screen like the ones of vanilla.
+        .patch("PISGA0", &image(56, 62, pistol), -132, -106)
+        .patch("PISGB0", &image(56, 62, pistol), -132, -106)
+        .patch("PISGC0", &image(56, 62, pistol), -132, -106)
+        .patch("PISFA0", &image(20, 20, |_, _| Some(FLASH)), -150, -90)
+        .marker("S_END")
+        .marker("F_START")
+        .flat("FLOOR0", &flat(tile))
+        .flat("CEIL0", &flat(ceiling))
+        .flat("F_SKY1", &flat(|_, _| 0))
+        .marker("F_END");
+    wad.build()
+}
+
+/// Index of a color of the 3-3-2 palette.
+const fn rgb(r: u8, g: u8, b: u8) -> u8 {
+    (r << 5) | (g << 2) | b
+}
+
+fn brick(x: usize, y: usize) -> u8 {
+    let row = y / 16;
+    let x = x + if row.is_multiple_of(2) { 0 } else { 16 };
+    if y.is_multiple_of(16) || x.is_multiple_of(32) {
+        MORTAR
+    } else {
+        BRICK
+    }
+}
+
+fn stripe(x: usize) -> u8 {
+    if (x / 8).is_multiple_of(2) {
+        STRIPE_DARK
+    } else {
+        STRIPE_LIGHT
+    }
+}
+
+fn sky(x: usize, y: usize) -> u8 {
+    // Marks every quarter of the texture, to see it scroll.
+    if x.is_multiple_of(64) {
+        return WHITE;
+    }
+    rgb(0, (y * 8 / 128) as u8, 3)
+}
+
+fn barrel(x: usize, y: usize) -> Option<u8> {
+    // Rounded corners, to check the see-through parts.
+    if (x == 0 || x == 15) && !(2..22).contains(&y) {
+        return None;
+    }
+    if y % 8 == 3 {
+        Some(BARREL_RING)
+    } else {
+        Some(BARREL)
+    }
+}
+
+fn pistol(x: usize, y: usize) -> Option<u8> {
+    // A barrel on top of a grip.
+    match (x, y) {
+        (27, ..40) => Some(GUN_LIGHT),
+        (20..36, ..40) | (16..40, 40..) => Some(GUN),
+        _ => None,
+    }
+}
+
+fn tile(x: usize, y: usize) -> u8 {
+    if (x / 8 + y / 8).is_multiple_of(2) {
+        TILE_DARK
+    } else {
+        TILE_LIGHT
+    }
+}
+
+fn ceiling(x: usize, y: usize) -> u8 {
+    if x.is_multiple_of(16) || y.is_multiple_of(16) {
+        CEILING_GRID
+    } else {
+        CEILING
+    }
+}
+
+/// The palettes used for pain and pick-up flashes are all this one.
+fn palette() -> [[u8; 3]; 256] {
+    std::array::from_fn(|index| {
+        let r = index >> 5;
+        let g = (index >> 2) & 7;
+        let b = index & 3;
+        [r * 255 / 7, g * 255 / 7, b * 255 / 3].map(|channel| channel as u8)
+    })
+}
+
+fn colormaps() -> Vec<[u8; 256]> {
+    let mut colormaps: Vec<[u8; 256]> = (0..32)
+        .map(|light| {
+            // Scale every channel down, from full light to black.
+            let scale = |channel: usize| (channel * (32 - light) / 32) as u8;
+            std::array::from_fn(|index| {
+                rgb(scale(index >> 5), scale((index >> 2) & 7), scale(index & 3))
+            })
+        })
+        .collect();
+    // Invulnerability colormap.
+    colormaps.push(std::array::from_fn(|index| 255 - index as u8));
+    // Unused, all black.
+    colormaps.push([0; 256]);
+    colormaps
+}
+
+/// Build the pixels of a patch, `pixel` giving the color of each
+/// pixel, or `None` for the see-through ones.
+fn image(
+    width: usize,
+    height: usize,
+    pixel: impl Fn(usize, usize) -> Option<u8>,
+) -> Array2d<Option<u8>> {
+    let mut pixels = Array2d::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            pixels[(x, y)] = pixel(x, y);
+        }
+    }
+    pixels
+}
+
+fn flat(pixel: impl Fn(usize, usize) -> u8) -> [u8; Flat::WIDTH * Flat::HEIGHT] {
+    std::array::from_fn(|i| pixel(i % Flat::WIDTH, i / Flat::WIDTH))
+}
+
+fn textures() -> Vec<TextureDef<'static>> {
+    // Name, width, height and patch. The first texture
+    // is never drawn, like in vanilla.
+    const TEXTURES: [(&str, i16, i16, &[TexturePatch]); 4] = [
+        ("AASTINKY", 64, 128, &[single_patch("WALL1")]),
+        ("WALL1", 64, 128, &[single_patch("WALL1")]),
+        ("WALL2", 64, 64, &[single_patch("WALL2")]),
+        ("SKY1", 256, 128, &[single_patch("SKY1")]),
+    ];
+    TEXTURES
+        .iter()
+        .map(|&(name, width, height, patches)| TextureDef {
+            name,
+            width,
+            height,
+            patches,
+        })
+        .collect()
+}
+
+const fn single_patch(patch: &str) -> TexturePatch<'_> {
+    TexturePatch {
+        origin_x: 0,
+        origin_y: 0,
+        patch,
+    }
+}
+
+struct Line {
+    v1: usize,
+    v2: usize,
+    front: i16,
+    back: i16,
+}
+
+fn map() -> MapLumps {
+    let size = ROOM_SIZE;
+    let vertexes = [
+        (0, 0),
+        (size, 0),
+        (2 * size, 0),
+        (2 * size, size),
+        (size, size),
+        (0, size),
+    ];
+    // The front side of a line is on its right.
+    let lines = [
+        Line {
+            v1: 0,
+            v2: 5,
+            front: 0,
+            back: -1,
+        },
+        Line {
+            v1: 5,
+            v2: 4,
+            front: 1,
+            back: -1,
+        },
+        // Between the two rooms.
+        Line {
+            v1: 4,
+            v2: 1,
+            front: 2,
+            back: 3,
+        },
+        Line {
+            v1: 1,
+            v2: 0,
+            front: 4,
+            back: -1,
+        },
+        Line {
+            v1: 4,
+            v2: 3,
+            front: 5,
+            back: -1,
+        },
+        Line {
+            v1: 3,
+            v2: 2,
+            front: 6,
+            back: -1,
+        },
+        Line {
+            v1: 2,
+            v2: 1,
+            front: 7,
+            back: -1,
+        },
+    ];
+    // Upper, lower and middle textures, and sector.
+    let sides = [
+        ("-", "-", "WALL1", 0),
+        ("-", "-", "WALL1", 0),
+        ("-", "WALL2", "-", 0),
+        ("WALL2", "-", "-", 1),
+        ("-", "-", "WALL1", 0),
+        ("-", "-", "WALL1", 1),
+        ("-", "-", "WALL1", 1),
+        ("-", "-", "WALL1", 1),
+    ];
+    // Line and side of each segment, grouped by sub sector.
+    let segments = [
+        (0, 0),
+        (1, 0),
+        (2, 0),
+        (3, 0),
+        (2, 1),
+        (4, 0),
+        (5, 0),
+        (6, 0),
+    ];
+
+    let things = [(64, 128, 1), (384, 128, 2035)]
+        .map(|(x, y, thing_type)| MapThing {
+            x,
+            y,
+            angle: 0,
+            thing_type,
+            options: 7, // Every skill.
+        })
+        .into();
+
+    let map_lines = lines
+        .iter()
+        .map(|line| MapLine {
+            v1: line.v1 as i16,
+            v2: line.v2 as i16,
+            // Two-sided lines don't block.
+            flags: if line.back == -1 { 1 } else { 4 },
+            special: 0,
+            tag: 0,
+            front_side: line.front,
+            back_side: line.back,
+        })
+        .collect();
+
+    let side_defs = sides
+        .map(|(upper, lower, middle, sector)| MapSideDef {
+            x_offset: 0,
+            y_offset: 0,
+            top_texture: upper.to_owned(),
+            lower_texture: lower.to_owned(),
+            middle_texture: middle.to_owned(),
+            sector,
+        })
+        .into();
+
+    let segments = segments
+        .map(|(line_num, side)| {
+            let line = &lines[line_num];
+            let (v1, v2) = if side == 0 {
+                (line.v1, line.v2)
+            } else {
+                (line.v2, line.v1)
+            };
+            let (x1, y1) = vertexes[v1];
+            let (x2, y2) = vertexes[v2];
+            let angle = f32::from(y2 - y1).atan2(f32::from(x2 - x1)).rem_euclid(TAU);
+            MapSegment {
+                v1: v1 as i16,
+                v2: v2 as i16,
+                angle: (angle / TAU * 65536.0) as u16 as i16,
+                line: line_num as i16,
+                side,
+                offset: 0,
+            }
+        })
+        .into();
+
+    let sub_sectors = [0, 4]
+        .map(|first_seg| MapSubSector {
+            num_segs: 4,
+            first_seg,
+        })
+        .into();
+
+    // A single node, splitting the rooms. The east one is on its right.
+    let node = MapNode {
+        x: size,
+        y: 0,
+        dx: 0,
+        dy: size,
+        right_box_top: size,
+        right_box_bottom: 0,
+        right_box_left: size,
+        right_box_right: 2 * size,
+        left_box_top: size,
+        left_box_bottom: 0,
+        left_box_left: 0,
+        left_box_right: size,
+        right_child: (0x8000u16 | 1) as i16,
+        left_child: 0x8000u16 as i16,
+    };
+
+    let sectors = [(0, 128, "CEIL0", 192), (24, 160, "F_SKY1", 144)]
+        .map(
+            |(floor_height, ceiling_height, ceiling_tex, light_level)| MapSector {
+                floor_height,
+                ceiling_height,
+                floor_tex: "FLOOR0".to_owned(),
+                ceiling_tex: ceiling_tex.to_owned(),
+                light_level,
+                special: 0,
+                tag: 0,
+            },
+        )
+        .into();
+
+    MapLumps {
+        things,
+        lines: map_lines,
+        side_defs,
+        vertexes: vertexes.map(|(x, y)| MapVertex { x, y }).into(),
+        segments,
+        sub_sectors,
+        nodes: vec![node],
+        sectors,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,14 @@
+//! Feeds arbitrary bytes to the WAD parser, which must return an error
+//! instead of panicking on malformed files.
+//!
+//! Run with `cargo +nightly fuzz run wad_file`. Seeding the corpus with
+//! a few real WADs gets past the header checks much faster.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wad::prelude::*;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = WadFileParser::parse(data, &[]);
+});
@@ -8,8 +8,11 @@ plugin_group! {
         bevy::time:::TimePlugin,
         bevy::state::app:::StatesPlugin,
         cli:::CliPlugin,
+        dehacked:::DehackedPlugin,
         exit:::ExitPlugin,
         game_state:::GameStatePlugin,
+        info:::InfoPlugin,
+        intermission:::IntermissionPlugin,
         level:::LevelPlugin,
         rand:::RandPlugin,
         screen_melt:::ScreenMeltPlugin,